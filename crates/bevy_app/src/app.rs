@@ -48,6 +48,18 @@ pub type InternedAppLabel = Interned<dyn AppLabel>;
 pub(crate) enum AppError {
     #[error("duplicate plugin {plugin_name:?}")]
     DuplicatePlugin { plugin_name: String },
+    #[error("plugin {plugin_name:?} requires plugins which were not added before it: {}", missing.join(", "))]
+    MissingPluginDependencies {
+        plugin_name: String,
+        missing: Vec<String>,
+    },
+    #[error("plugin {plugin_name:?} must be added before the plugins depending on it: {}", dependents.join(", "))]
+    PluginAddedAfterDependents {
+        plugin_name: String,
+        dependents: Vec<String>,
+    },
+    #[error("circular dependencies between plugins: {}", plugins.join(", "))]
+    CircularPluginDependencies { plugins: Vec<String> },
 }
 
 /// [`App`] is the primary API for writing user applications. It automates the setup of a
@@ -467,6 +479,46 @@ impl App {
             })?;
         }
 
+        let dependencies = plugin.dependencies();
+        let type_id = plugin.as_ref().as_any().type_id();
+        let main = self.main();
+        let missing: Vec<String> = dependencies
+            .required()
+            .filter(|dependency| {
+                !main.building_plugins.contains(&dependency.type_id())
+                    && !main
+                        .plugin_registry
+                        .iter()
+                        .any(|added| dependency.matches(added.as_ref()))
+            })
+            .map(|dependency| dependency.type_name().to_string())
+            .collect();
+        if !missing.is_empty() {
+            Err(AppError::MissingPluginDependencies {
+                plugin_name: plugin.name().to_string(),
+                missing,
+            })?;
+        }
+        if let Some(dependents) = main.plugin_dependents.get(&type_id) {
+            Err(AppError::PluginAddedAfterDependents {
+                plugin_name: plugin.name().to_string(),
+                dependents: dependents.clone(),
+            })?;
+        }
+        for dependency in dependencies.optional() {
+            let main = self.main_mut();
+            if !main
+                .plugin_registry
+                .iter()
+                .any(|added| dependency.matches(added.as_ref()))
+            {
+                main.plugin_dependents
+                    .entry(dependency.type_id())
+                    .or_default()
+                    .push(plugin.name().to_string());
+            }
+        }
+
         // Reserve position in the plugin registry. If the plugin adds more plugins,
         // they'll all end up in insertion order.
         let index = self.main().plugin_registry.len();
@@ -475,6 +527,7 @@ impl App {
             .push(Box::new(PlaceholderPlugin));
 
         self.main_mut().plugin_build_depth += 1;
+        self.main_mut().building_plugins.push(type_id);
 
         let f = AssertUnwindSafe(|| plugin.build(self));
 
@@ -488,6 +541,7 @@ impl App {
             .plugin_names
             .insert(plugin.name().to_string());
         self.main_mut().plugin_build_depth -= 1;
+        self.main_mut().building_plugins.pop();
 
         #[cfg(feature = "std")]
         if let Err(payload) = result {
@@ -498,6 +552,36 @@ impl App {
         Ok(self)
     }
 
+    /// Adds plugins added together, ordering them by their dependencies if
+    /// [auto-ordering](Self::set_plugin_auto_ordering) is enabled.
+    #[track_caller]
+    pub(crate) fn add_boxed_plugins(
+        &mut self,
+        plugins: Vec<Box<dyn Plugin>>,
+        group_name: Option<&str>,
+    ) {
+        let plugins = if self.main().auto_order_plugins {
+            order_plugins_by_dependencies(plugins).unwrap_or_else(|error| panic!("{error}"))
+        } else {
+            plugins
+        };
+        for plugin in plugins {
+            match (self.add_boxed_plugin(plugin), group_name) {
+                (Err(AppError::DuplicatePlugin { plugin_name }), Some(group_name)) => panic!(
+                    "Error adding plugin {plugin_name} in group {group_name}: plugin was already added in application"
+                ),
+                (Err(AppError::DuplicatePlugin { plugin_name }), None) => panic!(
+                    "Error adding plugin {plugin_name}: : plugin was already added in application"
+                ),
+                (Err(error), Some(group_name)) => {
+                    panic!("Error adding plugin in group {group_name}: {error}")
+                }
+                (Err(error), None) => panic!("Error adding plugin: {error}"),
+                (Ok(_), _) => {}
+            }
+        }
+    }
+
     /// Returns `true` if the [`Plugin`] has already been added.
     pub fn is_plugin_added<T>(&self) -> bool
     where
@@ -567,7 +651,8 @@ impl App {
     ///
     /// # Panics
     ///
-    /// Panics if one of the plugins had already been added to the application.
+    /// Panics if one of the plugins had already been added to the application,
+    /// or if the [dependencies](Plugin::dependencies) of one of the plugins are not satisfied.
     ///
     /// [`PluginGroup`]:super::PluginGroup
     #[track_caller]
@@ -580,7 +665,31 @@ impl App {
                 "Plugins cannot be added after App::cleanup() or App::finish() has been called."
             );
         }
-        plugins.add_to_app(self);
+        self.add_plugins_unchecked(plugins)
+    }
+
+    #[track_caller]
+    pub(crate) fn add_plugins_unchecked<M>(&mut self, plugins: impl Plugins<M>) -> &mut Self {
+        if self.main().auto_order_plugins {
+            let mut collected = Vec::new();
+            plugins.collect(&mut collected);
+            self.add_boxed_plugins(collected, None);
+        } else {
+            plugins.add_to_app(self);
+        }
+        self
+    }
+
+    /// Enables or disables ordering plugins by their [dependencies](Plugin::dependencies).
+    ///
+    /// When enabled, the plugins added together by a single call to [`add_plugins`](Self::add_plugins),
+    /// including the plugins of [`PluginGroup`](super::PluginGroup)s, are built in an order satisfying
+    /// their dependencies on each other, otherwise keeping the order they were given in.
+    /// Plugins added by separate calls are still built in the order of the calls.
+    ///
+    /// This is disabled by default, so that dependencies are only validated.
+    pub fn set_plugin_auto_ordering(&mut self, enabled: bool) -> &mut Self {
+        self.main_mut().set_plugin_auto_ordering(enabled);
         self
     }
 
@@ -780,6 +889,54 @@ impl App {
         self
     }
 
+    /// Registers the given method for type `T` in the [`AppTypeRegistry`] resource,
+    /// so that it can be called by name on any reflected value of type `T`.
+    ///
+    /// Most of the time the `#[reflect(methods(...))]` derive attribute can be used instead.
+    ///
+    /// See [`TypeRegistry::register_method`] for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` has not been registered, if a method has already been registered
+    /// for `T` with the given name, or if the method does not take `T` as its receiver.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_app::App;
+    /// use bevy_reflect::Reflect;
+    ///
+    /// #[derive(Reflect)]
+    /// struct Health(f32);
+    ///
+    /// impl Health {
+    ///     fn damage(&mut self, amount: f32) {
+    ///         self.0 -= amount;
+    ///     }
+    /// }
+    ///
+    /// App::new()
+    ///     .register_type::<Health>()
+    ///     .register_method::<Health, _, _>("damage", Health::damage);
+    /// ```
+    ///
+    /// [`TypeRegistry::register_method`]: bevy_reflect::TypeRegistry::register_method
+    #[cfg(feature = "reflect_functions")]
+    pub fn register_method<T, F, Marker>(
+        &mut self,
+        name: impl Into<alloc::borrow::Cow<'static, str>>,
+        method: F,
+    ) -> &mut Self
+    where
+        T: bevy_reflect::FromReflect + bevy_reflect::TypePath,
+        F: bevy_reflect::func::IntoFunction<'static, Marker> + 'static,
+    {
+        self.main_mut()
+            .register_method::<T, F, Marker>(name, method);
+        self
+    }
+
     /// Registers the given component `R` as a [required component] for `T`.
     ///
     /// When `T` is added to an entity, `R` and its own required components will also be added
@@ -1411,8 +1568,56 @@ impl Termination for AppExit {
     }
 }
 
+/// Stably orders plugins so that each plugin comes after the plugins it depends on.
+fn order_plugins_by_dependencies(
+    plugins: Vec<Box<dyn Plugin>>,
+) -> Result<Vec<Box<dyn Plugin>>, AppError> {
+    let names: Vec<String> = plugins
+        .iter()
+        .map(|plugin| plugin.name().to_string())
+        .collect();
+    let dependencies: Vec<Vec<usize>> = plugins
+        .iter()
+        .map(|plugin| {
+            plugin
+                .dependencies()
+                .iter()
+                .flat_map(|dependency| {
+                    plugins
+                        .iter()
+                        .enumerate()
+                        .filter(move |(_, other)| dependency.matches(other.as_ref()))
+                        .map(|(index, _)| index)
+                })
+                .collect()
+        })
+        .collect();
+
+    let mut plugins: Vec<Option<Box<dyn Plugin>>> = plugins.into_iter().map(Some).collect();
+    let mut ordered = Vec::with_capacity(plugins.len());
+    while ordered.len() < plugins.len() {
+        let next = (0..plugins.len()).find(|&index| {
+            plugins[index].is_some()
+                && dependencies[index]
+                    .iter()
+                    .all(|&dependency| dependency == index || plugins[dependency].is_none())
+        });
+        let Some(next) = next else {
+            return Err(AppError::CircularPluginDependencies {
+                plugins: (0..plugins.len())
+                    .filter(|&index| plugins[index].is_some())
+                    .map(|index| names[index].clone())
+                    .collect(),
+            });
+        };
+        ordered.extend(plugins[next].take());
+    }
+    Ok(ordered)
+}
+
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
     use core::{iter, marker::PhantomData};
     use std::sync::Mutex;
 
@@ -1429,7 +1634,7 @@ mod tests {
         world::{FromWorld, World},
     };
 
-    use crate::{App, AppExit, Plugin, SubApp, Update};
+    use crate::{App, AppExit, Plugin, PluginDependencies, SubApp, Update};
 
     struct PluginA;
     impl Plugin for PluginA {
@@ -1500,6 +1705,145 @@ mod tests {
         App::new().add_plugins(PluginRun);
     }
 
+    struct PluginRequiresA;
+    impl Plugin for PluginRequiresA {
+        fn build(&self, _app: &mut App) {}
+        fn dependencies(&self) -> PluginDependencies {
+            PluginDependencies::new().requires::<PluginA>()
+        }
+    }
+
+    struct PluginAfterB;
+    impl Plugin for PluginAfterB {
+        fn build(&self, _app: &mut App) {}
+        fn dependencies(&self) -> PluginDependencies {
+            PluginDependencies::new().after::<PluginB>()
+        }
+    }
+
+    #[test]
+    fn can_add_plugin_after_its_dependencies() {
+        App::new().add_plugins((PluginA, PluginRequiresA, PluginB, PluginAfterB));
+    }
+
+    #[test]
+    #[should_panic(expected = "requires plugins which were not added before it")]
+    fn cant_add_plugin_before_required_dependency() {
+        App::new().add_plugins((PluginRequiresA, PluginA));
+    }
+
+    #[test]
+    #[should_panic(expected = "must be added before the plugins depending on it")]
+    fn cant_add_optional_dependency_after_plugin() {
+        App::new().add_plugins((PluginAfterB, PluginB));
+    }
+
+    struct PluginRenamed;
+    impl Plugin for PluginRenamed {
+        fn build(&self, _app: &mut App) {}
+        fn name(&self) -> &str {
+            "renamed"
+        }
+    }
+
+    struct PluginNamedA;
+    impl Plugin for PluginNamedA {
+        fn build(&self, _app: &mut App) {}
+        fn name(&self) -> &str {
+            core::any::type_name::<PluginA>()
+        }
+    }
+
+    #[test]
+    fn dependencies_are_matched_by_type() {
+        struct PluginRequiresRenamed;
+        impl Plugin for PluginRequiresRenamed {
+            fn build(&self, _app: &mut App) {}
+            fn dependencies(&self) -> PluginDependencies {
+                PluginDependencies::new().requires::<PluginRenamed>()
+            }
+        }
+
+        App::new().add_plugins((PluginRenamed, PluginRequiresRenamed));
+    }
+
+    #[test]
+    #[should_panic(expected = "requires plugins which were not added before it")]
+    fn dependencies_are_not_matched_by_name() {
+        App::new().add_plugins((PluginNamedA, PluginRequiresA));
+    }
+
+    #[test]
+    #[should_panic(expected = "must be added before the plugins depending on it")]
+    fn renamed_optional_dependency_cant_be_added_after_plugin() {
+        struct PluginAfterRenamed;
+        impl Plugin for PluginAfterRenamed {
+            fn build(&self, _app: &mut App) {}
+            fn dependencies(&self) -> PluginDependencies {
+                PluginDependencies::new().after::<PluginRenamed>()
+            }
+        }
+
+        App::new().add_plugins((PluginAfterRenamed, PluginRenamed));
+    }
+
+    #[test]
+    fn auto_ordering_orders_plugins_by_dependencies() {
+        #[derive(Resource, Default)]
+        struct BuildOrder(Vec<&'static str>);
+
+        struct First;
+        impl Plugin for First {
+            fn build(&self, app: &mut App) {
+                app.world_mut()
+                    .get_resource_or_init::<BuildOrder>()
+                    .0
+                    .push("First");
+            }
+        }
+        struct Second;
+        impl Plugin for Second {
+            fn build(&self, app: &mut App) {
+                app.world_mut()
+                    .get_resource_or_init::<BuildOrder>()
+                    .0
+                    .push("Second");
+            }
+            fn dependencies(&self) -> PluginDependencies {
+                PluginDependencies::new().requires::<First>()
+            }
+        }
+
+        let mut app = App::new();
+        app.set_plugin_auto_ordering(true)
+            .add_plugins((PluginAfterB, Second, PluginB, First));
+        assert_eq!(app.world().resource::<BuildOrder>().0, ["First", "Second"]);
+        assert!(app.is_plugin_added::<PluginB>());
+    }
+
+    #[test]
+    #[should_panic(expected = "circular dependencies between plugins")]
+    fn auto_ordering_rejects_circular_dependencies() {
+        struct Ping;
+        impl Plugin for Ping {
+            fn build(&self, _app: &mut App) {}
+            fn dependencies(&self) -> PluginDependencies {
+                PluginDependencies::new().after::<Pong>()
+            }
+        }
+        struct Pong;
+        impl Plugin for Pong {
+            fn build(&self, _app: &mut App) {}
+            fn dependencies(&self) -> PluginDependencies {
+                PluginDependencies::new().after::<Ping>()
+            }
+        }
+
+        App::new()
+            .set_plugin_auto_ordering(true)
+            .add_plugins((Ping, Pong));
+    }
+
     #[derive(ScheduleLabel, Hash, Clone, PartialEq, Eq, Debug)]
     struct EnterMainMenu;
 
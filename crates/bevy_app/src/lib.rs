@@ -27,6 +27,8 @@ mod plugin;
 mod plugin_group;
 mod schedule_runner;
 mod sub_app;
+#[cfg(feature = "std")]
+mod sub_app_thread;
 #[cfg(feature = "bevy_tasks")]
 mod task_pool_plugin;
 #[cfg(all(any(unix, windows), feature = "std"))]
@@ -39,6 +41,8 @@ pub use plugin::*;
 pub use plugin_group::*;
 pub use schedule_runner::*;
 pub use sub_app::*;
+#[cfg(feature = "std")]
+pub use sub_app_thread::*;
 #[cfg(feature = "bevy_tasks")]
 pub use task_pool_plugin::*;
 #[cfg(all(any(unix, windows), feature = "std"))]
@@ -1,5 +1,6 @@
 use crate::App;
-use core::any::Any;
+use alloc::vec::Vec;
+use core::any::{Any, TypeId};
 use downcast_rs::{impl_downcast, Downcast};
 
 /// A collection of Bevy app logic and configuration.
@@ -21,6 +22,38 @@ use downcast_rs::{impl_downcast, Downcast};
 /// * it will then call all registered [`Plugin::finish`]
 /// * and call all registered [`Plugin::cleanup`]
 ///
+/// ## Dependencies of a plugin
+///
+/// A plugin can declare the plugins it depends on with [`Plugin::dependencies`].
+/// They are checked when the plugin is added, so that a misordered or missing plugin
+/// is reported with the names of the plugins involved, instead of failing later.
+///
+/// ```
+/// # use bevy_app::*;
+/// # pub struct AssetPlugin;
+/// # impl Plugin for AssetPlugin { fn build(&self, _: &mut App) {} }
+/// # pub struct RenderPlugin;
+/// # impl Plugin for RenderPlugin { fn build(&self, _: &mut App) {} }
+/// pub struct SpritePlugin;
+///
+/// impl Plugin for SpritePlugin {
+///     fn build(&self, app: &mut App) {
+///         // ...
+///     }
+///
+///     fn dependencies(&self) -> PluginDependencies {
+///         PluginDependencies::new()
+///             .requires::<AssetPlugin>()
+///             .after::<RenderPlugin>()
+///     }
+/// }
+///
+/// App::new()
+///     .set_plugin_auto_ordering(true)
+///     // Built in the order `AssetPlugin`, `RenderPlugin`, `SpritePlugin`
+///     .add_plugins((SpritePlugin, RenderPlugin, AssetPlugin));
+/// ```
+///
 /// ## Defining a plugin.
 ///
 /// Most plugins are simply functions that add configuration to an [`App`].
@@ -89,6 +122,16 @@ pub trait Plugin: Downcast + Any + Send + Sync {
     fn is_unique(&self) -> bool {
         true
     }
+
+    /// Declares the plugins this plugin depends on.
+    ///
+    /// When the plugin is added, the [`App`] checks that its [required](PluginDependencies::requires)
+    /// plugins were added before it, and later rejects its [optional](PluginDependencies::after)
+    /// dependencies if they are added after it.
+    /// With [`App::set_plugin_auto_ordering`], plugins added together are ordered by their dependencies.
+    fn dependencies(&self) -> PluginDependencies {
+        PluginDependencies::default()
+    }
 }
 
 impl_downcast!(Plugin);
@@ -99,6 +142,81 @@ impl<T: Fn(&mut App) + Send + Sync + 'static> Plugin for T {
     }
 }
 
+/// The plugins a [`Plugin`] depends on, see [`Plugin::dependencies`].
+///
+/// Dependencies are matched by the type of the plugins, regardless of their [`Plugin::name`].
+#[derive(Debug, Default, Clone)]
+pub struct PluginDependencies {
+    required: Vec<PluginDependency>,
+    optional: Vec<PluginDependency>,
+}
+
+/// A plugin a [`Plugin`] depends on, see [`PluginDependencies`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PluginDependency {
+    type_id: TypeId,
+    type_name: &'static str,
+}
+
+impl PluginDependency {
+    /// Creates the dependency on the plugin `T`.
+    pub fn of<T: Plugin>() -> Self {
+        Self {
+            type_id: TypeId::of::<T>(),
+            type_name: core::any::type_name::<T>(),
+        }
+    }
+
+    /// Returns the [`TypeId`] of the plugin.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Returns the type name of the plugin.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Returns `true` if `plugin` is of the type of this dependency.
+    pub fn matches(&self, plugin: &dyn Plugin) -> bool {
+        plugin.as_any().type_id() == self.type_id
+    }
+}
+
+impl PluginDependencies {
+    /// Creates an empty set of dependencies.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requires the plugin `T` to be added, and built, before this plugin.
+    pub fn requires<T: Plugin>(mut self) -> Self {
+        self.required.push(PluginDependency::of::<T>());
+        self
+    }
+
+    /// Requires the plugin `T`, if it's used, to be built before this plugin.
+    pub fn after<T: Plugin>(mut self) -> Self {
+        self.optional.push(PluginDependency::of::<T>());
+        self
+    }
+
+    /// Returns the plugins that must be added before this plugin.
+    pub fn required(&self) -> impl Iterator<Item = PluginDependency> + '_ {
+        self.required.iter().copied()
+    }
+
+    /// Returns the plugins that can't be added after this plugin.
+    pub fn optional(&self) -> impl Iterator<Item = PluginDependency> + '_ {
+        self.optional.iter().copied()
+    }
+
+    /// Returns all the dependencies, required and optional.
+    pub fn iter(&self) -> impl Iterator<Item = PluginDependency> + '_ {
+        self.required().chain(self.optional())
+    }
+}
+
 /// Plugins state in the application
 #[derive(PartialEq, Eq, Debug, Clone, Copy, PartialOrd, Ord)]
 pub enum PluginsState {
@@ -128,13 +246,15 @@ pub trait Plugins<Marker>: sealed::Plugins<Marker> {}
 impl<Marker, T> Plugins<Marker> for T where T: sealed::Plugins<Marker> {}
 
 mod sealed {
-    use alloc::boxed::Box;
+    use alloc::{boxed::Box, vec::Vec};
     use variadics_please::all_tuples;
 
     use crate::{App, AppError, Plugin, PluginGroup};
 
     pub trait Plugins<Marker> {
         fn add_to_app(self, app: &mut App);
+
+        fn collect(self, plugins: &mut Vec<Box<dyn Plugin>>);
     }
 
     pub struct PluginMarker;
@@ -144,14 +264,18 @@ mod sealed {
     impl<P: Plugin> Plugins<PluginMarker> for P {
         #[track_caller]
         fn add_to_app(self, app: &mut App) {
-            if let Err(AppError::DuplicatePlugin { plugin_name }) =
-                app.add_boxed_plugin(Box::new(self))
-            {
-                panic!(
+            match app.add_boxed_plugin(Box::new(self)) {
+                Err(AppError::DuplicatePlugin { plugin_name }) => panic!(
                     "Error adding plugin {plugin_name}: : plugin was already added in application"
-                )
+                ),
+                Err(error) => panic!("Error adding plugin: {error}"),
+                Ok(_) => {}
             }
         }
+
+        fn collect(self, plugins: &mut Vec<Box<dyn Plugin>>) {
+            plugins.push(Box::new(self));
+        }
     }
 
     impl<P: PluginGroup> Plugins<PluginGroupMarker> for P {
@@ -159,6 +283,10 @@ mod sealed {
         fn add_to_app(self, app: &mut App) {
             self.build().finish(app);
         }
+
+        fn collect(self, plugins: &mut Vec<Box<dyn Plugin>>) {
+            plugins.extend(self.build().into_plugins());
+        }
     }
 
     macro_rules! impl_plugins_tuples {
@@ -179,6 +307,17 @@ mod sealed {
                     let ($($plugins,)*) = self;
                     $($plugins.add_to_app(app);)*
                 }
+
+                #[expect(
+                    clippy::allow_attributes,
+                    reason = "This is inside a macro, and as such, may not trigger in all cases."
+                )]
+                #[allow(non_snake_case, reason = "`all_tuples!()` generates non-snake-case variable names.")]
+                #[allow(unused_variables, reason = "`plugins` is unused when implemented for the unit type `()`.")]
+                fn collect(self, plugins: &mut Vec<Box<dyn Plugin>>) {
+                    let ($($plugins,)*) = self;
+                    $($plugins.collect(plugins);)*
+                }
             }
         }
     }
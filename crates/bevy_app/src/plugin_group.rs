@@ -1,4 +1,4 @@
-use crate::{App, AppError, Plugin};
+use crate::{App, Plugin};
 use alloc::{
     boxed::Box,
     string::{String, ToString},
@@ -513,25 +513,26 @@ impl PluginGroupBuilder {
     ///
     /// # Panics
     ///
-    /// Panics if one of the plugin in the group was already added to the application.
+    /// Panics if one of the plugin in the group was already added to the application,
+    /// or if its [dependencies](Plugin::dependencies) are not satisfied.
     #[track_caller]
-    pub fn finish(mut self, app: &mut App) {
-        for ty in &self.order {
-            if let Some(entry) = self.plugins.remove(ty) {
-                if entry.enabled {
-                    debug!("added plugin: {}", entry.plugin.name());
-                    if let Err(AppError::DuplicatePlugin { plugin_name }) =
-                        app.add_boxed_plugin(entry.plugin)
-                    {
-                        panic!(
-                            "Error adding plugin {} in group {}: plugin was already added in application",
-                            plugin_name,
-                            self.group_name
-                        );
-                    }
-                }
-            }
+    pub fn finish(self, app: &mut App) {
+        let group_name = self.group_name.clone();
+        let plugins = self.into_plugins();
+        for plugin in &plugins {
+            debug!("added plugin: {}", plugin.name());
         }
+        app.add_boxed_plugins(plugins, Some(&group_name));
+    }
+
+    /// Returns the enabled plugins of the group, in order.
+    pub(crate) fn into_plugins(mut self) -> Vec<Box<dyn Plugin>> {
+        self.order
+            .iter()
+            .filter_map(|ty| self.plugins.remove(ty))
+            .filter(|entry| entry.enabled)
+            .map(|entry| entry.plugin)
+            .collect()
     }
 }
 
@@ -7,7 +7,11 @@ use bevy_ecs::{
     system::{SystemId, SystemInput},
 };
 use bevy_platform_support::collections::{HashMap, HashSet};
-use core::fmt::Debug;
+use bevy_utils::TypeIdMap;
+use core::{any::TypeId, fmt::Debug};
+
+#[cfg(feature = "std")]
+use crate::{sub_app_thread::SubAppThread, SubAppSyncPolicy};
 
 #[cfg(feature = "trace")]
 use tracing::info_span;
@@ -68,12 +72,24 @@ pub struct SubApp {
     pub(crate) plugin_names: HashSet<String>,
     /// Panics if an update is attempted while plugins are building.
     pub(crate) plugin_build_depth: usize,
+    /// The types of the plugins being built, from the outermost to the innermost.
+    pub(crate) building_plugins: Vec<TypeId>,
+    /// The names of the plugins depending on plugins which must be added before them, by type.
+    pub(crate) plugin_dependents: TypeIdMap<Vec<String>>,
+    /// Whether plugins added together are ordered by their dependencies.
+    pub(crate) auto_order_plugins: bool,
     pub(crate) plugins_state: PluginsState,
     /// The schedule that will be run by [`update`](Self::update).
     pub update_schedule: Option<InternedScheduleLabel>,
     /// A function that gives mutable access to two app worlds. This is primarily
     /// intended for copying data from the main world to secondary worlds.
     extract: Option<ExtractFn>,
+    /// The policy of the thread this sub-app will move to on its next [`extract`](Self::extract).
+    #[cfg(feature = "std")]
+    thread_policy: Option<SubAppSyncPolicy>,
+    /// The thread this sub-app was moved to, if any.
+    #[cfg(feature = "std")]
+    thread: Option<SubAppThread>,
 }
 
 impl Debug for SubApp {
@@ -91,9 +107,16 @@ impl Default for SubApp {
             plugin_registry: Vec::default(),
             plugin_names: HashSet::default(),
             plugin_build_depth: 0,
+            building_plugins: Vec::default(),
+            plugin_dependents: TypeIdMap::default(),
+            auto_order_plugins: false,
             plugins_state: PluginsState::Adding,
             update_schedule: None,
             extract: None,
+            #[cfg(feature = "std")]
+            thread_policy: None,
+            #[cfg(feature = "std")]
+            thread: None,
         }
     }
 }
@@ -149,7 +172,23 @@ impl SubApp {
     ///
     /// **Note:** There is no default extract method. Calling `extract` does nothing if
     /// [`set_extract`](Self::set_extract) has not been called.
+    ///
+    /// If the app [runs on its own thread](Self::run_on_thread), this synchronizes with the thread
+    /// instead, according to its [`SubAppSyncPolicy`].
     pub fn extract(&mut self, world: &mut World) {
+        #[cfg(feature = "std")]
+        if let Some(policy) = self.thread_policy.take() {
+            let sub_app = core::mem::take(self);
+            // Keep reporting the moved app's plugin state, so the app isn't finished again.
+            self.plugins_state = sub_app.plugins_state;
+            self.thread = Some(SubAppThread::spawn(sub_app, policy));
+        }
+        #[cfg(feature = "std")]
+        if let Some(thread) = self.thread.as_mut() {
+            thread.sync(world);
+            return;
+        }
+
         if let Some(f) = self.extract.as_mut() {
             f(world, &mut self.world);
         }
@@ -195,6 +234,29 @@ impl SubApp {
         self.extract.take()
     }
 
+    /// Moves the app to its own thread, synchronizing with the main app according to `policy`.
+    ///
+    /// The app is moved on its next [`extract`](Self::extract), after its plugins were finished,
+    /// and updated on its thread from then on. While it runs on its thread, this [`SubApp`] is left
+    /// empty in its place, so its [`World`] can only be accessed through its extract function,
+    /// its systems, or a [`sub_app_channel`](crate::sub_app_channel).
+    ///
+    /// If the thread panics, an [`AppExit::error`](crate::AppExit::error) is sent to the main app.
+    /// The thread is stopped when this [`SubApp`] is dropped.
+    ///
+    /// This has no effect on the main sub-app of an [`App`], which is never extracted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the app holds non-send resources, now or when it is moved to its thread.
+    #[cfg(feature = "std")]
+    #[track_caller]
+    pub fn run_on_thread(&mut self, policy: SubAppSyncPolicy) -> &mut Self {
+        crate::sub_app_thread::assert_sendable(&self.world);
+        self.thread_policy = Some(policy);
+        self
+    }
+
     /// See [`App::insert_resource`].
     pub fn insert_resource<R: Resource>(&mut self, resource: R) -> &mut Self {
         self.world.insert_resource(resource);
@@ -349,7 +411,15 @@ impl SubApp {
 
     /// See [`App::add_plugins`].
     pub fn add_plugins<M>(&mut self, plugins: impl Plugins<M>) -> &mut Self {
-        self.run_as_app(|app| plugins.add_to_app(app));
+        self.run_as_app(|app| {
+            app.add_plugins_unchecked(plugins);
+        });
+        self
+    }
+
+    /// See [`App::set_plugin_auto_ordering`].
+    pub fn set_plugin_auto_ordering(&mut self, enabled: bool) -> &mut Self {
+        self.auto_order_plugins = enabled;
         self
     }
 
@@ -469,6 +539,25 @@ impl SubApp {
         registry.write().register_with_name(name, function).unwrap();
         self
     }
+
+    /// See [`App::register_method`].
+    #[cfg(feature = "reflect_functions")]
+    pub fn register_method<T, F, Marker>(
+        &mut self,
+        name: impl Into<alloc::borrow::Cow<'static, str>>,
+        method: F,
+    ) -> &mut Self
+    where
+        T: bevy_reflect::FromReflect + bevy_reflect::TypePath,
+        F: bevy_reflect::func::IntoFunction<'static, Marker> + 'static,
+    {
+        let registry = self.world.resource_mut::<AppTypeRegistry>();
+        registry
+            .write()
+            .register_method::<T, F, Marker>(name, method)
+            .unwrap();
+        self
+    }
 }
 
 /// The collection of sub-apps that belong to an [`App`].
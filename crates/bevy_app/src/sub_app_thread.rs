@@ -0,0 +1,402 @@
+use crate::{AppExit, SubApp};
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use bevy_ecs::{resource::Resource, world::World};
+use bevy_platform_support::time::Instant;
+use bevy_utils::synccell::SyncCell;
+use core::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+use std::{
+    sync::mpsc::{self, Receiver, Sender},
+    thread::{self, JoinHandle},
+};
+
+#[cfg(feature = "trace")]
+use tracing::info_span;
+
+/// Determines when a [`SubApp`] running on its own thread synchronizes with the main app.
+///
+/// It is used by [`SubApp::run_on_thread`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SubAppSyncPolicy {
+    /// The sub-app is extracted and updated every frame, and the main app waits for its update
+    /// to finish before continuing.
+    ///
+    /// ```text
+    /// |--------------------|-------------------------------|-------------------------------|
+    /// | main thread        | frame 1 | extract |           | frame 2 | extract |           |
+    /// |--------------------|-------------------------------|-------------------------------|
+    /// | sub-app thread     |                   | update 1  |                   | update 2  |
+    /// |--------------------|-------------------------------|-------------------------------|
+    /// ```
+    Lockstep,
+    /// The sub-app is extracted every frame, and updates in parallel to the next frame of the
+    /// main app, like pipelined rendering.
+    ///
+    /// ```text
+    /// |--------------------|-----------------------|-----------------------|
+    /// | main thread        | frame 1 | extract     | frame 2 | extract     |
+    /// |--------------------|-----------------------|-----------------------|
+    /// | sub-app thread     |                       | update 1              |
+    /// |--------------------|-----------------------|-----------------------|
+    /// ```
+    OneFrameLag,
+    /// The sub-app updates continuously, independently of the main app, at most once every `interval`.
+    ///
+    /// The sub-app is never [extracted](SubApp::extract): use a [`sub_app_channel`]
+    /// to exchange messages with the main app instead.
+    FreeRunning {
+        /// The minimum duration between the start of two updates of the sub-app.
+        interval: Duration,
+    },
+}
+
+/// Creates a channel to send messages between the main app and a [`SubApp`] running on its own thread.
+///
+/// Insert the [`SubAppSender`] as a resource on one side and the [`SubAppReceiver`] on the other.
+///
+/// ```
+/// # use bevy_app::{App, AppLabel, Main, SubApp, SubAppReceiver, SubAppSyncPolicy, sub_app_channel};
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::ScheduleLabel;
+/// # use core::time::Duration;
+/// #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, AppLabel)]
+/// struct SimulationApp;
+///
+/// struct Spawn(u32);
+///
+/// fn spawn_requested(mut requests: ResMut<SubAppReceiver<Spawn>>, mut commands: Commands) {
+///     for Spawn(count) in requests.drain() {
+///         // ...
+///     }
+/// }
+///
+/// let mut app = App::new();
+/// let (sender, receiver) = sub_app_channel::<Spawn>();
+/// app.insert_resource(sender);
+///
+/// let mut sub_app = SubApp::new();
+/// sub_app.update_schedule = Some(Main.intern());
+/// sub_app
+///     .insert_resource(receiver)
+///     .add_systems(Main, spawn_requested)
+///     .run_on_thread(SubAppSyncPolicy::FreeRunning {
+///         interval: Duration::from_millis(20),
+///     });
+/// app.insert_sub_app(SimulationApp, sub_app);
+/// ```
+pub fn sub_app_channel<M: Send + 'static>() -> (SubAppSender<M>, SubAppReceiver<M>) {
+    let (sender, receiver) = mpsc::channel();
+    (
+        SubAppSender(sender),
+        SubAppReceiver(SyncCell::new(receiver)),
+    )
+}
+
+/// The sending half of a [`sub_app_channel`].
+#[derive(Resource, Debug)]
+pub struct SubAppSender<M: Send + 'static>(Sender<M>);
+
+impl<M: Send + 'static> SubAppSender<M> {
+    /// Sends a message to the [`SubAppReceiver`].
+    ///
+    /// Returns the message back if the [`SubAppReceiver`] was dropped.
+    pub fn send(&self, message: M) -> Result<(), M> {
+        self.0.send(message).map_err(|error| error.0)
+    }
+}
+
+impl<M: Send + 'static> Clone for SubAppSender<M> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+/// The receiving half of a [`sub_app_channel`].
+#[derive(Resource)]
+pub struct SubAppReceiver<M: Send + 'static>(SyncCell<Receiver<M>>);
+
+impl<M: Send + 'static> SubAppReceiver<M> {
+    /// Receives the next pending message, without blocking.
+    pub fn try_recv(&mut self) -> Option<M> {
+        self.0.get().try_recv().ok()
+    }
+
+    /// Returns an iterator over the pending messages, without blocking.
+    pub fn drain(&mut self) -> impl Iterator<Item = M> + '_ {
+        self.0.get().try_iter()
+    }
+
+    /// Receives the next message, blocking for at most `timeout`.
+    pub fn recv_timeout(&mut self, timeout: Duration) -> Option<M> {
+        self.0.get().recv_timeout(timeout).ok()
+    }
+}
+
+/// Panics if `world` holds non-send resources, which can't be moved to the thread of a sub-app.
+#[track_caller]
+pub(crate) fn assert_sendable(world: &World) {
+    let non_send_resources: Vec<String> = world
+        .storages()
+        .non_send_resources
+        .iter()
+        .filter(|(_, data)| data.is_present())
+        .map(|(id, _)| {
+            world
+                .components()
+                .get_name(id)
+                .map_or_else(|| format!("{id:?}"), ToString::to_string)
+        })
+        .collect();
+    assert!(
+        non_send_resources.is_empty(),
+        "A sub-app can't run on its own thread while it holds non-send resources: {}",
+        non_send_resources.join(", ")
+    );
+}
+
+/// The handle to a [`SubApp`] moved to its own thread, kept by the sub-app left in its place.
+pub(crate) struct SubAppThread {
+    policy: SubAppSyncPolicy,
+    /// The sub-app, when it's not on its thread.
+    home: Option<Box<SubApp>>,
+    app_to_thread_sender: Option<Sender<SubApp>>,
+    thread_to_app_receiver: Receiver<SubApp>,
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl SubAppThread {
+    pub(crate) fn spawn(sub_app: SubApp, policy: SubAppSyncPolicy) -> Self {
+        assert_sendable(sub_app.world());
+        let (app_to_thread_sender, app_to_thread_receiver) = mpsc::channel::<SubApp>();
+        let (thread_to_app_sender, thread_to_app_receiver) = mpsc::channel::<SubApp>();
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let (home, handle) = match policy {
+            SubAppSyncPolicy::Lockstep | SubAppSyncPolicy::OneFrameLag => {
+                let handle = thread::spawn(move || {
+                    #[cfg(feature = "trace")]
+                    let _span = info_span!("sub app thread").entered();
+
+                    while let Ok(mut sub_app) = app_to_thread_receiver.recv() {
+                        sub_app.update();
+                        if thread_to_app_sender.send(sub_app).is_err() {
+                            break;
+                        }
+                    }
+                });
+                (Some(Box::new(sub_app)), handle)
+            }
+            SubAppSyncPolicy::FreeRunning { interval } => {
+                let stop = stop.clone();
+                let mut sub_app = sub_app;
+                let handle = thread::spawn(move || {
+                    #[cfg(feature = "trace")]
+                    let _span = info_span!("sub app thread").entered();
+
+                    while !stop.load(Ordering::Acquire) {
+                        let start_time = Instant::now();
+                        sub_app.update();
+                        if let Some(delay) = interval.checked_sub(start_time.elapsed()) {
+                            // Woken up early when the sub-app is stopped.
+                            thread::park_timeout(delay);
+                        }
+                    }
+                });
+                (None, handle)
+            }
+        };
+
+        Self {
+            policy,
+            home,
+            app_to_thread_sender: Some(app_to_thread_sender),
+            thread_to_app_receiver,
+            stop,
+            handle: Some(handle),
+        }
+    }
+
+    /// Synchronizes the sub-app with the main `world` according to the [`SubAppSyncPolicy`].
+    ///
+    /// Sends [`AppExit::error`] if the thread of the sub-app panicked.
+    pub(crate) fn sync(&mut self, world: &mut World) {
+        if let SubAppSyncPolicy::FreeRunning { .. } = self.policy {
+            if self.handle.as_ref().is_some_and(JoinHandle::is_finished) {
+                world.send_event(AppExit::error());
+            }
+            return;
+        }
+
+        // Wait for the previous update to finish.
+        let sub_app = match self.home.take() {
+            Some(sub_app) => Some(*sub_app),
+            None => self.thread_to_app_receiver.recv().ok(),
+        };
+        let Some(mut sub_app) = sub_app else {
+            // The thread panicked.
+            world.send_event(AppExit::error());
+            return;
+        };
+        sub_app.extract(world);
+
+        let sender = self.app_to_thread_sender.as_ref().unwrap();
+        if sender.send(sub_app).is_err() {
+            world.send_event(AppExit::error());
+            return;
+        }
+        if self.policy == SubAppSyncPolicy::Lockstep {
+            match self.thread_to_app_receiver.recv() {
+                Ok(sub_app) => self.home = Some(Box::new(sub_app)),
+                Err(_) => {
+                    world.send_event(AppExit::error());
+                }
+            }
+        }
+    }
+}
+
+impl Drop for SubAppThread {
+    fn drop(&mut self) {
+        // Closing the channel stops the thread once the sub-app is done updating.
+        self.app_to_thread_sender = None;
+        self.stop.store(true, Ordering::Release);
+        if let Some(handle) = self.handle.take() {
+            handle.thread().unpark();
+            handle.join().ok();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        self as bevy_app, sub_app_channel, App, AppLabel, Main, PluginsState, SubApp,
+        SubAppReceiver, SubAppSender, SubAppSyncPolicy,
+    };
+    use alloc::{vec, vec::Vec};
+    use bevy_ecs::{prelude::*, schedule::ScheduleLabel};
+    use core::time::Duration;
+
+    #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, AppLabel)]
+    struct ThreadedApp;
+
+    #[derive(Resource, Default)]
+    struct Frame(u32);
+
+    #[derive(Resource, Default)]
+    struct Extracted(Vec<u32>);
+
+    fn threaded_app(policy: SubAppSyncPolicy) -> App {
+        let mut app = App::new();
+        app.init_resource::<Frame>()
+            .add_systems(Main, |mut frame: ResMut<Frame>| frame.0 += 1);
+
+        let (sender, receiver) = sub_app_channel::<Vec<u32>>();
+        app.insert_resource(receiver);
+
+        let mut sub_app = SubApp::new();
+        sub_app.update_schedule = Some(Main.intern());
+        sub_app
+            .init_resource::<Extracted>()
+            .insert_resource(sender)
+            .set_extract(|main_world, world| {
+                let frame = main_world.resource::<Frame>().0;
+                world.resource_mut::<Extracted>().0.push(frame);
+            })
+            .add_systems(
+                Main,
+                |extracted: Res<Extracted>, sender: Res<SubAppSender<Vec<u32>>>| {
+                    sender.send(extracted.0.clone()).unwrap();
+                },
+            )
+            .run_on_thread(policy);
+        app.insert_sub_app(ThreadedApp, sub_app);
+        app
+    }
+
+    fn last_message(app: &mut App) -> Option<Vec<u32>> {
+        app.world_mut()
+            .resource_mut::<SubAppReceiver<Vec<u32>>>()
+            .drain()
+            .last()
+    }
+
+    #[test]
+    fn lockstep_updates_within_the_frame() {
+        let mut app = threaded_app(SubAppSyncPolicy::Lockstep);
+        app.update();
+        assert_eq!(last_message(&mut app), Some(vec![1]));
+        app.update();
+        assert_eq!(last_message(&mut app), Some(vec![1, 2]));
+    }
+
+    #[test]
+    fn plugins_stay_cleaned_while_running_on_thread() {
+        let mut app = threaded_app(SubAppSyncPolicy::Lockstep);
+        app.finish();
+        app.cleanup();
+        app.update();
+        assert_eq!(app.plugins_state(), PluginsState::Cleaned);
+    }
+
+    #[test]
+    fn one_frame_lag_updates_during_the_next_frame() {
+        let mut app = threaded_app(SubAppSyncPolicy::OneFrameLag);
+        app.update();
+        app.update();
+        // The update of the first frame finished before the second frame was extracted.
+        let messages: Vec<_> = app
+            .world_mut()
+            .resource_mut::<SubAppReceiver<Vec<u32>>>()
+            .drain()
+            .collect();
+        assert_eq!(messages[0], vec![1]);
+    }
+
+    #[test]
+    fn free_running_updates_without_extracting() {
+        let mut app = threaded_app(SubAppSyncPolicy::FreeRunning {
+            interval: Duration::from_millis(1),
+        });
+        app.update();
+        let mut receiver = app
+            .world_mut()
+            .remove_resource::<SubAppReceiver<Vec<u32>>>()
+            .unwrap();
+        // Wait for the sub-app to update on its own.
+        let message = receiver
+            .recv_timeout(Duration::from_secs(10))
+            .expect("the sub-app should update on its own");
+        assert!(message.is_empty());
+        assert!(app.should_exit().is_none());
+    }
+
+    struct NonSendData;
+
+    #[test]
+    #[should_panic(expected = "while it holds non-send resources")]
+    fn non_send_resources_cant_run_on_thread() {
+        let mut sub_app = SubApp::new();
+        sub_app.world_mut().insert_non_send_resource(NonSendData);
+        sub_app.run_on_thread(SubAppSyncPolicy::Lockstep);
+    }
+
+    #[test]
+    #[should_panic(expected = "while it holds non-send resources")]
+    fn non_send_resources_added_later_cant_run_on_thread() {
+        let mut app = threaded_app(SubAppSyncPolicy::Lockstep);
+        app.sub_app_mut(ThreadedApp)
+            .world_mut()
+            .insert_non_send_resource(NonSendData);
+        app.update();
+    }
+}
@@ -1,4 +1,9 @@
-use alloc::vec::Vec;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::any::TypeId;
+use std::path::{Component, Path, PathBuf};
 
 use crate as bevy_asset;
 use crate::{Asset, UntypedHandle};
@@ -12,3 +17,228 @@ pub struct LoadedFolder {
     #[dependency]
     pub handles: Vec<UntypedHandle>,
 }
+
+/// A glob pattern that matches asset paths, such as `textures/**/*.ktx2`.
+///
+/// Patterns are matched against the whole path (relative to the asset source root), using `/` as the separator:
+/// * `?` matches any single character except `/`.
+/// * `*` matches any sequence of characters except `/`.
+/// * `**` matches any number of complete path segments, including none.
+/// * `{a,b}` matches any of the comma separated alternatives, which may themselves contain patterns.
+///
+/// All other characters match themselves.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AssetGlob {
+    pattern: String,
+    /// `pattern` with every `{a,b}` group expanded.
+    alternatives: Vec<Vec<char>>,
+}
+
+impl AssetGlob {
+    /// Creates a new glob from the given `pattern`.
+    pub fn new(pattern: impl Into<String>) -> Self {
+        let pattern = pattern.into();
+        let mut alternatives = Vec::new();
+        expand_braces(&pattern.chars().collect::<Vec<_>>(), &mut alternatives);
+        Self {
+            pattern,
+            alternatives,
+        }
+    }
+
+    /// Returns the pattern this glob was created from.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Returns the longest folder path that contains every path matched by this glob. This is the folder that
+    /// needs to be searched to find all matches.
+    pub fn base_path(&self) -> PathBuf {
+        let segments = self.pattern.split('/').collect::<Vec<_>>();
+        segments[..segments.len() - 1]
+            .iter()
+            .take_while(|segment| !segment.contains(['*', '?', '{']))
+            .collect()
+    }
+
+    /// Returns `true` if `path` matches this glob.
+    pub fn is_match(&self, path: &Path) -> bool {
+        let mut normalized = String::new();
+        for component in path.components() {
+            if let Component::Normal(segment) = component {
+                if !normalized.is_empty() {
+                    normalized.push('/');
+                }
+                normalized.push_str(&segment.to_string_lossy());
+            }
+        }
+        let path = normalized.chars().collect::<Vec<_>>();
+        self.alternatives
+            .iter()
+            .any(|pattern| glob_match(pattern, &path))
+    }
+}
+
+impl core::fmt::Display for AssetGlob {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.pattern)
+    }
+}
+
+impl From<&str> for AssetGlob {
+    fn from(pattern: &str) -> Self {
+        Self::new(pattern.to_string())
+    }
+}
+
+fn expand_braces(pattern: &[char], out: &mut Vec<Vec<char>>) {
+    let Some(start) = pattern.iter().position(|c| *c == '{') else {
+        out.push(pattern.to_vec());
+        return;
+    };
+    let mut depth = 0;
+    let mut options = Vec::new();
+    let mut option_start = start + 1;
+    for (i, c) in pattern.iter().enumerate().skip(start) {
+        match c {
+            '{' => depth += 1,
+            ',' if depth == 1 => {
+                options.push(&pattern[option_start..i]);
+                option_start = i + 1;
+            }
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    options.push(&pattern[option_start..i]);
+                    for option in options {
+                        let mut expanded = pattern[..start].to_vec();
+                        expanded.extend_from_slice(option);
+                        expanded.extend_from_slice(&pattern[i + 1..]);
+                        expand_braces(&expanded, out);
+                    }
+                    return;
+                }
+            }
+            _ => {}
+        }
+    }
+    // Unbalanced braces are matched literally.
+    out.push(pattern.to_vec());
+}
+
+fn glob_match(pattern: &[char], path: &[char]) -> bool {
+    match pattern {
+        [] => path.is_empty(),
+        ['*', '*', rest @ ..] => {
+            let rest = rest.strip_prefix(&['/']).unwrap_or(rest);
+            if rest.is_empty() {
+                return true;
+            }
+            (0..=path.len())
+                .filter(|i| *i == 0 || path[i - 1] == '/')
+                .any(|i| glob_match(rest, &path[i..]))
+        }
+        ['*', rest @ ..] => {
+            for i in 0..=path.len() {
+                if glob_match(rest, &path[i..]) {
+                    return true;
+                }
+                if path.get(i) == Some(&'/') {
+                    break;
+                }
+            }
+            false
+        }
+        ['?', rest @ ..] => {
+            matches!(path.first(), Some(c) if *c != '/') && glob_match(rest, &path[1..])
+        }
+        [c, rest @ ..] => path.first() == Some(c) && glob_match(rest, &path[1..]),
+    }
+}
+
+/// Filters the assets loaded by [`AssetServer::load_glob_with_filter`](crate::AssetServer::load_glob_with_filter).
+///
+/// An asset passes the filter if it matches any of the include patterns (or there are none), matches none of the exclude
+/// patterns, and (if set) is of the filtered asset type.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FolderFilter {
+    include: Vec<AssetGlob>,
+    exclude: Vec<AssetGlob>,
+    asset_type: Option<TypeId>,
+}
+
+impl FolderFilter {
+    /// Only includes assets whose path matches `pattern` (or another include pattern).
+    pub fn with_include(mut self, pattern: impl Into<String>) -> Self {
+        self.include.push(AssetGlob::new(pattern));
+        self
+    }
+
+    /// Excludes assets whose path matches `pattern`.
+    pub fn with_exclude(mut self, pattern: impl Into<String>) -> Self {
+        self.exclude.push(AssetGlob::new(pattern));
+        self
+    }
+
+    /// Only includes assets of type `A`.
+    pub fn with_asset_type<A: Asset>(mut self) -> Self {
+        self.asset_type = Some(TypeId::of::<A>());
+        self
+    }
+
+    /// Returns the asset type assets are filtered to, if any.
+    pub fn asset_type(&self) -> Option<TypeId> {
+        self.asset_type
+    }
+
+    /// Returns `true` if `path` passes the include and exclude patterns of this filter.
+    pub fn is_match(&self, path: &Path) -> bool {
+        (self.include.is_empty() || self.include.iter().any(|glob| glob.is_match(path)))
+            && !self.exclude.iter().any(|glob| glob.is_match(path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AssetGlob, FolderFilter};
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn glob_matching() {
+        let glob = AssetGlob::new("textures/**/*.ktx2");
+        assert_eq!(glob.base_path(), PathBuf::from("textures"));
+        assert!(glob.is_match(Path::new("textures/a.ktx2")));
+        assert!(glob.is_match(Path::new("textures/terrain/grass/a.ktx2")));
+        assert!(!glob.is_match(Path::new("textures/a.png")));
+        assert!(!glob.is_match(Path::new("models/a.ktx2")));
+
+        let glob = AssetGlob::new("sounds/*.{ogg,wav}");
+        assert_eq!(glob.base_path(), PathBuf::from("sounds"));
+        assert!(glob.is_match(Path::new("sounds/a.ogg")));
+        assert!(glob.is_match(Path::new("sounds/b.wav")));
+        assert!(!glob.is_match(Path::new("sounds/music/a.ogg")));
+        assert!(!glob.is_match(Path::new("sounds/a.mp3")));
+
+        let glob = AssetGlob::new("levels/level_?.ron");
+        assert!(glob.is_match(Path::new("levels/level_1.ron")));
+        assert!(!glob.is_match(Path::new("levels/level_10.ron")));
+
+        let glob = AssetGlob::new("*.png");
+        assert_eq!(glob.base_path(), PathBuf::new());
+        assert!(glob.is_match(Path::new("a.png")));
+        assert!(!glob.is_match(Path::new("b/a.png")));
+    }
+
+    #[test]
+    fn filter_matching() {
+        let filter = FolderFilter::default()
+            .with_include("**/*.png")
+            .with_include("**/*.jpg")
+            .with_exclude("**/debug/**");
+        assert!(filter.is_match(Path::new("a.png")));
+        assert!(filter.is_match(Path::new("ui/b.jpg")));
+        assert!(!filter.is_match(Path::new("ui/debug/b.jpg")));
+        assert!(!filter.is_match(Path::new("ui/b.ktx2")));
+        assert!(FolderFilter::default().is_match(Path::new("anything.bin")));
+    }
+}
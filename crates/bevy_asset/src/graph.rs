@@ -0,0 +1,422 @@
+//! Inspection of the dependency relationships between assets.
+//!
+//! An [`AssetDependencyGraph`] can be captured from a running [`AssetServer`](crate::AssetServer) with
+//! [`AssetServer::dependency_graph`](crate::AssetServer::dependency_graph), or from an
+//! [`AssetProcessor`](crate::processor::AssetProcessor) with
+//! [`AssetProcessor::dependency_graph`](crate::processor::AssetProcessor::dependency_graph).
+//! The graph can be exported as JSON or [DOT](https://graphviz.org/doc/info/lang.html) for external tooling,
+//! and can be used to find files in an [`AssetSource`] that are not referenced by any of a set of "root" assets.
+
+use crate::{
+    io::{AssetReaderError, AssetSource, ErasedAssetReader},
+    AssetPath,
+};
+use alloc::{
+    boxed::Box,
+    collections::VecDeque,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_platform_support::collections::{HashMap, HashSet};
+use core::fmt::Write;
+use futures_lite::StreamExt;
+use serde::{Serialize, Serializer};
+use std::path::PathBuf;
+
+/// Describes why one asset depends on another in an [`AssetDependencyGraph`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AssetDependencyKind {
+    /// The dependent asset holds a handle to the dependency, as reported by its
+    /// [`VisitAssetDependencies`](crate::VisitAssetDependencies) implementation.
+    Handle,
+    /// The dependency was used by the dependent asset's [`AssetLoader`](crate::AssetLoader) while loading.
+    /// These are only tracked by the [`AssetServer`](crate::AssetServer) when it is watching for changes.
+    Loader,
+    /// The dependency is a labeled sub-asset of the dependent asset.
+    Labeled,
+    /// The dependency was used while processing the dependent asset.
+    Process,
+}
+
+impl AssetDependencyKind {
+    /// Returns a short, lowercase name for this kind, used when exporting the graph.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AssetDependencyKind::Handle => "handle",
+            AssetDependencyKind::Loader => "loader",
+            AssetDependencyKind::Labeled => "labeled",
+            AssetDependencyKind::Process => "process",
+        }
+    }
+}
+
+/// A single outgoing edge in an [`AssetDependencyGraph`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize)]
+pub struct AssetDependency {
+    /// The path of the asset that is depended on.
+    pub path: AssetPath<'static>,
+    /// Why the dependency exists.
+    pub kind: AssetDependencyKind,
+}
+
+/// A directed graph of assets (keyed by [`AssetPath`]) and the assets they depend on.
+///
+/// The graph serializes as a list of assets with their dependencies, both sorted by path, for stable output.
+/// It can be exported with any `serde` serializer, for example as a JSON document of the form
+/// `{"assets":[{"path":"a.png","dependencies":[{"path":"b.png","kind":"handle"}]}]}`,
+/// or rendered in the DOT format with [`to_dot`](Self::to_dot).
+#[derive(Clone, Debug, Default)]
+pub struct AssetDependencyGraph {
+    nodes: HashMap<AssetPath<'static>, HashSet<AssetDependency>>,
+}
+
+impl AssetDependencyGraph {
+    /// Creates a new, empty graph.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `path` to the graph, if it is not already present.
+    pub fn add_asset(&mut self, path: AssetPath<'static>) {
+        self.nodes.entry(path).or_default();
+    }
+
+    /// Records that `dependent` depends on `dependency`. Both assets are added to the graph if they are not already present.
+    pub fn add_dependency(
+        &mut self,
+        dependent: AssetPath<'static>,
+        dependency: AssetPath<'static>,
+        kind: AssetDependencyKind,
+    ) {
+        self.nodes.entry(dependency.clone()).or_default();
+        self.nodes
+            .entry(dependent)
+            .or_default()
+            .insert(AssetDependency {
+                path: dependency,
+                kind,
+            });
+    }
+
+    /// Adds all assets and dependencies from `other` to this graph.
+    pub fn merge(&mut self, other: AssetDependencyGraph) {
+        for (path, dependencies) in other.nodes {
+            self.nodes.entry(path).or_default().extend(dependencies);
+        }
+    }
+
+    /// Returns `true` if `path` is part of this graph.
+    pub fn contains(&self, path: &AssetPath<'static>) -> bool {
+        self.nodes.contains_key(path)
+    }
+
+    /// Returns the number of assets in this graph.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if this graph contains no assets.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Iterates over every asset in this graph.
+    pub fn assets(&self) -> impl Iterator<Item = &AssetPath<'static>> {
+        self.nodes.keys()
+    }
+
+    /// Iterates over the direct dependencies of `path`.
+    pub fn dependencies<'a>(
+        &'a self,
+        path: &AssetPath<'static>,
+    ) -> impl Iterator<Item = &'a AssetDependency> {
+        self.nodes.get(path).into_iter().flatten()
+    }
+
+    /// Returns every asset that directly depends on `path`, along with the kind of dependency.
+    pub fn dependents(
+        &self,
+        path: &AssetPath<'static>,
+    ) -> Vec<(&AssetPath<'static>, AssetDependencyKind)> {
+        let mut dependents = self
+            .nodes
+            .iter()
+            .flat_map(|(dependent, dependencies)| {
+                dependencies
+                    .iter()
+                    .filter(|dependency| dependency.path == *path)
+                    .map(move |dependency| (dependent, dependency.kind))
+            })
+            .collect::<Vec<_>>();
+        dependents.sort_by(|a, b| a.0.to_string().cmp(&b.0.to_string()).then(a.1.cmp(&b.1)));
+        dependents
+    }
+
+    /// Returns every asset that can be reached by following dependencies from `roots`, including the roots themselves.
+    ///
+    /// Reaching a labeled sub-asset also reaches the asset it was loaded from, as the sub-asset cannot exist without it.
+    pub fn reachable<'a>(
+        &self,
+        roots: impl IntoIterator<Item = AssetPath<'a>>,
+    ) -> HashSet<AssetPath<'static>> {
+        let mut visited = <HashSet<AssetPath<'static>>>::default();
+        let mut queue = roots
+            .into_iter()
+            .map(AssetPath::into_owned)
+            .collect::<VecDeque<_>>();
+        while let Some(path) = queue.pop_front() {
+            if visited.contains(&path) {
+                continue;
+            }
+            if path.label().is_some() {
+                queue.push_back(path.without_label().into_owned());
+            }
+            queue.extend(self.dependencies(&path).map(|dep| dep.path.clone()));
+            visited.insert(path);
+        }
+        visited
+    }
+
+    /// Returns the paths of files in `source` that are not reachable from `roots`, sorted by path.
+    ///
+    /// Labeled sub-assets are attributed to the file they were loaded from. `.meta` files are not considered assets
+    /// and are never reported.
+    pub async fn unreferenced_assets<'a>(
+        &self,
+        source: &AssetSource,
+        roots: impl IntoIterator<Item = AssetPath<'a>>,
+    ) -> Result<Vec<AssetPath<'static>>, AssetReaderError> {
+        let referenced = self
+            .reachable(roots)
+            .into_iter()
+            .map(|path| path.without_label().into_owned())
+            .collect::<HashSet<_>>();
+
+        let mut paths = Vec::new();
+        collect_asset_paths(source.reader(), PathBuf::new(), &mut paths).await?;
+
+        let mut unreferenced = paths
+            .into_iter()
+            .map(|path| AssetPath::from(path).with_source(source.id()))
+            .filter(|path| !referenced.contains(path))
+            .collect::<Vec<_>>();
+        unreferenced.sort_by_key(ToString::to_string);
+        Ok(unreferenced)
+    }
+
+    /// Returns the assets in this graph with their dependencies, both sorted by path, for stable output.
+    fn sorted(&self) -> Vec<(&AssetPath<'static>, Vec<&AssetDependency>)> {
+        let mut nodes = self
+            .nodes
+            .iter()
+            .map(|(path, dependencies)| {
+                let mut dependencies = dependencies.iter().collect::<Vec<_>>();
+                dependencies.sort_by(|a, b| {
+                    a.path
+                        .to_string()
+                        .cmp(&b.path.to_string())
+                        .then(a.kind.cmp(&b.kind))
+                });
+                (path, dependencies)
+            })
+            .collect::<Vec<_>>();
+        nodes.sort_by_key(|(path, _)| path.to_string());
+        nodes
+    }
+
+    /// Renders this graph in the [DOT](https://graphviz.org/doc/info/lang.html) format.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph assets {\n");
+        for (path, _) in self.sorted() {
+            let _ = writeln!(dot, "    {};", quote(&path.to_string()));
+        }
+        for (path, dependencies) in self.sorted() {
+            for dependency in dependencies {
+                let _ = writeln!(
+                    dot,
+                    "    {} -> {} [label={}];",
+                    quote(&path.to_string()),
+                    quote(&dependency.path.to_string()),
+                    quote(dependency.kind.as_str())
+                );
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl Serialize for AssetDependencyGraph {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Asset<'a> {
+            path: &'a AssetPath<'static>,
+            dependencies: Vec<&'a AssetDependency>,
+        }
+
+        #[derive(Serialize)]
+        struct Graph<'a> {
+            assets: Vec<Asset<'a>>,
+        }
+
+        Graph {
+            assets: self
+                .sorted()
+                .into_iter()
+                .map(|(path, dependencies)| Asset { path, dependencies })
+                .collect(),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Quotes `value` as a DOT string literal.
+fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Recursively collects the paths of all files under `path` in `reader`.
+async fn collect_asset_paths(
+    reader: &dyn ErasedAssetReader,
+    path: PathBuf,
+    paths: &mut Vec<PathBuf>,
+) -> Result<(), AssetReaderError> {
+    if reader.is_directory(&path).await? {
+        let mut children = reader.read_directory(&path).await?;
+        while let Some(child) = children.next().await {
+            Box::pin(collect_asset_paths(reader, child, paths)).await?;
+        }
+    } else if path.extension().is_none_or(|ext| ext != "meta") {
+        paths.push(path);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AssetDependencyGraph, AssetDependencyKind};
+    use crate::{
+        io::{
+            memory::{Dir, MemoryAssetReader},
+            AssetSource, AssetSourceId,
+        },
+        AssetPath,
+    };
+    use alloc::{boxed::Box, string::ToString, vec, vec::Vec};
+    use bevy_tasks::block_on;
+    use std::path::Path;
+
+    fn test_graph() -> AssetDependencyGraph {
+        let mut graph = AssetDependencyGraph::new();
+        graph.add_dependency(
+            "level.scn.ron".into(),
+            "models/tree.gltf#Mesh0".into(),
+            AssetDependencyKind::Handle,
+        );
+        graph.add_dependency(
+            "models/tree.gltf".into(),
+            "models/tree.gltf#Mesh0".into(),
+            AssetDependencyKind::Labeled,
+        );
+        graph.add_dependency(
+            "models/tree.gltf".into(),
+            "textures/bark.png".into(),
+            AssetDependencyKind::Loader,
+        );
+        graph.add_asset("textures/unused.png".into());
+        graph
+    }
+
+    #[test]
+    fn reachable_follows_labels_to_their_file() {
+        let graph = test_graph();
+        let reachable = graph.reachable([AssetPath::from("level.scn.ron")]);
+        let mut reachable = reachable
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        reachable.sort();
+        assert_eq!(
+            reachable,
+            vec![
+                "level.scn.ron",
+                "models/tree.gltf",
+                "models/tree.gltf#Mesh0",
+                "textures/bark.png"
+            ]
+        );
+        assert_eq!(
+            graph.dependents(&AssetPath::from("models/tree.gltf#Mesh0")),
+            vec![
+                (
+                    &AssetPath::from("level.scn.ron"),
+                    AssetDependencyKind::Handle
+                ),
+                (
+                    &AssetPath::from("models/tree.gltf"),
+                    AssetDependencyKind::Labeled
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn export_formats() {
+        let mut graph = AssetDependencyGraph::new();
+        graph.add_dependency(
+            "a.ron".into(),
+            "b \"quoted\".ron".into(),
+            AssetDependencyKind::Handle,
+        );
+        assert_eq!(
+            serde_json::to_string(&graph).unwrap(),
+            r#"{"assets":[{"path":"a.ron","dependencies":[{"path":"b \"quoted\".ron","kind":"handle"}]},{"path":"b \"quoted\".ron","dependencies":[]}]}"#
+        );
+        assert_eq!(
+            graph.to_dot(),
+            "digraph assets {\n    \"a.ron\";\n    \"b \\\"quoted\\\".ron\";\n    \"a.ron\" -> \"b \\\"quoted\\\".ron\" [label=\"handle\"];\n}\n"
+        );
+    }
+
+    #[test]
+    fn unreferenced_assets() {
+        let dir = Dir::default();
+        for path in [
+            "level.scn.ron",
+            "models/tree.gltf",
+            "textures/bark.png",
+            "textures/unused.png",
+            "orphan.ogg",
+        ] {
+            dir.insert_asset_text(Path::new(path), "");
+        }
+        dir.insert_meta_text(Path::new("orphan.ogg"), "");
+
+        let mut builder = AssetSource::build()
+            .with_reader(move || Box::new(MemoryAssetReader { root: dir.clone() }));
+        let source = builder.build(AssetSourceId::Default, false, false).unwrap();
+
+        let unreferenced =
+            block_on(test_graph().unreferenced_assets(&source, [AssetPath::from("level.scn.ron")]))
+                .unwrap();
+        assert_eq!(
+            unreferenced,
+            vec![
+                AssetPath::from("orphan.ogg"),
+                AssetPath::from("textures/unused.png")
+            ]
+        );
+    }
+}
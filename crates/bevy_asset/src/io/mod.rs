@@ -13,6 +13,7 @@ pub mod file;
 pub mod gated;
 pub mod memory;
 pub mod processor_gated;
+pub mod transform;
 #[cfg(target_arch = "wasm32")]
 pub mod wasm;
 
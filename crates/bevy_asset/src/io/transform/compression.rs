@@ -0,0 +1,394 @@
+use crate::io::{transform::AssetTransform, AsyncSeekForward, Reader, Writer};
+use alloc::{boxed::Box, vec::Vec};
+use bevy_tasks::BoxedFuture;
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_io::{AsyncRead, AsyncWrite};
+use futures_lite::ready;
+use std::{
+    io::{Error, ErrorKind},
+    path::Path,
+};
+
+/// An [`AssetTransform`] that compresses assets.
+///
+/// Assets are compressed in independent blocks of up to 128 KiB, so they are decompressed as they are read, and
+/// compressed as they are written. Seeking forward skips whole blocks without decompressing them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AssetCompression {
+    /// [Zstandard](https://facebook.github.io/zstd/) compression, which favors compression ratio.
+    #[cfg(feature = "zstd")]
+    Zstd,
+    /// [LZ4](https://lz4.org/) compression, which favors decompression speed.
+    #[cfg(feature = "lz4")]
+    Lz4,
+}
+
+/// The maximum number of plain bytes in a block.
+const BLOCK_LEN: usize = 128 * 1024;
+/// Each block starts with the number of plain bytes and the number of compressed bytes in the block, as little-endian
+/// `u32`s, followed by the compressed bytes.
+const BLOCK_HEADER_LEN: usize = 8;
+
+#[cfg_attr(
+    not(any(feature = "zstd", feature = "lz4")),
+    expect(
+        unused_variables,
+        unreachable_code,
+        reason = "AssetCompression has no variants without a compression feature"
+    )
+)]
+impl AssetCompression {
+    fn compress_block(&self, plain: &[u8]) -> Vec<u8> {
+        match *self {
+            #[cfg(feature = "zstd")]
+            Self::Zstd => ruzstd::encoding::compress_to_vec(
+                plain,
+                ruzstd::encoding::CompressionLevel::Fastest,
+            ),
+            #[cfg(feature = "lz4")]
+            Self::Lz4 => lz4_flex::compress(plain),
+        }
+    }
+
+    fn decompress_block(&self, compressed: &[u8], plain_len: usize) -> std::io::Result<Vec<u8>> {
+        let plain: Vec<u8> = match *self {
+            #[cfg(feature = "zstd")]
+            Self::Zstd => {
+                use std::io::Read;
+
+                let mut decoder = ruzstd::decoding::StreamingDecoder::new(compressed)
+                    .map_err(|err| Error::new(ErrorKind::InvalidData, err))?;
+                let mut plain = Vec::with_capacity(plain_len);
+                decoder.read_to_end(&mut plain)?;
+                plain
+            }
+            #[cfg(feature = "lz4")]
+            Self::Lz4 => lz4_flex::decompress(compressed, plain_len)
+                .map_err(|err| Error::new(ErrorKind::InvalidData, err))?,
+        };
+        if plain.len() != plain_len {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "compressed block has the wrong length",
+            ));
+        }
+        Ok(plain)
+    }
+}
+
+impl AssetTransform for AssetCompression {
+    fn decode<'a>(
+        &'a self,
+        _path: &'a Path,
+        reader: Box<dyn Reader + 'a>,
+    ) -> BoxedFuture<'a, std::io::Result<Box<dyn Reader + 'a>>> {
+        Box::pin(async move {
+            let reader: Box<dyn Reader + 'a> = Box::new(DecompressReader {
+                reader,
+                compression: *self,
+                header: [0; BLOCK_HEADER_LEN],
+                header_read: 0,
+                compressed: Vec::new(),
+                compressed_read: 0,
+                plain: Vec::new(),
+                plain_read: 0,
+                position: 0,
+                seek_target: None,
+            });
+            Ok(reader)
+        })
+    }
+
+    fn encode(&self, _path: &Path, writer: Box<Writer>) -> Box<Writer> {
+        Box::new(CompressWriter {
+            writer,
+            compression: *self,
+            plain: Vec::new(),
+            block: Vec::new(),
+            block_written: 0,
+        })
+    }
+}
+
+/// The [`Reader`] returned by [`AssetCompression`], decompressing one block at a time.
+struct DecompressReader<'a> {
+    reader: Box<dyn Reader + 'a>,
+    compression: AssetCompression,
+    /// The header of the current block, and how many of its bytes were read.
+    header: [u8; BLOCK_HEADER_LEN],
+    header_read: usize,
+    /// The compressed bytes of the current block, and how many of them were read.
+    compressed: Vec<u8>,
+    compressed_read: usize,
+    /// The plain bytes of the last decompressed block, and how many of them were read.
+    plain: Vec<u8>,
+    plain_read: usize,
+    /// The number of plain bytes read or skipped so far.
+    position: u64,
+    /// The position a pending seek is seeking to.
+    seek_target: Option<u64>,
+}
+
+impl DecompressReader<'_> {
+    /// Reads the header of the next block, returning its plain and compressed lengths, or `None` at the end of the asset.
+    fn poll_header(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<std::io::Result<Option<(usize, usize)>>> {
+        while self.header_read < BLOCK_HEADER_LEN {
+            let n = ready!(
+                Pin::new(&mut self.reader).poll_read(cx, &mut self.header[self.header_read..])
+            )?;
+            if n == 0 {
+                return Poll::Ready(match self.header_read {
+                    0 => Ok(None),
+                    _ => Err(ErrorKind::UnexpectedEof.into()),
+                });
+            }
+            self.header_read += n;
+        }
+        let plain_len = u32::from_le_bytes(self.header[..4].try_into().unwrap()) as usize;
+        let compressed_len = u32::from_le_bytes(self.header[4..].try_into().unwrap()) as usize;
+        if plain_len > BLOCK_LEN || compressed_len > 2 * BLOCK_LEN {
+            return Poll::Ready(Err(Error::new(
+                ErrorKind::InvalidData,
+                "asset is not compressed",
+            )));
+        }
+        Poll::Ready(Ok(Some((plain_len, compressed_len))))
+    }
+
+    /// Reads and decompresses the next block into `plain`, returning `false` at the end of the asset.
+    fn poll_decompress_block(&mut self, cx: &mut Context<'_>) -> Poll<std::io::Result<bool>> {
+        let Some((plain_len, compressed_len)) = ready!(self.poll_header(cx))? else {
+            return Poll::Ready(Ok(false));
+        };
+        if self.compressed_read == 0 {
+            self.compressed.resize(compressed_len, 0);
+        }
+        while self.compressed_read < compressed_len {
+            let n = ready!(Pin::new(&mut self.reader)
+                .poll_read(cx, &mut self.compressed[self.compressed_read..]))?;
+            if n == 0 {
+                return Poll::Ready(Err(ErrorKind::UnexpectedEof.into()));
+            }
+            self.compressed_read += n;
+        }
+        self.plain = self
+            .compression
+            .decompress_block(&self.compressed, plain_len)?;
+        self.plain_read = 0;
+        self.header_read = 0;
+        self.compressed_read = 0;
+        Poll::Ready(Ok(true))
+    }
+}
+
+impl AsyncRead for DecompressReader<'_> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        while self.plain_read == self.plain.len() {
+            if !ready!(self.poll_decompress_block(cx))? {
+                return Poll::Ready(Ok(0));
+            }
+        }
+        let n = buf.len().min(self.plain.len() - self.plain_read);
+        buf[..n].copy_from_slice(&self.plain[self.plain_read..self.plain_read + n]);
+        self.plain_read += n;
+        self.position += n as u64;
+        Poll::Ready(Ok(n))
+    }
+}
+
+impl AsyncSeekForward for DecompressReader<'_> {
+    fn poll_seek_forward(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        offset: u64,
+    ) -> Poll<std::io::Result<u64>> {
+        // The target is kept until it's reached, as the seek is resumed with the same `offset` when it's pending.
+        let position = self.position;
+        let target = *self.seek_target.get_or_insert(position + offset);
+        let position = loop {
+            let buffered = (self.plain.len() - self.plain_read) as u64;
+            let skip = buffered.min(target - self.position);
+            self.plain_read += skip as usize;
+            self.position += skip;
+            if self.position == target {
+                break target;
+            }
+
+            let Some((plain_len, compressed_len)) = ready!(self.poll_header(cx))? else {
+                // Seeking beyond the end of the asset stops at its end.
+                break self.position;
+            };
+            if self.compressed_read == 0 && plain_len as u64 <= target - self.position {
+                // The whole block is skipped without decompressing it.
+                ready!(Pin::new(&mut self.reader).poll_seek_forward(cx, compressed_len as u64))?;
+                self.header_read = 0;
+                self.position += plain_len as u64;
+            } else if !ready!(self.poll_decompress_block(cx))? {
+                break self.position;
+            }
+        };
+        self.seek_target = None;
+        Poll::Ready(Ok(position))
+    }
+}
+
+impl Reader for DecompressReader<'_> {}
+
+/// The [`Writer`] returned by [`AssetCompression`], compressing one block at a time.
+struct CompressWriter {
+    writer: Box<Writer>,
+    compression: AssetCompression,
+    /// The plain bytes of the current block.
+    plain: Vec<u8>,
+    /// The last compressed block with its header, and how many of its bytes were written to `writer`.
+    block: Vec<u8>,
+    block_written: usize,
+}
+
+impl CompressWriter {
+    /// Compresses the plain bytes of the current block, if any, to be written by [`CompressWriter::poll_write_block`].
+    fn compress_block(&mut self) {
+        if self.plain.is_empty() {
+            return;
+        }
+        let compressed = self.compression.compress_block(&self.plain);
+        self.block.clear();
+        self.block
+            .extend_from_slice(&(self.plain.len() as u32).to_le_bytes());
+        self.block
+            .extend_from_slice(&(compressed.len() as u32).to_le_bytes());
+        self.block.extend_from_slice(&compressed);
+        self.block_written = 0;
+        self.plain.clear();
+    }
+
+    /// Writes the rest of the last compressed block to the wrapped writer.
+    fn poll_write_block(&mut self, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        while self.block_written < self.block.len() {
+            let n = ready!(
+                Pin::new(&mut self.writer).poll_write(cx, &self.block[self.block_written..])
+            )?;
+            if n == 0 {
+                return Poll::Ready(Err(ErrorKind::WriteZero.into()));
+            }
+            self.block_written += n;
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsyncWrite for CompressWriter {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        ready!(self.poll_write_block(cx))?;
+        if self.plain.len() == BLOCK_LEN {
+            self.compress_block();
+            ready!(self.poll_write_block(cx))?;
+        }
+        let n = buf.len().min(BLOCK_LEN - self.plain.len());
+        self.plain.extend_from_slice(&buf[..n]);
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        ready!(self.poll_write_block(cx))?;
+        self.compress_block();
+        ready!(self.poll_write_block(cx))?;
+        Pin::new(&mut self.writer).poll_flush(cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        ready!(self.poll_write_block(cx))?;
+        self.compress_block();
+        ready!(self.poll_write_block(cx))?;
+        Pin::new(&mut self.writer).poll_close(cx)
+    }
+}
+
+#[cfg(all(test, any(feature = "zstd", feature = "lz4")))]
+mod tests {
+    use super::{AssetCompression, BLOCK_LEN};
+    use crate::io::{
+        transform::{tests::SharedBuffer, AssetTransform},
+        AsyncSeekForwardExt, Reader, SliceReader, VecReader,
+    };
+    use alloc::{boxed::Box, vec::Vec};
+    use bevy_tasks::block_on;
+    use futures_lite::{AsyncReadExt, AsyncWriteExt};
+    use std::path::Path;
+
+    #[test]
+    fn compression_round_trip() {
+        let bytes = (0..3 * BLOCK_LEN + 100)
+            .map(|i| (i / 1000) as u8)
+            .collect::<Vec<_>>();
+        let path = Path::new("a.txt");
+        let compressions: Vec<AssetCompression> = alloc::vec![
+            #[cfg(feature = "zstd")]
+            AssetCompression::Zstd,
+            #[cfg(feature = "lz4")]
+            AssetCompression::Lz4,
+        ];
+        for compression in compressions {
+            let buffer = SharedBuffer::default();
+            block_on(async {
+                let mut writer = compression.encode(path, Box::new(buffer.clone()));
+                for chunk in bytes.chunks(10_000) {
+                    writer.write_all(chunk).await.unwrap();
+                }
+                writer.close().await.unwrap();
+            });
+            let encoded = buffer.take();
+            assert!(encoded.len() < bytes.len());
+
+            block_on(async {
+                let mut reader = compression
+                    .decode(path, Box::new(SliceReader::new(&encoded)))
+                    .await
+                    .unwrap();
+                let mut decoded = Vec::new();
+                Reader::read_to_end(&mut reader, &mut decoded)
+                    .await
+                    .unwrap();
+                assert_eq!(decoded, bytes);
+
+                // Seeking skips whole blocks, and lands in the middle of the next one.
+                let mut reader = compression
+                    .decode(path, Box::new(SliceReader::new(&encoded)))
+                    .await
+                    .unwrap();
+                let mut byte = [0];
+                reader.read_exact(&mut byte).await.unwrap();
+                let offset = 2 * BLOCK_LEN as u64 + 5_000;
+                assert_eq!(reader.seek_forward(offset).await.unwrap(), offset + 1);
+                reader.read_exact(&mut byte).await.unwrap();
+                assert_eq!(byte[0], bytes[offset as usize + 1]);
+
+                let mut reader = compression
+                    .decode(
+                        path,
+                        Box::new(VecReader::new(b"not compressed at all".to_vec())),
+                    )
+                    .await
+                    .unwrap();
+                assert!(Reader::read_to_end(&mut reader, &mut Vec::new())
+                    .await
+                    .is_err());
+            });
+        }
+    }
+}
@@ -0,0 +1,266 @@
+use crate::io::{
+    transform::{AssetTransform, BufferedTransformWriter},
+    Reader, VecReader, Writer,
+};
+use alloc::{boxed::Box, format, sync::Arc, vec::Vec};
+use bevy_tasks::BoxedFuture;
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng, Payload},
+    XChaCha20Poly1305, XNonce,
+};
+use std::{
+    io::{Error, ErrorKind},
+    path::Path,
+};
+
+/// A key used by [`AssetEncryption`], along with the id stored in the assets it encrypts.
+#[derive(Clone, Copy)]
+pub struct AssetKey {
+    /// The id of the key, used to find the key that decrypts an asset after keys were rotated.
+    pub id: u32,
+    /// The 256-bit key.
+    pub key: [u8; 32],
+}
+
+/// Provides the keys used by [`AssetEncryption`] to encrypt and decrypt assets.
+pub trait AssetKeyProvider: Send + Sync + 'static {
+    /// Returns the key used to encrypt the asset at `path`, or `None` if the asset is stored unencrypted.
+    fn key(&self, path: &Path) -> Option<AssetKey>;
+
+    /// Returns the key with the given `id`, used to decrypt the asset at `path`.
+    ///
+    /// Defaults to the key returned by [`AssetKeyProvider::key`] if it has this id. Override this to keep decrypting
+    /// assets encrypted with previous keys after rotating keys.
+    fn key_with_id(&self, path: &Path, id: u32) -> Option<[u8; 32]> {
+        self.key(path).filter(|key| key.id == id).map(|key| key.key)
+    }
+}
+
+/// Uses the same key for every asset.
+impl AssetKeyProvider for AssetKey {
+    fn key(&self, _path: &Path) -> Option<AssetKey> {
+        Some(*self)
+    }
+}
+
+/// Uses the same key for every asset, with the id `0`.
+impl AssetKeyProvider for [u8; 32] {
+    fn key(&self, _path: &Path) -> Option<AssetKey> {
+        Some(AssetKey { id: 0, key: *self })
+    }
+}
+
+/// Uses the key returned by the function for each asset, with the id `0`.
+impl<F: Fn(&Path) -> Option<[u8; 32]> + Send + Sync + 'static> AssetKeyProvider for F {
+    fn key(&self, path: &Path) -> Option<AssetKey> {
+        self(path).map(|key| AssetKey { id: 0, key })
+    }
+}
+
+/// An [`AssetTransform`] that encrypts and authenticates assets with keys from an [`AssetKeyProvider`].
+///
+/// Assets are encrypted with [XChaCha20-Poly1305](https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-xchacha), using
+/// a random nonce from the operating system's random number generator. Decrypting an asset that was modified or
+/// encrypted with a different key fails with [`ErrorKind::InvalidData`].
+///
+/// An encrypted asset must be authenticated in full before any of it is returned, so unlike [`AssetCompression`](super::AssetCompression)
+/// this doesn't stream: encrypted assets are read and decrypted in full, and are encrypted and written in full when
+/// their [`Writer`] is flushed or closed.
+///
+/// Note that keys shipped with an application can be extracted from it, so this protects assets from casual
+/// inspection and tampering rather than from a determined attacker.
+#[derive(Clone)]
+pub struct AssetEncryption {
+    keys: Arc<dyn AssetKeyProvider>,
+}
+
+/// Marks the start of an encrypted asset, followed by the format version, the key id, the nonce, and the ciphertext
+/// with its authentication tag. The magic, version and key id are authenticated along with the ciphertext.
+const MAGIC: &[u8; 7] = b"BEVYENC";
+const VERSION: u8 = 1;
+const HEADER_LEN: usize = MAGIC.len() + 1 + 4;
+const NONCE_LEN: usize = 24;
+
+impl AssetEncryption {
+    /// Creates a new [`AssetEncryption`] using the keys provided by `keys`.
+    pub fn new(keys: impl AssetKeyProvider) -> Self {
+        Self {
+            keys: Arc::new(keys),
+        }
+    }
+}
+
+fn encrypt(key: AssetKey, bytes: &[u8]) -> std::io::Result<Vec<u8>> {
+    let mut encrypted = Vec::with_capacity(HEADER_LEN + NONCE_LEN + bytes.len() + 16);
+    encrypted.extend_from_slice(MAGIC);
+    encrypted.push(VERSION);
+    encrypted.extend_from_slice(&key.id.to_le_bytes());
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = XChaCha20Poly1305::new(&key.key.into())
+        .encrypt(
+            &nonce,
+            Payload {
+                msg: bytes,
+                aad: &encrypted,
+            },
+        )
+        .map_err(|_| Error::other("failed to encrypt asset"))?;
+    encrypted.extend_from_slice(&nonce);
+    encrypted.extend_from_slice(&ciphertext);
+    Ok(encrypted)
+}
+
+fn decrypt(keys: &dyn AssetKeyProvider, path: &Path, bytes: &[u8]) -> std::io::Result<Vec<u8>> {
+    let invalid = |message: &str| Error::new(ErrorKind::InvalidData, message);
+    let Some(rest) = bytes.strip_prefix(MAGIC) else {
+        return Err(invalid("asset is not encrypted"));
+    };
+    if rest.len() < HEADER_LEN - MAGIC.len() + NONCE_LEN {
+        return Err(invalid("encrypted asset is truncated"));
+    }
+    if rest[0] != VERSION {
+        return Err(invalid("encrypted asset has an unsupported format version"));
+    }
+    let id = u32::from_le_bytes(rest[1..5].try_into().unwrap());
+    let key = keys.key_with_id(path, id).ok_or_else(|| {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("no key with id {id} to decrypt asset"),
+        )
+    })?;
+    let (header, rest) = bytes.split_at(HEADER_LEN);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    XChaCha20Poly1305::new(&key.into())
+        .decrypt(
+            XNonce::from_slice(nonce),
+            Payload {
+                msg: ciphertext,
+                aad: header,
+            },
+        )
+        .map_err(|_| {
+            invalid("encrypted asset failed authentication: it was modified or the key is wrong")
+        })
+}
+
+impl AssetTransform for AssetEncryption {
+    fn decode<'a>(
+        &'a self,
+        path: &'a Path,
+        mut reader: Box<dyn Reader + 'a>,
+    ) -> BoxedFuture<'a, std::io::Result<Box<dyn Reader + 'a>>> {
+        Box::pin(async move {
+            if self.keys.key(path).is_none() {
+                return Ok(reader);
+            }
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            let decrypted = decrypt(&*self.keys, path, &bytes)?;
+            let reader: Box<dyn Reader + 'a> = Box::new(VecReader::new(decrypted));
+            Ok(reader)
+        })
+    }
+
+    fn encode(&self, path: &Path, writer: Box<Writer>) -> Box<Writer> {
+        match self.keys.key(path) {
+            Some(key) => Box::new(BufferedTransformWriter::new(
+                writer,
+                move |bytes: &[u8]| encrypt(key, bytes),
+            )),
+            None => writer,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AssetEncryption, AssetKey, AssetKeyProvider};
+    use crate::io::{
+        transform::{tests::SharedBuffer, AssetTransform},
+        Reader, VecReader,
+    };
+    use alloc::{boxed::Box, vec::Vec};
+    use bevy_tasks::block_on;
+    use futures_lite::AsyncWriteExt;
+    use std::path::Path;
+
+    fn encrypt(encryption: &AssetEncryption, path: &Path, bytes: &[u8]) -> Vec<u8> {
+        let buffer = SharedBuffer::default();
+        block_on(async {
+            let mut writer = encryption.encode(path, Box::new(buffer.clone()));
+            writer.write_all(bytes).await.unwrap();
+            writer.close().await.unwrap();
+        });
+        buffer.take()
+    }
+
+    fn decrypt(
+        encryption: &AssetEncryption,
+        path: &Path,
+        bytes: &[u8],
+    ) -> std::io::Result<Vec<u8>> {
+        block_on(async {
+            let mut reader = encryption
+                .decode(path, Box::new(VecReader::new(bytes.to_vec())))
+                .await?;
+            let mut decrypted = Vec::new();
+            reader.read_to_end(&mut decrypted).await?;
+            Ok(decrypted)
+        })
+    }
+
+    #[test]
+    fn encryption_round_trip() {
+        let encryption = AssetEncryption::new([1; 32]);
+        let path = Path::new("a.txt");
+        let encrypted = encrypt(&encryption, path, b"secret");
+        assert_ne!(encrypt(&encryption, path, b"secret"), encrypted);
+        assert_eq!(decrypt(&encryption, path, &encrypted).unwrap(), b"secret");
+
+        let wrong_key = AssetEncryption::new([2; 32]);
+        assert!(decrypt(&wrong_key, path, &encrypted).is_err());
+
+        let mut tampered = encrypted.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(decrypt(&encryption, path, &tampered).is_err());
+        assert!(decrypt(&encryption, path, b"secret").is_err());
+
+        let only_secrets =
+            AssetEncryption::new(|path: &Path| path.starts_with("secrets").then_some([1; 32]));
+        assert_eq!(encrypt(&only_secrets, path, b"public"), b"public");
+        assert_eq!(decrypt(&only_secrets, path, b"public").unwrap(), b"public");
+    }
+
+    #[test]
+    fn encryption_key_rotation() {
+        struct RotatedKeys;
+
+        impl AssetKeyProvider for RotatedKeys {
+            fn key(&self, _path: &Path) -> Option<AssetKey> {
+                Some(AssetKey {
+                    id: 2,
+                    key: [2; 32],
+                })
+            }
+
+            fn key_with_id(&self, _path: &Path, id: u32) -> Option<[u8; 32]> {
+                (1..=2).contains(&id).then_some([id as u8; 32])
+            }
+        }
+
+        let path = Path::new("a.txt");
+        let old = encrypt(
+            &AssetEncryption::new(AssetKey {
+                id: 1,
+                key: [1; 32],
+            }),
+            path,
+            b"old",
+        );
+        let rotated = AssetEncryption::new(RotatedKeys);
+        assert_eq!(decrypt(&rotated, path, &old).unwrap(), b"old");
+        let new = encrypt(&rotated, path, b"new");
+        assert_eq!(decrypt(&rotated, path, &new).unwrap(), b"new");
+        assert!(decrypt(&AssetEncryption::new([1; 32]), path, &new).is_err());
+    }
+}
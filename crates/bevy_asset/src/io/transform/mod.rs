@@ -0,0 +1,376 @@
+//! [`AssetReader`] and [`AssetWriter`] adapters that transparently transform the bytes of assets stored in another
+//! [`AssetSource`](crate::io::AssetSource), such as compressing or encrypting them.
+//!
+//! Adapters compose, so assets can be both compressed and encrypted by wrapping a [`TransformedAssetReader`] in another
+//! [`TransformedAssetReader`]. Writers must apply the transforms in the opposite order to their readers:
+//!
+//! ```
+//! # use bevy_asset::io::{AssetSource, AssetSourceBuilder, transform::*};
+//! # #[cfg(all(feature = "encryption", feature = "zstd"))]
+//! # fn build(key: [u8; 32]) -> AssetSourceBuilder {
+//! AssetSource::build()
+//!     .with_reader(move || {
+//!         let reader = AssetSource::get_default_reader("assets".into())();
+//!         let reader = TransformedAssetReader::new(reader, AssetEncryption::new(key));
+//!         let reader = TransformedAssetReader::new(Box::new(reader), AssetCompression::Zstd);
+//!         Box::new(reader)
+//!     })
+//! # }
+//! ```
+//!
+//! [`AssetCompression`] requires the `zstd` or `lz4` feature, and [`AssetEncryption`] requires the `encryption`
+//! feature. Through `bevy`, these are the `asset_zstd`, `asset_lz4` and `asset_encryption` features.
+
+mod compression;
+#[cfg(feature = "encryption")]
+mod encryption;
+
+pub use compression::*;
+#[cfg(feature = "encryption")]
+pub use encryption::*;
+
+use crate::io::{
+    AssetReader, AssetReaderError, AssetWriter, AssetWriterError, ErasedAssetReader,
+    ErasedAssetWriter, PathStream, Reader, Writer,
+};
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use bevy_tasks::BoxedFuture;
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_io::AsyncWrite;
+use futures_lite::ready;
+use std::path::Path;
+
+/// A reversible transformation of the bytes of an asset (or its meta), applied by [`TransformedAssetReader`] and
+/// [`TransformedAssetWriter`].
+///
+/// Transforms should stream the bytes they decode and encode when possible. Transforms that can only process whole
+/// assets can read the stored bytes in full and return a [`VecReader`](crate::io::VecReader), and encode with a
+/// [`BufferedTransformWriter`].
+pub trait AssetTransform: Send + Sync + 'static {
+    /// Returns a [`Reader`] of the plain bytes of the asset at `path`, decoded from the stored bytes read by `reader`.
+    fn decode<'a>(
+        &'a self,
+        path: &'a Path,
+        reader: Box<dyn Reader + 'a>,
+    ) -> BoxedFuture<'a, std::io::Result<Box<dyn Reader + 'a>>>;
+    /// Returns a [`Writer`] encoding the plain bytes of the asset at `path`, and writing the stored bytes to `writer`.
+    fn encode(&self, path: &Path, writer: Box<Writer>) -> Box<Writer>;
+}
+
+/// An [`AssetReader`] that decodes the bytes read by another [`AssetReader`] with an [`AssetTransform`].
+pub struct TransformedAssetReader {
+    reader: Box<dyn ErasedAssetReader>,
+    transform: Arc<dyn AssetTransform>,
+    transform_meta: bool,
+}
+
+impl TransformedAssetReader {
+    /// Creates a new [`TransformedAssetReader`], which decodes assets and meta files read by `reader` with `transform`.
+    pub fn new(reader: Box<dyn ErasedAssetReader>, transform: impl AssetTransform) -> Self {
+        Self {
+            reader,
+            transform: Arc::new(transform),
+            transform_meta: true,
+        }
+    }
+
+    /// Sets whether meta files are decoded in addition to assets. Defaults to `true`.
+    pub fn with_transform_meta(mut self, transform_meta: bool) -> Self {
+        self.transform_meta = transform_meta;
+        self
+    }
+}
+
+impl AssetReader for TransformedAssetReader {
+    async fn read<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        let reader = self.reader.read(path).await?;
+        Ok(self.transform.decode(path, reader).await?)
+    }
+
+    async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        let reader = self.reader.read_meta(path).await?;
+        if !self.transform_meta {
+            return Ok(reader);
+        }
+        Ok(self.transform.decode(path, reader).await?)
+    }
+
+    async fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<Box<PathStream>, AssetReaderError> {
+        self.reader.read_directory(path).await
+    }
+
+    async fn is_directory<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        self.reader.is_directory(path).await
+    }
+}
+
+/// An [`AssetWriter`] that encodes the bytes written to another [`AssetWriter`] with an [`AssetTransform`].
+pub struct TransformedAssetWriter {
+    writer: Box<dyn ErasedAssetWriter>,
+    transform: Arc<dyn AssetTransform>,
+    transform_meta: bool,
+}
+
+impl TransformedAssetWriter {
+    /// Creates a new [`TransformedAssetWriter`], which encodes assets and meta files with `transform` before writing
+    /// them to `writer`.
+    pub fn new(writer: Box<dyn ErasedAssetWriter>, transform: impl AssetTransform) -> Self {
+        Self {
+            writer,
+            transform: Arc::new(transform),
+            transform_meta: true,
+        }
+    }
+
+    /// Sets whether meta files are encoded in addition to assets. Defaults to `true`.
+    pub fn with_transform_meta(mut self, transform_meta: bool) -> Self {
+        self.transform_meta = transform_meta;
+        self
+    }
+}
+
+impl AssetWriter for TransformedAssetWriter {
+    async fn write<'a>(&'a self, path: &'a Path) -> Result<Box<Writer>, AssetWriterError> {
+        let writer = self.writer.write(path).await?;
+        Ok(self.transform.encode(path, writer))
+    }
+
+    async fn write_meta<'a>(&'a self, path: &'a Path) -> Result<Box<Writer>, AssetWriterError> {
+        let writer = self.writer.write_meta(path).await?;
+        if !self.transform_meta {
+            return Ok(writer);
+        }
+        Ok(self.transform.encode(path, writer))
+    }
+
+    async fn remove<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        self.writer.remove(path).await
+    }
+
+    async fn remove_meta<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        self.writer.remove_meta(path).await
+    }
+
+    async fn rename<'a>(
+        &'a self,
+        old_path: &'a Path,
+        new_path: &'a Path,
+    ) -> Result<(), AssetWriterError> {
+        self.writer.rename(old_path, new_path).await
+    }
+
+    async fn rename_meta<'a>(
+        &'a self,
+        old_path: &'a Path,
+        new_path: &'a Path,
+    ) -> Result<(), AssetWriterError> {
+        self.writer.rename_meta(old_path, new_path).await
+    }
+
+    async fn create_directory<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        self.writer.create_directory(path).await
+    }
+
+    async fn remove_directory<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        self.writer.remove_directory(path).await
+    }
+
+    async fn remove_empty_directory<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        self.writer.remove_empty_directory(path).await
+    }
+
+    async fn remove_assets_in_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<(), AssetWriterError> {
+        self.writer.remove_assets_in_directory(path).await
+    }
+}
+
+/// A [`Writer`] that buffers the plain bytes written to it, and encodes them all at once when it's flushed or closed,
+/// for [`AssetTransform`]s that can only encode whole assets.
+///
+/// Writing after the [`Writer`] was flushed or closed returns an error.
+pub struct BufferedTransformWriter<F> {
+    writer: Box<Writer>,
+    encode: Option<F>,
+    /// The plain bytes written so far.
+    buffer: Vec<u8>,
+    /// The encoded bytes, and how many of them have been written to `writer`.
+    encoded: Option<(Vec<u8>, usize)>,
+}
+
+impl<F: FnOnce(&[u8]) -> std::io::Result<Vec<u8>> + Unpin> BufferedTransformWriter<F> {
+    /// Creates a new [`BufferedTransformWriter`], which writes the plain bytes encoded with `encode` to `writer`.
+    pub fn new(writer: Box<Writer>, encode: F) -> Self {
+        Self {
+            writer,
+            encode: Some(encode),
+            buffer: Vec::new(),
+            encoded: None,
+        }
+    }
+
+    /// Encodes the buffered bytes (if that hasn't happened yet) and writes them to the wrapped writer.
+    fn poll_write_encoded(&mut self, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        if let Some(encode) = self.encode.take() {
+            let encoded = encode(&self.buffer)?;
+            self.buffer = Vec::new();
+            self.encoded = Some((encoded, 0));
+        }
+        let Some((encoded, written)) = self.encoded.as_mut() else {
+            // Encoding failed.
+            return Poll::Ready(Err(std::io::ErrorKind::InvalidData.into()));
+        };
+        while *written < encoded.len() {
+            let n = ready!(Pin::new(&mut self.writer).poll_write(cx, &encoded[*written..]))?;
+            if n == 0 {
+                return Poll::Ready(Err(std::io::ErrorKind::WriteZero.into()));
+            }
+            *written += n;
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<F: FnOnce(&[u8]) -> std::io::Result<Vec<u8>> + Unpin> AsyncWrite
+    for BufferedTransformWriter<F>
+{
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        if self.encode.is_none() {
+            return Poll::Ready(Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "cannot write to a transformed asset after it has been flushed",
+            )));
+        }
+        self.buffer.extend_from_slice(buf);
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        ready!(self.poll_write_encoded(cx))?;
+        Pin::new(&mut self.writer).poll_flush(cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        ready!(self.poll_write_encoded(cx))?;
+        Pin::new(&mut self.writer).poll_close(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AssetTransform, BufferedTransformWriter, TransformedAssetReader};
+    use crate::io::{
+        memory::{Dir, MemoryAssetReader},
+        AssetReader, AssetReaderError, Reader, VecReader, Writer,
+    };
+    use alloc::{boxed::Box, sync::Arc, vec::Vec};
+    use bevy_tasks::{block_on, BoxedFuture};
+    use core::{
+        pin::Pin,
+        task::{Context, Poll},
+    };
+    use futures_io::AsyncWrite;
+    use futures_lite::AsyncWriteExt;
+    use std::{path::Path, sync::Mutex};
+
+    /// A [`Writer`] to a buffer that can be read once it was written.
+    #[derive(Clone, Default)]
+    pub(super) struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl SharedBuffer {
+        pub(super) fn take(&self) -> Vec<u8> {
+            core::mem::take(&mut self.0.lock().unwrap())
+        }
+    }
+
+    impl AsyncWrite for SharedBuffer {
+        fn poll_write(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<std::io::Result<usize>> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Poll::Ready(Ok(buf.len()))
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    /// Reverses whole assets, and fails to decode empty ones.
+    struct Reverse;
+
+    impl AssetTransform for Reverse {
+        fn decode<'a>(
+            &'a self,
+            _path: &'a Path,
+            mut reader: Box<dyn Reader + 'a>,
+        ) -> BoxedFuture<'a, std::io::Result<Box<dyn Reader + 'a>>> {
+            Box::pin(async move {
+                let mut bytes = Vec::new();
+                reader.read_to_end(&mut bytes).await?;
+                if bytes.is_empty() {
+                    return Err(std::io::ErrorKind::InvalidData.into());
+                }
+                bytes.reverse();
+                let reader: Box<dyn Reader + 'a> = Box::new(VecReader::new(bytes));
+                Ok(reader)
+            })
+        }
+
+        fn encode(&self, _path: &Path, writer: Box<Writer>) -> Box<Writer> {
+            Box::new(BufferedTransformWriter::new(writer, |bytes: &[u8]| {
+                Ok(bytes.iter().rev().copied().collect())
+            }))
+        }
+    }
+
+    #[test]
+    fn transformed_reader_round_trip() {
+        let path = Path::new("secret.txt");
+
+        let buffer = SharedBuffer::default();
+        let mut writer = Reverse.encode(path, Box::new(buffer.clone()));
+        block_on(writer.write_all(b"hello ")).unwrap();
+        block_on(writer.write_all(b"world")).unwrap();
+        block_on(writer.flush()).unwrap();
+        assert!(block_on(writer.write_all(b"!")).is_err());
+        drop(writer);
+        let encoded = buffer.take();
+        assert_eq!(encoded, b"dlrow olleh");
+
+        let dir = Dir::default();
+        dir.insert_asset(path, encoded);
+        let reader =
+            TransformedAssetReader::new(Box::new(MemoryAssetReader { root: dir.clone() }), Reverse);
+        let mut bytes = Vec::new();
+        block_on(async {
+            let mut reader = reader.read(path).await.unwrap();
+            Reader::read_to_end(&mut reader, &mut bytes).await.unwrap();
+        });
+        assert_eq!(bytes, b"hello world");
+
+        dir.insert_asset_text(Path::new("empty.txt"), "");
+        assert!(matches!(
+            block_on(reader.read(Path::new("empty.txt"))).err(),
+            Some(AssetReaderError::Io(_))
+        ));
+    }
+}
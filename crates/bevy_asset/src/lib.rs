@@ -149,6 +149,7 @@
 extern crate alloc;
 extern crate std;
 
+pub mod graph;
 pub mod io;
 pub mod meta;
 pub mod processor;
@@ -361,6 +362,7 @@ impl Plugin for AssetPlugin {
                         app.insert_resource(AssetServer::new_with_loaders(
                             sources,
                             processor.server().data.loaders.clone(),
+                            processor.server().data.meta_migrations.clone(),
                             AssetServerMode::Processed,
                             AssetMetaCheck::Always,
                             watch,
@@ -509,6 +511,32 @@ pub trait AssetApp {
     /// Preregisters a loader for the given extensions, that will block asset loads until a real loader
     /// is registered.
     fn preregister_asset_loader<L: AssetLoader>(&mut self, extensions: &[&str]) -> &mut Self;
+    /// Registers a migration of the [`AssetLoader::Settings`] of `L` from `from_version` to `from_version + 1`, which
+    /// is applied to `.meta` files written with an older [`AssetLoader::SETTINGS_VERSION`].
+    ///
+    /// `Old` is the settings type as it was serialized at `from_version`, and `New` is the settings type at the next version.
+    fn register_loader_settings_migration<L, Old, New>(
+        &mut self,
+        from_version: u32,
+        migrate: impl Fn(Old) -> New + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        L: AssetLoader,
+        Old: serde::Serialize + serde::de::DeserializeOwned,
+        New: serde::Serialize;
+    /// Registers a migration of the [`Process::Settings`] of `P` from `from_version` to `from_version + 1`, which
+    /// is applied to `.meta` files written with an older [`Process::SETTINGS_VERSION`].
+    ///
+    /// `Old` is the settings type as it was serialized at `from_version`, and `New` is the settings type at the next version.
+    fn register_processor_settings_migration<P, Old, New>(
+        &mut self,
+        from_version: u32,
+        migrate: impl Fn(Old) -> New + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        P: Process,
+        Old: serde::Serialize + serde::de::DeserializeOwned,
+        New: serde::Serialize;
 }
 
 impl AssetApp for App {
@@ -612,6 +640,38 @@ impl AssetApp for App {
             .preregister_loader::<L>(extensions);
         self
     }
+
+    fn register_loader_settings_migration<L, Old, New>(
+        &mut self,
+        from_version: u32,
+        migrate: impl Fn(Old) -> New + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        L: AssetLoader,
+        Old: serde::Serialize + serde::de::DeserializeOwned,
+        New: serde::Serialize,
+    {
+        self.world()
+            .resource::<AssetServer>()
+            .register_loader_settings_migration::<L, Old, New>(from_version, migrate);
+        self
+    }
+
+    fn register_processor_settings_migration<P, Old, New>(
+        &mut self,
+        from_version: u32,
+        migrate: impl Fn(Old) -> New + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        P: Process,
+        Old: serde::Serialize + serde::de::DeserializeOwned,
+        New: serde::Serialize,
+    {
+        self.world()
+            .resource::<AssetServer>()
+            .register_processor_settings_migration::<P, Old, New>(from_version, migrate);
+        self
+    }
 }
 
 /// A system set that holds all "track asset" operations.
@@ -628,7 +688,7 @@ pub struct AssetEvents;
 mod tests {
     use crate::{
         self as bevy_asset,
-        folder::LoadedFolder,
+        folder::{FolderFilter, LoadedFolder},
         handle::Handle,
         io::{
             gated::{GateOpener, GatedReader},
@@ -1632,6 +1692,89 @@ mod tests {
         });
     }
 
+    #[test]
+    fn load_glob() {
+        // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
+        #[cfg(not(feature = "multi_threaded"))]
+        panic!("This test requires the \"multi_threaded\" feature, otherwise it will deadlock.\ncargo test --package bevy_asset --features multi_threaded");
+
+        let dir = Dir::default();
+
+        let text_ron = |text: &str| {
+            format!(
+                r#"
+(
+    text: "{text}",
+    dependencies: [],
+    embedded_dependencies: [],
+    sub_texts: [],
+)"#
+            )
+        };
+        let a_path = "text/a.cool.ron";
+        let c_path = "text/nested/c.cool.ron";
+        dir.insert_asset_text(Path::new(a_path), &text_ron("a"));
+        dir.insert_asset_text(Path::new("b.cool.ron"), &text_ron("b"));
+        dir.insert_asset_text(Path::new(c_path), &text_ron("c"));
+        dir.insert_asset_text(Path::new("text/skip/d.cool.ron"), &text_ron("d"));
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let handle = asset_server.load_glob_with_filter(
+            "text/**/*.cool.ron",
+            FolderFilter::default()
+                .with_exclude("**/skip/**")
+                .with_asset_type::<CoolText>(),
+        );
+        gate_opener.open(a_path);
+        gate_opener.open(c_path);
+
+        run_app_until(&mut app, |world| {
+            let asset_server = world.resource::<AssetServer>();
+            if !asset_server.is_loaded_with_dependencies(&handle) {
+                return None;
+            }
+            let loaded_folder = world
+                .resource::<Assets<LoadedFolder>>()
+                .get(&handle)
+                .unwrap();
+            let mut paths = loaded_folder
+                .handles
+                .iter()
+                .map(|handle| handle.path().unwrap().to_string())
+                .collect::<Vec<_>>();
+            paths.sort();
+            assert_eq!(paths, vec![a_path, c_path]);
+            Some(())
+        });
+    }
+
+    #[test]
+    fn load_glob_handles_are_keyed_by_filter() {
+        let (mut app, _gate_opener) = test_app(Dir::default());
+        app.init_asset::<CoolText>();
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let filter = || FolderFilter::default().with_exclude("**/skip/**");
+
+        let unfiltered = asset_server.load_glob("text/**/*.cool.ron");
+        let filtered = asset_server.load_glob_with_filter("text/**/*.cool.ron", filter());
+        let other = asset_server
+            .load_glob_with_filter("text/**/*.cool.ron", filter().with_asset_type::<CoolText>());
+        assert_ne!(unfiltered, filtered);
+        assert_ne!(filtered, other);
+        assert_eq!(
+            filtered,
+            asset_server.load_glob_with_filter("text/**/*.cool.ron", filter())
+        );
+        assert_eq!(
+            unfiltered,
+            asset_server.load_glob_with_filter("text/**/*.cool.ron", FolderFilter::default())
+        );
+    }
+
     /// Tests that `AssetLoadFailedEvent<A>` events are emitted and can be used to retry failed assets.
     #[test]
     fn load_error_events() {
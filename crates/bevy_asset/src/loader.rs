@@ -35,6 +35,11 @@ pub trait AssetLoader: Send + Sync + 'static {
     type Settings: Settings + Default + Serialize + for<'a> Deserialize<'a>;
     /// The type of [error](`std::error::Error`) which could be encountered by this loader.
     type Error: Into<Box<dyn core::error::Error + Send + Sync + 'static>>;
+    /// The version of [`AssetLoader::Settings`], which is written to `.meta` files alongside the settings.
+    ///
+    /// Bump this whenever [`AssetLoader::Settings`] changes in a way that breaks existing `.meta` files, and register a
+    /// migration from the previous version with [`AssetApp::register_loader_settings_migration`](crate::AssetApp::register_loader_settings_migration).
+    const SETTINGS_VERSION: u32 = 0;
     /// Asynchronously loads [`AssetLoader::Asset`] (and any other labeled assets) from the bytes provided by [`Reader`].
     fn load(
         &self,
@@ -71,6 +76,8 @@ pub trait ErasedAssetLoader: Send + Sync + 'static {
     fn default_meta(&self) -> Box<dyn AssetMetaDyn>;
     /// Returns the type name of the [`AssetLoader`].
     fn type_name(&self) -> &'static str;
+    /// Returns the current [`AssetLoader::SETTINGS_VERSION`].
+    fn settings_version(&self) -> u32;
     /// Returns the [`TypeId`] of the [`AssetLoader`].
     fn type_id(&self) -> TypeId;
     /// Returns the type name of the top-level [`Asset`] loaded by the [`AssetLoader`].
@@ -126,6 +133,10 @@ where
         core::any::type_name::<L>()
     }
 
+    fn settings_version(&self) -> u32 {
+        L::SETTINGS_VERSION
+    }
+
     fn type_id(&self) -> TypeId {
         TypeId::of::<L>()
     }
@@ -309,6 +320,19 @@ pub enum DeserializeMetaError {
     DeserializeSettings(#[from] SpannedError),
     #[error("Failed to deserialize minimal asset meta: {0:?}")]
     DeserializeMinimal(SpannedError),
+    #[error("Asset meta for {type_name} has settings version {version}, which is newer than the supported version {current_version}")]
+    UnsupportedSettingsVersion {
+        type_name: String,
+        version: u32,
+        current_version: u32,
+    },
+    #[error("No settings migration is registered for {type_name} from version {from_version}")]
+    MissingMigration {
+        type_name: String,
+        from_version: u32,
+    },
+    #[error("Failed to serialize migrated asset meta: {0:?}")]
+    SerializeMigrated(ron::Error),
 }
 
 /// A context that provides access to assets in [`AssetLoader`]s, tracks dependencies, and collects asset load state.
@@ -1,4 +1,5 @@
 use alloc::{
+    borrow::Cow,
     boxed::Box,
     string::{String, ToString},
     vec::Vec,
@@ -8,9 +9,10 @@ use crate::{
     self as bevy_asset, loader::AssetLoader, processor::Process, Asset, AssetPath,
     DeserializeMetaError, VisitAssetDependencies,
 };
+use bevy_platform_support::collections::HashMap;
 use downcast_rs::{impl_downcast, Downcast};
 use ron::ser::PrettyConfig;
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use tracing::error;
 
 pub const META_FORMAT_VERSION: &str = "1.0";
@@ -25,6 +27,10 @@ pub struct AssetMeta<L: AssetLoader, P: Process> {
     /// The version of the meta format being used. This will change whenever a breaking change is made to
     /// the meta format.
     pub meta_format_version: String,
+    /// The version of the loader or processor settings stored in [`AssetMeta::asset`].
+    /// See [`AssetLoader::SETTINGS_VERSION`] and [`Process::SETTINGS_VERSION`]. This is omitted from the serialized meta when it is `0`.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub settings_version: u32,
     /// Information produced by the [`AssetProcessor`] _after_ processing this asset.
     /// This will only exist alongside processed versions of assets. You should not manually set it in your asset source files.
     ///
@@ -37,8 +43,14 @@ pub struct AssetMeta<L: AssetLoader, P: Process> {
 
 impl<L: AssetLoader, P: Process> AssetMeta<L, P> {
     pub fn new(asset: AssetAction<L::Settings, P::Settings>) -> Self {
+        let settings_version = match &asset {
+            AssetAction::Load { .. } => L::SETTINGS_VERSION,
+            AssetAction::Process { .. } => P::SETTINGS_VERSION,
+            AssetAction::Ignore => 0,
+        };
         Self {
             meta_format_version: META_FORMAT_VERSION.to_string(),
+            settings_version,
             processed_info: None,
             asset,
         }
@@ -101,6 +113,8 @@ pub struct ProcessDependencyInfo {
 // using a type registry.
 #[derive(Serialize, Deserialize)]
 pub struct AssetMetaMinimal {
+    #[serde(default)]
+    pub settings_version: u32,
     pub asset: AssetActionMinimal,
 }
 
@@ -238,6 +252,189 @@ pub(crate) fn loader_settings_meta_transform<S: Settings>(
     Box::new(move |meta| meta_transform_settings(meta, &settings))
 }
 
+fn is_zero(value: &u32) -> bool {
+    *value == 0
+}
+
+/// A type-erased function that upgrades serialized [`AssetMeta`] from one settings version to the next.
+pub type MetaMigrationFn =
+    Box<dyn Fn(&[u8]) -> Result<Vec<u8>, DeserializeMetaError> + Send + Sync>;
+
+/// The registered settings migrations for [`AssetLoader`]s and [`Process`]ors, keyed by their type name.
+///
+/// Whenever the `Settings` type of a loader or processor changes in a way that breaks existing `.meta` files, bump its
+/// `SETTINGS_VERSION` and register a migration from the previous version. `.meta` files with an older `settings_version`
+/// will have every migration between their version and the current one applied before they are deserialized.
+///
+/// Migrations are generally registered with [`AssetApp::register_loader_settings_migration`] and
+/// [`AssetApp::register_processor_settings_migration`].
+///
+/// [`AssetApp::register_loader_settings_migration`]: crate::AssetApp::register_loader_settings_migration
+/// [`AssetApp::register_processor_settings_migration`]: crate::AssetApp::register_processor_settings_migration
+#[derive(Default)]
+pub struct MetaMigrations {
+    migrations: HashMap<&'static str, HashMap<u32, MetaMigrationFn>>,
+}
+
+/// Mirrors the serialized layout of [`AssetMeta`], with arbitrary settings types. This is used to read and write
+/// the intermediate versions of a meta file while it is being migrated.
+#[derive(Serialize, Deserialize)]
+struct VersionedAssetMeta<LoaderSettings, ProcessSettings> {
+    meta_format_version: String,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    settings_version: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    processed_info: Option<ProcessedInfo>,
+    asset: AssetAction<LoaderSettings, ProcessSettings>,
+}
+
+impl<LoaderSettings, ProcessSettings> VersionedAssetMeta<LoaderSettings, ProcessSettings>
+where
+    LoaderSettings: Serialize + DeserializeOwned,
+    ProcessSettings: Serialize + DeserializeOwned,
+{
+    fn migrate<NewLoaderSettings, NewProcessSettings>(
+        bytes: &[u8],
+        migrate: impl FnOnce(
+            AssetAction<LoaderSettings, ProcessSettings>,
+        ) -> AssetAction<NewLoaderSettings, NewProcessSettings>,
+    ) -> Result<Vec<u8>, DeserializeMetaError>
+    where
+        NewLoaderSettings: Serialize,
+        NewProcessSettings: Serialize,
+    {
+        let meta: Self = ron::de::from_bytes(bytes)?;
+        let migrated = VersionedAssetMeta {
+            meta_format_version: meta.meta_format_version,
+            settings_version: meta.settings_version + 1,
+            processed_info: meta.processed_info,
+            asset: migrate(meta.asset),
+        };
+        Ok(
+            ron::ser::to_string_pretty(&migrated, PrettyConfig::default())
+                .map_err(DeserializeMetaError::SerializeMigrated)?
+                .into_bytes(),
+        )
+    }
+}
+
+impl MetaMigrations {
+    /// Registers a migration of the [`AssetLoader::Settings`] of `L` from `from_version` to `from_version + 1`.
+    ///
+    /// `Old` is the settings type as it was serialized at `from_version`, and `New` is the settings type at the next version.
+    pub fn register_loader_migration<L, Old, New>(
+        &mut self,
+        from_version: u32,
+        migrate: impl Fn(Old) -> New + Send + Sync + 'static,
+    ) where
+        L: AssetLoader,
+        Old: Serialize + DeserializeOwned,
+        New: Serialize,
+    {
+        self.register(
+            core::any::type_name::<L>(),
+            from_version,
+            Box::new(move |bytes| {
+                VersionedAssetMeta::<Old, ()>::migrate(bytes, |action| match action {
+                    AssetAction::Load { loader, settings } => AssetAction::<New, ()>::Load {
+                        loader,
+                        settings: migrate(settings),
+                    },
+                    AssetAction::Process { processor, .. } => AssetAction::Process {
+                        processor,
+                        settings: (),
+                    },
+                    AssetAction::Ignore => AssetAction::Ignore,
+                })
+            }),
+        );
+    }
+
+    /// Registers a migration of the [`Process::Settings`] of `P` from `from_version` to `from_version + 1`.
+    ///
+    /// `Old` is the settings type as it was serialized at `from_version`, and `New` is the settings type at the next version.
+    pub fn register_processor_migration<P, Old, New>(
+        &mut self,
+        from_version: u32,
+        migrate: impl Fn(Old) -> New + Send + Sync + 'static,
+    ) where
+        P: Process,
+        Old: Serialize + DeserializeOwned,
+        New: Serialize,
+    {
+        self.register(
+            core::any::type_name::<P>(),
+            from_version,
+            Box::new(move |bytes| {
+                VersionedAssetMeta::<(), Old>::migrate(bytes, |action| match action {
+                    AssetAction::Process {
+                        processor,
+                        settings,
+                    } => AssetAction::<(), New>::Process {
+                        processor,
+                        settings: migrate(settings),
+                    },
+                    AssetAction::Load { loader, .. } => AssetAction::Load {
+                        loader,
+                        settings: (),
+                    },
+                    AssetAction::Ignore => AssetAction::Ignore,
+                })
+            }),
+        );
+    }
+
+    /// Registers a type-erased migration for the loader or processor with the given `type_name`.
+    pub fn register(
+        &mut self,
+        type_name: &'static str,
+        from_version: u32,
+        migrate: MetaMigrationFn,
+    ) {
+        let previous = self
+            .migrations
+            .entry(type_name)
+            .or_default()
+            .insert(from_version, migrate);
+        if previous.is_some() {
+            error!(
+                "Replaced an existing settings migration for {type_name} from version {from_version}"
+            );
+        }
+    }
+
+    /// Upgrades the serialized `meta` for the loader or processor with the given `type_name`, whose settings are at `version`,
+    /// to `current_version`. Returns [`Cow::Borrowed`] if no migration was required.
+    pub fn migrate<'a>(
+        &self,
+        type_name: &str,
+        meta: &'a [u8],
+        version: u32,
+        current_version: u32,
+    ) -> Result<Cow<'a, [u8]>, DeserializeMetaError> {
+        if version > current_version {
+            return Err(DeserializeMetaError::UnsupportedSettingsVersion {
+                type_name: type_name.to_string(),
+                version,
+                current_version,
+            });
+        }
+        let mut meta = Cow::Borrowed(meta);
+        for from_version in version..current_version {
+            let migrate = self
+                .migrations
+                .get(type_name)
+                .and_then(|migrations| migrations.get(&from_version))
+                .ok_or_else(|| DeserializeMetaError::MissingMigration {
+                    type_name: type_name.to_string(),
+                    from_version,
+                })?;
+            meta = Cow::Owned(migrate(&meta)?);
+        }
+        Ok(meta)
+    }
+}
+
 pub type AssetHash = [u8; 32];
 
 /// NOTE: changing the hashing logic here is a _breaking change_ that requires a [`META_FORMAT_VERSION`] bump.
@@ -260,3 +457,126 @@ pub(crate) fn get_full_asset_hash(
     }
     *hasher.finalize().as_bytes()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{AssetMetaMinimal, MetaMigrations};
+    use crate::{io::Reader, AssetLoader, DeserializeMetaError, ErasedAssetLoader, LoadContext};
+    use alloc::borrow::Cow;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct SettingsV0 {
+        size: f32,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct SettingsV1 {
+        scale: f32,
+    }
+
+    #[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+    struct SettingsV2 {
+        scale: f32,
+        flip: bool,
+    }
+
+    struct VersionedLoader;
+
+    impl AssetLoader for VersionedLoader {
+        type Asset = ();
+        type Settings = SettingsV2;
+        type Error = std::io::Error;
+        const SETTINGS_VERSION: u32 = 2;
+
+        async fn load(
+            &self,
+            _reader: &mut dyn Reader,
+            _settings: &Self::Settings,
+            _load_context: &mut LoadContext<'_>,
+        ) -> Result<Self::Asset, Self::Error> {
+            Ok(())
+        }
+    }
+
+    const META_V0: &str = r#"(
+    meta_format_version: "1.0",
+    asset: Load(
+        loader: "bevy_asset::meta::tests::VersionedLoader",
+        settings: (
+            size: 2.0,
+        ),
+    ),
+)"#;
+
+    fn migrations() -> MetaMigrations {
+        let mut migrations = MetaMigrations::default();
+        migrations.register_loader_migration::<VersionedLoader, _, _>(0, |old: SettingsV0| {
+            SettingsV1 { scale: old.size }
+        });
+        migrations.register_loader_migration::<VersionedLoader, _, _>(1, |old: SettingsV1| {
+            SettingsV2 {
+                scale: old.scale,
+                flip: false,
+            }
+        });
+        migrations
+    }
+
+    #[test]
+    fn migrate_loader_settings() {
+        let type_name = ErasedAssetLoader::type_name(&VersionedLoader);
+        let migrated = migrations()
+            .migrate(type_name, META_V0.as_bytes(), 0, 2)
+            .unwrap();
+
+        let minimal: AssetMetaMinimal = ron::de::from_bytes(&migrated).unwrap();
+        assert_eq!(minimal.settings_version, 2);
+
+        let meta = VersionedLoader.deserialize_meta(&migrated).unwrap();
+        assert_eq!(
+            meta.loader_settings()
+                .unwrap()
+                .downcast_ref::<SettingsV2>()
+                .unwrap(),
+            &SettingsV2 {
+                scale: 2.0,
+                flip: false
+            }
+        );
+
+        // Current metas are passed through untouched.
+        let current = VersionedLoader.default_meta().serialize();
+        assert!(matches!(
+            migrations().migrate(type_name, &current, 2, 2),
+            Ok(Cow::Borrowed(_))
+        ));
+    }
+
+    #[test]
+    fn migrate_loader_settings_errors() {
+        let type_name = ErasedAssetLoader::type_name(&VersionedLoader);
+        let mut migrations = MetaMigrations::default();
+        migrations.register_loader_migration::<VersionedLoader, _, _>(1, |old: SettingsV1| {
+            SettingsV2 {
+                scale: old.scale,
+                flip: false,
+            }
+        });
+        assert_eq!(
+            migrations.migrate(type_name, META_V0.as_bytes(), 0, 2),
+            Err(DeserializeMetaError::MissingMigration {
+                type_name: type_name.into(),
+                from_version: 0
+            })
+        );
+        assert_eq!(
+            migrations.migrate(type_name, META_V0.as_bytes(), 3, 2),
+            Err(DeserializeMetaError::UnsupportedSettingsVersion {
+                type_name: type_name.into(),
+                version: 3,
+                current_version: 2
+            })
+        );
+    }
+}
@@ -0,0 +1,292 @@
+use crate::{
+    meta::{AssetHash, META_FORMAT_VERSION},
+    AssetPath,
+};
+use alloc::{boxed::Box, string::ToString, vec::Vec};
+use bevy_tasks::{BoxedFuture, ConditionalSendFuture};
+use thiserror::Error;
+
+#[cfg(not(target_arch = "wasm32"))]
+use {
+    alloc::{format, string::String},
+    core::fmt::Write,
+    futures_io::ErrorKind,
+    std::path::PathBuf,
+};
+
+/// A processed asset stored in a [`ProcessedAssetCache`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedProcessedAsset {
+    /// The bytes of the processed asset.
+    pub asset: Vec<u8>,
+    /// The bytes of the processed asset's meta, including its [`ProcessedInfo`](crate::meta::ProcessedInfo).
+    pub meta: Vec<u8>,
+}
+
+/// An error that occurs while reading from or writing to a [`ProcessedAssetCache`].
+#[derive(Error, Debug)]
+pub enum ProcessedAssetCacheError {
+    /// Encountered an I/O error while accessing the cache.
+    #[error("encountered an io error while accessing the processed asset cache: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// A content-addressed store of processed assets that the [`AssetProcessor`](crate::processor::AssetProcessor)
+/// consults before running a [`Process`](crate::processor::Process) implementation.
+///
+/// Entries are keyed by [`get_processed_asset_cache_key`], which covers the source asset bytes, its `.meta` file (and
+/// therefore the processor settings), the processor's [`VERSION`](crate::processor::Process::VERSION), and the paths and
+/// hashes of the process dependencies used to produce the entry.
+///
+/// As process dependencies are only known once an asset is processed, the processor also stores an index entry under
+/// [`get_process_dependencies_cache_key`], whose meta lists the dependencies of the latest processing of the same inputs.
+/// Index entries have an empty [`CachedProcessedAsset::asset`].
+///
+/// Because identical inputs produce identical keys, a cache can be shared between machines (e.g. on a network mount)
+/// so that assets processed by one developer don't have to be processed again by another.
+/// See [`DirectoryProcessedAssetCache`] for a cache backed by a local directory.
+pub trait ProcessedAssetCache: Send + Sync + 'static {
+    /// Returns the entry stored for `key`, if it exists.
+    fn get<'a>(
+        &'a self,
+        key: &'a AssetHash,
+    ) -> impl ConditionalSendFuture<
+        Output = Result<Option<CachedProcessedAsset>, ProcessedAssetCacheError>,
+    >;
+    /// Stores `asset` for `key`, replacing any existing entry.
+    fn put<'a>(
+        &'a self,
+        key: &'a AssetHash,
+        asset: &'a CachedProcessedAsset,
+    ) -> impl ConditionalSendFuture<Output = Result<(), ProcessedAssetCacheError>>;
+}
+
+/// A type-erased variant of [`ProcessedAssetCache`].
+pub trait ErasedProcessedAssetCache: Send + Sync + 'static {
+    /// Type-erased variant of [`ProcessedAssetCache::get`].
+    fn get<'a>(
+        &'a self,
+        key: &'a AssetHash,
+    ) -> BoxedFuture<'a, Result<Option<CachedProcessedAsset>, ProcessedAssetCacheError>>;
+    /// Type-erased variant of [`ProcessedAssetCache::put`].
+    fn put<'a>(
+        &'a self,
+        key: &'a AssetHash,
+        asset: &'a CachedProcessedAsset,
+    ) -> BoxedFuture<'a, Result<(), ProcessedAssetCacheError>>;
+}
+
+impl<T: ProcessedAssetCache> ErasedProcessedAssetCache for T {
+    fn get<'a>(
+        &'a self,
+        key: &'a AssetHash,
+    ) -> BoxedFuture<'a, Result<Option<CachedProcessedAsset>, ProcessedAssetCacheError>> {
+        Box::pin(ProcessedAssetCache::get(self, key))
+    }
+
+    fn put<'a>(
+        &'a self,
+        key: &'a AssetHash,
+        asset: &'a CachedProcessedAsset,
+    ) -> BoxedFuture<'a, Result<(), ProcessedAssetCacheError>> {
+        Box::pin(ProcessedAssetCache::put(self, key, asset))
+    }
+}
+
+/// Computes the [`ProcessedAssetCache`] key for an asset with the given `asset_hash` (the hash of its source bytes and
+/// `.meta` file, which names the processor and contains its settings), processed by a processor with the given
+/// [`VERSION`](crate::processor::Process::VERSION), with the given process dependency paths and
+/// [full hashes](crate::meta::ProcessedInfo::full_hash), in any order.
+///
+/// NOTE: changing the hashing logic here is a _breaking change_ that invalidates every existing cache entry.
+pub fn get_processed_asset_cache_key<'a>(
+    asset_hash: AssetHash,
+    processor_version: u32,
+    process_dependencies: impl IntoIterator<Item = (&'a AssetPath<'static>, AssetHash)>,
+) -> AssetHash {
+    let mut process_dependencies = process_dependencies
+        .into_iter()
+        .map(|(path, hash)| (path.to_string(), hash))
+        .collect::<Vec<_>>();
+    process_dependencies.sort();
+
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(META_FORMAT_VERSION.as_bytes());
+    hasher.update(&asset_hash);
+    hasher.update(&processor_version.to_le_bytes());
+    hasher.update(&(process_dependencies.len() as u64).to_le_bytes());
+    for (path, hash) in &process_dependencies {
+        hasher.update(&(path.len() as u64).to_le_bytes());
+        hasher.update(path.as_bytes());
+        hasher.update(hash);
+    }
+    *hasher.finalize().as_bytes()
+}
+
+/// Computes the [`ProcessedAssetCache`] key of the index entry listing the process dependencies of an asset with the
+/// given `asset_hash`, processed by a processor with the given [`VERSION`](crate::processor::Process::VERSION).
+///
+/// NOTE: changing the hashing logic here is a _breaking change_ that invalidates every existing cache entry.
+pub fn get_process_dependencies_cache_key(
+    asset_hash: AssetHash,
+    processor_version: u32,
+) -> AssetHash {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(b"process_dependencies");
+    hasher.update(META_FORMAT_VERSION.as_bytes());
+    hasher.update(&asset_hash);
+    hasher.update(&processor_version.to_le_bytes());
+    *hasher.finalize().as_bytes()
+}
+
+/// A [`ProcessedAssetCache`] that stores entries as files in a directory.
+///
+/// Entries are written to a temporary file and then renamed into place, so multiple processors can safely share the same
+/// directory (for example on a network mount).
+#[cfg(not(target_arch = "wasm32"))]
+pub struct DirectoryProcessedAssetCache {
+    root_path: PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl DirectoryProcessedAssetCache {
+    /// Creates a new cache that stores its entries in the directory at `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            root_path: path.into(),
+        }
+    }
+
+    /// Returns the directory this cache stores its entries in.
+    pub fn root_path(&self) -> &PathBuf {
+        &self.root_path
+    }
+
+    /// Returns the path of the entry for `key`, without an extension.
+    fn entry_path(&self, key: &AssetHash) -> PathBuf {
+        let mut name = String::with_capacity(key.len() * 2);
+        for byte in key {
+            let _ = write!(name, "{byte:02x}");
+        }
+        self.root_path.join(&name[..2]).join(name)
+    }
+
+    async fn write_atomic(path: &PathBuf, bytes: &[u8]) -> std::io::Result<()> {
+        let temp_path = path.with_extension(format!("{}.tmp", uuid::Uuid::new_v4()));
+        async_fs::write(&temp_path, bytes).await?;
+        if let Err(err) = async_fs::rename(&temp_path, path).await {
+            let _ = async_fs::remove_file(&temp_path).await;
+            return Err(err);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl ProcessedAssetCache for DirectoryProcessedAssetCache {
+    async fn get<'a>(
+        &'a self,
+        key: &'a AssetHash,
+    ) -> Result<Option<CachedProcessedAsset>, ProcessedAssetCacheError> {
+        let path = self.entry_path(key);
+        // The meta is written last, so if it exists the asset is guaranteed to be complete.
+        let meta = match async_fs::read(path.with_extension("meta")).await {
+            Ok(meta) => meta,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let asset = match async_fs::read(path.with_extension("asset")).await {
+            Ok(asset) => asset,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        Ok(Some(CachedProcessedAsset { asset, meta }))
+    }
+
+    async fn put<'a>(
+        &'a self,
+        key: &'a AssetHash,
+        asset: &'a CachedProcessedAsset,
+    ) -> Result<(), ProcessedAssetCacheError> {
+        let path = self.entry_path(key);
+        if let Some(parent) = path.parent() {
+            async_fs::create_dir_all(parent).await?;
+        }
+        Self::write_atomic(&path.with_extension("asset"), &asset.asset).await?;
+        Self::write_atomic(&path.with_extension("meta"), &asset.meta).await?;
+        Ok(())
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::{
+        get_process_dependencies_cache_key, get_processed_asset_cache_key, CachedProcessedAsset,
+        DirectoryProcessedAssetCache, ProcessedAssetCache,
+    };
+    use crate::AssetPath;
+    use alloc::{format, vec};
+    use bevy_tasks::block_on;
+
+    #[test]
+    fn directory_cache_round_trip() {
+        let root = std::env::temp_dir().join(format!(
+            "bevy_asset_processed_cache_{}",
+            uuid::Uuid::new_v4()
+        ));
+        let cache = DirectoryProcessedAssetCache::new(&root);
+        let key = get_processed_asset_cache_key([1; 32], 0, []);
+        assert_ne!(key, get_processed_asset_cache_key([1; 32], 1, []));
+        assert_ne!(key, get_process_dependencies_cache_key([1; 32], 0));
+
+        assert_eq!(block_on(cache.get(&key)).unwrap(), None);
+
+        let entry = CachedProcessedAsset {
+            asset: vec![1, 2, 3],
+            meta: b"()".to_vec(),
+        };
+        block_on(cache.put(&key, &entry)).unwrap();
+        assert_eq!(block_on(cache.get(&key)).unwrap(), Some(entry));
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn key_covers_process_dependencies() {
+        let root = std::env::temp_dir().join(format!(
+            "bevy_asset_processed_cache_{}",
+            uuid::Uuid::new_v4()
+        ));
+        let cache = DirectoryProcessedAssetCache::new(&root);
+        let a = AssetPath::from("a.png");
+        let b = AssetPath::from("b.png");
+
+        let key = get_processed_asset_cache_key([1; 32], 0, [(&a, [2; 32]), (&b, [3; 32])]);
+        assert_eq!(
+            key,
+            get_processed_asset_cache_key([1; 32], 0, [(&b, [3; 32]), (&a, [2; 32])])
+        );
+        // A dependency changed.
+        let changed_key = get_processed_asset_cache_key([1; 32], 0, [(&a, [2; 32]), (&b, [4; 32])]);
+        assert_ne!(key, changed_key);
+
+        // Entries for both dependency states are kept side by side.
+        let entry = CachedProcessedAsset {
+            asset: vec![1],
+            meta: b"()".to_vec(),
+        };
+        let changed_entry = CachedProcessedAsset {
+            asset: vec![2],
+            meta: b"()".to_vec(),
+        };
+        block_on(cache.put(&key, &entry)).unwrap();
+        block_on(cache.put(&changed_key, &changed_entry)).unwrap();
+        assert_eq!(block_on(cache.get(&key)).unwrap(), Some(entry));
+        assert_eq!(
+            block_on(cache.get(&changed_key)).unwrap(),
+            Some(changed_entry)
+        );
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
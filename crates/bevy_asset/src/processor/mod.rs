@@ -37,13 +37,16 @@
 //!
 //! In most cases, [`LoadTransformAndSave`] should be sufficient.
 
+mod cache;
 mod log;
 mod process;
 
+pub use cache::*;
 pub use log::*;
 pub use process::*;
 
 use crate::{
+    graph::{AssetDependencyGraph, AssetDependencyKind},
     io::{
         AssetReaderError, AssetSource, AssetSourceBuilders, AssetSourceEvent, AssetSourceId,
         AssetSources, AssetWriterError, ErasedAssetReader, ErasedAssetWriter,
@@ -60,12 +63,13 @@ use alloc::{borrow::ToOwned, boxed::Box, collections::VecDeque, sync::Arc, vec,
 use bevy_ecs::prelude::*;
 use bevy_platform_support::collections::{HashMap, HashSet};
 use bevy_tasks::IoTaskPool;
+use core::sync::atomic::{AtomicBool, Ordering};
 use futures_io::ErrorKind;
 use futures_lite::{AsyncReadExt, AsyncWriteExt, StreamExt};
 use parking_lot::RwLock;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
-use tracing::{debug, error, trace, warn};
+use tracing::{debug, error, info, trace, warn};
 
 #[cfg(feature = "trace")]
 use {
@@ -102,6 +106,12 @@ pub struct AssetProcessorData {
     processors: RwLock<HashMap<&'static str, Arc<dyn ErasedProcessor>>>,
     /// Default processors for file extensions
     default_processors: RwLock<HashMap<Box<str>, &'static str>>,
+    /// If `true`, source `.meta` files with outdated settings versions are rewritten after they are migrated.
+    rewrite_outdated_meta: AtomicBool,
+    /// The assets whose source `.meta` files were upgraded to a newer settings version.
+    upgraded_meta: RwLock<Vec<UpgradedMeta>>,
+    /// The cache consulted before processing assets, if one is configured.
+    cache: RwLock<Option<Arc<dyn ErasedProcessedAssetCache>>>,
     state: async_lock::RwLock<ProcessorState>,
     sources: AssetSources,
     initialized_sender: async_broadcast::Sender<()>,
@@ -190,6 +200,63 @@ impl AssetProcessor {
         log.end_processing(path).await.unwrap();
     }
 
+    /// Sets the [`ProcessedAssetCache`] that is consulted before an asset is processed, and that newly processed assets
+    /// are stored in. Assets whose inputs match an existing cache entry are fetched from the cache instead of being processed.
+    pub fn set_cache(&self, cache: impl ProcessedAssetCache) {
+        *self.data.cache.write() = Some(Arc::new(cache));
+    }
+
+    /// Sets whether source `.meta` files with an outdated settings version should be rewritten in place once they have been
+    /// migrated. If this is `false` (the default), outdated `.meta` files are migrated in memory every time they are processed.
+    ///
+    /// Upgraded files are reported by [`AssetProcessor::upgraded_meta_files`] either way.
+    pub fn set_rewrite_outdated_meta(&self, rewrite: bool) {
+        self.data
+            .rewrite_outdated_meta
+            .store(rewrite, Ordering::Relaxed);
+    }
+
+    /// Returns every asset whose source `.meta` file was migrated to a newer settings version by this processor.
+    pub fn upgraded_meta_files(&self) -> Vec<UpgradedMeta> {
+        self.data.upgraded_meta.read().clone()
+    }
+
+    /// Records that the source meta of `asset_path` was migrated, and rewrites it if [`AssetProcessor::set_rewrite_outdated_meta`] is enabled.
+    async fn handle_upgraded_meta(
+        &self,
+        source: &AssetSource,
+        asset_path: &AssetPath<'static>,
+        meta_bytes: &[u8],
+        from_version: u32,
+        to_version: u32,
+    ) -> Result<(), ProcessError> {
+        let rewritten = self.data.rewrite_outdated_meta.load(Ordering::Relaxed);
+        if rewritten {
+            source
+                .writer()?
+                .write_meta_bytes(asset_path.path(), meta_bytes)
+                .await
+                .map_err(|err| ProcessError::AssetWriterError {
+                    path: asset_path.clone(),
+                    err,
+                })?;
+            info!(
+                "Rewrote meta for {asset_path} from settings version {from_version} to {to_version}"
+            );
+        } else {
+            debug!("Migrated meta for {asset_path} from settings version {from_version} to {to_version}");
+        }
+        let mut upgraded_meta = self.data.upgraded_meta.write();
+        upgraded_meta.retain(|upgraded| upgraded.path != *asset_path);
+        upgraded_meta.push(UpgradedMeta {
+            path: asset_path.clone(),
+            from_version,
+            to_version,
+            rewritten,
+        });
+        Ok(())
+    }
+
     /// Starts the processor in a background thread.
     pub fn start(_processor: Res<Self>) {
         #[cfg(any(target_arch = "wasm32", not(feature = "multi_threaded")))]
@@ -523,6 +590,12 @@ impl AssetProcessor {
         }
     }
 
+    /// Returns an [`AssetDependencyGraph`] of every asset known to this processor, with an
+    /// [`AssetDependencyKind::Process`] edge for each asset that was used while processing another.
+    pub async fn dependency_graph(&self) -> AssetDependencyGraph {
+        self.data.asset_infos.read().await.dependency_graph()
+    }
+
     /// Register a new asset processor.
     pub fn register_processor<P: Process>(&self, processor: P) {
         let mut process_plans = self.data.processors.write();
@@ -763,24 +836,57 @@ impl AssetProcessor {
                 let minimal: AssetMetaMinimal = ron::de::from_bytes(&meta_bytes).map_err(|e| {
                     ProcessError::DeserializeMetaError(DeserializeMetaError::DeserializeMinimal(e))
                 })?;
-                let (meta, processor) = match minimal.asset {
-                    AssetActionMinimal::Load { loader } => {
-                        let loader = server.get_asset_loader_with_type_name(&loader).await?;
-                        let meta = loader.deserialize_meta(&meta_bytes)?;
-                        (meta, None)
+                let (meta, processor, current_version, migrated_bytes) = match minimal.asset {
+                    AssetActionMinimal::Load {
+                        loader: loader_name,
+                    } => {
+                        let loader = server.get_asset_loader_with_type_name(&loader_name).await?;
+                        let migrated_bytes = server.migrate_meta(
+                            &loader_name,
+                            &meta_bytes,
+                            minimal.settings_version,
+                            loader.settings_version(),
+                        )?;
+                        let meta = loader.deserialize_meta(&migrated_bytes)?;
+                        let current_version = loader.settings_version();
+                        (meta, None, current_version, migrated_bytes.into_owned())
                     }
-                    AssetActionMinimal::Process { processor } => {
-                        let processor = self
-                            .get_processor(&processor)
-                            .ok_or_else(|| ProcessError::MissingProcessor(processor))?;
-                        let meta = processor.deserialize_meta(&meta_bytes)?;
-                        (meta, Some(processor))
+                    AssetActionMinimal::Process {
+                        processor: processor_name,
+                    } => {
+                        let processor = self.get_processor(&processor_name).ok_or_else(|| {
+                            ProcessError::MissingProcessor(processor_name.clone())
+                        })?;
+                        let migrated_bytes = server.migrate_meta(
+                            &processor_name,
+                            &meta_bytes,
+                            minimal.settings_version,
+                            processor.settings_version(),
+                        )?;
+                        let meta = processor.deserialize_meta(&migrated_bytes)?;
+                        let current_version = processor.settings_version();
+                        (
+                            meta,
+                            Some(processor),
+                            current_version,
+                            migrated_bytes.into_owned(),
+                        )
                     }
                     AssetActionMinimal::Ignore => {
                         return Ok(ProcessResult::Ignored);
                     }
                 };
-                (meta, meta_bytes, processor)
+                if migrated_bytes != meta_bytes {
+                    self.handle_upgraded_meta(
+                        source,
+                        asset_path,
+                        &migrated_bytes,
+                        minimal.settings_version,
+                        current_version,
+                    )
+                    .await?;
+                }
+                (meta, migrated_bytes, processor)
             }
             Err(AssetReaderError::NotFound(_path)) => {
                 let (meta, processor) = if let Some(processor) = asset_path
@@ -861,6 +967,21 @@ impl AssetProcessor {
                 }
             }
         }
+        let cache = processor
+            .as_ref()
+            .and_then(|_| self.data.cache.read().clone());
+        let processor_version = processor
+            .as_ref()
+            .map(|processor| processor.version())
+            .unwrap_or_default();
+        let cached = match &cache {
+            Some(cache) => {
+                self.fetch_from_cache(&**cache, asset_path, new_hash, processor_version)
+                    .await
+            }
+            None => None,
+        };
+
         // Note: this lock must remain alive until all processed asset and meta writes have finished (or failed)
         // See ProcessedAssetInfo::file_transaction_lock docs for more info
         let _transaction_lock = {
@@ -873,23 +994,54 @@ impl AssetProcessor {
         // Directly writing to the asset destination in the processor necessitates this behavior
         // TODO: this class of failure can be recovered via re-processing + smarter log validation that allows for duplicate transactions in the event of failures
         self.log_begin_processing(asset_path).await;
-        if let Some(processor) = processor {
-            let mut writer = processed_writer.write(path).await.map_err(writer_err)?;
-            let mut processed_meta = {
+        if let Some((cached, processed_info)) = cached {
+            debug!("Fetched processed asset {asset_path} from the processed asset cache");
+            processed_writer
+                .write_bytes(path, &cached.asset)
+                .await
+                .map_err(writer_err)?;
+            processed_writer
+                .write_meta_bytes(path, &cached.meta)
+                .await
+                .map_err(writer_err)?;
+            new_processed_info = processed_info;
+        } else if let Some(processor) = processor {
+            let mut processed_bytes = Vec::new();
+            let mut processed_meta = if cache.is_some() {
+                // The processed bytes are buffered so they can also be stored in the cache.
                 let mut context =
                     ProcessContext::new(self, asset_path, &asset_bytes, &mut new_processed_info);
-                processor
-                    .process(&mut context, source_meta, &mut *writer)
-                    .await?
-            };
+                let processed_meta = processor
+                    .process(&mut context, source_meta, &mut processed_bytes)
+                    .await?;
+                processed_writer
+                    .write_bytes(path, &processed_bytes)
+                    .await
+                    .map_err(writer_err)?;
+                processed_meta
+            } else {
+                let mut writer = processed_writer.write(path).await.map_err(writer_err)?;
+                let processed_meta = {
+                    let mut context = ProcessContext::new(
+                        self,
+                        asset_path,
+                        &asset_bytes,
+                        &mut new_processed_info,
+                    );
+                    processor
+                        .process(&mut context, source_meta, &mut *writer)
+                        .await?
+                };
 
-            writer
-                .flush()
-                .await
-                .map_err(|e| ProcessError::AssetWriterError {
-                    path: asset_path.clone(),
-                    err: AssetWriterError::Io(e),
-                })?;
+                writer
+                    .flush()
+                    .await
+                    .map_err(|e| ProcessError::AssetWriterError {
+                        path: asset_path.clone(),
+                        err: AssetWriterError::Io(e),
+                    })?;
+                processed_meta
+            };
 
             let full_hash = get_full_asset_hash(
                 new_hash,
@@ -905,6 +1057,35 @@ impl AssetProcessor {
                 .write_meta_bytes(path, &meta_bytes)
                 .await
                 .map_err(writer_err)?;
+            if let Some(cache) = &cache {
+                let cache_key = get_processed_asset_cache_key(
+                    new_hash,
+                    processor_version,
+                    new_processed_info
+                        .process_dependencies
+                        .iter()
+                        .map(|dependency| (&dependency.path, dependency.full_hash)),
+                );
+                let index = CachedProcessedAsset {
+                    asset: Vec::new(),
+                    meta: meta_bytes.clone(),
+                };
+                let cached = CachedProcessedAsset {
+                    asset: processed_bytes,
+                    meta: meta_bytes,
+                };
+                let dependencies_key =
+                    get_process_dependencies_cache_key(new_hash, processor_version);
+                // The index is written last, so that it only refers to stored entries.
+                if let Err(err) = async {
+                    cache.put(&cache_key, &cached).await?;
+                    cache.put(&dependencies_key, &index).await
+                }
+                .await
+                {
+                    warn!("Failed to store processed asset {asset_path} in the processed asset cache: {err}");
+                }
+            }
         } else {
             processed_writer
                 .write_bytes(path, &asset_bytes)
@@ -922,6 +1103,70 @@ impl AssetProcessor {
         Ok(ProcessResult::Processed(new_processed_info))
     }
 
+    /// Returns the cache entry for an asset with the given `hash` and its [`ProcessedInfo`], if an entry exists for the
+    /// current hashes of the process dependencies of its latest processing. This waits for those dependencies to be processed.
+    async fn fetch_from_cache(
+        &self,
+        cache: &dyn ErasedProcessedAssetCache,
+        asset_path: &AssetPath<'static>,
+        hash: AssetHash,
+        processor_version: u32,
+    ) -> Option<(CachedProcessedAsset, ProcessedInfo)> {
+        let read_processed_info = |cached: &CachedProcessedAsset| match ron::de::from_bytes::<
+            ProcessedInfoMinimal,
+        >(&cached.meta)
+        {
+            Ok(minimal) => minimal.processed_info.filter(|info| info.hash == hash),
+            Err(err) => {
+                warn!("Ignoring invalid processed asset cache entry for {asset_path}: {err}");
+                None
+            }
+        };
+        let get = |key: AssetHash| async move {
+            match cache.get(&key).await {
+                Ok(cached) => cached,
+                Err(err) => {
+                    warn!("Failed to read {asset_path} from the processed asset cache: {err}");
+                    None
+                }
+            }
+        };
+
+        let index = get(get_process_dependencies_cache_key(hash, processor_version)).await?;
+        let index_info = read_processed_info(&index)?;
+        let mut dependencies = Vec::with_capacity(index_info.process_dependencies.len());
+        for dependency in &index_info.process_dependencies {
+            if self
+                .data
+                .wait_until_processed(dependency.path.clone())
+                .await
+                != ProcessStatus::Processed
+            {
+                return None;
+            }
+            let infos = self.data.asset_infos.read().await;
+            let current_hash = infos
+                .get(&dependency.path)
+                .and_then(|info| info.processed_info.as_ref())?
+                .full_hash;
+            dependencies.push((&dependency.path, current_hash));
+        }
+
+        let cached = get(get_processed_asset_cache_key(
+            hash,
+            processor_version,
+            dependencies.iter().copied(),
+        ))
+        .await?;
+        let processed_info = read_processed_info(&cached)?;
+        let unchanged = processed_info.process_dependencies.len() == dependencies.len()
+            && processed_info
+                .process_dependencies
+                .iter()
+                .all(|dependency| dependencies.contains(&(&dependency.path, dependency.full_hash)));
+        unchanged.then_some((cached, processed_info))
+    }
+
     async fn validate_transaction_log_and_recover(&self) {
         if let Err(err) = ProcessorTransactionLog::validate().await {
             let state_is_valid = match err {
@@ -1029,6 +1274,9 @@ impl AssetProcessorData {
             processors: Default::default(),
             asset_infos: Default::default(),
             default_processors: Default::default(),
+            rewrite_outdated_meta: AtomicBool::new(false),
+            upgraded_meta: Default::default(),
+            cache: Default::default(),
         }
     }
 
@@ -1094,6 +1342,8 @@ struct InstrumentedAssetProcessor<T>(T);
 impl<T: Process> Process for InstrumentedAssetProcessor<T> {
     type Settings = T::Settings;
     type OutputLoader = T::OutputLoader;
+    const SETTINGS_VERSION: u32 = T::SETTINGS_VERSION;
+    const VERSION: u32 = T::VERSION;
 
     fn process(
         &self,
@@ -1106,6 +1356,7 @@ impl<T: Process> Process for InstrumentedAssetProcessor<T> {
         // Change the processor type for the `AssetMeta`, which works because we share the `Settings` type.
         let meta = AssetMeta {
             meta_format_version: meta.meta_format_version,
+            settings_version: meta.settings_version,
             processed_info: meta.processed_info,
             asset: meta.asset,
         };
@@ -1126,6 +1377,19 @@ pub enum ProcessResult {
     Ignored,
 }
 
+/// A source `.meta` file that was migrated to a newer settings version by the [`AssetProcessor`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpgradedMeta {
+    /// The asset the `.meta` file belongs to.
+    pub path: AssetPath<'static>,
+    /// The settings version the `.meta` file was stored with.
+    pub from_version: u32,
+    /// The settings version the `.meta` file was migrated to.
+    pub to_version: u32,
+    /// Whether the migrated `.meta` file was written back to the asset source.
+    pub rewritten: bool,
+}
+
 /// The final status of processing an asset
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum ProcessStatus {
@@ -1388,6 +1652,24 @@ impl ProcessorAssetInfos {
         }
     }
 
+    fn dependency_graph(&self) -> AssetDependencyGraph {
+        let mut graph = AssetDependencyGraph::new();
+        for (path, info) in &self.infos {
+            graph.add_asset(path.clone());
+            let Some(processed_info) = &info.processed_info else {
+                continue;
+            };
+            for dependency in &processed_info.process_dependencies {
+                graph.add_dependency(
+                    path.clone(),
+                    dependency.path.clone(),
+                    AssetDependencyKind::Process,
+                );
+            }
+        }
+        graph
+    }
+
     fn clear_dependencies(&mut self, asset_path: &AssetPath<'static>, removed_info: ProcessedInfo) {
         for old_load_dep in removed_info.process_dependencies {
             if let Some(info) = self.infos.get_mut(&old_load_dep.path) {
@@ -30,6 +30,14 @@ pub trait Process: Send + Sync + Sized + 'static {
     type Settings: Settings + Default + Serialize + for<'a> Deserialize<'a>;
     /// The [`AssetLoader`] that will be used to load the final processed asset.
     type OutputLoader: AssetLoader;
+    /// The version of [`Process::Settings`], which is written to `.meta` files alongside the settings.
+    ///
+    /// Bump this whenever [`Process::Settings`] changes in a way that breaks existing `.meta` files, and register a
+    /// migration from the previous version with [`AssetApp::register_processor_settings_migration`](crate::AssetApp::register_processor_settings_migration).
+    const SETTINGS_VERSION: u32 = 0;
+    /// The version of this processor's output. Bump this whenever the processor produces different output for the same input,
+    /// so that entries produced by older versions in a [`ProcessedAssetCache`](crate::processor::ProcessedAssetCache) are not reused.
+    const VERSION: u32 = 0;
     /// Processes the asset stored on `context` in some way using the settings stored on `meta`. The results are written to `writer`. The
     /// final written processed asset is loadable using [`Process::OutputLoader`]. This load will use the returned [`AssetLoader::Settings`].
     fn process(
@@ -221,6 +229,10 @@ pub trait ErasedProcessor: Send + Sync {
     fn deserialize_meta(&self, meta: &[u8]) -> Result<Box<dyn AssetMetaDyn>, DeserializeMetaError>;
     /// Returns the default type-erased [`AssetMeta`] for the underlying [`Process`] impl.
     fn default_meta(&self) -> Box<dyn AssetMetaDyn>;
+    /// Returns the current [`Process::SETTINGS_VERSION`].
+    fn settings_version(&self) -> u32;
+    /// Returns the current [`Process::VERSION`].
+    fn version(&self) -> u32;
 }
 
 impl<P: Process> ErasedProcessor for P {
@@ -255,6 +267,14 @@ impl<P: Process> ErasedProcessor for P {
             settings: P::Settings::default(),
         }))
     }
+
+    fn settings_version(&self) -> u32 {
+        P::SETTINGS_VERSION
+    }
+
+    fn version(&self) -> u32 {
+        P::VERSION
+    }
 }
 
 /// Provides scoped data access to the [`AssetProcessor`].
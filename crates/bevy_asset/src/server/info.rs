@@ -1,4 +1,6 @@
 use crate::{
+    folder::{AssetGlob, FolderFilter},
+    graph::{AssetDependencyGraph, AssetDependencyKind},
     meta::{AssetHash, MetaTransform},
     Asset, AssetHandleProvider, AssetLoadError, AssetPath, DependencyLoadState, ErasedLoadedAsset,
     Handle, InternalAssetEvent, LoadState, RecursiveDependencyLoadState, StrongHandle,
@@ -33,6 +35,10 @@ pub(crate) struct AssetInfo {
     failed_rec_dependencies: HashSet<UntypedAssetId>,
     dependents_waiting_on_load: HashSet<UntypedAssetId>,
     dependents_waiting_on_recursive_dep_load: HashSet<UntypedAssetId>,
+    /// The assets this asset holds handles to, as reported by [`VisitAssetDependencies`] when it finished loading.
+    ///
+    /// [`VisitAssetDependencies`]: crate::VisitAssetDependencies
+    dependencies: HashSet<UntypedAssetId>,
     /// The asset paths required to load this asset. Hashes will only be set for processed assets.
     /// This is set using the value from [`LoadedAsset`].
     /// This will only be populated if [`AssetInfos::watching_for_changes`] is set to `true` to
@@ -59,6 +65,7 @@ impl AssetInfo {
             failed_dependencies: HashSet::default(),
             loading_rec_dependencies: HashSet::default(),
             failed_rec_dependencies: HashSet::default(),
+            dependencies: HashSet::default(),
             loader_dependencies: HashMap::default(),
             dependents_waiting_on_load: HashSet::default(),
             dependents_waiting_on_recursive_dep_load: HashSet::default(),
@@ -81,6 +88,9 @@ pub(crate) struct AssetInfos {
     /// Tracks living labeled assets for a given source asset.
     /// This should only be set when watching for changes to avoid unnecessary work.
     pub(crate) living_labeled_assets: HashMap<AssetPath<'static>, HashSet<Box<str>>>,
+    /// Tracks the glob and filter of each [`LoadedFolder`](crate::LoadedFolder) loaded with a glob, keyed by its pattern.
+    /// This should only be set when watching for changes to avoid unnecessary work.
+    pub(crate) globs: HashMap<AssetPath<'static>, (AssetGlob, Arc<FolderFilter>)>,
     pub(crate) handle_providers: TypeIdMap<AssetHandleProvider>,
     pub(crate) dependency_loaded_event_sender: TypeIdMap<fn(&mut World, UntypedAssetId)>,
     pub(crate) dependency_failed_event_sender:
@@ -345,6 +355,43 @@ impl AssetInfos {
         Some(UntypedHandle::Strong(strong_handle))
     }
 
+    /// Builds an [`AssetDependencyGraph`] from every tracked asset that has a path.
+    pub(crate) fn dependency_graph(&self) -> AssetDependencyGraph {
+        let mut graph = AssetDependencyGraph::new();
+        for info in self.infos.values() {
+            let Some(path) = &info.path else {
+                continue;
+            };
+            graph.add_asset(path.clone());
+            if path.label().is_some() {
+                graph.add_dependency(
+                    path.without_label().into_owned(),
+                    path.clone(),
+                    AssetDependencyKind::Labeled,
+                );
+            }
+            for dependency in &info.dependencies {
+                if let Some(dependency_path) =
+                    self.infos.get(dependency).and_then(|i| i.path.as_ref())
+                {
+                    graph.add_dependency(
+                        path.clone(),
+                        dependency_path.clone(),
+                        AssetDependencyKind::Handle,
+                    );
+                }
+            }
+            for dependency_path in info.loader_dependencies.keys() {
+                graph.add_dependency(
+                    path.clone(),
+                    dependency_path.clone(),
+                    AssetDependencyKind::Loader,
+                );
+            }
+        }
+        graph
+    }
+
     /// Returns `true` if the asset this path points to is still alive
     pub(crate) fn is_path_alive<'a>(&self, path: impl Into<AssetPath<'a>>) -> bool {
         let path = path.into();
@@ -397,6 +444,7 @@ impl AssetInfos {
         }
 
         loaded_asset.value.insert(loaded_asset_id, world);
+        let dependencies = loaded_asset.dependencies.clone();
         let mut loading_deps = loaded_asset.dependencies;
         let mut failed_deps = <HashSet<_>>::default();
         let mut dep_error = None;
@@ -493,6 +541,7 @@ impl AssetInfos {
             let info = self
                 .get_mut(loaded_asset_id)
                 .expect("Asset info should always exist at this point");
+            info.dependencies = dependencies;
             info.loading_dependencies = loading_deps;
             info.failed_dependencies = failed_deps;
             info.loading_rec_dependencies = loading_rec_deps;
@@ -2,7 +2,8 @@ mod info;
 mod loaders;
 
 use crate::{
-    folder::LoadedFolder,
+    folder::{AssetGlob, FolderFilter, LoadedFolder},
+    graph::AssetDependencyGraph,
     io::{
         AssetReaderError, AssetSource, AssetSourceEvent, AssetSourceId, AssetSources,
         ErasedAssetReader, MissingAssetSourceError, MissingProcessedAssetReaderError, Reader,
@@ -10,14 +11,20 @@ use crate::{
     loader::{AssetLoader, ErasedAssetLoader, LoadContext, LoadedAsset},
     meta::{
         loader_settings_meta_transform, AssetActionMinimal, AssetMetaDyn, AssetMetaMinimal,
-        MetaTransform, Settings,
+        MetaMigrations, MetaTransform, Settings,
     },
     path::AssetPath,
+    processor::Process,
     Asset, AssetEvent, AssetHandleProvider, AssetId, AssetLoadFailedEvent, AssetMetaCheck, Assets,
     DeserializeMetaError, ErasedLoadedAsset, Handle, LoadedUntypedAsset, UntypedAssetId,
     UntypedAssetLoadFailedEvent, UntypedHandle,
 };
-use alloc::{borrow::ToOwned, boxed::Box, vec, vec::Vec};
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    vec,
+    vec::Vec,
+};
 use alloc::{
     format,
     string::{String, ToString},
@@ -25,15 +32,16 @@ use alloc::{
 };
 use atomicow::CowArc;
 use bevy_ecs::prelude::*;
-use bevy_platform_support::collections::HashSet;
+use bevy_platform_support::{collections::HashSet, hash::FixedHasher};
 use bevy_tasks::IoTaskPool;
-use core::{any::TypeId, future::Future, panic::AssertUnwindSafe, task::Poll};
+use core::{any::TypeId, future::Future, hash::BuildHasher, panic::AssertUnwindSafe, task::Poll};
 use crossbeam_channel::{Receiver, Sender};
 use either::Either;
 use futures_lite::{FutureExt, StreamExt};
 use info::*;
 use loaders::*;
 use parking_lot::{RwLock, RwLockWriteGuard};
+use serde::{de::DeserializeOwned, Serialize};
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 use tracing::{error, info};
@@ -61,6 +69,7 @@ pub struct AssetServer {
 pub(crate) struct AssetServerData {
     pub(crate) infos: RwLock<AssetInfos>,
     pub(crate) loaders: Arc<RwLock<AssetLoaders>>,
+    pub(crate) meta_migrations: Arc<RwLock<MetaMigrations>>,
     asset_event_sender: Sender<InternalAssetEvent>,
     asset_event_receiver: Receiver<InternalAssetEvent>,
     sources: AssetSources,
@@ -84,6 +93,7 @@ impl AssetServer {
         Self::new_with_loaders(
             sources,
             Default::default(),
+            Default::default(),
             mode,
             AssetMetaCheck::Always,
             watching_for_changes,
@@ -101,6 +111,7 @@ impl AssetServer {
         Self::new_with_loaders(
             sources,
             Default::default(),
+            Default::default(),
             mode,
             meta_check,
             watching_for_changes,
@@ -110,6 +121,7 @@ impl AssetServer {
     pub(crate) fn new_with_loaders(
         sources: AssetSources,
         loaders: Arc<RwLock<AssetLoaders>>,
+        meta_migrations: Arc<RwLock<MetaMigrations>>,
         mode: AssetServerMode,
         meta_check: AssetMetaCheck,
         watching_for_changes: bool,
@@ -125,6 +137,7 @@ impl AssetServer {
                 asset_event_sender,
                 asset_event_receiver,
                 loaders,
+                meta_migrations,
                 infos: RwLock::new(infos),
             }),
         }
@@ -148,6 +161,57 @@ impl AssetServer {
         self.data.loaders.write().push(loader);
     }
 
+    /// Registers a migration of the [`AssetLoader::Settings`] of `L` from `from_version` to `from_version + 1`.
+    /// See [`MetaMigrations::register_loader_migration`].
+    pub fn register_loader_settings_migration<L, Old, New>(
+        &self,
+        from_version: u32,
+        migrate: impl Fn(Old) -> New + Send + Sync + 'static,
+    ) where
+        L: AssetLoader,
+        Old: Serialize + DeserializeOwned,
+        New: Serialize,
+    {
+        self.data
+            .meta_migrations
+            .write()
+            .register_loader_migration::<L, Old, New>(from_version, migrate);
+    }
+
+    /// Registers a migration of the [`Process::Settings`] of `P` from `from_version` to `from_version + 1`.
+    /// See [`MetaMigrations::register_processor_migration`].
+    ///
+    /// [`Process::Settings`]: crate::processor::Process::Settings
+    pub fn register_processor_settings_migration<P, Old, New>(
+        &self,
+        from_version: u32,
+        migrate: impl Fn(Old) -> New + Send + Sync + 'static,
+    ) where
+        P: Process,
+        Old: Serialize + DeserializeOwned,
+        New: Serialize,
+    {
+        self.data
+            .meta_migrations
+            .write()
+            .register_processor_migration::<P, Old, New>(from_version, migrate);
+    }
+
+    /// Applies the registered settings migrations for the loader or processor with the given `type_name` to `meta_bytes`,
+    /// upgrading them from `version` to `current_version`.
+    pub(crate) fn migrate_meta<'b>(
+        &self,
+        type_name: &str,
+        meta_bytes: &'b [u8],
+        version: u32,
+        current_version: u32,
+    ) -> Result<Cow<'b, [u8]>, DeserializeMetaError> {
+        self.data
+            .meta_migrations
+            .read()
+            .migrate(type_name, meta_bytes, version, current_version)
+    }
+
     /// Registers a new [`Asset`] type. [`Asset`] types must be registered before assets of that type can be loaded.
     pub fn register_asset<A: Asset>(&self, assets: &Assets<A>) {
         self.register_handle_provider(assets.get_handle_provider());
@@ -865,12 +929,87 @@ impl AssetServer {
         handle
     }
 
+    /// Loads all assets whose path matches the given glob `pattern`, such as `textures/**/*.ktx2`. The [`LoadedFolder`]
+    /// asset (when it loads) will contain handles to all matching assets. See [`AssetGlob`] for the supported syntax.
+    /// The pattern may be prefixed with an asset source, e.g. `remote://textures/*.png`.
+    ///
+    /// Loading the same pattern multiple times will return the same handle. If the `file_watcher` feature is enabled,
+    /// [`LoadedFolder`] handles will reload when a matching file is added, removed or moved.
+    #[must_use = "not using the returned strong handle may result in the unexpected release of the assets"]
+    pub fn load_glob<'a>(&self, pattern: impl Into<AssetPath<'a>>) -> Handle<LoadedFolder> {
+        self.load_glob_with_filter(pattern, FolderFilter::default())
+    }
+
+    /// Loads all assets whose path matches the given glob `pattern` and passes the given [`FolderFilter`]. The filter
+    /// can add include and exclude patterns, and restrict the loaded assets to a single asset type.
+    ///
+    /// Loading the same pattern with the same filter multiple times will return the same handle. Patterns loaded with
+    /// a non-default filter are keyed by a label identifying the filter, so each filter gets its own [`LoadedFolder`].
+    /// See [`AssetServer::load_glob`] for more details.
+    #[must_use = "not using the returned strong handle may result in the unexpected release of the assets"]
+    pub fn load_glob_with_filter<'a>(
+        &self,
+        pattern: impl Into<AssetPath<'a>>,
+        filter: FolderFilter,
+    ) -> Handle<LoadedFolder> {
+        let mut path = pattern.into().into_owned();
+        if filter != FolderFilter::default() {
+            path = path.with_label(format!("filter-{:016x}", FixedHasher.hash_one(&filter)));
+        }
+        let mut infos = self.data.infos.write();
+        let (handle, should_load) = infos.get_or_create_path_handle::<LoadedFolder>(
+            path.clone(),
+            HandleLoadingMode::Request,
+            None,
+        );
+        if !should_load {
+            return handle;
+        }
+        let glob = AssetGlob::new(path.path().to_string_lossy());
+        let filter = Arc::new(filter);
+        if infos.watching_for_changes {
+            infos
+                .globs
+                .insert(path.clone(), (glob.clone(), filter.clone()));
+        }
+        drop(infos);
+        self.load_glob_internal(handle.id().untyped(), path, glob, filter);
+
+        handle
+    }
+
     pub(crate) fn load_folder_internal(&self, id: UntypedAssetId, path: AssetPath) {
+        let path = path.into_owned();
+        let folder = path.path().to_owned();
+        self.load_folder_filtered_internal(id, path, folder, None);
+    }
+
+    pub(crate) fn load_glob_internal(
+        &self,
+        id: UntypedAssetId,
+        path: AssetPath<'static>,
+        glob: AssetGlob,
+        filter: Arc<FolderFilter>,
+    ) {
+        let folder = glob.base_path();
+        self.load_folder_filtered_internal(id, path, folder, Some((glob, filter)));
+    }
+
+    /// Loads the assets in `folder` into the [`LoadedFolder`] with the given `id`, skipping those that do not match the
+    /// given glob and filter.
+    fn load_folder_filtered_internal(
+        &self,
+        id: UntypedAssetId,
+        path: AssetPath<'static>,
+        folder: PathBuf,
+        filter: Option<(AssetGlob, Arc<FolderFilter>)>,
+    ) {
         async fn load_folder<'a>(
             source: AssetSourceId<'static>,
             path: &'a Path,
             reader: &'a dyn ErasedAssetReader,
             server: &'a AssetServer,
+            filter: Option<&'a (AssetGlob, Arc<FolderFilter>)>,
             handles: &'a mut Vec<UntypedHandle>,
         ) -> Result<(), AssetLoadError> {
             let is_dir = reader.is_directory(path).await?;
@@ -883,14 +1022,35 @@ impl AssetServer {
                             &child_path,
                             reader,
                             server,
+                            filter,
                             handles,
                         ))
                         .await?;
                     } else {
+                        if let Some((glob, filter)) = filter {
+                            if !glob.is_match(&child_path) || !filter.is_match(&child_path) {
+                                continue;
+                            }
+                        }
+                        let asset_type = filter.and_then(|(_, filter)| filter.asset_type());
                         let path = child_path.to_str().expect("Path should be a valid string.");
                         let asset_path = AssetPath::parse(path).with_source(source.clone());
+                        if let Some(asset_type) = asset_type {
+                            // avoid loading assets whose loader is known to produce a different type
+                            if let Ok(loader) = server.get_path_asset_loader(&asset_path).await {
+                                if loader.asset_type_id() != asset_type {
+                                    continue;
+                                }
+                            }
+                        }
                         match server.load_untyped_async(asset_path).await {
-                            Ok(handle) => handles.push(handle),
+                            Ok(handle) => {
+                                if asset_type
+                                    .is_none_or(|asset_type| handle.type_id() == asset_type)
+                                {
+                                    handles.push(handle);
+                                }
+                            }
                             // skip assets that cannot be loaded
                             Err(
                                 AssetLoadError::MissingAssetLoaderForTypeName(_)
@@ -904,7 +1064,6 @@ impl AssetServer {
             Ok(())
         }
 
-        let path = path.into_owned();
         let server = self.clone();
         IoTaskPool::get()
             .spawn(async move {
@@ -931,7 +1090,7 @@ impl AssetServer {
                 };
 
                 let mut handles = Vec::new();
-                match load_folder(source.id(), path.path(), asset_reader, &server, &mut handles).await {
+                match load_folder(source.id(), &folder, asset_reader, &server, filter.as_ref(), &mut handles).await {
                     Ok(_) => server.send_asset_event(InternalAssetEvent::Loaded {
                         id,
                         loaded_asset: LoadedAsset::new_with_dependencies(
@@ -1160,6 +1319,18 @@ impl AssetServer {
         Some(info.path.as_ref()?.clone())
     }
 
+    /// Returns an [`AssetDependencyGraph`] describing every asset with a path that is currently tracked by this server,
+    /// and the assets each of them depends on.
+    ///
+    /// Only assets that are loaded (or loading) are included, so this should generally be called after the root assets of
+    /// interest have finished loading with their dependencies. [`AssetDependencyKind::Loader`] dependencies are only
+    /// recorded when this server is [watching for changes](Self::watching_for_changes).
+    ///
+    /// [`AssetDependencyKind::Loader`]: crate::graph::AssetDependencyKind::Loader
+    pub fn dependency_graph(&self) -> AssetDependencyGraph {
+        self.data.infos.read().dependency_graph()
+    }
+
     /// Returns the [`AssetServerMode`] this server is currently in.
     pub fn mode(&self) -> AssetServerMode {
         self.data.mode
@@ -1264,12 +1435,18 @@ impl AssetServer {
                         }
                     };
                     let loader = self.get_asset_loader_with_type_name(&loader_name).await?;
-                    let meta = loader.deserialize_meta(&meta_bytes).map_err(|e| {
-                        AssetLoadError::DeserializeMeta {
+                    let meta = self
+                        .migrate_meta(
+                            &loader_name,
+                            &meta_bytes,
+                            minimal.settings_version,
+                            loader.settings_version(),
+                        )
+                        .and_then(|meta_bytes| loader.deserialize_meta(&meta_bytes))
+                        .map_err(|e| AssetLoadError::DeserializeMeta {
                             path: asset_path.clone_owned(),
                             error: e.into(),
-                        }
-                    })?;
+                        })?;
 
                     Ok((meta, loader, reader))
                 }
@@ -1552,6 +1729,25 @@ pub fn handle_internal_asset_events(world: &mut World) {
             }
         };
 
+        let mut globs_to_reload = <HashSet<AssetPath<'static>>>::default();
+        let mut queue_matching_globs =
+            |path: &Path, source: &AssetSourceId<'static>, is_folder: bool| {
+                for (glob_path, (glob, filter)) in &infos.globs {
+                    if glob_path.source() != source {
+                        continue;
+                    }
+                    let affected = if is_folder {
+                        let base_path = glob.base_path();
+                        path.starts_with(&base_path) || base_path.starts_with(path)
+                    } else {
+                        glob.is_match(path) && filter.is_match(path)
+                    };
+                    if affected {
+                        globs_to_reload.insert(glob_path.clone());
+                    }
+                }
+            };
+
         let mut paths_to_reload = <HashSet<_>>::default();
         let mut handle_event = |source: AssetSourceId<'static>, event: AssetSourceEvent| {
             match event {
@@ -1563,13 +1759,21 @@ pub fn handle_internal_asset_events(world: &mut World) {
                     paths_to_reload.insert(path);
                 }
                 AssetSourceEvent::RenamedFolder { old, new } => {
+                    queue_matching_globs(&old, &source, true);
+                    queue_matching_globs(&new, &source, true);
                     reload_parent_folders(old, &source);
                     reload_parent_folders(new, &source);
                 }
-                AssetSourceEvent::AddedAsset(path)
-                | AssetSourceEvent::RemovedAsset(path)
-                | AssetSourceEvent::RemovedFolder(path)
-                | AssetSourceEvent::AddedFolder(path) => {
+                AssetSourceEvent::RenamedAsset { old, new } => {
+                    queue_matching_globs(&old, &source, false);
+                    queue_matching_globs(&new, &source, false);
+                }
+                AssetSourceEvent::AddedAsset(path) | AssetSourceEvent::RemovedAsset(path) => {
+                    queue_matching_globs(&path, &source, false);
+                    reload_parent_folders(path, &source);
+                }
+                AssetSourceEvent::RemovedFolder(path) | AssetSourceEvent::AddedFolder(path) => {
+                    queue_matching_globs(&path, &source, true);
                     reload_parent_folders(path, &source);
                 }
                 _ => {}
@@ -1600,6 +1804,25 @@ pub fn handle_internal_asset_events(world: &mut World) {
             server.reload(path);
         }
 
+        for glob_path in globs_to_reload {
+            let handles = infos.get_path_handles(&glob_path).collect::<Vec<_>>();
+            if handles.is_empty() {
+                // the glob is no longer loaded
+                infos.globs.remove(&glob_path);
+                continue;
+            }
+            let (glob, filter) = infos.globs[&glob_path].clone();
+            for handle in handles {
+                info!("Reloading glob {glob_path} because matching content has changed");
+                server.load_glob_internal(
+                    handle.id(),
+                    glob_path.clone(),
+                    glob.clone(),
+                    filter.clone(),
+                );
+            }
+        }
+
         #[cfg(not(any(target_arch = "wasm32", not(feature = "multi_threaded"))))]
         infos
             .pending_tasks
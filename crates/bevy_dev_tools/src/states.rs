@@ -1,7 +1,21 @@
 //! Tools for debugging states.
 
-use bevy_ecs::event::EventReader;
-use bevy_state::state::{StateTransitionEvent, States};
+use core::{marker::PhantomData, panic::Location};
+use std::collections::VecDeque;
+
+use bevy_app::{App, Plugin};
+use bevy_diagnostic::FrameCount;
+use bevy_ecs::{
+    event::EventReader,
+    resource::Resource,
+    schedule::IntoSystemConfigs,
+    system::{Res, ResMut},
+    world::World,
+};
+use bevy_state::state::{
+    FreelyMutableState, NextState, PendingTransition, StateTransition, StateTransitionEvent,
+    StateTransitionSteps, States,
+};
 use tracing::info;
 
 /// Logs state transitions into console.
@@ -16,3 +30,460 @@ pub fn log_transitions<S: States>(mut transitions: EventReader<StateTransitionEv
     let StateTransitionEvent { exited, entered } = transition;
     info!("{} transition: {:?} => {:?}", name, exited, entered);
 }
+
+/// Records the transitions of state `S` in a queryable [`StateHistory<S>`] resource.
+///
+/// When the `track_location` feature is enabled, the history can also record the code locations
+/// which requested each transition through [`NextState<S>`], see `with_requests`.
+pub struct StateHistoryPlugin<S: States> {
+    /// Maximum number of transitions kept in the history.
+    pub capacity: usize,
+    /// Whether to log every recorded transition into console.
+    pub log: bool,
+    track_requests: Option<fn(&mut App)>,
+    _marker: PhantomData<fn() -> S>,
+}
+
+impl<S: States> Default for StateHistoryPlugin<S> {
+    fn default() -> Self {
+        Self {
+            capacity: 256,
+            log: false,
+            track_requests: None,
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "track_location")]
+impl<S: FreelyMutableState> StateHistoryPlugin<S> {
+    /// Also record the code locations which requested each transition through [`NextState<S>`].
+    ///
+    /// Requests are recorded at the end of every schedule of the [`MainScheduleOrder`] and [`FixedMainScheduleOrder`],
+    /// so a request overridden by another system of the same schedule before it could be recorded is not listed.
+    ///
+    /// This requires the `track_location` feature.
+    ///
+    /// [`MainScheduleOrder`]: bevy_app::MainScheduleOrder
+    /// [`FixedMainScheduleOrder`]: bevy_app::FixedMainScheduleOrder
+    pub fn with_requests(mut self) -> Self {
+        self.track_requests = Some(track_requests::<S>);
+        self
+    }
+}
+
+impl<S: States> Plugin for StateHistoryPlugin<S> {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(StateHistory::<S> {
+            entries: VecDeque::new(),
+            capacity: self.capacity,
+            log: self.log,
+            pending_requests: Vec::new(),
+            rewinding: None,
+            next_target: None,
+        });
+        app.add_systems(
+            StateTransition,
+            (record_transitions::<S>, forget_abandoned_requests::<S>)
+                .chain()
+                .after(StateTransitionSteps::EnterSchedules),
+        );
+    }
+
+    fn finish(&self, app: &mut App) {
+        // Schedules are only all known once every plugin is built.
+        if let Some(track_requests) = self.track_requests {
+            track_requests(app);
+        }
+    }
+}
+
+/// A recorded transition of state `S`, see [`StateHistory<S>`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateHistoryEntry<S: States> {
+    /// The [`FrameCount`] of the frame the transition happened in.
+    pub frame: u32,
+    /// The state being exited.
+    pub exited: Option<S>,
+    /// The state being entered.
+    pub entered: Option<S>,
+    /// The distinct code locations which requested the transition through [`NextState<S>`],
+    /// in the order they were recorded.
+    ///
+    /// This is only recorded for plugins built with `StateHistoryPlugin::with_requests`,
+    /// which requires the `track_location` feature.
+    pub requested_by: Vec<&'static Location<'static>>,
+    /// Whether the transition was a [rewind](StateHistory::rewind) to a previous state.
+    pub rewind: bool,
+}
+
+/// The history of the transitions of state `S`, recorded by [`StateHistoryPlugin<S>`].
+///
+/// The history can also be used to rewind a [freely mutable](FreelyMutableState) state
+/// to a previous value, which goes through the [`StateTransition`] schedule like any other transition.
+#[derive(Resource, Debug)]
+pub struct StateHistory<S: States> {
+    entries: VecDeque<StateHistoryEntry<S>>,
+    capacity: usize,
+    log: bool,
+    pending_requests: Vec<&'static Location<'static>>,
+    rewinding: Option<S>,
+    next_target: Option<fn(&World) -> Option<S>>,
+}
+
+impl<S: States> StateHistory<S> {
+    /// Returns an iterator over the recorded transitions, from the oldest to the latest.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &StateHistoryEntry<S>> {
+        self.entries.iter()
+    }
+
+    /// Returns the latest recorded transition.
+    pub fn last(&self) -> Option<&StateHistoryEntry<S>> {
+        self.entries.back()
+    }
+
+    /// Returns the number of recorded transitions.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no transition is recorded.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes all recorded transitions.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns the state entered `steps` transitions ago, `0` being the current state.
+    pub fn previous(&self, steps: usize) -> Option<&S> {
+        let index = self.entries.len().checked_sub(steps + 1)?;
+        self.entries[index].entered.as_ref()
+    }
+
+    fn push(&mut self, entry: StateHistoryEntry<S>) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+}
+
+impl<S: FreelyMutableState> StateHistory<S> {
+    /// Queues a transition back to the state entered `steps` transitions ago.
+    ///
+    /// Returns the state being rewound to, or `None` if the history doesn't go back that far
+    /// or the state didn't exist at that point.
+    ///
+    /// The transition is only recorded as a rewind if it enters that state: the rewind is forgotten
+    /// when it is overridden by another request, or abandoned by the [gates](bevy_state::state::StateGates)
+    /// of the current state.
+    pub fn rewind(&mut self, steps: usize, next_state: &mut NextState<S>) -> Option<S> {
+        let state = self.previous(steps)?.clone();
+        next_state.set(state.clone());
+        self.rewinding = Some(state.clone());
+        self.next_target = Some(next_target::<S>);
+        Some(state)
+    }
+}
+
+/// Returns the state of the transition requested or held by gates, if any.
+fn next_target<S: FreelyMutableState>(world: &World) -> Option<S> {
+    if let Some(NextState::Pending(target)) = world.get_resource::<NextState<S>>() {
+        return Some(target.clone());
+    }
+    world
+        .get_resource::<PendingTransition<S>>()
+        .map(|pending| pending.target().clone())
+}
+
+#[cfg(feature = "track_location")]
+fn track_requests<S: FreelyMutableState>(app: &mut App) {
+    use bevy_app::{FixedMainScheduleOrder, MainScheduleOrder};
+    use bevy_ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+
+    let mut labels: Vec<InternedScheduleLabel> = Vec::new();
+    if let Some(order) = app.world().get_resource::<MainScheduleOrder>() {
+        labels.extend(order.startup_labels.iter().chain(&order.labels));
+    }
+    if let Some(order) = app.world().get_resource::<FixedMainScheduleOrder>() {
+        labels.extend(&order.labels);
+    }
+    for label in labels {
+        if label != StateTransition.intern() {
+            app.add_systems(label, record_requests::<S>);
+        }
+    }
+    app.add_systems(
+        StateTransition,
+        record_requests::<S>.before(StateTransitionSteps::DependentTransitions),
+    );
+    if let Some(mut history) = app.world_mut().get_resource_mut::<StateHistory<S>>() {
+        history.next_target = Some(next_target::<S>);
+    }
+}
+
+#[cfg(feature = "track_location")]
+fn record_requests<S: FreelyMutableState>(
+    next_state: Option<Res<NextState<S>>>,
+    mut history: ResMut<StateHistory<S>>,
+) {
+    use bevy_ecs::change_detection::DetectChanges;
+
+    if let Some(next_state) = next_state {
+        if next_state.is_changed() && matches!(*next_state, NextState::Pending(_)) {
+            let location = next_state.changed_by();
+            if !history.pending_requests.contains(&location) {
+                history.pending_requests.push(location);
+            }
+        }
+    }
+}
+
+fn record_transitions<S: States>(
+    mut transitions: EventReader<StateTransitionEvent<S>>,
+    frame_count: Option<Res<FrameCount>>,
+    mut history: ResMut<StateHistory<S>>,
+) {
+    let frame = frame_count.map_or(0, |frame_count| frame_count.0);
+    for transition in transitions.read() {
+        let requested_by = core::mem::take(&mut history.pending_requests);
+        let rewind = history.rewinding.is_some() && history.rewinding == transition.entered;
+        if rewind {
+            history.rewinding = None;
+        }
+        if history.log {
+            info!(
+                "{} transition in frame {}: {:?} => {:?}{}{}",
+                core::any::type_name::<S>(),
+                frame,
+                transition.exited,
+                transition.entered,
+                if rewind { " (rewind)" } else { "" },
+                RequestedBy(&requested_by)
+            );
+        }
+        history.push(StateHistoryEntry {
+            frame,
+            exited: transition.exited.clone(),
+            entered: transition.entered.clone(),
+            requested_by,
+            rewind,
+        });
+    }
+}
+
+/// Forgets the requests and rewind of transitions which were overridden or abandoned instead of applied,
+/// so that they aren't attributed to a later transition.
+fn forget_abandoned_requests<S: States>(world: &mut World) {
+    let Some(next_target) = world
+        .get_resource::<StateHistory<S>>()
+        .and_then(|history| history.next_target)
+    else {
+        return;
+    };
+    let target = next_target(world);
+    let mut history = world.resource_mut::<StateHistory<S>>();
+    if target.is_none() {
+        history.pending_requests.clear();
+    }
+    if history.rewinding.is_some() && history.rewinding != target {
+        history.rewinding = None;
+    }
+}
+
+struct RequestedBy<'a>(&'a [&'static Location<'static>]);
+
+impl core::fmt::Display for RequestedBy<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, location) in self.0.iter().enumerate() {
+            f.write_str(if i == 0 { ", requested by " } else { ", " })?;
+            write!(f, "{location}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::change_detection::Mut;
+    use bevy_state::{
+        app::{AppExtStates, StatesPlugin},
+        state::GateStatus,
+    };
+
+    use super::*;
+
+    #[derive(States, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    enum TestState {
+        #[default]
+        A,
+        B,
+        C,
+    }
+
+    #[derive(Resource)]
+    struct FailGate(bool);
+
+    fn test_app(plugin: StateHistoryPlugin<TestState>) -> App {
+        let mut app = App::new();
+        app.add_plugins((StatesPlugin, plugin))
+            .init_state::<TestState>()
+            .insert_resource(FailGate(true))
+            .add_state_gate(TestState::C, |fail: Res<FailGate>| {
+                if fail.0 {
+                    GateStatus::Failed
+                } else {
+                    GateStatus::Ready
+                }
+            });
+        app.finish();
+        app.update();
+        app
+    }
+
+    fn history(app: &App) -> &StateHistory<TestState> {
+        app.world().resource::<StateHistory<TestState>>()
+    }
+
+    fn last(app: &App) -> (Option<TestState>, Option<TestState>, bool) {
+        let entry = history(app).last().unwrap();
+        (entry.exited, entry.entered, entry.rewind)
+    }
+
+    fn transition(app: &mut App, state: TestState) {
+        app.world_mut()
+            .resource_mut::<NextState<TestState>>()
+            .set(state);
+        app.update();
+    }
+
+    fn rewind(app: &mut App, steps: usize) -> Option<TestState> {
+        app.world_mut()
+            .resource_scope(|world, mut history: Mut<StateHistory<TestState>>| {
+                history.rewind(steps, &mut world.resource_mut::<NextState<TestState>>())
+            })
+    }
+
+    #[test]
+    fn records_transitions() {
+        let mut app = test_app(StateHistoryPlugin::default());
+        assert_eq!(history(&app).len(), 1);
+        assert_eq!(last(&app), (None, Some(TestState::A), false));
+
+        transition(&mut app, TestState::B);
+        assert_eq!(history(&app).len(), 2);
+        assert_eq!(last(&app), (Some(TestState::A), Some(TestState::B), false));
+        assert_eq!(history(&app).previous(0), Some(&TestState::B));
+        assert_eq!(history(&app).previous(1), Some(&TestState::A));
+        assert_eq!(history(&app).previous(2), None);
+    }
+
+    #[test]
+    fn drops_oldest_transitions_over_capacity() {
+        let mut app = test_app(StateHistoryPlugin {
+            capacity: 2,
+            ..Default::default()
+        });
+        transition(&mut app, TestState::B);
+        transition(&mut app, TestState::C);
+        let entered = history(&app)
+            .iter()
+            .map(|entry| entry.entered)
+            .collect::<Vec<_>>();
+        assert_eq!(entered, vec![Some(TestState::B), Some(TestState::C)]);
+
+        let mut app = test_app(StateHistoryPlugin {
+            capacity: 0,
+            ..Default::default()
+        });
+        transition(&mut app, TestState::B);
+        assert!(history(&app).is_empty());
+    }
+
+    #[test]
+    fn rewinds_to_previous_states() {
+        let mut app = test_app(StateHistoryPlugin::default());
+        transition(&mut app, TestState::B);
+        assert_eq!(rewind(&mut app, 3), None);
+        assert_eq!(rewind(&mut app, 1), Some(TestState::A));
+        app.update();
+        assert_eq!(last(&app), (Some(TestState::B), Some(TestState::A), true));
+
+        transition(&mut app, TestState::B);
+        assert_eq!(last(&app), (Some(TestState::A), Some(TestState::B), false));
+    }
+
+    #[test]
+    fn forgets_overridden_rewinds() {
+        let mut app = test_app(StateHistoryPlugin::default());
+        transition(&mut app, TestState::B);
+        assert_eq!(rewind(&mut app, 1), Some(TestState::A));
+        transition(&mut app, TestState::C);
+        assert_eq!(last(&app), (Some(TestState::B), Some(TestState::C), false));
+
+        app.world_mut().resource_mut::<FailGate>().0 = false;
+        transition(&mut app, TestState::A);
+        assert_eq!(last(&app), (Some(TestState::C), Some(TestState::A), false));
+    }
+
+    #[test]
+    fn forgets_rewinds_abandoned_by_gates() {
+        let mut app = test_app(StateHistoryPlugin::default());
+        transition(&mut app, TestState::B);
+        transition(&mut app, TestState::C);
+        assert_eq!(rewind(&mut app, 1), Some(TestState::B));
+        app.update();
+        assert_eq!(history(&app).len(), 3);
+
+        app.world_mut().resource_mut::<FailGate>().0 = false;
+        transition(&mut app, TestState::B);
+        assert_eq!(history(&app).len(), 4);
+        assert_eq!(last(&app), (Some(TestState::C), Some(TestState::B), false));
+    }
+
+    #[cfg(feature = "track_location")]
+    #[test]
+    fn records_every_requesting_system() {
+        use bevy_app::{Last, Update};
+        use bevy_state::condition::in_state;
+
+        fn request_in_update(mut next_state: ResMut<NextState<TestState>>) {
+            next_state.set(TestState::B);
+        }
+
+        fn request_in_last(mut next_state: ResMut<NextState<TestState>>) {
+            next_state.set(TestState::B);
+        }
+
+        let mut app = App::new();
+        app.add_plugins((
+            StatesPlugin,
+            StateHistoryPlugin::<TestState>::default().with_requests(),
+        ))
+        .init_state::<TestState>()
+        .add_systems(Update, request_in_update.run_if(in_state(TestState::A)))
+        .add_systems(Last, request_in_last.run_if(in_state(TestState::A)));
+        app.finish();
+        app.update();
+        app.update();
+
+        let entry = history(&app).last().unwrap();
+        assert_eq!(entry.entered, Some(TestState::B));
+        assert_eq!(entry.requested_by.len(), 2);
+        assert_ne!(entry.requested_by[0], entry.requested_by[1]);
+        assert!(entry
+            .requested_by
+            .iter()
+            .all(|location| location.file() == file!()));
+
+        // The request of `transition` is recorded, but not the ones of the previous transition.
+        transition(&mut app, TestState::C);
+        assert_eq!(history(&app).last().unwrap().requested_by.len(), 1);
+    }
+}
@@ -0,0 +1,170 @@
+use bevy_platform_support::{collections::HashSet, hash::FixedHasher};
+use bevy_reflect::{TypeInfo, VariantInfo};
+use core::{
+    alloc::Layout,
+    any::TypeId,
+    hash::{BuildHasher, Hash, Hasher},
+};
+
+/// The layout of a type registered by a gameplay library.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct TypeLayout {
+    pub(crate) type_path: &'static str,
+    /// The size and alignment of the type in the build of the library.
+    pub(crate) memory: Layout,
+    /// The hash of the reflected layout of the type, see [`layout_hash`].
+    pub(crate) hash: u64,
+}
+
+/// Computes a hash of the reflected layout of a type.
+///
+/// This covers the type's kind, along with the names and types of its fields and variants, recursively.
+/// Opaque types are only identified by their type path, as their layout isn't reflected.
+pub(crate) fn layout_hash(info: &TypeInfo) -> u64 {
+    let mut hasher = FixedHasher.build_hasher();
+    hash_type(info, &mut hasher, &mut HashSet::default());
+    hasher.finish()
+}
+
+fn hash_type(info: &TypeInfo, hasher: &mut impl Hasher, visited: &mut HashSet<TypeId>) {
+    info.type_path().hash(hasher);
+    // Recursive types are only hashed once.
+    if !visited.insert(info.type_id()) {
+        return;
+    }
+
+    let mut hash_field = |info: Option<&TypeInfo>, hasher: &mut _| match info {
+        Some(info) => hash_type(info, hasher, visited),
+        None => "<unknown>".hash(hasher),
+    };
+    match info {
+        TypeInfo::Struct(info) => {
+            "struct".hash(hasher);
+            for field in info.iter() {
+                field.name().hash(hasher);
+                hash_field(field.type_info(), hasher);
+            }
+        }
+        TypeInfo::TupleStruct(info) => {
+            "tuple_struct".hash(hasher);
+            for field in info.iter() {
+                hash_field(field.type_info(), hasher);
+            }
+        }
+        TypeInfo::Tuple(info) => {
+            "tuple".hash(hasher);
+            for field in info.iter() {
+                hash_field(field.type_info(), hasher);
+            }
+        }
+        TypeInfo::List(info) => {
+            "list".hash(hasher);
+            hash_field(info.item_info(), hasher);
+        }
+        TypeInfo::Array(info) => {
+            "array".hash(hasher);
+            info.capacity().hash(hasher);
+            hash_field(info.item_info(), hasher);
+        }
+        TypeInfo::Map(info) => {
+            "map".hash(hasher);
+            hash_field(info.key_info(), hasher);
+            hash_field(info.value_info(), hasher);
+        }
+        TypeInfo::Set(info) => {
+            "set".hash(hasher);
+            info.value_ty().path().hash(hasher);
+        }
+        TypeInfo::Enum(info) => {
+            "enum".hash(hasher);
+            for variant in info.iter() {
+                variant.name().hash(hasher);
+                match variant {
+                    VariantInfo::Struct(variant) => {
+                        "struct".hash(hasher);
+                        for field in variant.iter() {
+                            field.name().hash(hasher);
+                            hash_field(field.type_info(), hasher);
+                        }
+                    }
+                    VariantInfo::Tuple(variant) => {
+                        "tuple".hash(hasher);
+                        for field in variant.iter() {
+                            hash_field(field.type_info(), hasher);
+                        }
+                    }
+                    VariantInfo::Unit(_) => "unit".hash(hasher),
+                }
+            }
+        }
+        TypeInfo::Opaque(_) => "opaque".hash(hasher),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::layout_hash;
+    use bevy_reflect::{Reflect, Typed};
+
+    // Two builds of the same types, sharing their type paths.
+    mod before {
+        use bevy_reflect::Reflect;
+
+        #[derive(Reflect)]
+        #[type_path = "gameplay"]
+        pub struct Player {
+            pub health: u32,
+            pub inventory: Vec<Item>,
+        }
+
+        #[derive(Reflect)]
+        #[type_path = "gameplay"]
+        pub struct Item {
+            pub weight: f32,
+        }
+    }
+
+    mod after {
+        use bevy_reflect::Reflect;
+
+        #[derive(Reflect)]
+        #[type_path = "gameplay"]
+        pub struct Player {
+            pub health: u32,
+            pub inventory: Vec<Item>,
+        }
+
+        #[derive(Reflect)]
+        #[type_path = "gameplay"]
+        pub struct Item {
+            pub weight: f32,
+            pub value: u32,
+        }
+    }
+
+    #[derive(Reflect)]
+    #[reflect(no_field_bounds)]
+    struct Tree {
+        children: Vec<Tree>,
+    }
+
+    #[test]
+    fn layout_hash_covers_nested_fields() {
+        assert_ne!(
+            layout_hash(before::Item::type_info()),
+            layout_hash(after::Item::type_info())
+        );
+        assert_ne!(
+            layout_hash(before::Player::type_info()),
+            layout_hash(after::Player::type_info())
+        );
+    }
+
+    #[test]
+    fn layout_hash_of_recursive_type() {
+        assert_eq!(
+            layout_hash(Tree::type_info()),
+            layout_hash(Tree::type_info())
+        );
+    }
+}
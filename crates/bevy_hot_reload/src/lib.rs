@@ -0,0 +1,297 @@
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![doc(
+    html_logo_url = "https://bevyengine.org/assets/icon.png",
+    html_favicon_url = "https://bevyengine.org/assets/icon.png"
+)]
+#![cfg(target_os = "linux")]
+
+//! Hot reloading of gameplay systems from a dynamic library, for development builds on Linux desktop.
+//!
+//! Gameplay systems are built in their own crate, as a dynamic library loaded by the game at runtime
+//! with the [`HotReloadPlugin`]. When the library is rebuilt, its systems are swapped in place in the
+//! schedules they were added to, without restarting the game: the [`World`], with its entities and
+//! resources, is kept as is.
+//!
+//! # Setup
+//!
+//! Both the game and the gameplay library must be built with the `dynamic_linking` feature of Bevy,
+//! by the same compiler, so that they share the same Bevy types. The gameplay crate is a `dylib`,
+//! which the game must not depend on:
+//!
+//! ```toml
+//! [lib]
+//! crate-type = ["dylib"]
+//!
+//! [dependencies]
+//! bevy = { version = "0.16", features = ["dynamic_linking"] }
+//! ```
+//!
+//! The library adds its systems from a function exported with [`reloadable_systems!`]:
+//!
+//! ```ignore
+//! use bevy::{hot_reload::ReloadableSystems, prelude::*};
+//!
+//! #[derive(Resource, Reflect, Default)]
+//! struct Score(u32);
+//!
+//! fn score(mut score: ResMut<Score>) {
+//!     score.0 += 1;
+//! }
+//!
+//! fn register(systems: &mut ReloadableSystems) {
+//!     systems
+//!         .register_type::<Score>()
+//!         .init_resource::<Score>()
+//!         .add_systems(Update, score);
+//! }
+//!
+//! bevy::hot_reload::reloadable_systems!(register);
+//! ```
+//!
+//! And the game loads it, here from `libgameplay.so` next to its executable:
+//!
+//! ```ignore
+//! // SAFETY: The types of the gameplay library stored in the world are registered.
+//! let hot_reload = unsafe { HotReloadPlugin::from_crate_name("gameplay") };
+//! App::new()
+//!     .add_plugins((DefaultPlugins, hot_reload))
+//!     .run();
+//! ```
+//!
+//! Running `cargo build -p gameplay` while the game runs then reloads the gameplay systems.
+//!
+//! # Type layouts
+//!
+//! A rebuilt library keeps the [`TypeId`](core::any::TypeId)s of its types, so components and
+//! resources already in the [`World`] are read by the new systems. To make sure they are read with
+//! the layout they were written with, the types of the library must be registered with
+//! [`ReloadableSystems::register_type`]. Their size and alignment are compared with the ones of the
+//! components and resources of the [`World`] with the same type, and their reflected layouts are
+//! hashed and compared with the ones of the previous build.
+//!
+//! A rebuilt library in which one of these layouts changed is rejected, keeping the previous systems
+//! running until the game is restarted: a [`World`] can't change the layout of a component or resource
+//! once registered, so the data of these types can't be reset. The data of the other types is preserved.
+//! Types which aren't registered aren't checked, which is why [`HotReloadPlugin::new`] is `unsafe`.
+//!
+//! Libraries are never unloaded, as the [`World`] can still refer to their code after a reload,
+//! for example to drop components.
+//!
+//! [`World`]: bevy_ecs::world::World
+
+extern crate alloc;
+
+mod layout;
+mod library;
+
+pub use library::*;
+
+use alloc::{boxed::Box, vec::Vec};
+use bevy_app::{App, First, MainScheduleOrder, Plugin};
+use bevy_ecs::{
+    resource::Resource,
+    schedule::{
+        ExecutorKind, InternedScheduleLabel, IntoSystemConfigs, Schedule, ScheduleLabel, SystemSet,
+    },
+    world::{FromWorld, World},
+};
+use bevy_platform_support::collections::HashMap;
+use bevy_reflect::{GetTypeRegistration, TypeRegistry};
+use core::{alloc::Layout, any::TypeId, time::Duration};
+use std::path::{Path, PathBuf};
+
+/// The hot reload prelude.
+///
+/// This includes the most common types in this crate, re-exported for your convenience.
+pub mod prelude {
+    #[doc(hidden)]
+    pub use crate::{reloadable_systems, HotReloadPlugin, ReloadableSystems};
+}
+
+/// Loads gameplay systems from a dynamic library, and reloads them when the library is rebuilt.
+///
+/// See the [crate documentation](crate) for how to build the library.
+///
+/// # Panics
+///
+/// Panics if the library can't be loaded when the plugin is built.
+/// Failures to reload the library are logged, and the previous systems keep running.
+pub struct HotReloadPlugin {
+    path: PathBuf,
+    debounce: Duration,
+}
+
+#[expect(
+    unsafe_code,
+    reason = "The library is trusted to register the types it stores in the world."
+)]
+impl HotReloadPlugin {
+    /// Creates a plugin loading the library at `path`.
+    ///
+    /// # Safety
+    ///
+    /// Every type the library stores in the [`World`], as a component, a resource, or in the fields of one,
+    /// must be registered with [`ReloadableSystems::register_type`] by each build of the library.
+    /// The layouts of other types aren't checked when the library is reloaded, and reading their data
+    /// with a different layout than the one it was written with is undefined behavior.
+    pub unsafe fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            debounce: Duration::from_millis(250),
+        }
+    }
+
+    /// Creates a plugin loading the library of the crate `name` from the directory of the
+    /// current executable, where Cargo outputs the libraries of the workspace.
+    ///
+    /// # Safety
+    ///
+    /// See [`HotReloadPlugin::new`].
+    pub unsafe fn from_crate_name(name: &str) -> Self {
+        let directory = std::env::current_exe()
+            .ok()
+            .and_then(|executable| executable.parent().map(Path::to_path_buf))
+            .unwrap_or_default();
+        // SAFETY: Upheld by the caller.
+        unsafe { Self::new(directory.join(format!("lib{}.so", name.replace('-', "_")))) }
+    }
+}
+
+impl HotReloadPlugin {
+    /// Sets how long the library must be left unchanged after being rebuilt before it's reloaded,
+    /// so that a library still being written isn't loaded.
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Returns the path of the gameplay library.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Plugin for HotReloadPlugin {
+    fn build(&self, app: &mut App) {
+        let mut library = GameplayLibrary::new(self.path.clone(), self.debounce);
+        let systems = library.load().unwrap_or_else(|error| {
+            panic!(
+                "Failed to load the gameplay library {}: {error}",
+                self.path.display()
+            )
+        });
+
+        let mut schedule = Schedule::new(HotReload);
+        schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+        app.add_schedule(schedule)
+            .init_resource::<ReloadableSchedules>()
+            .add_systems(HotReload, reload_gameplay_library);
+        app.world_mut()
+            .resource_mut::<MainScheduleOrder>()
+            .insert_before(First, HotReload);
+
+        library
+            .install(systems, app.world_mut())
+            .unwrap_or_else(|error| {
+                panic!(
+                    "Failed to load the gameplay library {}: {error}",
+                    self.path.display()
+                )
+            });
+        app.insert_resource(library);
+    }
+}
+
+/// The schedule that reloads the gameplay library when it was rebuilt, before [`First`].
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HotReload;
+
+/// The set of the systems running the gameplay systems added to each schedule by the library.
+///
+/// This can be used to order the systems of the game relative to the gameplay systems.
+#[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GameplaySystems;
+
+/// The systems and types of a gameplay library, added by the function it exports with
+/// [`reloadable_systems!`].
+pub struct ReloadableSystems {
+    schedules: HashMap<InternedScheduleLabel, Schedule>,
+    type_registry: TypeRegistry,
+    memory_layouts: HashMap<TypeId, Layout>,
+    resources: Vec<Box<dyn FnOnce(&mut World) + Send>>,
+}
+
+impl Default for ReloadableSystems {
+    fn default() -> Self {
+        Self {
+            schedules: HashMap::default(),
+            // Only the types registered by the library, along with their dependencies.
+            type_registry: TypeRegistry::empty(),
+            memory_layouts: HashMap::default(),
+            resources: Vec::new(),
+        }
+    }
+}
+
+impl ReloadableSystems {
+    /// Adds systems to the `schedule`, see [`App::add_systems`].
+    ///
+    /// The systems are run by a system of the [`GameplaySystems`] set in the `schedule`, so they
+    /// can only be ordered relative to each other.
+    pub fn add_systems<M>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        systems: impl IntoSystemConfigs<M>,
+    ) -> &mut Self {
+        let schedule = schedule.intern();
+        self.schedules
+            .entry(schedule)
+            .or_insert_with(|| Schedule::new(schedule))
+            .add_systems(systems);
+        self
+    }
+
+    /// Registers the type `T`, so that its layout is checked when the library is reloaded.
+    ///
+    /// See [`App::register_type`].
+    pub fn register_type<T: GetTypeRegistration>(&mut self) -> &mut Self {
+        self.type_registry.register::<T>();
+        self.memory_layouts
+            .insert(TypeId::of::<T>(), Layout::new::<T>());
+        self
+    }
+
+    /// Initializes the resource `R` if it doesn't exist yet, see [`App::init_resource`].
+    ///
+    /// Resources are kept when the library is reloaded.
+    pub fn init_resource<R: Resource + FromWorld>(&mut self) -> &mut Self {
+        self.resources.push(Box::new(|world: &mut World| {
+            world.init_resource::<R>();
+        }));
+        self
+    }
+}
+
+/// The gameplay schedules of the loaded library, run by the systems of the [`GameplaySystems`] set.
+#[derive(Resource, Default)]
+struct ReloadableSchedules(HashMap<InternedScheduleLabel, Schedule>);
+
+/// Exports the function adding the systems of a gameplay library, to be loaded by the [`HotReloadPlugin`].
+///
+/// The function takes a [`&mut ReloadableSystems`](ReloadableSystems).
+#[macro_export]
+macro_rules! reloadable_systems {
+    ($register:path) => {
+        #[no_mangle]
+        #[doc(hidden)]
+        pub fn bevy_hot_reload_register(systems: &mut $crate::ReloadableSystems) {
+            $register(systems);
+        }
+
+        #[no_mangle]
+        #[doc(hidden)]
+        pub fn bevy_hot_reload_version() -> ::core::any::TypeId {
+            ::core::any::TypeId::of::<$crate::ReloadableSystems>()
+        }
+    };
+}
@@ -0,0 +1,446 @@
+#![expect(
+    unsafe_code,
+    reason = "Loading a dynamic library and calling into it is inherently unsafe."
+)]
+
+use crate::{
+    layout::{layout_hash, TypeLayout},
+    GameplaySystems, ReloadableSchedules, ReloadableSystems,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_ecs::{
+    reflect::AppTypeRegistry,
+    resource::Resource,
+    schedule::{InternedScheduleLabel, IntoSystemConfigs, Schedules},
+    world::{Mut, World},
+};
+use bevy_platform_support::{
+    collections::{HashMap, HashSet},
+    time::Instant,
+};
+use core::{any::TypeId, time::Duration};
+use libloading::Library;
+use std::{fs, io, path::PathBuf, time::SystemTime};
+use thiserror::Error;
+use tracing::{error, info};
+
+/// The symbol of the function adding the systems of the library, exported by [`reloadable_systems!`](crate::reloadable_systems).
+const REGISTER_SYMBOL: &[u8] = b"bevy_hot_reload_register\0";
+/// The symbol of the function identifying the build of Bevy the library was built with.
+const VERSION_SYMBOL: &[u8] = b"bevy_hot_reload_version\0";
+
+/// An error that occurs when loading a gameplay library.
+#[derive(Error, Debug)]
+pub enum HotReloadError {
+    /// The library file couldn't be read.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    /// The library couldn't be loaded, or doesn't export its systems with [`reloadable_systems!`](crate::reloadable_systems).
+    #[error(transparent)]
+    Load(#[from] libloading::Error),
+    /// The library was built with a different build of Bevy than the game.
+    #[error("the library was built with a different build of Bevy, make sure both the game and the library use the `dynamic_linking` feature")]
+    IncompatibleBuild,
+    /// The layout of some types registered by the library changed since it was last loaded.
+    #[error("the layout of {} changed, restart the game to apply the changes", types.join(", "))]
+    LayoutChanged {
+        /// The type paths of the types whose layout changed.
+        types: Vec<String>,
+    },
+}
+
+/// The gameplay library loaded by the [`HotReloadPlugin`](crate::HotReloadPlugin).
+#[derive(Resource)]
+pub struct GameplayLibrary {
+    path: PathBuf,
+    debounce: Duration,
+    generation: u32,
+    loads: u32,
+    modified: Option<SystemTime>,
+    /// A modification of the library waiting for the debounce duration.
+    pending: Option<(SystemTime, Instant)>,
+    /// The layouts of the types registered by the library, by type.
+    layouts: HashMap<TypeId, TypeLayout>,
+    /// The schedules in which a system of the [`GameplaySystems`] set was added.
+    installed: HashSet<InternedScheduleLabel>,
+}
+
+impl GameplayLibrary {
+    pub(crate) fn new(path: PathBuf, debounce: Duration) -> Self {
+        Self {
+            path,
+            debounce,
+            generation: 0,
+            loads: 0,
+            modified: None,
+            pending: None,
+            layouts: HashMap::default(),
+            installed: HashSet::default(),
+        }
+    }
+
+    /// Returns the path of the library.
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// Returns the number of times the library was successfully loaded, including the first time.
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+
+    /// Returns `true` if the library was rebuilt, and left unchanged for the debounce duration since.
+    fn poll(&mut self) -> bool {
+        let Ok(modified) = fs::metadata(&self.path).and_then(|metadata| metadata.modified()) else {
+            return false;
+        };
+        if self.modified == Some(modified) {
+            self.pending = None;
+            return false;
+        }
+        match self.pending {
+            Some((pending, since)) if pending == modified => {
+                if since.elapsed() < self.debounce {
+                    return false;
+                }
+                self.modified = Some(modified);
+                self.pending = None;
+                true
+            }
+            _ => {
+                self.pending = Some((modified, Instant::now()));
+                false
+            }
+        }
+    }
+
+    /// Loads the library and collects its systems.
+    pub(crate) fn load(&mut self) -> Result<ReloadableSystems, HotReloadError> {
+        self.modified = Some(fs::metadata(&self.path)?.modified()?);
+
+        // A library stays loaded once loaded, so each build is loaded from a copy with a distinct path,
+        // otherwise the previous build would be returned.
+        let file_name = self.path.file_name().unwrap_or_default().to_string_lossy();
+        let copy = std::env::temp_dir().join(format!(
+            "bevy-hot-reload-{}-{}-{file_name}",
+            std::process::id(),
+            self.loads
+        ));
+        self.loads += 1;
+        fs::copy(&self.path, &copy)?;
+        // SAFETY: The library is only loaded in development builds, and is trusted to be a gameplay
+        // library built by the developer. Its initialization routines don't have further requirements.
+        let library = unsafe { Library::new(&copy) };
+        // The copy stays mapped while the library is loaded.
+        fs::remove_file(&copy).ok();
+        let library = library?;
+
+        // SAFETY: The symbols are exported by `reloadable_systems!` with these signatures.
+        let (version, register) = unsafe {
+            (
+                *library.get::<fn() -> TypeId>(VERSION_SYMBOL)?,
+                *library.get::<fn(&mut ReloadableSystems)>(REGISTER_SYMBOL)?,
+            )
+        };
+        // Code of the library can be referred to by the world after it's reloaded, for example by the
+        // drop functions of its components, so the library is never unloaded.
+        core::mem::forget(library);
+
+        // Types only have the same id when both sides were built with the same build of Bevy.
+        if version() != TypeId::of::<ReloadableSystems>() {
+            return Err(HotReloadError::IncompatibleBuild);
+        }
+        let mut systems = ReloadableSystems::default();
+        register(&mut systems);
+        Ok(systems)
+    }
+
+    /// Swaps the systems of the previously loaded library for `systems`.
+    ///
+    /// The world is left unchanged if the layout of a type changed since it was last loaded,
+    /// or differs from the layout of the component or resource of the same type in the world.
+    pub(crate) fn install(
+        &mut self,
+        systems: ReloadableSystems,
+        world: &mut World,
+    ) -> Result<(), HotReloadError> {
+        let ReloadableSystems {
+            schedules,
+            type_registry,
+            memory_layouts,
+            resources,
+        } = systems;
+
+        // Only the types registered with `register_type` are checked. Their dependencies are covered by their
+        // layout hash, and may be types of the game or the engine the library doesn't own.
+        let layouts: HashMap<_, _> = memory_layouts
+            .iter()
+            .filter_map(|(type_id, memory)| {
+                let info = type_registry.get_type_info(*type_id)?;
+                let layout = TypeLayout {
+                    type_path: info.type_path(),
+                    memory: *memory,
+                    hash: layout_hash(info),
+                };
+                Some((*type_id, layout))
+            })
+            .collect();
+        // Components and resources keep the layout they were registered with for the lifetime of the world,
+        // even if they were registered by another build of the library or before it was first loaded.
+        let mut changed: Vec<String> = world
+            .components()
+            .iter()
+            .filter_map(|info| {
+                let layout = layouts.get(&info.type_id()?)?;
+                (layout.memory != info.layout()).then(|| layout.type_path.to_string())
+            })
+            .chain(
+                layouts
+                    .iter()
+                    .filter(|(type_id, layout)| {
+                        self.layouts
+                            .get(*type_id)
+                            .is_some_and(|previous| previous != *layout)
+                    })
+                    .map(|(_, layout)| layout.type_path.to_string()),
+            )
+            .collect();
+        if !changed.is_empty() {
+            changed.sort();
+            changed.dedup();
+            return Err(HotReloadError::LayoutChanged { types: changed });
+        }
+
+        if let Some(app_registry) = world.get_resource::<AppTypeRegistry>() {
+            let mut app_registry = app_registry.write();
+            for registration in type_registry.iter() {
+                // Only replace the registrations of types from the library, keeping the type data other
+                // plugins added to their dependencies.
+                if layouts.contains_key(&registration.type_id()) {
+                    app_registry.overwrite_registration(registration.clone());
+                } else {
+                    app_registry.add_registration(registration.clone());
+                }
+            }
+        }
+        self.layouts = layouts;
+
+        for label in schedules.keys().copied() {
+            if self.installed.insert(label) {
+                world.resource_mut::<Schedules>().add_systems(
+                    label,
+                    run_reloadable_schedule(label).in_set(GameplaySystems),
+                );
+            }
+        }
+        world.resource_mut::<ReloadableSchedules>().0 = schedules;
+        for init_resource in resources {
+            init_resource(world);
+        }
+
+        self.generation += 1;
+        Ok(())
+    }
+}
+
+/// Runs the gameplay systems of the library added to the schedule `label`.
+fn run_reloadable_schedule(label: InternedScheduleLabel) -> impl FnMut(&mut World) {
+    move |world: &mut World| {
+        // The schedule is taken out while it runs, as gameplay schedules can be nested,
+        // for example in `RunFixedMainLoop` and `FixedUpdate`.
+        let Some(mut schedule) = world.resource_mut::<ReloadableSchedules>().0.remove(&label)
+        else {
+            return;
+        };
+        schedule.run(world);
+        world
+            .resource_mut::<ReloadableSchedules>()
+            .0
+            .insert(label, schedule);
+    }
+}
+
+/// Reloads the gameplay library when it was rebuilt.
+pub(crate) fn reload_gameplay_library(world: &mut World) {
+    world.resource_scope(|world, mut library: Mut<GameplayLibrary>| {
+        if !library.poll() {
+            return;
+        }
+        match library
+            .load()
+            .and_then(|systems| library.install(systems, world))
+        {
+            Ok(()) => info!(
+                "Reloaded the gameplay library {} (generation {})",
+                library.path.display(),
+                library.generation
+            ),
+            Err(error) => error!(
+                "Failed to reload the gameplay library {}: {error}",
+                library.path.display()
+            ),
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GameplayLibrary, HotReloadError};
+    use crate::{ReloadableSchedules, ReloadableSystems};
+    use bevy_app::{App, Update};
+    use bevy_ecs::{
+        component::Component, reflect::AppTypeRegistry, resource::Resource, system::ResMut,
+    };
+    use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+    use core::{alloc::Layout, any::TypeId, time::Duration};
+    use std::{path::PathBuf, process::Command};
+
+    #[derive(Resource, Reflect, Default)]
+    struct Score(u32);
+
+    #[derive(Component, Reflect, Default)]
+    struct Level(u32);
+
+    #[derive(Resource, Reflect, Default)]
+    struct Stats {
+        level: Level,
+    }
+
+    fn app_with_unloaded_library() -> (App, GameplayLibrary) {
+        let mut app = App::new();
+        app.init_resource::<ReloadableSchedules>();
+        let library = GameplayLibrary::new("libgameplay.so".into(), Duration::ZERO);
+        (app, library)
+    }
+
+    fn systems(points: u32) -> ReloadableSystems {
+        let mut systems = ReloadableSystems::default();
+        systems
+            .register_type::<Score>()
+            .init_resource::<Score>()
+            .add_systems(Update, move |mut score: ResMut<Score>| score.0 += points);
+        systems
+    }
+
+    #[test]
+    fn reload_swaps_systems_and_keeps_resources() {
+        let (mut app, mut library) = app_with_unloaded_library();
+        library.install(systems(1), app.world_mut()).unwrap();
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<Score>().0, 2);
+
+        library.install(systems(10), app.world_mut()).unwrap();
+        app.update();
+        assert_eq!(app.world().resource::<Score>().0, 12);
+        assert_eq!(library.generation(), 2);
+    }
+
+    #[test]
+    fn reload_rejects_changed_layouts() {
+        let (mut app, mut library) = app_with_unloaded_library();
+        library.install(systems(1), app.world_mut()).unwrap();
+        // Simulate a rebuild in which the layout of `Score` changed.
+        library
+            .layouts
+            .get_mut(&TypeId::of::<Score>())
+            .unwrap()
+            .hash += 1;
+
+        let error = library.install(systems(10), app.world_mut()).unwrap_err();
+        assert!(matches!(error, HotReloadError::LayoutChanged { types } if types.len() == 1));
+        app.update();
+        assert_eq!(app.world().resource::<Score>().0, 1);
+        assert_eq!(library.generation(), 1);
+    }
+
+    #[test]
+    fn reload_rejects_layouts_differing_from_the_world() {
+        let (mut app, mut library) = app_with_unloaded_library();
+        library.install(systems(1), app.world_mut()).unwrap();
+        // Simulate a build of the library in which `Score` has a different size than in the world,
+        // without a previous build to compare with.
+        library.layouts.clear();
+        let mut rebuilt = systems(10);
+        rebuilt
+            .memory_layouts
+            .insert(TypeId::of::<Score>(), Layout::new::<u64>());
+
+        let error = library.install(rebuilt, app.world_mut()).unwrap_err();
+        assert!(matches!(error, HotReloadError::LayoutChanged { types } if types.len() == 1));
+        app.update();
+        assert_eq!(app.world().resource::<Score>().0, 1);
+        assert_eq!(library.generation(), 1);
+    }
+
+    #[test]
+    fn reload_only_checks_registered_types() {
+        let (mut app, mut library) = app_with_unloaded_library();
+        // `Level` is only registered by the library as a field of `Stats`.
+        app.world_mut().spawn(Level(1));
+        let mut systems = ReloadableSystems::default();
+        systems.register_type::<Stats>();
+
+        library.install(systems, app.world_mut()).unwrap();
+        assert_eq!(library.layouts.len(), 1);
+    }
+
+    #[test]
+    fn reload_keeps_type_data_of_dependencies() {
+        let (mut app, mut library) = app_with_unloaded_library();
+        app.register_type::<Level>()
+            .register_type_data::<Level, ReflectDefault>();
+        for _ in 0..2 {
+            let mut systems = ReloadableSystems::default();
+            systems.register_type::<Stats>();
+            library.install(systems, app.world_mut()).unwrap();
+        }
+
+        let registry = app.world().resource::<AppTypeRegistry>().read();
+        assert!(registry
+            .get_type_data::<ReflectDefault>(TypeId::of::<Level>())
+            .is_some());
+        assert!(registry.contains(TypeId::of::<Stats>()));
+    }
+
+    /// Compiles the fixture library `name` from `tests/fixtures`, returning the path of the library.
+    fn build_fixture(name: &str) -> PathBuf {
+        let directory =
+            std::env::temp_dir().join(format!("bevy-hot-reload-fixtures-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let library = directory.join(format!("lib{name}.so"));
+        let source = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures")
+            .join(format!("{name}.rs"));
+        let status = Command::new(std::env::var_os("RUSTC").unwrap_or_else(|| "rustc".into()))
+            .args(["--crate-type", "cdylib", "--edition", "2021", "-o"])
+            .arg(&library)
+            .arg(source)
+            .status()
+            .unwrap();
+        assert!(status.success(), "failed to compile the fixture {name}");
+        library
+    }
+
+    #[test]
+    fn load_rejects_libraries_from_other_builds() {
+        let path = build_fixture("other_build");
+        let mut library = GameplayLibrary::new(path.clone(), Duration::ZERO);
+
+        assert!(matches!(
+            library.load(),
+            Err(HotReloadError::IncompatibleBuild)
+        ));
+        assert_eq!(library.generation(), 0);
+        // The library is loaded from a copy, which is removed once loaded.
+        let copy = std::env::temp_dir().join(format!(
+            "bevy-hot-reload-{}-0-libother_build.so",
+            std::process::id()
+        ));
+        assert!(!copy.exists());
+        std::fs::remove_file(path).ok();
+    }
+}
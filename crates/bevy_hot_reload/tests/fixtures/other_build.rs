@@ -0,0 +1,15 @@
+//! A gameplay library built without Bevy, standing for a library built with a different build of Bevy.
+//!
+//! It's compiled as a `cdylib` by the tests of `bevy_hot_reload`.
+
+use core::any::TypeId;
+
+#[no_mangle]
+pub fn bevy_hot_reload_version() -> TypeId {
+    TypeId::of::<u8>()
+}
+
+#[no_mangle]
+pub fn bevy_hot_reload_register(_systems: *mut u8) {
+    panic!("the systems of a library built with a different build of Bevy must not be registered");
+}
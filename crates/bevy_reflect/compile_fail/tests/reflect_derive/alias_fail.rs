@@ -0,0 +1,29 @@
+use bevy_reflect::Reflect;
+
+// Reason: An alias cannot shadow the name of another field
+#[derive(Reflect)]
+struct Foo {
+    #[reflect(alias = "b")]
+    //~^ ERROR: already the name of a field
+    a: u32,
+    b: u32,
+}
+
+// Reason: An alias cannot be given to more than one field
+#[derive(Reflect)]
+struct Bar {
+    #[reflect(alias = "old")]
+    a: u32,
+    #[reflect(alias = "old")]
+    //~^ ERROR: given to more than one field
+    b: u32,
+}
+
+// Reason: An alias cannot also be a removed field
+#[derive(Reflect)]
+#[reflect(removed_fields("old"))]
+struct Baz {
+    #[reflect(alias = "old")]
+    //~^ ERROR: also listed as a removed field
+    a: u32,
+}
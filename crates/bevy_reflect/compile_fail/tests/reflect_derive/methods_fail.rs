@@ -0,0 +1,15 @@
+use bevy_reflect::Reflect;
+
+// Reason: Associated functions without a receiver cannot be registered as methods
+#[derive(Reflect)]
+//~^ ERROR: can't be registered as a reflected method of `Foo`
+#[reflect(methods(new))]
+struct Foo {
+    value: u32,
+}
+
+impl Foo {
+    fn new(value: u32) -> Self {
+        Self { value }
+    }
+}
@@ -13,8 +13,8 @@ use bevy_macro_utils::fq_std::{FQAny, FQOption};
 use proc_macro2::{Ident, Span};
 use quote::quote_spanned;
 use syn::{
-    ext::IdentExt, parenthesized, parse::ParseStream, spanned::Spanned, token, Expr, LitBool,
-    MetaList, MetaNameValue, Path, Token, WhereClause,
+    ext::IdentExt, parenthesized, parse::ParseStream, punctuated::Punctuated, spanned::Spanned,
+    token, Expr, LitBool, LitStr, MetaList, MetaNameValue, Path, Token, WhereClause,
 };
 
 mod kw {
@@ -25,6 +25,9 @@ mod kw {
     syn::custom_keyword!(Hash);
     syn::custom_keyword!(no_field_bounds);
     syn::custom_keyword!(opaque);
+    syn::custom_keyword!(type_path_alias);
+    syn::custom_keyword!(removed_fields);
+    syn::custom_keyword!(methods);
 }
 
 // The "special" trait idents that are used internally for reflection.
@@ -184,6 +187,9 @@ pub(crate) struct ContainerAttributes {
     no_field_bounds: bool,
     custom_attributes: CustomAttributes,
     is_opaque: bool,
+    type_path_aliases: Vec<LitStr>,
+    removed_fields: Vec<LitStr>,
+    methods: Vec<Path>,
     idents: Vec<Ident>,
 }
 
@@ -232,6 +238,12 @@ impl ContainerAttributes {
             self.parse_from_reflect(input, trait_)
         } else if lookahead.peek(kw::type_path) {
             self.parse_type_path(input, trait_)
+        } else if lookahead.peek(kw::type_path_alias) {
+            self.parse_type_path_alias(input)
+        } else if lookahead.peek(kw::removed_fields) {
+            self.parse_removed_fields(input)
+        } else if lookahead.peek(kw::methods) {
+            self.parse_methods(input)
         } else if lookahead.peek(kw::opaque) {
             self.parse_opaque(input)
         } else if lookahead.peek(kw::no_field_bounds) {
@@ -354,6 +366,71 @@ impl ContainerAttributes {
         Ok(())
     }
 
+    /// Parse `type_path_alias` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(type_path_alias = "my_crate::old_module::Foo")]`
+    fn parse_type_path_alias(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::type_path_alias>()?;
+        input.parse::<Token![=]>()?;
+        self.type_path_aliases.push(input.parse()?);
+        Ok(())
+    }
+
+    /// Parse `removed_fields` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(removed_fields("old_field", "other_old_field"))]`
+    fn parse_removed_fields(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::removed_fields>()?;
+
+        let content;
+        parenthesized!(content in input);
+        self.removed_fields
+            .extend(Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?);
+        Ok(())
+    }
+
+    /// Parse `methods` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(methods(reset, set_duration))]`
+    /// - `#[reflect(methods(look_at::<Vec3>))]`
+    fn parse_methods(&mut self, input: ParseStream) -> syn::Result<()> {
+        let ident = input.parse::<kw::methods>()?;
+
+        if cfg!(not(feature = "functions")) {
+            return Err(syn::Error::new(
+                ident.span,
+                "registering reflected methods requires the `functions` feature of `bevy_reflect`",
+            ));
+        }
+
+        let content;
+        parenthesized!(content in input);
+        for method in Punctuated::<Path, Token![,]>::parse_terminated(&content)? {
+            if method.leading_colon.is_some() || method.segments.len() != 1 {
+                return Err(syn::Error::new(
+                    method.span(),
+                    "expected the name of a method, optionally followed by its generic arguments",
+                ));
+            }
+            let name = &method.segments[0].ident;
+            if self
+                .methods
+                .iter()
+                .any(|existing| existing.segments[0].ident == *name)
+            {
+                return Err(syn::Error::new(
+                    method.span(),
+                    format_args!("method `{name}` is already registered"),
+                ));
+            }
+            self.methods.push(method);
+        }
+        Ok(())
+    }
+
     /// Parse `where` attribute.
     ///
     /// Examples:
@@ -454,6 +531,23 @@ impl ContainerAttributes {
         &self.type_path_attrs
     }
 
+    /// The former type paths of this type, found within `#[reflect(type_path_alias = "...")]` attributes.
+    pub fn type_path_aliases(&self) -> &[LitStr] {
+        &self.type_path_aliases
+    }
+
+    /// The names of fields that have been removed from this type,
+    /// found within `#[reflect(removed_fields(...))]` attributes.
+    pub fn removed_fields(&self) -> &[LitStr] {
+        &self.removed_fields
+    }
+
+    /// The methods of this type to register as reflected methods,
+    /// found within `#[reflect(methods(...))]` attributes.
+    pub fn methods(&self) -> &[Path] {
+        &self.methods
+    }
+
     /// Returns the implementation of `PartialReflect::reflect_hash` as a `TokenStream`.
     ///
     /// If `Hash` was not registered, returns `None`.
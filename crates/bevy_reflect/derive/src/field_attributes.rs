@@ -16,6 +16,7 @@ mod kw {
     syn::custom_keyword!(skip_serializing);
     syn::custom_keyword!(default);
     syn::custom_keyword!(remote);
+    syn::custom_keyword!(alias);
 }
 
 pub(crate) const IGNORE_SERIALIZATION_ATTR: &str = "skip_serializing";
@@ -80,6 +81,8 @@ pub(crate) struct FieldAttributes {
     pub custom_attributes: CustomAttributes,
     /// For defining the remote wrapper type that should be used in place of the field for reflection logic.
     pub remote: Option<Type>,
+    /// Former names of this field that are still accepted during deserialization.
+    pub aliases: Vec<LitStr>,
 }
 
 impl FieldAttributes {
@@ -125,6 +128,8 @@ impl FieldAttributes {
             self.parse_default(input)
         } else if lookahead.peek(kw::remote) {
             self.parse_remote(input)
+        } else if lookahead.peek(kw::alias) {
+            self.parse_alias(input)
         } else {
             Err(lookahead.error())
         }
@@ -188,6 +193,19 @@ impl FieldAttributes {
         Ok(())
     }
 
+    /// Parse `alias` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(alias = "old_name")]`
+    fn parse_alias(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::alias>()?;
+        input.parse::<Token![=]>()?;
+
+        self.aliases.push(input.parse::<LitStr>()?);
+
+        Ok(())
+    }
+
     /// Parse `@` (custom attribute) attribute.
     ///
     /// Examples:
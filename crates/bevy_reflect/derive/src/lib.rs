@@ -286,6 +286,64 @@ fn match_reflect_impls(ast: DeriveInput, source: ReflectImplSource) -> TokenStre
 /// // {/* ... */}
 /// ```
 ///
+/// ## `#[reflect(type_path_alias = "...")]`
+///
+/// This attribute registers a former type path of the type, such as the path it had before being moved or renamed.
+/// It may be given more than once.
+///
+/// The aliases are registered as `TypePathAliases` type data, which the `TypeRegistry` uses to resolve
+/// lookups by type path, so that data serialized with the old path can still be deserialized.
+/// An alias never shadows the actual type path of another registered type.
+///
+/// ## `#[reflect(removed_fields("...", ...))]`
+///
+/// This attribute lists the names of fields that were removed from a struct.
+///
+/// When deserializing the struct from a format that serializes structs as maps, such as RON,
+/// the values of these fields are discarded instead of causing an error.
+/// To recover the old values instead, register a `ReflectUpgrade` for the type.
+///
+/// ### Example
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// #[reflect(type_path_alias = "my_game::old_module::Player", removed_fields("legacy_flag"))]
+/// struct Player {
+///   health: u32,
+/// }
+/// ```
+///
+/// ## `#[reflect(methods(...))]`
+///
+/// This attribute registers methods of the type as `ReflectMethods` type data,
+/// each under its own name, so that they can be called on a `dyn PartialReflect`.
+/// Generic methods must be given their generic arguments, such as `methods(look_at::<Vec3>)`.
+/// It requires the `functions` feature of `bevy_reflect`.
+///
+/// The first argument of each method must be its receiver: `self`, `&self`, or `&mut self`.
+/// Listing an associated function without a receiver is a compile error.
+///
+/// ### Example
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// #[reflect(methods(reset, add))]
+/// struct Counter {
+///   value: i32,
+/// }
+///
+/// impl Counter {
+///   fn reset(&mut self) {
+///     self.value = 0;
+///   }
+///
+///   fn add(&mut self, amount: i32) -> i32 {
+///     self.value += amount;
+///     self.value
+///   }
+/// }
+/// ```
+///
 /// ## `#[reflect(@...)]`
 ///
 /// This attribute can be used to register custom attributes to the type's `TypeInfo`.
@@ -339,6 +397,25 @@ fn match_reflect_impls(ast: DeriveInput, source: ReflectImplSource) -> TokenStre
 /// What this does is register the `SerializationData` type within the `GetTypeRegistration` implementation,
 /// which will be used by the reflection serializers to determine whether or not the field is serializable.
 ///
+/// ## `#[reflect(alias = "...")]`
+///
+/// This attribute registers a former name of a named field, which is accepted in place of its current name
+/// when deserializing the struct from a format that serializes structs as maps, such as RON.
+/// It may be given more than once.
+///
+/// An alias may not be the name of another field, the alias of another field, or one of the `removed_fields`.
+///
+/// ### Example
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// struct Player {
+///   // Used to be called `hp`
+///   #[reflect(alias = "hp")]
+///   health: u32,
+/// }
+/// ```
+///
 /// ## `#[reflect(@...)]`
 ///
 /// This attribute can be used to register custom attributes to the field's `TypeInfo`.
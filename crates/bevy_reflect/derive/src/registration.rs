@@ -45,6 +45,28 @@ pub(crate) fn impl_get_type_registration<'a>(
         }
     });
 
+    let type_path_aliases = meta.attrs().type_path_aliases();
+    let type_path_aliases = (!type_path_aliases.is_empty()).then(|| {
+        quote! {
+            registration.insert::<#bevy_reflect_path::TypePathAliases>(
+                #bevy_reflect_path::TypePathAliases::new([#(#type_path_aliases),*])
+            );
+        }
+    });
+
+    let methods = meta.attrs().methods();
+    let methods_data = (!methods.is_empty()).then(|| {
+        let names = methods
+            .iter()
+            .map(|method| method.segments[0].ident.to_string());
+        quote! {
+            registration.insert::<#bevy_reflect_path::func::ReflectMethods>(
+                #bevy_reflect_path::func::ReflectMethods::new::<Self>()
+                    #(.with_method_of::<Self, _, _, _>(#names, <Self>::#methods))*
+            );
+        }
+    });
+
     quote! {
         #[allow(unused_mut)]
         impl #impl_generics #bevy_reflect_path::GetTypeRegistration for #type_path #ty_generics #where_reflect_clause {
@@ -53,6 +75,8 @@ pub(crate) fn impl_get_type_registration<'a>(
                 registration.insert::<#bevy_reflect_path::ReflectFromPtr>(#bevy_reflect_path::FromType::<Self>::from_type());
                 #from_reflect_data
                 #serialization_data
+                #type_path_aliases
+                #methods_data
                 #(registration.insert::<#registration_data>(#bevy_reflect_path::FromType::<Self>::from_type());)*
                 registration
             }
@@ -5,7 +5,7 @@ use crate::{
 use bevy_macro_utils::fq_std::FQDefault;
 use quote::quote;
 use std::collections::HashMap;
-use syn::{spanned::Spanned, Path};
+use syn::{spanned::Spanned, LitStr, Path};
 
 type ReflectionIndex = usize;
 
@@ -13,29 +13,66 @@ type ReflectionIndex = usize;
 pub(crate) struct SerializationDataDef {
     /// Maps a field's _reflection_ index to its [`SkippedFieldDef`] if marked as `#[reflect(skip_serializing)]`.
     skipped: HashMap<ReflectionIndex, SkippedFieldDef>,
+    /// Maps a serialized field's _reflection_ index to its [`SkippedFieldDef`] if marked as `#[reflect(default)]`.
+    defaulted: HashMap<ReflectionIndex, SkippedFieldDef>,
+    /// The former names of fields, as given by `#[reflect(alias = "...")]`, along with their _reflection_ index.
+    aliases: Vec<(LitStr, ReflectionIndex)>,
+    /// The names of fields removed from the type, as given by `#[reflect(removed_fields(...))]`.
+    removed: Vec<LitStr>,
 }
 
 impl SerializationDataDef {
     /// Attempts to create a new `SerializationDataDef` from the given collection of fields.
     ///
-    /// Returns `Ok(Some(data))` if there are any fields needing to be skipped during serialization.
+    /// Returns `Ok(Some(data))` if there are any fields needing to be skipped, defaulted, or aliased
+    /// during (de)serialization, or if any `removed_fields` were given.
     /// Otherwise, returns `Ok(None)`.
     pub fn new(
         fields: &[StructField<'_>],
+        removed_fields: &[LitStr],
         bevy_reflect_path: &Path,
     ) -> Result<Option<Self>, syn::Error> {
         let mut skipped = <HashMap<_, _>>::default();
+        let mut defaulted = <HashMap<_, _>>::default();
+        let mut aliases = Vec::new();
 
         for field in fields {
+            if field.attrs.ignore.is_ignored() {
+                continue;
+            }
+
+            let reflection_index = field.reflection_index.ok_or_else(|| {
+                syn::Error::new(
+                    field.data.span(),
+                    "internal error: field is missing a reflection index",
+                )
+            })?;
+
+            if !field.attrs.aliases.is_empty() && field.data.ident.is_none() {
+                return Err(syn::Error::new(
+                    field.attrs.aliases[0].span(),
+                    "aliases can only be given to named fields",
+                ));
+            }
+
+            aliases.extend(
+                field
+                    .attrs
+                    .aliases
+                    .iter()
+                    .map(|alias| (alias.clone(), reflection_index)),
+            );
+
             match field.attrs.ignore {
                 ReflectIgnoreBehavior::IgnoreSerialization => {
                     skipped.insert(
-                        field.reflection_index.ok_or_else(|| {
-                            syn::Error::new(
-                                field.data.span(),
-                                "internal error: field is missing a reflection index",
-                            )
-                        })?,
+                        reflection_index,
+                        SkippedFieldDef::new(field, bevy_reflect_path)?,
+                    );
+                }
+                _ if !matches!(field.attrs.default, DefaultBehavior::Required) => {
+                    defaulted.insert(
+                        reflection_index,
                         SkippedFieldDef::new(field, bevy_reflect_path)?,
                     );
                 }
@@ -43,10 +80,51 @@ impl SerializationDataDef {
             }
         }
 
-        if skipped.is_empty() {
+        // Aliases share the map of field names used when deserializing, so they must not shadow any other name.
+        for (index, (alias, _)) in aliases.iter().enumerate() {
+            let name = alias.value();
+            if fields.iter().any(|field| {
+                field
+                    .data
+                    .ident
+                    .as_ref()
+                    .is_some_and(|ident| *ident == name)
+            }) {
+                return Err(syn::Error::new(
+                    alias.span(),
+                    format_args!("alias `{name}` is already the name of a field"),
+                ));
+            }
+            if aliases[..index]
+                .iter()
+                .any(|(other, _)| other.value() == name)
+            {
+                return Err(syn::Error::new(
+                    alias.span(),
+                    format_args!("alias `{name}` is given to more than one field"),
+                ));
+            }
+            if removed_fields.iter().any(|removed| removed.value() == name) {
+                return Err(syn::Error::new(
+                    alias.span(),
+                    format_args!("alias `{name}` is also listed as a removed field"),
+                ));
+            }
+        }
+
+        if skipped.is_empty()
+            && defaulted.is_empty()
+            && aliases.is_empty()
+            && removed_fields.is_empty()
+        {
             Ok(None)
         } else {
-            Ok(Some(Self { skipped }))
+            Ok(Some(Self {
+                skipped,
+                defaulted,
+                aliases,
+                removed: removed_fields.to_vec(),
+            }))
         }
     }
 
@@ -61,10 +139,31 @@ impl SerializationDataDef {
                         #bevy_reflect_path::serde::SkippedField::new(#default_fn)
                     )}
                 });
+        let defaulted =
+            self.defaulted
+                .iter()
+                .map(|(reflection_index, SkippedFieldDef { default_fn })| {
+                    quote! {
+                        .with_field_default(#reflection_index, #default_fn)
+                    }
+                });
+        let aliases = self.aliases.iter().map(|(alias, reflection_index)| {
+            quote! {
+                .with_field_alias(#alias, #reflection_index)
+            }
+        });
+        let removed = self.removed.iter().map(|name| {
+            quote! {
+                .with_removed_field(#name)
+            }
+        });
         quote! {
             #bevy_reflect_path::serde::SerializationData::new(
                 ::core::iter::IntoIterator::into_iter([#(#fields),*])
             )
+            #(#defaulted)*
+            #(#aliases)*
+            #(#removed)*
         }
     }
 }
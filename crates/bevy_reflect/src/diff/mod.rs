@@ -0,0 +1,606 @@
+//! Structural diffing and patching of reflected values.
+//!
+//! [`ReflectPatch::diff`] compares two values of the same type and returns a [`ReflectPatch`] describing how to turn
+//! the first into the second. The patch only contains the parts of the value that changed, and can be applied to any
+//! value of the same type with [`ReflectPatch::apply`].
+//!
+//! Patches can be serialized with [`ReflectPatchSerializer`] and deserialized with [`ReflectPatchDeserializer`],
+//! which makes them suitable for things like undo/redo stacks and delta-compressed network synchronization.
+//!
+//! # Example
+//!
+//! ```
+//! # use bevy_reflect::{diff::ReflectPatch, Reflect};
+//! #[derive(Reflect, Clone, Debug, PartialEq)]
+//! struct Player {
+//!     name: String,
+//!     health: u32,
+//!     inventory: Vec<String>,
+//! }
+//!
+//! let old = Player {
+//!     name: "Ferris".to_string(),
+//!     health: 10,
+//!     inventory: vec!["sword".to_string()],
+//! };
+//! let mut new = old.clone();
+//! new.health = 5;
+//! new.inventory.push("shield".to_string());
+//!
+//! let patch = ReflectPatch::diff(&old, &new).unwrap().unwrap();
+//!
+//! let mut value = old.clone();
+//! patch.apply(&mut value).unwrap();
+//! assert_eq!(value, new);
+//! ```
+
+mod serde;
+
+pub use self::serde::*;
+
+use crate::{
+    access::Access, AccessError, ApplyError, Enum, List, Map, PartialReflect, ReflectKind,
+    ReflectMut, ReflectRef, Set,
+};
+use alloc::{borrow::Cow, boxed::Box, string::String, vec::Vec};
+use thiserror::Error;
+
+/// A structural delta between two reflected values, as returned by [`ReflectPatch::diff`].
+#[derive(Debug)]
+pub enum ReflectPatch {
+    /// Replaces the entire value. This is used for opaque values, and for enums that switched variants.
+    Replace(Box<dyn PartialReflect>),
+    /// Patches individual fields of a struct, tuple struct, tuple, array or enum variant.
+    Fields(Vec<(Access<'static>, ReflectPatch)>),
+    /// Patches the elements of a list. The operations are applied in order.
+    List(Vec<ListPatchOp>),
+    /// Patches the entries of a map.
+    Map(Vec<MapPatchOp>),
+    /// Patches the values of a set.
+    Set(Vec<SetPatchOp>),
+}
+
+/// An operation in a [`ReflectPatch::List`].
+///
+/// Indices refer to the list as it is when the operation is applied, after any preceding operations.
+#[derive(Debug)]
+pub enum ListPatchOp {
+    /// Inserts `value` at `index`.
+    Insert {
+        index: usize,
+        value: Box<dyn PartialReflect>,
+    },
+    /// Removes the element at `index`.
+    Remove { index: usize },
+    /// Patches the element at `index`.
+    Patch { index: usize, patch: ReflectPatch },
+}
+
+/// An operation in a [`ReflectPatch::Map`].
+#[derive(Debug)]
+pub enum MapPatchOp {
+    /// Inserts the entry `key` with `value`.
+    Insert {
+        key: Box<dyn PartialReflect>,
+        value: Box<dyn PartialReflect>,
+    },
+    /// Removes the entry `key`.
+    Remove { key: Box<dyn PartialReflect> },
+    /// Patches the value of the entry `key`.
+    Patch {
+        key: Box<dyn PartialReflect>,
+        patch: ReflectPatch,
+    },
+}
+
+/// An operation in a [`ReflectPatch::Set`].
+#[derive(Debug)]
+pub enum SetPatchOp {
+    /// Inserts the value.
+    Insert(Box<dyn PartialReflect>),
+    /// Removes the value.
+    Remove(Box<dyn PartialReflect>),
+}
+
+/// An error returned by [`ReflectPatch::diff`].
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ReflectDiffError {
+    /// The values are of different kinds.
+    #[error("cannot diff a `{old}` with a `{new}`")]
+    MismatchedKinds { old: ReflectKind, new: ReflectKind },
+    /// The values are of different types.
+    #[error("cannot diff a `{old}` with a `{new}`")]
+    MismatchedTypes { old: Box<str>, new: Box<str> },
+}
+
+/// An error returned by [`ReflectPatch::apply`].
+#[derive(Error, Debug)]
+pub enum ReflectPatchError {
+    /// Applying a replacement value failed.
+    #[error(transparent)]
+    Apply(#[from] ApplyError),
+    /// A field patched by [`ReflectPatch::Fields`] could not be accessed.
+    #[error(transparent)]
+    Access(#[from] AccessError<'static>),
+    /// The patch does not match the kind of the patched value.
+    #[error("cannot apply a `{patch}` patch to a `{target}`")]
+    MismatchedKinds {
+        patch: ReflectKind,
+        target: ReflectKind,
+    },
+    /// A list operation referred to an index outside of the list.
+    #[error("list index {index} is out of bounds for a list of length {len}")]
+    IndexOutOfBounds { index: usize, len: usize },
+    /// A map operation referred to a key that is not in the map.
+    #[error("the patched map does not contain the key `{key}`")]
+    MissingKey { key: String },
+}
+
+impl Clone for ReflectPatch {
+    fn clone(&self) -> Self {
+        match self {
+            ReflectPatch::Replace(value) => ReflectPatch::Replace(value.clone_value()),
+            ReflectPatch::Fields(fields) => ReflectPatch::Fields(fields.clone()),
+            ReflectPatch::List(ops) => ReflectPatch::List(ops.clone()),
+            ReflectPatch::Map(ops) => ReflectPatch::Map(ops.clone()),
+            ReflectPatch::Set(ops) => ReflectPatch::Set(ops.clone()),
+        }
+    }
+}
+
+impl Clone for ListPatchOp {
+    fn clone(&self) -> Self {
+        match self {
+            ListPatchOp::Insert { index, value } => ListPatchOp::Insert {
+                index: *index,
+                value: value.clone_value(),
+            },
+            ListPatchOp::Remove { index } => ListPatchOp::Remove { index: *index },
+            ListPatchOp::Patch { index, patch } => ListPatchOp::Patch {
+                index: *index,
+                patch: patch.clone(),
+            },
+        }
+    }
+}
+
+impl Clone for MapPatchOp {
+    fn clone(&self) -> Self {
+        match self {
+            MapPatchOp::Insert { key, value } => MapPatchOp::Insert {
+                key: key.clone_value(),
+                value: value.clone_value(),
+            },
+            MapPatchOp::Remove { key } => MapPatchOp::Remove {
+                key: key.clone_value(),
+            },
+            MapPatchOp::Patch { key, patch } => MapPatchOp::Patch {
+                key: key.clone_value(),
+                patch: patch.clone(),
+            },
+        }
+    }
+}
+
+impl Clone for SetPatchOp {
+    fn clone(&self) -> Self {
+        match self {
+            SetPatchOp::Insert(value) => SetPatchOp::Insert(value.clone_value()),
+            SetPatchOp::Remove(value) => SetPatchOp::Remove(value.clone_value()),
+        }
+    }
+}
+
+impl ReflectPatch {
+    /// Computes the patch that turns `old` into `new`, or `None` if the values are equal.
+    ///
+    /// Opaque values without a [`PartialReflect::reflect_partial_eq`] implementation are always considered changed.
+    pub fn diff(
+        old: &dyn PartialReflect,
+        new: &dyn PartialReflect,
+    ) -> Result<Option<Self>, ReflectDiffError> {
+        if old.reflect_kind() != new.reflect_kind() {
+            return Err(ReflectDiffError::MismatchedKinds {
+                old: old.reflect_kind(),
+                new: new.reflect_kind(),
+            });
+        }
+        if let (Some(old_info), Some(new_info)) = (
+            old.get_represented_type_info(),
+            new.get_represented_type_info(),
+        ) {
+            if old_info.type_id() != new_info.type_id() {
+                return Err(ReflectDiffError::MismatchedTypes {
+                    old: old_info.type_path().into(),
+                    new: new_info.type_path().into(),
+                });
+            }
+        }
+
+        let replace = || Ok(Some(ReflectPatch::Replace(new.clone_value())));
+        match (old.reflect_ref(), new.reflect_ref()) {
+            (ReflectRef::Struct(old), ReflectRef::Struct(new)) => {
+                if old.field_len() != new.field_len()
+                    || (0..old.field_len()).any(|i| old.name_at(i) != new.name_at(i))
+                {
+                    return replace();
+                }
+                diff_fields((0..old.field_len()).map(|i| {
+                    (
+                        Access::Field(Cow::Owned(old.name_at(i).unwrap().into())),
+                        old.field_at(i).unwrap(),
+                        new.field_at(i).unwrap(),
+                    )
+                }))
+            }
+            (ReflectRef::TupleStruct(old), ReflectRef::TupleStruct(new)) => {
+                if old.field_len() != new.field_len() {
+                    return replace();
+                }
+                diff_fields((0..old.field_len()).map(|i| {
+                    (
+                        Access::TupleIndex(i),
+                        old.field(i).unwrap(),
+                        new.field(i).unwrap(),
+                    )
+                }))
+            }
+            (ReflectRef::Tuple(old), ReflectRef::Tuple(new)) => {
+                if old.field_len() != new.field_len() {
+                    return replace();
+                }
+                diff_fields((0..old.field_len()).map(|i| {
+                    (
+                        Access::TupleIndex(i),
+                        old.field(i).unwrap(),
+                        new.field(i).unwrap(),
+                    )
+                }))
+            }
+            (ReflectRef::Array(old), ReflectRef::Array(new)) => {
+                if old.len() != new.len() {
+                    return replace();
+                }
+                diff_fields((0..old.len()).map(|i| {
+                    (
+                        Access::ListIndex(i),
+                        old.get(i).unwrap(),
+                        new.get(i).unwrap(),
+                    )
+                }))
+            }
+            (ReflectRef::Enum(old), ReflectRef::Enum(new)) => diff_enum(old, new),
+            (ReflectRef::List(old), ReflectRef::List(new)) => diff_list(old, new),
+            (ReflectRef::Map(old), ReflectRef::Map(new)) => diff_map(old, new),
+            (ReflectRef::Set(old), ReflectRef::Set(new)) => Ok(diff_set(old, new)),
+            _ => match old.reflect_partial_eq(new) {
+                Some(true) => Ok(None),
+                _ => replace(),
+            },
+        }
+    }
+
+    /// Applies this patch to `target`.
+    ///
+    /// If an error is returned, `target` may have been partially patched.
+    pub fn apply(&self, target: &mut dyn PartialReflect) -> Result<(), ReflectPatchError> {
+        match self {
+            ReflectPatch::Replace(value) => target.try_apply(&**value)?,
+            ReflectPatch::Fields(fields) => {
+                for (access, patch) in fields {
+                    patch.apply(access.element_mut(target, None)?)?;
+                }
+            }
+            ReflectPatch::List(ops) => {
+                let ReflectMut::List(list) = target.reflect_mut() else {
+                    return Err(self.mismatched_kinds(target));
+                };
+                for op in ops {
+                    apply_list_op(list, op)?;
+                }
+            }
+            ReflectPatch::Map(ops) => {
+                let ReflectMut::Map(map) = target.reflect_mut() else {
+                    return Err(self.mismatched_kinds(target));
+                };
+                for op in ops {
+                    match op {
+                        MapPatchOp::Insert { key, value } => {
+                            map.insert_boxed(key.clone_value(), value.clone_value());
+                        }
+                        MapPatchOp::Remove { key } => {
+                            map.remove(&**key);
+                        }
+                        MapPatchOp::Patch { key, patch } => {
+                            let Some(value) = map.get_mut(&**key) else {
+                                return Err(ReflectPatchError::MissingKey {
+                                    key: alloc::format!("{key:?}"),
+                                });
+                            };
+                            patch.apply(value)?;
+                        }
+                    }
+                }
+            }
+            ReflectPatch::Set(ops) => {
+                let ReflectMut::Set(set) = target.reflect_mut() else {
+                    return Err(self.mismatched_kinds(target));
+                };
+                for op in ops {
+                    match op {
+                        SetPatchOp::Insert(value) => {
+                            set.insert_boxed(value.clone_value());
+                        }
+                        SetPatchOp::Remove(value) => {
+                            set.remove(&**value);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the [`ReflectKind`] of the values this patch can be applied to, or `None` if it can be applied to any
+    /// value.
+    pub fn kind(&self) -> Option<ReflectKind> {
+        match self {
+            ReflectPatch::Replace(_) | ReflectPatch::Fields(_) => None,
+            ReflectPatch::List(_) => Some(ReflectKind::List),
+            ReflectPatch::Map(_) => Some(ReflectKind::Map),
+            ReflectPatch::Set(_) => Some(ReflectKind::Set),
+        }
+    }
+
+    fn mismatched_kinds(&self, target: &dyn PartialReflect) -> ReflectPatchError {
+        ReflectPatchError::MismatchedKinds {
+            patch: self.kind().unwrap_or(ReflectKind::Opaque),
+            target: target.reflect_kind(),
+        }
+    }
+}
+
+fn diff_fields<'a>(
+    fields: impl Iterator<
+        Item = (
+            Access<'static>,
+            &'a dyn PartialReflect,
+            &'a dyn PartialReflect,
+        ),
+    >,
+) -> Result<Option<ReflectPatch>, ReflectDiffError> {
+    let mut patches = Vec::new();
+    for (access, old, new) in fields {
+        if let Some(patch) = ReflectPatch::diff(old, new)? {
+            patches.push((access, patch));
+        }
+    }
+    Ok((!patches.is_empty()).then_some(ReflectPatch::Fields(patches)))
+}
+
+fn diff_enum(old: &dyn Enum, new: &dyn Enum) -> Result<Option<ReflectPatch>, ReflectDiffError> {
+    if old.variant_name() != new.variant_name() || old.field_len() != new.field_len() {
+        return Ok(Some(ReflectPatch::Replace(new.clone_value())));
+    }
+    diff_fields((0..old.field_len()).map(|i| {
+        let access = match old.name_at(i) {
+            Some(name) => Access::Field(Cow::Owned(name.into())),
+            None => Access::TupleIndex(i),
+        };
+        (access, old.field_at(i).unwrap(), new.field_at(i).unwrap())
+    }))
+}
+
+/// Diffs two lists by patching the elements between their longest common prefix and suffix in place, then inserting
+/// or removing the remaining elements.
+fn diff_list(old: &dyn List, new: &dyn List) -> Result<Option<ReflectPatch>, ReflectDiffError> {
+    let equal =
+        |a: &dyn PartialReflect, b: &dyn PartialReflect| a.reflect_partial_eq(b) == Some(true);
+    let max_common = old.len().min(new.len());
+    let prefix = (0..max_common)
+        .take_while(|&i| equal(old.get(i).unwrap(), new.get(i).unwrap()))
+        .count();
+    let suffix = (0..max_common - prefix)
+        .take_while(|&i| {
+            equal(
+                old.get(old.len() - 1 - i).unwrap(),
+                new.get(new.len() - 1 - i).unwrap(),
+            )
+        })
+        .count();
+
+    let old_changed = old.len() - prefix - suffix;
+    let new_changed = new.len() - prefix - suffix;
+    let mut ops = Vec::new();
+    for i in prefix..prefix + old_changed.min(new_changed) {
+        if let Some(patch) = ReflectPatch::diff(old.get(i).unwrap(), new.get(i).unwrap())? {
+            ops.push(ListPatchOp::Patch { index: i, patch });
+        }
+    }
+    let end = prefix + old_changed.min(new_changed);
+    for _ in new_changed..old_changed {
+        ops.push(ListPatchOp::Remove { index: end });
+    }
+    for index in end..prefix + new_changed {
+        ops.push(ListPatchOp::Insert {
+            index,
+            value: new.get(index).unwrap().clone_value(),
+        });
+    }
+    Ok((!ops.is_empty()).then_some(ReflectPatch::List(ops)))
+}
+
+fn diff_map(old: &dyn Map, new: &dyn Map) -> Result<Option<ReflectPatch>, ReflectDiffError> {
+    let mut ops = Vec::new();
+    for (key, old_value) in old.iter() {
+        match new.get(key) {
+            Some(new_value) => {
+                if let Some(patch) = ReflectPatch::diff(old_value, new_value)? {
+                    ops.push(MapPatchOp::Patch {
+                        key: key.clone_value(),
+                        patch,
+                    });
+                }
+            }
+            None => ops.push(MapPatchOp::Remove {
+                key: key.clone_value(),
+            }),
+        }
+    }
+    for (key, new_value) in new.iter() {
+        if old.get(key).is_none() {
+            ops.push(MapPatchOp::Insert {
+                key: key.clone_value(),
+                value: new_value.clone_value(),
+            });
+        }
+    }
+    Ok((!ops.is_empty()).then_some(ReflectPatch::Map(ops)))
+}
+
+fn diff_set(old: &dyn Set, new: &dyn Set) -> Option<ReflectPatch> {
+    let mut ops = Vec::new();
+    for value in old.iter() {
+        if !new.contains(value) {
+            ops.push(SetPatchOp::Remove(value.clone_value()));
+        }
+    }
+    for value in new.iter() {
+        if !old.contains(value) {
+            ops.push(SetPatchOp::Insert(value.clone_value()));
+        }
+    }
+    (!ops.is_empty()).then_some(ReflectPatch::Set(ops))
+}
+
+fn apply_list_op(list: &mut dyn List, op: &ListPatchOp) -> Result<(), ReflectPatchError> {
+    let len = list.len();
+    match op {
+        ListPatchOp::Insert { index, value } => {
+            let index = *index;
+            if index > len {
+                return Err(ReflectPatchError::IndexOutOfBounds { index, len });
+            }
+            list.insert(index, value.clone_value());
+        }
+        ListPatchOp::Remove { index } => {
+            let index = *index;
+            if index >= len {
+                return Err(ReflectPatchError::IndexOutOfBounds { index, len });
+            }
+            list.remove(index);
+        }
+        ListPatchOp::Patch { index, patch } => {
+            let index = *index;
+            let element = list
+                .get_mut(index)
+                .ok_or(ReflectPatchError::IndexOutOfBounds { index, len })?;
+            patch.apply(element)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ListPatchOp, ReflectDiffError, ReflectPatch};
+    use crate::{self as bevy_reflect, Reflect};
+    use alloc::{
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+    use bevy_platform_support::collections::{HashMap, HashSet};
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    enum Shape {
+        Circle { radius: f32 },
+        Rect(f32, f32),
+        Empty,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Scene {
+        name: String,
+        shapes: Vec<Shape>,
+        tags: HashSet<String>,
+        scores: HashMap<String, u32>,
+        position: (f32, f32),
+        corners: [u8; 2],
+    }
+
+    fn scene() -> Scene {
+        Scene {
+            name: "scene".to_string(),
+            shapes: vec![
+                Shape::Circle { radius: 1.0 },
+                Shape::Rect(1.0, 2.0),
+                Shape::Empty,
+            ],
+            tags: ["a".to_string(), "b".to_string()].into_iter().collect(),
+            scores: [("x".to_string(), 1), ("y".to_string(), 2)]
+                .into_iter()
+                .collect(),
+            position: (0.0, 0.0),
+            corners: [0, 0],
+        }
+    }
+
+    #[test]
+    fn diff_equal_values() {
+        assert!(ReflectPatch::diff(&scene(), &scene()).unwrap().is_none());
+    }
+
+    #[test]
+    fn diff_and_apply() {
+        let old = scene();
+        let mut new = scene();
+        new.name = "renamed".to_string();
+        new.shapes[0] = Shape::Circle { radius: 2.0 };
+        new.shapes[1] = Shape::Empty;
+        new.shapes.remove(2);
+        new.shapes.insert(0, Shape::Rect(5.0, 5.0));
+        new.tags.remove("a");
+        new.tags.insert("c".to_string());
+        new.scores.remove("x");
+        new.scores.insert("y".to_string(), 3);
+        new.scores.insert("z".to_string(), 4);
+        new.position.1 = 1.0;
+        new.corners[1] = 1;
+
+        let patch = ReflectPatch::diff(&old, &new).unwrap().unwrap();
+        let mut value = old.clone();
+        patch.apply(&mut value).unwrap();
+        assert_eq!(value, new);
+
+        let reverse = ReflectPatch::diff(&new, &old).unwrap().unwrap();
+        reverse.apply(&mut value).unwrap();
+        assert_eq!(value, old);
+    }
+
+    #[test]
+    fn diff_only_contains_changes() {
+        let old = vec![1, 2, 3, 4];
+        let new = vec![1, 2, 5, 3, 4];
+        let Some(ReflectPatch::List(ops)) = ReflectPatch::diff(&old, &new).unwrap() else {
+            panic!("expected a list patch");
+        };
+        assert_eq!(ops.len(), 1);
+        assert!(matches!(ops[0], ListPatchOp::Insert { index: 2, .. }));
+    }
+
+    #[test]
+    fn diff_mismatched_types() {
+        assert_eq!(
+            ReflectPatch::diff(&1_u32, &1_i32).unwrap_err(),
+            ReflectDiffError::MismatchedTypes {
+                old: "u32".into(),
+                new: "i32".into()
+            }
+        );
+        assert!(matches!(
+            ReflectPatch::diff(&1_u32, &vec![1_u32]),
+            Err(ReflectDiffError::MismatchedKinds { .. })
+        ));
+    }
+}
@@ -0,0 +1,625 @@
+use crate::{
+    diff::{ListPatchOp, MapPatchOp, ReflectPatch, SetPatchOp},
+    serde::{ReflectDeserializer, ReflectSerializer},
+    Access, ParsedPath, PartialReflect, ReflectFromReflect, TypeRegistry,
+};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt, fmt::Formatter, marker::PhantomData};
+use serde::{
+    de::{DeserializeSeed, EnumAccess, Error, MapAccess, SeqAccess, VariantAccess, Visitor},
+    ser::{SerializeMap, SerializeSeq, SerializeTupleVariant},
+    Serialize, Serializer,
+};
+
+const PATCH_VARIANTS: &[&str] = &["Replace", "Fields", "List", "Map", "Set"];
+const LIST_OP_VARIANTS: &[&str] = &["Insert", "Remove", "Patch"];
+const MAP_OP_VARIANTS: &[&str] = &["Insert", "Remove", "Patch"];
+const SET_OP_VARIANTS: &[&str] = &["Insert", "Remove"];
+
+/// A serializer for [`ReflectPatch`] values.
+///
+/// Values contained in the patch are serialized with a [`ReflectSerializer`], so their types must be registered in the
+/// given [`TypeRegistry`].
+pub struct ReflectPatchSerializer<'a> {
+    patch: &'a ReflectPatch,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> ReflectPatchSerializer<'a> {
+    /// Creates a serializer for `patch`.
+    pub fn new(patch: &'a ReflectPatch, registry: &'a TypeRegistry) -> Self {
+        Self { patch, registry }
+    }
+}
+
+impl Serialize for ReflectPatchSerializer<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let registry = self.registry;
+        match self.patch {
+            ReflectPatch::Replace(value) => serializer.serialize_newtype_variant(
+                "ReflectPatch",
+                0,
+                PATCH_VARIANTS[0],
+                &ReflectSerializer::new(&**value, registry),
+            ),
+            ReflectPatch::Fields(fields) => serializer.serialize_newtype_variant(
+                "ReflectPatch",
+                1,
+                PATCH_VARIANTS[1],
+                &FieldsSerializer { fields, registry },
+            ),
+            ReflectPatch::List(ops) => serializer.serialize_newtype_variant(
+                "ReflectPatch",
+                2,
+                PATCH_VARIANTS[2],
+                &OpsSerializer { ops, registry },
+            ),
+            ReflectPatch::Map(ops) => serializer.serialize_newtype_variant(
+                "ReflectPatch",
+                3,
+                PATCH_VARIANTS[3],
+                &OpsSerializer { ops, registry },
+            ),
+            ReflectPatch::Set(ops) => serializer.serialize_newtype_variant(
+                "ReflectPatch",
+                4,
+                PATCH_VARIANTS[4],
+                &OpsSerializer { ops, registry },
+            ),
+        }
+    }
+}
+
+struct FieldsSerializer<'a> {
+    fields: &'a [(Access<'static>, ReflectPatch)],
+    registry: &'a TypeRegistry,
+}
+
+impl Serialize for FieldsSerializer<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.fields.len()))?;
+        for (access, patch) in self.fields {
+            map.serialize_entry(
+                &access.to_string(),
+                &ReflectPatchSerializer::new(patch, self.registry),
+            )?;
+        }
+        map.end()
+    }
+}
+
+/// Serializes a patch operation with the values it contains.
+trait SerializeOp {
+    fn serialize_op<S: Serializer>(
+        &self,
+        serializer: S,
+        registry: &TypeRegistry,
+    ) -> Result<S::Ok, S::Error>;
+}
+
+struct OpsSerializer<'a, T> {
+    ops: &'a [T],
+    registry: &'a TypeRegistry,
+}
+
+impl<T: SerializeOp> Serialize for OpsSerializer<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.ops.len()))?;
+        for op in self.ops {
+            seq.serialize_element(&OpSerializer {
+                op,
+                registry: self.registry,
+            })?;
+        }
+        seq.end()
+    }
+}
+
+struct OpSerializer<'a, T> {
+    op: &'a T,
+    registry: &'a TypeRegistry,
+}
+
+impl<T: SerializeOp> Serialize for OpSerializer<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.op.serialize_op(serializer, self.registry)
+    }
+}
+
+fn serialize_pair<S: Serializer>(
+    serializer: S,
+    name: &'static str,
+    variants: &'static [&'static str],
+    index: u32,
+    first: &impl Serialize,
+    second: &impl Serialize,
+) -> Result<S::Ok, S::Error> {
+    let mut variant =
+        serializer.serialize_tuple_variant(name, index, variants[index as usize], 2)?;
+    variant.serialize_field(first)?;
+    variant.serialize_field(second)?;
+    variant.end()
+}
+
+impl SerializeOp for ListPatchOp {
+    fn serialize_op<S: Serializer>(
+        &self,
+        serializer: S,
+        registry: &TypeRegistry,
+    ) -> Result<S::Ok, S::Error> {
+        const NAME: &str = "ListPatchOp";
+        match self {
+            ListPatchOp::Insert { index, value } => serialize_pair(
+                serializer,
+                NAME,
+                LIST_OP_VARIANTS,
+                0,
+                index,
+                &ReflectSerializer::new(&**value, registry),
+            ),
+            ListPatchOp::Remove { index } => {
+                serializer.serialize_newtype_variant(NAME, 1, LIST_OP_VARIANTS[1], index)
+            }
+            ListPatchOp::Patch { index, patch } => serialize_pair(
+                serializer,
+                NAME,
+                LIST_OP_VARIANTS,
+                2,
+                index,
+                &ReflectPatchSerializer::new(patch, registry),
+            ),
+        }
+    }
+}
+
+impl SerializeOp for MapPatchOp {
+    fn serialize_op<S: Serializer>(
+        &self,
+        serializer: S,
+        registry: &TypeRegistry,
+    ) -> Result<S::Ok, S::Error> {
+        const NAME: &str = "MapPatchOp";
+        match self {
+            MapPatchOp::Insert { key, value } => serialize_pair(
+                serializer,
+                NAME,
+                MAP_OP_VARIANTS,
+                0,
+                &ReflectSerializer::new(&**key, registry),
+                &ReflectSerializer::new(&**value, registry),
+            ),
+            MapPatchOp::Remove { key } => serializer.serialize_newtype_variant(
+                NAME,
+                1,
+                MAP_OP_VARIANTS[1],
+                &ReflectSerializer::new(&**key, registry),
+            ),
+            MapPatchOp::Patch { key, patch } => serialize_pair(
+                serializer,
+                NAME,
+                MAP_OP_VARIANTS,
+                2,
+                &ReflectSerializer::new(&**key, registry),
+                &ReflectPatchSerializer::new(patch, registry),
+            ),
+        }
+    }
+}
+
+impl SerializeOp for SetPatchOp {
+    fn serialize_op<S: Serializer>(
+        &self,
+        serializer: S,
+        registry: &TypeRegistry,
+    ) -> Result<S::Ok, S::Error> {
+        let (index, value) = match self {
+            SetPatchOp::Insert(value) => (0, value),
+            SetPatchOp::Remove(value) => (1, value),
+        };
+        serializer.serialize_newtype_variant(
+            "SetPatchOp",
+            index,
+            SET_OP_VARIANTS[index as usize],
+            &ReflectSerializer::new(&**value, registry),
+        )
+    }
+}
+
+/// A deserializer for [`ReflectPatch`] values serialized with [`ReflectPatchSerializer`].
+///
+/// Values contained in the patch are deserialized with a [`ReflectDeserializer`], and converted to their concrete
+/// types if they register [`ReflectFromReflect`].
+pub struct ReflectPatchDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> ReflectPatchDeserializer<'a> {
+    /// Creates a deserializer that looks up the types of patched values in `registry`.
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for ReflectPatchDeserializer<'_> {
+    type Value = ReflectPatch;
+
+    fn deserialize<D: serde::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        struct PatchVisitor<'a> {
+            registry: &'a TypeRegistry,
+        }
+
+        impl<'de> Visitor<'de> for PatchVisitor<'_> {
+            type Value = ReflectPatch;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("a reflect patch")
+            }
+
+            fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+                let registry = self.registry;
+                let (index, variant) = data.variant_seed(VariantSeed(PATCH_VARIANTS))?;
+                Ok(match index {
+                    0 => ReflectPatch::Replace(variant.newtype_variant_seed(ValueSeed(registry))?),
+                    1 => ReflectPatch::Fields(variant.newtype_variant_seed(FieldsSeed(registry))?),
+                    2 => ReflectPatch::List(variant.newtype_variant_seed(OpsSeed::new(registry))?),
+                    3 => ReflectPatch::Map(variant.newtype_variant_seed(OpsSeed::new(registry))?),
+                    _ => ReflectPatch::Set(variant.newtype_variant_seed(OpsSeed::new(registry))?),
+                })
+            }
+        }
+
+        deserializer.deserialize_enum(
+            "ReflectPatch",
+            PATCH_VARIANTS,
+            PatchVisitor {
+                registry: self.registry,
+            },
+        )
+    }
+}
+
+/// Deserializes the index of an enum variant from its name or index.
+struct VariantSeed(&'static [&'static str]);
+
+impl<'de> DeserializeSeed<'de> for VariantSeed {
+    type Value = usize;
+
+    fn deserialize<D: serde::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for VariantSeed {
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a variant identifier")
+    }
+
+    fn visit_u64<E: Error>(self, index: u64) -> Result<Self::Value, E> {
+        usize::try_from(index)
+            .ok()
+            .filter(|index| *index < self.0.len())
+            .ok_or_else(|| Error::invalid_value(serde::de::Unexpected::Unsigned(index), &self))
+    }
+
+    fn visit_str<E: Error>(self, name: &str) -> Result<Self::Value, E> {
+        self.0
+            .iter()
+            .position(|variant| *variant == name)
+            .ok_or_else(|| Error::unknown_variant(name, self.0))
+    }
+}
+
+/// Deserializes a reflected value, converting it to its concrete type if possible.
+struct ValueSeed<'a>(&'a TypeRegistry);
+
+impl<'de> DeserializeSeed<'de> for ValueSeed<'_> {
+    type Value = Box<dyn PartialReflect>;
+
+    fn deserialize<D: serde::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        let value = ReflectDeserializer::new(self.0).deserialize(deserializer)?;
+        let concrete = value
+            .get_represented_type_info()
+            .and_then(|info| self.0.get_type_data::<ReflectFromReflect>(info.type_id()))
+            .and_then(|from_reflect| from_reflect.from_reflect(&*value));
+        Ok(match concrete {
+            Some(concrete) => concrete.into_partial_reflect(),
+            None => value,
+        })
+    }
+}
+
+struct FieldsSeed<'a>(&'a TypeRegistry);
+
+impl<'de> DeserializeSeed<'de> for FieldsSeed<'_> {
+    type Value = Vec<(Access<'static>, ReflectPatch)>;
+
+    fn deserialize<D: serde::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for FieldsSeed<'_> {
+    type Value = Vec<(Access<'static>, ReflectPatch)>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a map of field accesses to patches")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut fields = Vec::with_capacity(map.size_hint().unwrap_or_default());
+        while let Some(access) = map.next_key::<String>()? {
+            let path = ParsedPath::parse(&access).map_err(Error::custom)?;
+            let [access] = <[_; 1]>::try_from(path.0).map_err(|_| {
+                Error::custom(format_args!("`{access}` is not a single field access"))
+            })?;
+            let patch = map.next_value_seed(ReflectPatchDeserializer::new(self.0))?;
+            fields.push((access.access, patch));
+        }
+        Ok(fields)
+    }
+}
+
+/// Deserializes a patch operation with the values it contains.
+trait DeserializeOp: Sized {
+    const NAME: &'static str;
+    const VARIANTS: &'static [&'static str];
+
+    fn deserialize_op<'de, A: EnumAccess<'de>>(
+        data: A,
+        registry: &TypeRegistry,
+    ) -> Result<Self, A::Error>;
+}
+
+struct OpsSeed<'a, T> {
+    registry: &'a TypeRegistry,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<'a, T> OpsSeed<'a, T> {
+    fn new(registry: &'a TypeRegistry) -> Self {
+        Self {
+            registry,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, T: DeserializeOp> DeserializeSeed<'de> for OpsSeed<'_, T> {
+    type Value = Vec<T>;
+
+    fn deserialize<D: serde::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, T: DeserializeOp> Visitor<'de> for OpsSeed<'_, T> {
+    type Value = Vec<T>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "a sequence of `{}`", T::NAME)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut ops = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(op) = seq.next_element_seed(OpSeed {
+            registry: self.registry,
+            marker: PhantomData::<fn() -> T>,
+        })? {
+            ops.push(op);
+        }
+        Ok(ops)
+    }
+}
+
+struct OpSeed<'a, T> {
+    registry: &'a TypeRegistry,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<'de, T: DeserializeOp> DeserializeSeed<'de> for OpSeed<'_, T> {
+    type Value = T;
+
+    fn deserialize<D: serde::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_enum(T::NAME, T::VARIANTS, self)
+    }
+}
+
+impl<'de, T: DeserializeOp> Visitor<'de> for OpSeed<'_, T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "a `{}`", T::NAME)
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        T::deserialize_op(data, self.registry)
+    }
+}
+
+/// Deserializes the two fields of a tuple variant.
+struct PairVisitor<A, B>(A, B);
+
+impl<'de, A: DeserializeSeed<'de>, B: DeserializeSeed<'de>> Visitor<'de> for PairVisitor<A, B> {
+    type Value = (A::Value, B::Value);
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a tuple of two values")
+    }
+
+    fn visit_seq<S: SeqAccess<'de>>(self, mut seq: S) -> Result<Self::Value, S::Error> {
+        let first = seq
+            .next_element_seed(self.0)?
+            .ok_or_else(|| Error::invalid_length(0, &"2"))?;
+        let second = seq
+            .next_element_seed(self.1)?
+            .ok_or_else(|| Error::invalid_length(1, &"2"))?;
+        Ok((first, second))
+    }
+}
+
+impl DeserializeOp for ListPatchOp {
+    const NAME: &'static str = "ListPatchOp";
+    const VARIANTS: &'static [&'static str] = LIST_OP_VARIANTS;
+
+    fn deserialize_op<'de, A: EnumAccess<'de>>(
+        data: A,
+        registry: &TypeRegistry,
+    ) -> Result<Self, A::Error> {
+        let (index, variant) = data.variant_seed(VariantSeed(Self::VARIANTS))?;
+        Ok(match index {
+            0 => {
+                let (index, value) = variant
+                    .tuple_variant(2, PairVisitor(PhantomData::<usize>, ValueSeed(registry)))?;
+                ListPatchOp::Insert { index, value }
+            }
+            1 => ListPatchOp::Remove {
+                index: variant.newtype_variant()?,
+            },
+            _ => {
+                let (index, patch) = variant.tuple_variant(
+                    2,
+                    PairVisitor(
+                        PhantomData::<usize>,
+                        ReflectPatchDeserializer::new(registry),
+                    ),
+                )?;
+                ListPatchOp::Patch { index, patch }
+            }
+        })
+    }
+}
+
+impl DeserializeOp for MapPatchOp {
+    const NAME: &'static str = "MapPatchOp";
+    const VARIANTS: &'static [&'static str] = MAP_OP_VARIANTS;
+
+    fn deserialize_op<'de, A: EnumAccess<'de>>(
+        data: A,
+        registry: &TypeRegistry,
+    ) -> Result<Self, A::Error> {
+        let (index, variant) = data.variant_seed(VariantSeed(Self::VARIANTS))?;
+        Ok(match index {
+            0 => {
+                let (key, value) = variant
+                    .tuple_variant(2, PairVisitor(ValueSeed(registry), ValueSeed(registry)))?;
+                MapPatchOp::Insert { key, value }
+            }
+            1 => MapPatchOp::Remove {
+                key: variant.newtype_variant_seed(ValueSeed(registry))?,
+            },
+            _ => {
+                let (key, patch) = variant.tuple_variant(
+                    2,
+                    PairVisitor(ValueSeed(registry), ReflectPatchDeserializer::new(registry)),
+                )?;
+                MapPatchOp::Patch { key, patch }
+            }
+        })
+    }
+}
+
+impl DeserializeOp for SetPatchOp {
+    const NAME: &'static str = "SetPatchOp";
+    const VARIANTS: &'static [&'static str] = SET_OP_VARIANTS;
+
+    fn deserialize_op<'de, A: EnumAccess<'de>>(
+        data: A,
+        registry: &TypeRegistry,
+    ) -> Result<Self, A::Error> {
+        let (index, variant) = data.variant_seed(VariantSeed(Self::VARIANTS))?;
+        let value = variant.newtype_variant_seed(ValueSeed(registry))?;
+        Ok(match index {
+            0 => SetPatchOp::Insert(value),
+            _ => SetPatchOp::Remove(value),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ReflectPatchDeserializer, ReflectPatchSerializer};
+    use crate::{self as bevy_reflect, diff::ReflectPatch, Reflect, TypeRegistry};
+    use alloc::{
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+    use bevy_platform_support::collections::HashMap;
+    use bincode::Options;
+    use serde::de::DeserializeSeed;
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    enum Weapon {
+        Sword { damage: u32 },
+        Bow,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Player {
+        name: String,
+        weapons: Vec<Weapon>,
+        stats: HashMap<String, f32>,
+    }
+
+    #[test]
+    fn patch_serialization_round_trip() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+
+        let old = Player {
+            name: "Ferris".to_string(),
+            weapons: vec![Weapon::Sword { damage: 1 }, Weapon::Bow],
+            stats: [("speed".to_string(), 1.0)].into_iter().collect(),
+        };
+        let mut new = old.clone();
+        new.name = "Crab".to_string();
+        new.weapons[0] = Weapon::Sword { damage: 2 };
+        new.weapons.push(Weapon::Sword { damage: 3 });
+        new.stats.insert("speed".to_string(), 2.0);
+        new.stats.insert("strength".to_string(), 5.0);
+        let patch = ReflectPatch::diff(&old, &new).unwrap().unwrap();
+
+        let serialized = ron::to_string(&ReflectPatchSerializer::new(&patch, &registry)).unwrap();
+        let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
+        let deserialized = ReflectPatchDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        let mut value = old.clone();
+        deserialized.apply(&mut value).unwrap();
+        assert_eq!(value, new);
+
+        let serialized = bincode::DefaultOptions::new()
+            .serialize(&ReflectPatchSerializer::new(&patch, &registry))
+            .unwrap();
+        let deserialized = bincode::DefaultOptions::new()
+            .deserialize_seed(ReflectPatchDeserializer::new(&registry), &serialized)
+            .unwrap();
+        let mut value = old.clone();
+        deserialized.apply(&mut value).unwrap();
+        assert_eq!(value, new);
+    }
+}
@@ -47,6 +47,12 @@ impl ArgInfo {
         self
     }
 
+    /// Set the index of the argument within its function.
+    pub(crate) fn with_index(mut self, index: usize) -> Self {
+        self.index = index;
+        self
+    }
+
     /// The index of the argument within its function.
     pub fn index(&self) -> usize {
         self.index
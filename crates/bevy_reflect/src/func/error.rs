@@ -69,3 +69,43 @@ pub enum FunctionRegistrationError {
     #[error("function name is missing")]
     MissingName,
 }
+
+/// An error that occurs when calling a method through [`ReflectMethods`].
+///
+/// [`ReflectMethods`]: crate::func::ReflectMethods
+#[derive(Debug, Error, PartialEq)]
+pub enum MethodError {
+    /// No method with the given name is registered for the type.
+    #[error("no method named {name:?} is registered for `{type_path}`")]
+    NotFound {
+        name: Cow<'static, str>,
+        type_path: &'static str,
+    },
+    /// An error occurred while calling the method.
+    #[error(transparent)]
+    FunctionError(#[from] FunctionError),
+}
+
+impl From<ArgError> for MethodError {
+    fn from(err: ArgError) -> Self {
+        Self::FunctionError(FunctionError::ArgError(err))
+    }
+}
+
+/// An error that occurs when registering a method into [`ReflectMethods`].
+///
+/// [`ReflectMethods`]: crate::func::ReflectMethods
+#[derive(Debug, Error, PartialEq)]
+pub enum MethodRegistrationError {
+    /// A method with the given name has already been registered.
+    ///
+    /// Contains the duplicate method name.
+    #[error("a method has already been registered with name {0:?}")]
+    DuplicateName(Cow<'static, str>),
+    /// The method does not take the type as its first argument in every signature.
+    #[error("method {name:?} must take `self`, `&self`, or `&mut self` of type `{type_path}` as its first argument")]
+    InvalidReceiver {
+        name: Cow<'static, str>,
+        type_path: &'static str,
+    },
+}
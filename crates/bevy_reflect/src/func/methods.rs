@@ -0,0 +1,484 @@
+use alloc::{borrow::Cow, boxed::Box, vec::Vec};
+use bevy_platform_support::collections::HashMap;
+use core::{any::TypeId, fmt::Debug};
+use variadics_please::all_tuples;
+
+use crate::{
+    func::{
+        args::{ArgError, ArgInfo, ArgValue, Ownership},
+        ArgList, DynamicFunction, DynamicFunctionMut, FunctionError, FunctionInfo, IntoFunction,
+        MethodError, MethodRegistrationError, Return, SignatureInfo,
+    },
+    FromReflect, PartialReflect, TypePath,
+};
+
+/// [Type data] containing the reflected methods of a type.
+///
+/// A method is a [`DynamicFunction`] whose first argument is the receiver:
+/// `self`, `&self`, or `&mut self`.
+/// Methods are registered by name and can be invoked on a `&mut dyn PartialReflect`
+/// without knowing the concrete type of the receiver,
+/// which makes them useful for scripting and remote callers.
+///
+/// Methods can be registered when deriving [`Reflect`] using the `#[reflect(methods(...))]` attribute,
+/// which registers each listed method under its own name,
+/// or manually using [`TypeRegistry::register_method`].
+///
+/// Methods that take `self` by value are called on a copy of the receiver created with [`FromReflect`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{func::{ArgList, ReflectMethods}, PartialReflect, Reflect, TypeRegistry};
+/// #[derive(Reflect)]
+/// #[reflect(methods(reset, add))]
+/// struct Counter {
+///     value: i32,
+/// }
+///
+/// impl Counter {
+///     fn reset(&mut self) {
+///         self.value = 0;
+///     }
+///
+///     fn add(&mut self, amount: i32) -> i32 {
+///         self.value += amount;
+///         self.value
+///     }
+/// }
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Counter>();
+///
+/// let mut counter = Counter { value: 5 };
+/// let receiver: &mut dyn PartialReflect = &mut counter;
+///
+/// let methods = registry
+///     .get_type_data::<ReflectMethods>(core::any::TypeId::of::<Counter>())
+///     .unwrap();
+/// let result = methods
+///     .call("add", receiver, ArgList::new().with_owned(10_i32))
+///     .unwrap();
+/// assert_eq!(result.unwrap_owned().try_take::<i32>().unwrap(), 15);
+///
+/// methods.call("reset", &mut counter, ArgList::new()).unwrap();
+/// assert_eq!(counter.value, 0);
+/// ```
+///
+/// [Type data]: crate::TypeData
+/// [`Reflect`]: crate::Reflect
+/// [`TypeRegistry::register_method`]: crate::TypeRegistry::register_method
+#[derive(Clone)]
+pub struct ReflectMethods {
+    type_path: &'static str,
+    receiver_type_id: fn(Ownership) -> TypeId,
+    from_reflect: fn(&dyn PartialReflect) -> Option<Box<dyn PartialReflect>>,
+    methods: HashMap<Cow<'static, str>, DynamicFunction<'static>>,
+}
+
+impl ReflectMethods {
+    /// Create an empty set of methods for type `T`.
+    pub fn new<T: FromReflect + TypePath>() -> Self {
+        Self {
+            type_path: T::type_path(),
+            receiver_type_id: |ownership| match ownership {
+                Ownership::Ref => TypeId::of::<&'static T>(),
+                Ownership::Mut => TypeId::of::<&'static mut T>(),
+                Ownership::Owned => TypeId::of::<T>(),
+            },
+            from_reflect: |value| {
+                T::from_reflect(value).map(|value| Box::new(value) as Box<dyn PartialReflect>)
+            },
+            methods: HashMap::default(),
+        }
+    }
+
+    /// Attempts to register the given method with the given name.
+    ///
+    /// This accepts both functions that satisfy [`IntoFunction`] and direct [`DynamicFunction`] instances.
+    /// The first argument of every signature of the method must be the receiver:
+    /// `T`, `&T`, or `&mut T`, with the same ownership across all [overloads].
+    ///
+    /// Returns an error if the method has no valid receiver
+    /// or if a method with the same name has already been registered.
+    ///
+    /// [overloads]: DynamicFunction::with_overload
+    pub fn register<F, Marker>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        method: F,
+    ) -> Result<&mut Self, MethodRegistrationError>
+    where
+        F: IntoFunction<'static, Marker> + 'static,
+    {
+        let name = name.into();
+        let method = method.into_function();
+
+        let ownership = receiver_ownership(method.info());
+        let is_valid = ownership.is_some_and(|ownership| {
+            let receiver_type_id = (self.receiver_type_id)(ownership);
+            method.info().signatures().iter().all(|signature| {
+                signature.args().first().is_some_and(|receiver| {
+                    receiver.ownership() == ownership && receiver.type_id() == receiver_type_id
+                })
+            })
+        });
+        if !is_valid {
+            return Err(MethodRegistrationError::InvalidReceiver {
+                name,
+                type_path: self.type_path,
+            });
+        }
+
+        self.methods
+            .try_insert(name, method)
+            .map_err(|err| MethodRegistrationError::DuplicateName(err.entry.key().clone()))?;
+        Ok(self)
+    }
+
+    /// Registers the given method with the given name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the method cannot be [registered].
+    ///
+    /// [registered]: Self::register
+    pub fn with_method<F, Marker>(mut self, name: impl Into<Cow<'static, str>>, method: F) -> Self
+    where
+        F: IntoFunction<'static, Marker> + 'static,
+    {
+        if let Err(err) = self.register(name, method) {
+            panic!("{err}");
+        }
+        self
+    }
+
+    /// Registers the given method of type `T` with the given name.
+    ///
+    /// Unlike [`with_method`](Self::with_method), this checks at compile time that the first argument
+    /// of the method is a receiver of type `T`.
+    /// It's used by the `#[reflect(methods(...))]` derive attribute.
+    #[doc(hidden)]
+    pub fn with_method_of<T, F, Marker1, Marker2>(self, name: &'static str, method: F) -> Self
+    where
+        F: IntoFunction<'static, (Marker1, Marker2)> + 'static,
+        Marker1: MethodSignature<T>,
+    {
+        self.with_method(name, method)
+    }
+
+    /// Returns the [type path] of the type these methods belong to.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn type_path(&self) -> &'static str {
+        self.type_path
+    }
+
+    /// Get a reference to the method with the given name.
+    ///
+    /// The returned function takes the receiver as its first argument.
+    pub fn get(&self, name: &str) -> Option<&DynamicFunction<'static>> {
+        self.methods.get(name)
+    }
+
+    /// Returns `true` if a method with the given name is registered.
+    pub fn contains(&self, name: &str) -> bool {
+        self.methods.contains_key(name)
+    }
+
+    /// Returns an iterator over the names and functions of all registered methods.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (&str, &DynamicFunction<'static>)> {
+        self.methods
+            .iter()
+            .map(|(name, method)| (name.as_ref(), method))
+    }
+
+    /// Returns the number of registered methods.
+    pub fn len(&self) -> usize {
+        self.methods.len()
+    }
+
+    /// Returns `true` if no methods are registered.
+    pub fn is_empty(&self) -> bool {
+        self.methods.is_empty()
+    }
+
+    /// Calls the method with the given name on `receiver`.
+    ///
+    /// The receiver is passed to the method as its first argument, followed by `args`.
+    /// Methods returning a reference borrow it from the receiver or the arguments as usual.
+    pub fn call<'a>(
+        &self,
+        name: &str,
+        receiver: &'a mut dyn PartialReflect,
+        args: ArgList<'a>,
+    ) -> Result<Return<'a>, MethodError> {
+        let method = self.get(name).ok_or_else(|| MethodError::NotFound {
+            name: Cow::Owned(name.into()),
+            type_path: self.type_path,
+        })?;
+        let ownership = receiver_ownership(method.info()).unwrap_or(Ownership::Mut);
+
+        let receiver = receiver_arg(ownership, receiver, self.type_path, self.from_reflect)?;
+        Ok(method.call(prepend_arg(receiver, args)?)?)
+    }
+
+    /// Binds the method with the given name to `receiver`,
+    /// returning a [`DynamicFunctionMut`] that takes the remaining arguments.
+    ///
+    /// Since the receiver is borrowed for as long as the function exists,
+    /// methods returning a reference instead return an owned [clone] of the referenced value.
+    ///
+    /// [clone]: PartialReflect::clone_value
+    pub fn bind<'a>(
+        &self,
+        name: &str,
+        receiver: &'a mut dyn PartialReflect,
+    ) -> Result<DynamicFunctionMut<'a>, MethodError> {
+        let method = self
+            .get(name)
+            .ok_or_else(|| MethodError::NotFound {
+                name: Cow::Owned(name.into()),
+                type_path: self.type_path,
+            })?
+            .clone();
+        let ownership = receiver_ownership(method.info()).unwrap_or(Ownership::Mut);
+
+        let info = FunctionInfo::try_from_iter(method.info().signatures().iter().map(unbind))
+            .expect("overloads of a method should share the same receiver");
+        let (type_path, from_reflect) = (self.type_path, self.from_reflect);
+
+        let function = DynamicFunctionMut::new(
+            move |args: ArgList| {
+                let receiver = receiver_arg(ownership, &mut *receiver, type_path, from_reflect)?;
+                Ok(match method.call(prepend_arg(receiver, args)?)? {
+                    Return::Owned(value) => Return::Owned(value),
+                    Return::Ref(value) => Return::Owned(value.clone_value()),
+                    Return::Mut(value) => Return::Owned(value.clone_value()),
+                })
+            },
+            info,
+        );
+        Ok(function)
+    }
+}
+
+impl Debug for ReflectMethods {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ReflectMethods")
+            .field("type_path", &self.type_path)
+            .field("methods", &self.methods)
+            .finish()
+    }
+}
+
+/// Implemented for the [`ReflectFn`] markers of functions whose first argument is a receiver of type `T`:
+/// `T`, `&T`, or `&mut T`.
+///
+/// [`ReflectFn`]: crate::func::ReflectFn
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "this function can't be registered as a reflected method of `{T}`",
+    label = "expected a function taking `self`, `&self`, or `&mut self` as its first argument",
+    note = "functions without a receiver can be registered in a `FunctionRegistry` instead"
+)]
+pub trait MethodSignature<T> {}
+
+macro_rules! impl_method_signature {
+    ($($Arg:ident),*) => {
+        impl<T, $($Arg,)* ReturnType> MethodSignature<T> for fn(T, $($Arg),*) -> [ReturnType] {}
+        impl<T, $($Arg,)* ReturnType> MethodSignature<T> for fn(&'static T, $($Arg),*) -> [ReturnType] {}
+        impl<T, $($Arg,)* ReturnType> MethodSignature<T> for fn(&'static mut T, $($Arg),*) -> [ReturnType] {}
+        impl<T, $($Arg,)* ReturnType> MethodSignature<T> for fn(&T, $($Arg),*) -> &ReturnType {}
+        impl<T, $($Arg,)* ReturnType> MethodSignature<T> for fn(&mut T, $($Arg),*) -> &mut ReturnType {}
+        impl<T, $($Arg,)* ReturnType> MethodSignature<T> for fn(&mut T, $($Arg),*) -> &ReturnType {}
+    };
+}
+
+all_tuples!(impl_method_signature, 0, 15, Arg);
+
+/// Returns the ownership of the receiver of a method, if it takes any arguments.
+fn receiver_ownership(info: &FunctionInfo) -> Option<Ownership> {
+    info.base().args().first().map(ArgInfo::ownership)
+}
+
+/// Creates the receiver argument for a method with the given receiver ownership.
+///
+/// Owned receivers are created from `receiver` using `from_reflect`.
+fn receiver_arg<'a>(
+    ownership: Ownership,
+    receiver: &'a mut dyn PartialReflect,
+    type_path: &'static str,
+    from_reflect: fn(&dyn PartialReflect) -> Option<Box<dyn PartialReflect>>,
+) -> Result<ArgValue<'a>, FunctionError> {
+    Ok(match ownership {
+        Ownership::Ref => ArgValue::Ref(receiver),
+        Ownership::Mut => ArgValue::Mut(receiver),
+        Ownership::Owned => {
+            let receiver = from_reflect(receiver).ok_or_else(|| ArgError::UnexpectedType {
+                index: 0,
+                expected: Cow::Borrowed(type_path),
+                received: Cow::Owned(receiver.reflect_type_path().into()),
+            })?;
+            ArgValue::Owned(receiver)
+        }
+    })
+}
+
+/// Returns a copy of the signature without its receiver argument.
+fn unbind(signature: &SignatureInfo) -> SignatureInfo {
+    let args = signature
+        .args()
+        .iter()
+        .skip(1)
+        .enumerate()
+        .map(|(index, arg)| arg.clone().with_index(index))
+        .collect::<Vec<_>>();
+    let unbound = match signature.name() {
+        Some(name) => SignatureInfo::named(name.clone()),
+        None => SignatureInfo::anonymous(),
+    };
+    unbound
+        .with_args(args)
+        .with_return_info(signature.return_info().clone())
+}
+
+/// Returns a new argument list with `first` followed by the arguments of `args`.
+fn prepend_arg<'a>(first: ArgValue<'a>, mut args: ArgList<'a>) -> Result<ArgList<'a>, ArgError> {
+    let mut list = ArgList::new();
+    list.push_arg(first);
+    while !args.is_empty() {
+        list.push_arg(args.take_arg()?.take_value());
+    }
+    Ok(list)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{func::args::ArgCount, Reflect};
+    use alloc::string::String;
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Player {
+        name: String,
+        health: i32,
+    }
+
+    impl Player {
+        fn name(&self) -> &String {
+            &self.name
+        }
+
+        fn heal(&mut self, amount: i32) -> i32 {
+            self.health += amount;
+            self.health
+        }
+
+        fn damaged(self, amount: i32) -> Self {
+            Self {
+                health: self.health - amount,
+                ..self
+            }
+        }
+    }
+
+    fn player() -> Player {
+        Player {
+            name: String::from("Ferris"),
+            health: 10,
+        }
+    }
+
+    fn methods() -> ReflectMethods {
+        ReflectMethods::new::<Player>()
+            .with_method("name", Player::name)
+            .with_method("heal", Player::heal)
+            .with_method("damaged", Player::damaged)
+    }
+
+    #[test]
+    fn should_call_methods_with_any_receiver() {
+        let methods = methods();
+        let mut player = player();
+
+        let name = methods.call("name", &mut player, ArgList::new()).unwrap();
+        assert_eq!(
+            name.unwrap_ref().try_downcast_ref::<String>().unwrap(),
+            "Ferris"
+        );
+
+        let health = methods
+            .call("heal", &mut player, ArgList::new().with_owned(5_i32))
+            .unwrap();
+        assert_eq!(health.unwrap_owned().try_take::<i32>().unwrap(), 15);
+        assert_eq!(player.health, 15);
+
+        let damaged = methods
+            .call("damaged", &mut player, ArgList::new().with_owned(20_i32))
+            .unwrap();
+        assert_eq!(
+            damaged.unwrap_owned().try_take::<Player>().unwrap().health,
+            -5
+        );
+        assert_eq!(player.health, 15);
+    }
+
+    #[test]
+    fn should_bind_methods_to_receiver() {
+        let methods = methods();
+        let mut player = player();
+
+        let mut heal = methods.bind("heal", &mut player).unwrap();
+        assert_eq!(heal.arg_count(), ArgCount::new(1).unwrap());
+        heal.call(ArgList::new().with_owned(1_i32)).unwrap();
+        let health = heal.call(ArgList::new().with_owned(2_i32)).unwrap();
+        assert_eq!(health.unwrap_owned().try_take::<i32>().unwrap(), 13);
+        drop(heal);
+        assert_eq!(player.health, 13);
+
+        let name = methods
+            .bind("name", &mut player)
+            .unwrap()
+            .call_once(ArgList::new())
+            .unwrap();
+        assert_eq!(name.unwrap_owned().try_take::<String>().unwrap(), "Ferris");
+    }
+
+    #[test]
+    fn should_error_on_missing_method() {
+        let methods = methods();
+        let mut player = player();
+        let result = methods.call("jump", &mut player, ArgList::new());
+        assert_eq!(
+            result.unwrap_err(),
+            MethodError::NotFound {
+                name: Cow::Borrowed("jump"),
+                type_path: Player::type_path(),
+            }
+        );
+    }
+
+    #[test]
+    fn should_error_on_invalid_receiver() {
+        fn add(a: i32, b: i32) -> i32 {
+            a + b
+        }
+
+        let mut methods = ReflectMethods::new::<Player>();
+        assert_eq!(
+            methods.register("add", add).unwrap_err(),
+            MethodRegistrationError::InvalidReceiver {
+                name: Cow::Borrowed("add"),
+                type_path: Player::type_path(),
+            }
+        );
+
+        methods.register("heal", Player::heal).unwrap();
+        assert_eq!(
+            methods.register("heal", Player::heal).unwrap_err(),
+            MethodRegistrationError::DuplicateName(Cow::Borrowed("heal"))
+        );
+    }
+}
@@ -149,7 +149,40 @@
 //! assert_eq!(value.unwrap_owned().try_downcast_ref::<i32>(), Some(&50));
 //! ```
 //!
+//! # Methods
+//!
+//! Methods of a type can be registered as [`ReflectMethods`] type data,
+//! either with the `#[reflect(methods(...))]` derive attribute or with [`TypeRegistry::register_method`].
+//! They can then be called by name on any `&mut dyn PartialReflect` of that type,
+//! or bound to such a receiver as a [`DynamicFunctionMut`].
+//!
+//! ```
+//! # use bevy_reflect::{func::{ArgList, ReflectMethods}, PartialReflect, Reflect, TypeRegistry};
+//! #[derive(Reflect)]
+//! #[reflect(methods(scale))]
+//! struct Size(f32);
+//!
+//! impl Size {
+//!     fn scale(&mut self, factor: f32) {
+//!         self.0 *= factor;
+//!     }
+//! }
+//!
+//! let mut registry = TypeRegistry::new();
+//! registry.register::<Size>();
+//!
+//! let mut size = Size(2.0);
+//! let value: &mut dyn PartialReflect = &mut size;
+//! let methods = registry.get_type_data::<ReflectMethods>(core::any::TypeId::of::<Size>()).unwrap();
+//!
+//! let mut scale = methods.bind("scale", value).unwrap();
+//! scale.call(ArgList::new().with_owned(3.0_f32)).unwrap();
+//! drop(scale);
+//! assert_eq!(size.0, 6.0);
+//! ```
+//!
 //! [`PartialReflect`]: crate::PartialReflect
+//! [`TypeRegistry::register_method`]: crate::TypeRegistry::register_method
 //! [`Reflect`]: crate::Reflect
 //! [lack of variadic generics]: https://poignardazur.github.io/2024/05/25/report-on-rustnl-variadics/
 //! [coherence issues]: https://doc.rust-lang.org/rustc/lints/listing/warn-by-default.html#coherence-leak-check
@@ -166,6 +199,7 @@ pub use function::*;
 pub use info::*;
 pub use into_function::*;
 pub use into_function_mut::*;
+pub use methods::*;
 pub use reflect_fn::*;
 pub use reflect_fn_mut::*;
 pub use registry::*;
@@ -181,6 +215,7 @@ mod info;
 mod into_function;
 mod into_function_mut;
 pub(crate) mod macros;
+mod methods;
 mod reflect_fn;
 mod reflect_fn_mut;
 mod registry;
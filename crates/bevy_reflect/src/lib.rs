@@ -603,6 +603,7 @@ mod impls {
 }
 
 pub mod attributes;
+pub mod diff;
 mod enums;
 mod generics;
 pub mod serde;
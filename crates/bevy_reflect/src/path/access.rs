@@ -107,7 +107,7 @@ impl<'a> Access<'a> {
         }
     }
 
-    pub(super) fn element_mut<'r>(
+    pub(crate) fn element_mut<'r>(
         &self,
         base: &'r mut dyn PartialReflect,
         offset: Option<usize>,
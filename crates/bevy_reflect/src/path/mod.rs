@@ -8,7 +8,10 @@ mod parse;
 pub use parse::ParseError;
 use parse::PathParser;
 
-use crate::{PartialReflect, Reflect};
+mod query;
+pub use query::*;
+
+use crate::{PartialReflect, Reflect, ReflectKind};
 use alloc::vec::Vec;
 use core::fmt;
 use derive_more::derive::From;
@@ -38,6 +41,20 @@ pub enum ReflectPathError<'a> {
         /// The underlying error.
         error: ParseError<'a>,
     },
+
+    /// An error caused by applying a wildcard or filter of a [`PathQuery`]
+    /// to a value whose elements or fields can't be iterated over.
+    #[error("Error querying elements with `{selector}` (offset {offset}): Expected {expected}, found a {actual} instead.")]
+    InvalidSelector {
+        /// The wildcard or filter that was applied.
+        selector: &'static str,
+        /// Position of the selector in the query string.
+        offset: usize,
+        /// A description of the kinds of values the selector can be applied to.
+        expected: &'static str,
+        /// The [`ReflectKind`] of the value it was applied to.
+        actual: ReflectKind,
+    },
 }
 
 impl<'a> From<AccessError<'a>> for ReflectPathError<'a> {
@@ -10,11 +10,11 @@ use super::{Access, ReflectPathError};
 /// An error that occurs when parsing reflect path strings.
 #[derive(Debug, PartialEq, Eq, Error)]
 #[error(transparent)]
-pub struct ParseError<'a>(Error<'a>);
+pub struct ParseError<'a>(pub(super) Error<'a>);
 
 /// A parse error for a path string.
 #[derive(Debug, PartialEq, Eq, Error)]
-enum Error<'a> {
+pub(super) enum Error<'a> {
     #[error("expected an identifier, but reached end of path string")]
     NoIdent,
 
@@ -32,6 +32,24 @@ enum Error<'a> {
 
     #[error("a ']' was found before an opening '['")]
     CloseBeforeOpen,
+
+    #[error("a '[?(' wasn't closed, reached end of path string before finding a ')]'")]
+    UnclosedFilter,
+
+    #[error("expected a path in filter, got '{0}' instead")]
+    ExpectedOperand(&'a str),
+
+    #[error("expected a number, boolean, or string literal in filter, got '{0}' instead")]
+    InvalidLiteral(&'a str),
+
+    #[error("a string literal in filter wasn't closed")]
+    UnclosedString,
+
+    #[error("unexpected '{0}' in filter")]
+    UnexpectedInFilter(&'a str),
+
+    #[error("reached end of filter while expecting a path or literal")]
+    EndOfFilter,
 }
 
 pub(super) struct PathParser<'a> {
@@ -122,7 +140,7 @@ impl<'a> Iterator for PathParser<'a> {
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct Ident<'a>(&'a str);
+pub(super) struct Ident<'a>(&'a str);
 
 impl<'a> Ident<'a> {
     fn field(self) -> Access<'a> {
@@ -142,7 +160,7 @@ impl<'a> Ident<'a> {
 // the optimizer happy, and shaves off a few cycles.
 #[derive(Debug, PartialEq, Eq)]
 #[repr(u8)]
-enum Token<'a> {
+pub(super) enum Token<'a> {
     Dot = b'.',
     Pound = b'#',
     OpenBracket = b'[',
@@ -0,0 +1,913 @@
+//! Queries matching any number of elements within a type.
+
+use alloc::{borrow::Cow, boxed::Box, string::String, vec::Vec};
+use core::{cmp::Ordering, fmt};
+
+use super::{
+    parse::{Error, PathParser},
+    OffsetAccess, ParseError, ParsedPath, PathResult, ReflectPath, ReflectPathError,
+};
+use crate::{PartialReflect, Reflect, ReflectMut, ReflectRef};
+
+/// A pre-parsed query matching any number of elements within a type.
+///
+/// Queries extend the [path syntax] of [`ParsedPath`] with selectors that match multiple values:
+/// - `[*]` matches every element of a [`List`], [`Array`], [`Set`], or every value of a [`Map`].
+/// - `.*` matches every field of a [`Struct`], [`TupleStruct`], [`Tuple`], or of the current variant of an [`Enum`].
+/// - `[?(filter)]` matches the elements (as with `[*]`) for which `filter` holds.
+///
+/// Any other part of a query is a regular [`Access`], which is applied to every value matched so far.
+///
+/// # Filters
+///
+/// A filter compares a value, given as a path relative to the element being tested,
+/// to a literal using one of `==`, `!=`, `<`, `<=`, `>`, or `>=`.
+/// The element itself can be referred to with `@`,
+/// which may also optionally prefix relative paths (e.g. `@.count`).
+///
+/// Literals may be numbers, booleans (`true` or `false`), or strings in single or double quotes.
+/// Numbers compare with any primitive number type, and strings compare with
+/// `String`, `&'static str`, `Cow<'static, str>`, and the variant name of enums.
+///
+/// A filter without a comparison (e.g. `[?(.enabled)]`) holds if the path exists,
+/// and, if the value is a `bool`, if it is `true`.
+/// Comparisons can be combined with `&&` and `||`, with `&&` binding more tightly.
+///
+/// A filter never produces an error: if the relative path doesn't exist,
+/// or the value can't be compared with the literal, the filter simply doesn't hold.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{PathQuery, Reflect};
+/// #[derive(Reflect)]
+/// struct Inventory {
+///     items: Vec<Item>,
+/// }
+///
+/// #[derive(Reflect)]
+/// struct Item {
+///     id: u32,
+///     count: u32,
+/// }
+///
+/// let mut inventory = Inventory {
+///     items: vec![
+///         Item { id: 1, count: 0 },
+///         Item { id: 2, count: 3 },
+///         Item { id: 3, count: 5 },
+///     ],
+/// };
+///
+/// let ids = PathQuery::parse("items[?(.count > 0)].id").unwrap();
+/// assert_eq!(ids.elements::<u32>(&inventory).unwrap(), vec![&2, &3]);
+///
+/// let counts = PathQuery::parse("items[*].count").unwrap();
+/// counts
+///     .elements_mut::<u32>(&mut inventory, |count| *count += 1)
+///     .unwrap();
+/// assert_eq!(counts.elements::<u32>(&inventory).unwrap(), vec![&1, &4, &6]);
+/// ```
+///
+/// [path syntax]: crate::GetPath#syntax
+/// [`List`]: crate::List
+/// [`Array`]: crate::Array
+/// [`Set`]: crate::Set
+/// [`Map`]: crate::Map
+/// [`Struct`]: crate::Struct
+/// [`TupleStruct`]: crate::TupleStruct
+/// [`Tuple`]: crate::Tuple
+/// [`Enum`]: crate::Enum
+/// [`Access`]: super::Access
+#[derive(Clone, Debug, PartialEq)]
+pub struct PathQuery(Vec<Segment>);
+
+/// A single part of a [`PathQuery`].
+#[derive(Clone, Debug, PartialEq)]
+enum Segment {
+    /// A regular access to a single element.
+    Access(OffsetAccess),
+    /// `[*]`: every element of a list, array, set or map.
+    Elements { offset: usize },
+    /// `.*`: every field of a struct, tuple struct, tuple or enum variant.
+    Fields { offset: usize },
+    /// `[?(filter)]`: the elements for which the filter holds.
+    Filter {
+        filter: Filter,
+        source: String,
+        offset: usize,
+    },
+}
+
+impl PathQuery {
+    /// Parses a [`PathQuery`] from a string.
+    ///
+    /// Returns an error if the string does not represent a valid query.
+    pub fn parse(string: &str) -> PathResult<Self> {
+        let bytes = string.as_bytes();
+        let mut segments = Vec::new();
+        let mut plain_start = 0;
+        let mut index = 0;
+
+        while index < bytes.len() {
+            let rest = &bytes[index..];
+            let (segment, len) = if rest.starts_with(b"[*]") {
+                (Segment::Elements { offset: index }, 3)
+            } else if rest.starts_with(b".*") || (index == 0 && rest.starts_with(b"*")) {
+                let len = if rest[0] == b'*' { 1 } else { 2 };
+                (Segment::Fields { offset: index }, len)
+            } else if rest.starts_with(b"[?(") {
+                let start = index + 3;
+                let end = find_filter_end(string, start).ok_or(ReflectPathError::ParseError {
+                    offset: index,
+                    path: string,
+                    error: ParseError(Error::UnclosedFilter),
+                })?;
+                let filter = FilterParser::new(string, start, end).parse()?;
+                let segment = Segment::Filter {
+                    filter,
+                    source: string[start..end].trim().into(),
+                    offset: index,
+                };
+                (segment, end + 2 - index)
+            } else {
+                index += 1;
+                continue;
+            };
+
+            parse_accesses(string, plain_start, index, &mut segments)?;
+            segments.push(segment);
+            index += len;
+            plain_start = index;
+        }
+        parse_accesses(string, plain_start, bytes.len(), &mut segments)?;
+
+        Ok(Self(segments))
+    }
+
+    /// Returns references to all elements matched by this query, in order.
+    ///
+    /// Returns an error if an [access] fails or a selector is applied to a value it doesn't support.
+    ///
+    /// [access]: super::Access
+    pub fn reflect_elements<'r>(
+        &self,
+        root: &'r dyn PartialReflect,
+    ) -> PathResult<'static, Vec<&'r dyn PartialReflect>> {
+        let mut matches = Vec::new();
+        visit(root, &self.0, &mut matches)?;
+        Ok(matches)
+    }
+
+    /// Calls `f` with a mutable reference to each element matched by this query, in order.
+    ///
+    /// Returns the number of matched elements,
+    /// or an error if an [access] fails or a selector is applied to a value it doesn't support.
+    /// Note that `f` may already have been called for some elements when an error is returned.
+    ///
+    /// Since elements of a [`Set`] can't be mutated, `[*]` and filters can't be applied to sets.
+    ///
+    /// [access]: super::Access
+    /// [`Set`]: crate::Set
+    pub fn reflect_elements_mut(
+        &self,
+        root: &mut dyn PartialReflect,
+        mut f: impl FnMut(&mut dyn PartialReflect),
+    ) -> PathResult<'static, usize> {
+        let mut count = 0;
+        visit_mut(root, &self.0, &mut |value| {
+            count += 1;
+            f(value);
+        })?;
+        Ok(count)
+    }
+
+    /// Returns statically typed references to all elements matched by this query, in order.
+    ///
+    /// Returns [`ReflectPathError::InvalidDowncast`] if any of the elements is not of type `T`.
+    pub fn elements<'r, T: Reflect>(
+        &self,
+        root: &'r dyn PartialReflect,
+    ) -> PathResult<'static, Vec<&'r T>> {
+        self.reflect_elements(root)?
+            .into_iter()
+            .map(|value| {
+                value
+                    .try_downcast_ref::<T>()
+                    .ok_or(ReflectPathError::InvalidDowncast)
+            })
+            .collect()
+    }
+
+    /// Calls `f` with a statically typed mutable reference to each element matched by this query, in order.
+    ///
+    /// Returns the number of matched elements,
+    /// or [`ReflectPathError::InvalidDowncast`] as soon as an element is not of type `T`.
+    pub fn elements_mut<T: Reflect>(
+        &self,
+        root: &mut dyn PartialReflect,
+        mut f: impl FnMut(&mut T),
+    ) -> PathResult<'static, usize> {
+        let mut count = 0;
+        let mut invalid = false;
+        visit_mut(root, &self.0, &mut |value| {
+            if invalid {
+                return;
+            }
+            match value.try_downcast_mut::<T>() {
+                Some(value) => {
+                    count += 1;
+                    f(value);
+                }
+                None => invalid = true,
+            }
+        })?;
+        if invalid {
+            return Err(ReflectPathError::InvalidDowncast);
+        }
+        Ok(count)
+    }
+}
+
+impl From<ParsedPath> for PathQuery {
+    fn from(path: ParsedPath) -> Self {
+        Self(path.0.into_iter().map(Segment::Access).collect())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for PathQuery {
+    type Error = ReflectPathError<'a>;
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        PathQuery::parse(value)
+    }
+}
+
+impl fmt::Display for PathQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for segment in &self.0 {
+            match segment {
+                Segment::Access(OffsetAccess { access, .. }) => write!(f, "{access}")?,
+                Segment::Elements { .. } => f.write_str("[*]")?,
+                Segment::Fields { .. } => f.write_str(".*")?,
+                Segment::Filter { source, .. } => write!(f, "[?({source})]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses the regular accesses in `path[start..end]` into `segments`.
+fn parse_accesses<'a>(
+    path: &'a str,
+    start: usize,
+    end: usize,
+    segments: &mut Vec<Segment>,
+) -> PathResult<'a, ()> {
+    for (access, offset) in PathParser::new(&path[start..end]) {
+        let access = access.map_err(|err| relocate(err, path, start))?;
+        segments.push(Segment::Access(OffsetAccess {
+            access: access.into_owned(),
+            offset: Some(start + offset),
+        }));
+    }
+    Ok(())
+}
+
+/// Adjusts a parse error for a substring of `path` starting at `start` to refer to `path` itself.
+fn relocate<'a>(err: ReflectPathError<'a>, path: &'a str, start: usize) -> ReflectPathError<'a> {
+    match err {
+        ReflectPathError::ParseError { offset, error, .. } => ReflectPathError::ParseError {
+            offset: start + offset,
+            path,
+            error,
+        },
+        err => err,
+    }
+}
+
+/// Returns the offset of the `)` closing a filter whose expression starts at `start`,
+/// if it is directly followed by a `]`.
+fn find_filter_end(path: &str, start: usize) -> Option<usize> {
+    let bytes = path.as_bytes();
+    let mut depth = 0_usize;
+    let mut quote = None;
+    for (index, &byte) in bytes.iter().enumerate().skip(start) {
+        match (quote, byte) {
+            (Some(open), _) if byte == open => quote = None,
+            (None, b'\'' | b'"') => quote = Some(byte),
+            (None, b'(') => depth += 1,
+            (None, b')') if depth > 0 => depth -= 1,
+            (None, b')') => return (bytes.get(index + 1) == Some(&b']')).then_some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Collects the values matched by `segments` in `value`.
+fn visit<'r>(
+    value: &'r dyn PartialReflect,
+    segments: &[Segment],
+    matches: &mut Vec<&'r dyn PartialReflect>,
+) -> PathResult<'static, ()> {
+    let Some((segment, rest)) = segments.split_first() else {
+        matches.push(value);
+        return Ok(());
+    };
+    match segment {
+        Segment::Access(OffsetAccess { access, offset }) => {
+            visit(access.element(value, *offset)?, rest, matches)
+        }
+        Segment::Fields { offset } => {
+            for field in fields(value, *offset)? {
+                visit(field, rest, matches)?;
+            }
+            Ok(())
+        }
+        Segment::Elements { offset } => {
+            for element in elements(value, "[*]", *offset)? {
+                visit(element, rest, matches)?;
+            }
+            Ok(())
+        }
+        Segment::Filter { filter, offset, .. } => {
+            for element in elements(value, "[?(..)]", *offset)? {
+                if filter.matches(element) {
+                    visit(element, rest, matches)?;
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Calls `f` with each value matched by `segments` in `value`.
+fn visit_mut(
+    value: &mut dyn PartialReflect,
+    segments: &[Segment],
+    f: &mut dyn FnMut(&mut dyn PartialReflect),
+) -> PathResult<'static, ()> {
+    let Some((segment, rest)) = segments.split_first() else {
+        f(value);
+        return Ok(());
+    };
+    match segment {
+        Segment::Access(OffsetAccess { access, offset }) => {
+            visit_mut(access.element_mut(value, *offset)?, rest, f)
+        }
+        Segment::Fields { offset } => {
+            for index in 0..fields(value, *offset)?.len() {
+                if let Some(field) = field_at_mut(value, index) {
+                    visit_mut(field, rest, f)?;
+                }
+            }
+            Ok(())
+        }
+        Segment::Elements { offset } => {
+            for index in 0..element_len_mut(value, "[*]", *offset)? {
+                if let Some(element) = element_at_mut(value, index) {
+                    visit_mut(element, rest, f)?;
+                }
+            }
+            Ok(())
+        }
+        Segment::Filter { filter, offset, .. } => {
+            for index in 0..element_len_mut(value, "[?(..)]", *offset)? {
+                if let Some(element) = element_at_mut(value, index) {
+                    if filter.matches(element) {
+                        visit_mut(element, rest, f)?;
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+const FIELDS_EXPECTED: &str = "a struct, tuple struct, tuple, or enum";
+const ELEMENTS_EXPECTED: &str = "a list, array, map, or set";
+const ELEMENTS_MUT_EXPECTED: &str = "a list, array, or map";
+
+/// Returns the fields of a struct, tuple struct, tuple, or enum variant.
+fn fields(
+    value: &dyn PartialReflect,
+    offset: usize,
+) -> PathResult<'static, Vec<&dyn PartialReflect>> {
+    Ok(match value.reflect_ref() {
+        ReflectRef::Struct(value) => value.iter_fields().collect(),
+        ReflectRef::TupleStruct(value) => value.iter_fields().collect(),
+        ReflectRef::Tuple(value) => value.iter_fields().collect(),
+        ReflectRef::Enum(value) => value.iter_fields().map(|field| field.value()).collect(),
+        actual => {
+            return Err(ReflectPathError::InvalidSelector {
+                selector: ".*",
+                offset,
+                expected: FIELDS_EXPECTED,
+                actual: actual.into(),
+            })
+        }
+    })
+}
+
+fn field_at_mut(value: &mut dyn PartialReflect, index: usize) -> Option<&mut dyn PartialReflect> {
+    match value.reflect_mut() {
+        ReflectMut::Struct(value) => value.field_at_mut(index),
+        ReflectMut::TupleStruct(value) => value.field_mut(index),
+        ReflectMut::Tuple(value) => value.field_mut(index),
+        ReflectMut::Enum(value) => value.field_at_mut(index),
+        _ => None,
+    }
+}
+
+/// Returns the elements of a list, array or set, or the values of a map.
+fn elements<'r>(
+    value: &'r dyn PartialReflect,
+    selector: &'static str,
+    offset: usize,
+) -> PathResult<'static, Vec<&'r dyn PartialReflect>> {
+    Ok(match value.reflect_ref() {
+        ReflectRef::List(value) => value.iter().collect(),
+        ReflectRef::Array(value) => value.iter().collect(),
+        ReflectRef::Map(value) => value.iter().map(|(_, value)| value).collect(),
+        ReflectRef::Set(value) => value.iter().collect(),
+        actual => {
+            return Err(ReflectPathError::InvalidSelector {
+                selector,
+                offset,
+                expected: ELEMENTS_EXPECTED,
+                actual: actual.into(),
+            })
+        }
+    })
+}
+
+/// Returns the number of mutable elements of a list, array or map.
+fn element_len_mut(
+    value: &dyn PartialReflect,
+    selector: &'static str,
+    offset: usize,
+) -> PathResult<'static, usize> {
+    match value.reflect_ref() {
+        ReflectRef::List(value) => Ok(value.len()),
+        ReflectRef::Array(value) => Ok(value.len()),
+        ReflectRef::Map(value) => Ok(value.len()),
+        actual => Err(ReflectPathError::InvalidSelector {
+            selector,
+            offset,
+            expected: ELEMENTS_MUT_EXPECTED,
+            actual: actual.into(),
+        }),
+    }
+}
+
+fn element_at_mut(value: &mut dyn PartialReflect, index: usize) -> Option<&mut dyn PartialReflect> {
+    match value.reflect_mut() {
+        ReflectMut::List(value) => value.get_mut(index),
+        ReflectMut::Array(value) => value.get_mut(index),
+        ReflectMut::Map(value) => value.get_at_mut(index).map(|(_, value)| value),
+        _ => None,
+    }
+}
+
+/// A predicate tested against each element by a `[?(filter)]` selector.
+#[derive(Clone, Debug, PartialEq)]
+enum Filter {
+    Or(Box<Filter>, Box<Filter>),
+    And(Box<Filter>, Box<Filter>),
+    /// Holds if the path exists and, for `bool` values, is `true`.
+    Exists(ParsedPath),
+    Compare {
+        path: ParsedPath,
+        op: CompareOp,
+        literal: Literal,
+    },
+}
+
+impl Filter {
+    fn matches(&self, element: &dyn PartialReflect) -> bool {
+        match self {
+            Filter::Or(a, b) => a.matches(element) || b.matches(element),
+            Filter::And(a, b) => a.matches(element) && b.matches(element),
+            Filter::Exists(path) => path
+                .reflect_element(element)
+                .is_ok_and(|value| value.try_downcast_ref::<bool>().copied().unwrap_or(true)),
+            Filter::Compare { path, op, literal } => path
+                .reflect_element(element)
+                .ok()
+                .and_then(|value| literal.compare(value))
+                .is_some_and(|ordering| op.holds(ordering)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl CompareOp {
+    const ALL: [(&'static str, CompareOp); 6] = [
+        ("==", CompareOp::Eq),
+        ("!=", CompareOp::Ne),
+        ("<=", CompareOp::Le),
+        (">=", CompareOp::Ge),
+        ("<", CompareOp::Lt),
+        (">", CompareOp::Gt),
+    ];
+
+    fn holds(self, ordering: Ordering) -> bool {
+        match self {
+            CompareOp::Eq => ordering.is_eq(),
+            CompareOp::Ne => ordering.is_ne(),
+            CompareOp::Lt => ordering.is_lt(),
+            CompareOp::Le => ordering.is_le(),
+            CompareOp::Gt => ordering.is_gt(),
+            CompareOp::Ge => ordering.is_ge(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Literal {
+    Number(f64),
+    Bool(bool),
+    String(String),
+}
+
+impl Literal {
+    /// Compares `value` to this literal, if they are comparable.
+    fn compare(&self, value: &dyn PartialReflect) -> Option<Ordering> {
+        match self {
+            Literal::Number(number) => as_number(value)?.partial_cmp(number),
+            Literal::Bool(bool) => Some(value.try_downcast_ref::<bool>()?.cmp(bool)),
+            Literal::String(string) => Some(as_str(value)?.cmp(string.as_str())),
+        }
+    }
+}
+
+fn as_number(value: &dyn PartialReflect) -> Option<f64> {
+    macro_rules! try_number {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(value) = value.try_downcast_ref::<$ty>() {
+                    return Some(*value as f64);
+                }
+            )*
+        };
+    }
+    try_number!(f32, f64, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    None
+}
+
+fn as_str(value: &dyn PartialReflect) -> Option<&str> {
+    if let ReflectRef::Enum(value) = value.reflect_ref() {
+        return Some(value.variant_name());
+    }
+    if let Some(value) = value.try_downcast_ref::<String>() {
+        return Some(value);
+    }
+    if let Some(value) = value.try_downcast_ref::<&'static str>() {
+        return Some(value);
+    }
+    value
+        .try_downcast_ref::<Cow<'static, str>>()
+        .map(AsRef::as_ref)
+}
+
+/// Parses the expression of a `[?(filter)]` selector.
+struct FilterParser<'a> {
+    path: &'a str,
+    position: usize,
+    end: usize,
+}
+
+impl<'a> FilterParser<'a> {
+    fn new(path: &'a str, start: usize, end: usize) -> Self {
+        Self {
+            path,
+            position: start,
+            end,
+        }
+    }
+
+    fn parse(mut self) -> PathResult<'a, Filter> {
+        let filter = self.parse_or()?;
+        self.skip_whitespace();
+        if self.position < self.end {
+            return Err(self.error(Error::UnexpectedInFilter(self.rest())));
+        }
+        Ok(filter)
+    }
+
+    fn parse_or(&mut self) -> PathResult<'a, Filter> {
+        let mut filter = self.parse_and()?;
+        while self.eat("||") {
+            filter = Filter::Or(Box::new(filter), Box::new(self.parse_and()?));
+        }
+        Ok(filter)
+    }
+
+    fn parse_and(&mut self) -> PathResult<'a, Filter> {
+        let mut filter = self.parse_comparison()?;
+        while self.eat("&&") {
+            filter = Filter::And(Box::new(filter), Box::new(self.parse_comparison()?));
+        }
+        Ok(filter)
+    }
+
+    fn parse_comparison(&mut self) -> PathResult<'a, Filter> {
+        let path = self.parse_operand()?;
+        let Some(op) = CompareOp::ALL
+            .into_iter()
+            .find_map(|(token, op)| self.eat(token).then_some(op))
+        else {
+            return Ok(Filter::Exists(path));
+        };
+        let literal = self.parse_literal()?;
+        Ok(Filter::Compare { path, op, literal })
+    }
+
+    fn parse_operand(&mut self) -> PathResult<'a, ParsedPath> {
+        self.skip_whitespace();
+        let start = self.position;
+        let token =
+            self.take_while(|byte| !byte.is_ascii_whitespace() && !b"=!<>&|()'\"".contains(&byte));
+        if token.is_empty() {
+            return Err(self.operand_error());
+        }
+
+        let (path_start, path) = match token.strip_prefix('@') {
+            Some(path) => (start + 1, path),
+            None => (start, token),
+        };
+        let mut accesses = Vec::new();
+        for (access, offset) in PathParser::new(path) {
+            let access = access.map_err(|err| relocate(err, self.path, path_start))?;
+            accesses.push(OffsetAccess {
+                access: access.into_owned(),
+                offset: Some(path_start + offset),
+            });
+        }
+        Ok(ParsedPath(accesses))
+    }
+
+    fn parse_literal(&mut self) -> PathResult<'a, Literal> {
+        self.skip_whitespace();
+        let bytes = self.path.as_bytes();
+        if self.position >= self.end {
+            return Err(self.error(Error::EndOfFilter));
+        }
+
+        let quote = bytes[self.position];
+        if quote == b'\'' || quote == b'"' {
+            let start = self.position + 1;
+            let len = bytes[start..self.end]
+                .iter()
+                .position(|&byte| byte == quote)
+                .ok_or_else(|| self.error(Error::UnclosedString))?;
+            self.position = start + len + 1;
+            return Ok(Literal::String(self.path[start..start + len].into()));
+        }
+
+        let token = self.take_while(|byte| !byte.is_ascii_whitespace() && !b"&|()".contains(&byte));
+        match token {
+            "true" => Ok(Literal::Bool(true)),
+            "false" => Ok(Literal::Bool(false)),
+            _ => token.parse().map(Literal::Number).map_err(|_| {
+                self.position -= token.len();
+                self.error(Error::InvalidLiteral(token))
+            }),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.take_while(|byte| byte.is_ascii_whitespace());
+    }
+
+    /// Consumes `token` if it is next in the filter.
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(token) {
+            self.position += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(u8) -> bool) -> &'a str {
+        let start = self.position;
+        let len = self.path.as_bytes()[start..self.end]
+            .iter()
+            .position(|&byte| !predicate(byte))
+            .unwrap_or(self.end - start);
+        self.position += len;
+        &self.path[start..start + len]
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.path[self.position..self.end]
+    }
+
+    fn operand_error(&self) -> ReflectPathError<'a> {
+        match self.rest().chars().next() {
+            Some(char) => {
+                let len = char.len_utf8();
+                self.error(Error::ExpectedOperand(
+                    &self.path[self.position..self.position + len],
+                ))
+            }
+            None => self.error(Error::EndOfFilter),
+        }
+    }
+
+    fn error(&self, error: Error<'a>) -> ReflectPathError<'a> {
+        ReflectPathError::ParseError {
+            offset: self.position,
+            path: self.path,
+            error: ParseError(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{Reflect, ReflectKind};
+    use alloc::{collections::BTreeMap, string::ToString, vec};
+
+    #[derive(Reflect)]
+    struct Skeleton {
+        bones: Vec<Bone>,
+    }
+
+    #[derive(Reflect)]
+    struct Bone {
+        name: String,
+        scale: f32,
+        enabled: bool,
+        kind: BoneKind,
+    }
+
+    #[derive(Reflect)]
+    enum BoneKind {
+        Root,
+        Limb,
+    }
+
+    fn skeleton() -> Skeleton {
+        let bone = |name: &str, scale, enabled, kind| Bone {
+            name: name.into(),
+            scale,
+            enabled,
+            kind,
+        };
+        Skeleton {
+            bones: vec![
+                bone("hips", 1.0, true, BoneKind::Root),
+                bone("arm", 2.0, false, BoneKind::Limb),
+                bone("leg", 3.0, true, BoneKind::Limb),
+            ],
+        }
+    }
+
+    fn names<'a>(query: &str, skeleton: &'a Skeleton) -> Vec<&'a String> {
+        PathQuery::parse(query)
+            .unwrap()
+            .elements::<String>(skeleton)
+            .unwrap()
+    }
+
+    #[test]
+    fn wildcards() {
+        let skeleton = skeleton();
+        assert_eq!(names("bones[*].name", &skeleton), ["hips", "arm", "leg"]);
+
+        let query = PathQuery::parse("bones[1].*").unwrap();
+        assert_eq!(query.reflect_elements(&skeleton).unwrap().len(), 4);
+
+        let map = BTreeMap::from([(1, vec![1_u8, 2]), (2, vec![3])]);
+        let query = PathQuery::parse("[*][*]").unwrap();
+        assert_eq!(query.elements::<u8>(&map).unwrap(), [&1, &2, &3]);
+    }
+
+    #[test]
+    fn filters() {
+        let skeleton = skeleton();
+        assert_eq!(
+            names("bones[?(.scale > 1)].name", &skeleton),
+            ["arm", "leg"]
+        );
+        assert_eq!(names("bones[?(.enabled)].name", &skeleton), ["hips", "leg"]);
+        assert_eq!(
+            names(
+                "bones[?(@.kind == 'Limb' && enabled == true)].name",
+                &skeleton
+            ),
+            ["leg"]
+        );
+        assert_eq!(
+            names(
+                "bones[?(.name == \"arm\" || .scale <= 1.0)].name",
+                &skeleton
+            ),
+            ["hips", "arm"]
+        );
+        assert_eq!(
+            names("bones[?(.missing == 1)].name", &skeleton),
+            [] as [&str; 0]
+        );
+
+        let numbers = vec![5_i32, -2, 7];
+        let query = PathQuery::parse("[?(@ >= 0)]").unwrap();
+        assert_eq!(query.elements::<i32>(&numbers).unwrap(), [&5, &7]);
+    }
+
+    #[test]
+    fn mutate_matches() {
+        let mut skeleton = skeleton();
+        let query = PathQuery::parse("bones[?(.kind == 'Limb')].scale").unwrap();
+        let count = query
+            .elements_mut::<f32>(&mut skeleton, |scale| *scale *= 10.0)
+            .unwrap();
+        assert_eq!(count, 2);
+
+        let query = PathQuery::parse("bones[*].scale").unwrap();
+        assert_eq!(
+            query.elements::<f32>(&skeleton).unwrap(),
+            [&1.0, &20.0, &30.0]
+        );
+
+        let query = PathQuery::parse("bones[*].*").unwrap();
+        let count = query.reflect_elements_mut(&mut skeleton, |_| {}).unwrap();
+        assert_eq!(count, 12);
+    }
+
+    #[test]
+    fn display() {
+        let query = ".bones[*].name[?(@.x > 1 && .y)].*#0";
+        assert_eq!(PathQuery::parse(query).unwrap().to_string(), query);
+    }
+
+    #[test]
+    fn invalid_queries() {
+        let skeleton = skeleton();
+        assert_eq!(
+            PathQuery::parse("bones[*].name.*")
+                .unwrap()
+                .reflect_elements(&skeleton)
+                .err(),
+            Some(ReflectPathError::InvalidSelector {
+                selector: ".*",
+                offset: 13,
+                expected: FIELDS_EXPECTED,
+                actual: ReflectKind::Opaque,
+            })
+        );
+        assert!(matches!(
+            PathQuery::parse("bones[*].missing")
+                .unwrap()
+                .reflect_elements(&skeleton),
+            Err(ReflectPathError::InvalidAccess(_))
+        ));
+
+        assert_eq!(
+            PathQuery::parse("bones[?(.scale > 1]"),
+            Err(ReflectPathError::ParseError {
+                offset: 5,
+                path: "bones[?(.scale > 1]",
+                error: ParseError(Error::UnclosedFilter),
+            })
+        );
+        assert_eq!(
+            PathQuery::parse("bones[?(.scale > big)]"),
+            Err(ReflectPathError::ParseError {
+                offset: 17,
+                path: "bones[?(.scale > big)]",
+                error: ParseError(Error::InvalidLiteral("big")),
+            })
+        );
+        assert_eq!(
+            PathQuery::parse("bones[?(== 1)]"),
+            Err(ReflectPathError::ParseError {
+                offset: 8,
+                path: "bones[?(== 1)]",
+                error: ParseError(Error::ExpectedOperand("=")),
+            })
+        );
+        assert_eq!(
+            PathQuery::parse("bones[*].x[y]"),
+            Err(ReflectPathError::ParseError {
+                offset: 11,
+                path: "bones[*].x[y]",
+                error: ParseError(Error::InvalidIndex("y".parse::<usize>().unwrap_err())),
+            })
+        );
+    }
+}
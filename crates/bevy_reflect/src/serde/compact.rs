@@ -0,0 +1,637 @@
+use crate::{
+    serde::{
+        ReflectDeserializeWithRegistry, ReflectSerializeWithRegistry, SerializationData,
+        TypedReflectDeserializer, TypedReflectSerializer,
+    },
+    PartialReflect, ReflectDeserialize, ReflectSerialize, TypeInfo, TypeRegistration, TypeRegistry,
+    VariantInfo,
+};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use bevy_platform_support::collections::HashMap;
+use core::{any::TypeId, fmt, fmt::Formatter};
+use serde::{
+    de::{DeserializeSeed, Error as _, SeqAccess, Visitor},
+    ser::{Error as _, SerializeSeq, SerializeTuple},
+    Deserializer, Serialize, Serializer,
+};
+
+/// A serializer for a stream of reflected values in a compact binary layout.
+///
+/// This is the serializer counterpart to [`CompactReflectDeserializer`].
+///
+/// Unlike [`ReflectSerializer`], which writes the full [type path] of every value,
+/// this serializer writes each distinct type path once in a table at the start of the stream,
+/// and refers to it by its index in that table for each value.
+/// Values themselves are serialized with [`TypedReflectSerializer`],
+/// which non-self-describing formats such as `bincode` or `postcard` lay out positionally,
+/// without any field names.
+///
+/// Since positional data can only be read back by a registry with the exact same type layouts,
+/// the stream starts with a hash of the schema of every type it references
+/// (including the types of their fields, recursively).
+/// [`CompactReflectDeserializer`] refuses to read a stream whose schema hash doesn't match its own registry.
+///
+/// # Output
+///
+/// This serializer outputs a tuple of:
+/// 1. The schema hash, as a `u64`
+/// 2. The type table, as a sequence of type paths, sorted alphabetically
+/// 3. The values, as a sequence of `(index, value)` pairs, where `index` is the value's position in the type table
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::prelude::*;
+/// # use bevy_reflect::{TypeRegistry, serde::{CompactReflectDeserializer, CompactReflectSerializer}};
+/// # use bincode::Options;
+/// #[derive(Reflect, PartialEq, Debug)]
+/// struct Position {
+///   x: f32,
+///   y: f32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Position>();
+///
+/// let values = [Position { x: 1.0, y: 2.0 }, Position { x: 3.0, y: 4.0 }];
+/// let serializer = CompactReflectSerializer::new(
+///   values.iter().map(|value| value.as_partial_reflect()),
+///   &registry,
+/// );
+/// let bytes = bincode::DefaultOptions::new().serialize(&serializer).unwrap();
+///
+/// let deserializer = CompactReflectDeserializer::new(&registry);
+/// let output = bincode::DefaultOptions::new()
+///   .deserialize_seed(deserializer, &bytes)
+///   .unwrap();
+///
+/// assert_eq!(output.len(), 2);
+/// assert_eq!(Position::from_reflect(&*output[1]), Some(Position { x: 3.0, y: 4.0 }));
+/// ```
+///
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+/// [type path]: crate::TypePath::type_path
+pub struct CompactReflectSerializer<'a> {
+    values: Vec<&'a dyn PartialReflect>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> CompactReflectSerializer<'a> {
+    /// Creates a serializer for the given stream of values.
+    pub fn new(
+        values: impl IntoIterator<Item = &'a dyn PartialReflect>,
+        registry: &'a TypeRegistry,
+    ) -> Self {
+        Self {
+            values: values.into_iter().collect(),
+            registry,
+        }
+    }
+}
+
+impl Serialize for CompactReflectSerializer<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut registrations = Vec::with_capacity(self.values.len());
+        for value in &self.values {
+            let registration = value
+                .get_represented_type_info()
+                .and_then(|info| self.registry.get(info.type_id()))
+                .ok_or_else(|| {
+                    S::Error::custom(format_args!(
+                        "type `{}` is not registered in the type registry",
+                        value.reflect_type_path()
+                    ))
+                })?;
+            registrations.push(registration);
+        }
+
+        let mut table = registrations.clone();
+        table.sort_unstable_by_key(|registration| registration.type_info().type_path());
+        table.dedup_by_key(|registration| registration.type_id());
+        let indices = table
+            .iter()
+            .enumerate()
+            .map(|(index, registration)| (registration.type_id(), index as u32))
+            .collect::<HashMap<_, _>>();
+
+        let mut state = serializer.serialize_tuple(3)?;
+        state.serialize_element(&schema_hash(&table, self.registry))?;
+        state.serialize_element(&TypeTableSerializer(&table))?;
+        state.serialize_element(&ValuesSerializer {
+            values: &self.values,
+            indices: &registrations
+                .iter()
+                .map(|registration| indices[&registration.type_id()])
+                .collect::<Vec<_>>(),
+            registry: self.registry,
+        })?;
+        state.end()
+    }
+}
+
+struct TypeTableSerializer<'a>(&'a [&'a TypeRegistration]);
+
+impl Serialize for TypeTableSerializer<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.0.len()))?;
+        for registration in self.0 {
+            state.serialize_element(registration.type_info().type_path())?;
+        }
+        state.end()
+    }
+}
+
+struct ValuesSerializer<'a> {
+    values: &'a [&'a dyn PartialReflect],
+    indices: &'a [u32],
+    registry: &'a TypeRegistry,
+}
+
+impl Serialize for ValuesSerializer<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.values.len()))?;
+        for (value, index) in self.values.iter().zip(self.indices) {
+            state
+                .serialize_element(&(index, TypedReflectSerializer::new(*value, self.registry)))?;
+        }
+        state.end()
+    }
+}
+
+/// A deserializer for a stream of reflected values written by [`CompactReflectSerializer`].
+///
+/// The type table at the start of the stream is resolved against the registry by type path
+/// (so [type path aliases] are taken into account),
+/// and the stream's schema hash is checked against the registry's before any value is read.
+///
+/// Each value is deserialized with [`TypedReflectDeserializer`],
+/// so the output follows the same rules, i.e. values are generally dynamic types
+/// which can be converted to concrete types with [`FromReflect`].
+///
+/// [type path aliases]: crate::TypePathAliases
+/// [`FromReflect`]: crate::FromReflect
+pub struct CompactReflectDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> CompactReflectDeserializer<'a> {
+    /// Creates a deserializer that resolves types using the given registry.
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for CompactReflectDeserializer<'_> {
+    type Value = Vec<Box<dyn PartialReflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct CompactVisitor<'a> {
+            registry: &'a TypeRegistry,
+        }
+
+        impl<'de> Visitor<'de> for CompactVisitor<'_> {
+            type Value = Vec<Box<dyn PartialReflect>>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("compact reflected value stream")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let hash = seq
+                    .next_element::<u64>()?
+                    .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+                let type_paths = seq
+                    .next_element::<Vec<String>>()?
+                    .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+
+                let table = type_paths
+                    .iter()
+                    .map(|type_path| {
+                        self.registry.get_with_type_path(type_path).ok_or_else(|| {
+                            A::Error::custom(format_args!(
+                                "no registration found for type `{type_path}`"
+                            ))
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let expected_hash = schema_hash(&table, self.registry);
+                if hash != expected_hash {
+                    return Err(A::Error::custom(format_args!(
+                        "schema hash mismatch: the data was serialized with a schema hash of {hash:#018x}, \
+                         but the type registry has a schema hash of {expected_hash:#018x}"
+                    )));
+                }
+
+                seq.next_element_seed(ValuesSeed {
+                    table: &table,
+                    registry: self.registry,
+                })?
+                .ok_or_else(|| A::Error::invalid_length(2, &self))
+            }
+        }
+
+        deserializer.deserialize_tuple(
+            3,
+            CompactVisitor {
+                registry: self.registry,
+            },
+        )
+    }
+}
+
+struct ValuesSeed<'a> {
+    table: &'a [&'a TypeRegistration],
+    registry: &'a TypeRegistry,
+}
+
+impl<'de> DeserializeSeed<'de> for ValuesSeed<'_> {
+    type Value = Vec<Box<dyn PartialReflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ValuesSeed<'_> {
+    type Value = Vec<Box<dyn PartialReflect>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("sequence of compact reflected values")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(value) = seq.next_element_seed(ValueSeed {
+            table: self.table,
+            registry: self.registry,
+        })? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+struct ValueSeed<'a> {
+    table: &'a [&'a TypeRegistration],
+    registry: &'a TypeRegistry,
+}
+
+impl<'de> DeserializeSeed<'de> for ValueSeed<'_> {
+    type Value = Box<dyn PartialReflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'de> Visitor<'de> for ValueSeed<'_> {
+    type Value = Box<dyn PartialReflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("compact reflected value")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let index = seq
+            .next_element::<u32>()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let registration = self.table.get(index as usize).ok_or_else(|| {
+            A::Error::custom(format_args!(
+                "type index {index} is out of bounds for a type table of length {}",
+                self.table.len()
+            ))
+        })?;
+        seq.next_element_seed(TypedReflectDeserializer::new(registration, self.registry))?
+            .ok_or_else(|| A::Error::invalid_length(1, &self))
+    }
+}
+
+/// Computes a hash of the layout of the given types, as serialized by [`TypedReflectSerializer`].
+///
+/// This covers each type's kind, along with the names and types of its fields and variants, recursively.
+/// Types that are serialized with serde directly are identified by their type path,
+/// since their layout is controlled by their `Serialize` implementation.
+///
+/// NOTE: changing the hashing logic here is a _breaking change_ that invalidates all existing compact data.
+fn schema_hash(table: &[&TypeRegistration], registry: &TypeRegistry) -> u64 {
+    let mut hasher = SchemaHasher::default();
+    let mut visited = HashMap::default();
+    for registration in table {
+        hasher.hash_type(registration.type_id(), registry, &mut visited);
+    }
+    hasher.finish()
+}
+
+/// A stable [FNV-1a] hasher, so that schema hashes are consistent across platforms and builds.
+///
+/// [FNV-1a]: https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function
+struct SchemaHasher(u64);
+
+impl Default for SchemaHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl SchemaHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_usize(&mut self, value: usize) {
+        self.write(&(value as u64).to_le_bytes());
+    }
+
+    fn write_str(&mut self, value: &str) {
+        self.write_usize(value.len());
+        self.write(value.as_bytes());
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn hash_type(
+        &mut self,
+        type_id: TypeId,
+        registry: &TypeRegistry,
+        visited: &mut HashMap<TypeId, usize>,
+    ) {
+        let Some(registration) = registry.get(type_id) else {
+            self.write_str("<unregistered>");
+            return;
+        };
+        // Types that were already hashed are referred to by the order in which they were visited,
+        // which keeps recursive types finite.
+        if let Some(index) = visited.get(&type_id) {
+            self.write_str("<visited>");
+            self.write_usize(*index);
+            return;
+        }
+        visited.insert(type_id, visited.len());
+
+        // The type paths of types with a structural layout aren't part of the hash,
+        // so that types can be moved (see `TypePathAliases`) without invalidating existing data.
+        if registration.data::<ReflectSerialize>().is_some()
+            || registration.data::<ReflectDeserialize>().is_some()
+            || registration
+                .data::<ReflectSerializeWithRegistry>()
+                .is_some()
+            || registration
+                .data::<ReflectDeserializeWithRegistry>()
+                .is_some()
+        {
+            self.write_str("serde");
+            self.write_str(registration.type_info().type_path());
+            return;
+        }
+
+        match registration.type_info() {
+            TypeInfo::Struct(info) => {
+                self.write_str("struct");
+                let serialization_data = registration.data::<SerializationData>();
+                self.write_usize(info.field_len());
+                for (index, field) in info.iter().enumerate() {
+                    if serialization_data.is_some_and(|data| data.is_field_skipped(index)) {
+                        continue;
+                    }
+                    self.write_str(field.name());
+                    self.hash_type(field.type_id(), registry, visited);
+                }
+            }
+            TypeInfo::TupleStruct(info) => {
+                self.write_str("tuple_struct");
+                let serialization_data = registration.data::<SerializationData>();
+                self.write_usize(info.field_len());
+                for (index, field) in info.iter().enumerate() {
+                    if serialization_data.is_some_and(|data| data.is_field_skipped(index)) {
+                        continue;
+                    }
+                    self.hash_type(field.type_id(), registry, visited);
+                }
+            }
+            TypeInfo::Tuple(info) => {
+                self.write_str("tuple");
+                self.write_usize(info.field_len());
+                for field in info.iter() {
+                    self.hash_type(field.type_id(), registry, visited);
+                }
+            }
+            TypeInfo::List(info) => {
+                self.write_str("list");
+                self.hash_type(info.item_ty().id(), registry, visited);
+            }
+            TypeInfo::Array(info) => {
+                self.write_str("array");
+                self.write_usize(info.capacity());
+                self.hash_type(info.item_ty().id(), registry, visited);
+            }
+            TypeInfo::Map(info) => {
+                self.write_str("map");
+                self.hash_type(info.key_ty().id(), registry, visited);
+                self.hash_type(info.value_ty().id(), registry, visited);
+            }
+            TypeInfo::Set(info) => {
+                self.write_str("set");
+                self.hash_type(info.value_ty().id(), registry, visited);
+            }
+            TypeInfo::Enum(info) => {
+                self.write_str("enum");
+                self.write_usize(info.variant_len());
+                for variant in info.iter() {
+                    self.write_str(variant.name());
+                    match variant {
+                        VariantInfo::Struct(variant) => {
+                            self.write_str("struct");
+                            self.write_usize(variant.field_len());
+                            for field in variant.iter() {
+                                self.write_str(field.name());
+                                self.hash_type(field.type_id(), registry, visited);
+                            }
+                        }
+                        VariantInfo::Tuple(variant) => {
+                            self.write_str("tuple");
+                            self.write_usize(variant.field_len());
+                            for field in variant.iter() {
+                                self.hash_type(field.type_id(), registry, visited);
+                            }
+                        }
+                        VariantInfo::Unit(_) => self.write_str("unit"),
+                    }
+                }
+            }
+            TypeInfo::Opaque(info) => {
+                self.write_str("opaque");
+                self.write_str(info.type_path());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        self as bevy_reflect,
+        serde::{CompactReflectDeserializer, CompactReflectSerializer, ReflectSerializer},
+        FromReflect, PartialReflect, Reflect, TypeRegistry,
+    };
+    use alloc::{
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+    use bincode::Options;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Player {
+        name: String,
+        health: u32,
+        inventory: Vec<Item>,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    enum Item {
+        Sword { damage: u32 },
+        Potion(u8),
+        Key,
+    }
+
+    fn get_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+        registry.register::<Item>();
+        registry
+    }
+
+    fn get_players() -> Vec<Player> {
+        vec![
+            Player {
+                name: String::from("Alice"),
+                health: 100,
+                inventory: vec![Item::Sword { damage: 10 }, Item::Key],
+            },
+            Player {
+                name: String::from("Bob"),
+                health: 50,
+                inventory: vec![Item::Potion(3)],
+            },
+        ]
+    }
+
+    #[test]
+    fn should_round_trip_compact_stream() {
+        let registry = get_registry();
+        let players = get_players();
+        let item = Item::Potion(1);
+
+        let values = players
+            .iter()
+            .map(PartialReflect::as_partial_reflect)
+            .chain([item.as_partial_reflect()]);
+        let serializer = CompactReflectSerializer::new(values, &registry);
+        let bytes = bincode::DefaultOptions::new()
+            .serialize(&serializer)
+            .unwrap();
+
+        let deserializer = CompactReflectDeserializer::new(&registry);
+        let output = bincode::DefaultOptions::new()
+            .deserialize_seed(deserializer, &bytes)
+            .unwrap();
+
+        assert_eq!(3, output.len());
+        assert_eq!(
+            Some(&players[0]),
+            Player::from_reflect(&*output[0]).as_ref()
+        );
+        assert_eq!(
+            Some(&players[1]),
+            Player::from_reflect(&*output[1]).as_ref()
+        );
+        assert_eq!(Some(item), Item::from_reflect(&*output[2]));
+
+        // Type paths are only written once per stream
+        let full_size = players
+            .iter()
+            .map(|player| {
+                bincode::DefaultOptions::new()
+                    .serialize(&ReflectSerializer::new(player, &registry))
+                    .unwrap()
+                    .len()
+            })
+            .sum::<usize>();
+        let serializer = CompactReflectSerializer::new(
+            players.iter().map(PartialReflect::as_partial_reflect),
+            &registry,
+        );
+        let compact_size = bincode::DefaultOptions::new()
+            .serialize(&serializer)
+            .unwrap()
+            .len();
+        assert!(compact_size < full_size);
+    }
+
+    #[test]
+    fn should_reject_mismatched_schema() {
+        let registry = get_registry();
+        let players = get_players();
+        let serializer = CompactReflectSerializer::new(
+            players.iter().map(PartialReflect::as_partial_reflect),
+            &registry,
+        );
+        let bytes = bincode::DefaultOptions::new()
+            .serialize(&serializer)
+            .unwrap();
+
+        // A registry where `Player` has a different layout
+        mod other {
+            use crate::{self as bevy_reflect, Reflect};
+
+            #[derive(Reflect)]
+            #[reflect(type_path_alias = "bevy_reflect::serde::compact::tests::Player")]
+            pub(super) struct Player {
+                pub name: alloc::string::String,
+                pub health: u64,
+            }
+        }
+        let mut other_registry = TypeRegistry::default();
+        other_registry.register::<other::Player>();
+        other_registry.register::<Item>();
+
+        let deserializer = CompactReflectDeserializer::new(&other_registry);
+        let error = bincode::DefaultOptions::new()
+            .deserialize_seed(deserializer, &bytes)
+            .unwrap_err();
+        assert!(error.to_string().contains("schema hash mismatch"));
+    }
+}
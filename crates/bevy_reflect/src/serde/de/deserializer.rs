@@ -406,7 +406,10 @@ impl<'de, P: ReflectDeserializerProcessor> DeserializeSeed<'de>
                 }
                 TypeInfo::TupleStruct(tuple_struct_info) => {
                     let mut dynamic_tuple_struct = if tuple_struct_info.field_len() == 1
-                        && self.registration.data::<SerializationData>().is_none()
+                        && self
+                            .registration
+                            .data::<SerializationData>()
+                            .map_or(true, SerializationData::is_empty)
                     {
                         deserializer.deserialize_newtype_struct(
                             tuple_struct_info.type_path_table().ident().unwrap(),
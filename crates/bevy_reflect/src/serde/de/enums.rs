@@ -7,7 +7,7 @@ use crate::{
             struct_utils::{visit_struct, visit_struct_seq},
             tuple_utils::{visit_tuple, TupleLikeInfo},
         },
-        TypedReflectDeserializer,
+        ReflectUpgrade, TypedReflectDeserializer,
     },
     DynamicEnum, DynamicStruct, DynamicTuple, DynamicVariant, EnumInfo, StructVariantInfo,
     TupleVariantInfo, TypeRegistration, TypeRegistry, VariantInfo,
@@ -152,6 +152,15 @@ struct StructVariantVisitor<'a, P> {
     processor: Option<&'a mut P>,
 }
 
+impl<'a, P> StructVariantVisitor<'a, P> {
+    /// Returns the [`ReflectUpgrade`] registered for this variant on the enum, if any.
+    fn upgrade(&self) -> Option<&'a ReflectUpgrade> {
+        self.registration
+            .data::<ReflectUpgrade>()
+            .and_then(|upgrade| upgrade.variant(self.struct_info.name()))
+    }
+}
+
 impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for StructVariantVisitor<'_, P> {
     type Value = DynamicStruct;
 
@@ -168,6 +177,7 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for StructVariantVisitor
             self.struct_info,
             self.registration,
             self.registry,
+            self.upgrade(),
             self.processor,
         )
     }
@@ -181,6 +191,7 @@ impl<'de, P: ReflectDeserializerProcessor> Visitor<'de> for StructVariantVisitor
             self.struct_info,
             self.registration,
             self.registry,
+            self.upgrade(),
             self.processor,
         )
     }
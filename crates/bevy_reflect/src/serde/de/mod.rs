@@ -26,6 +26,7 @@ mod tuples;
 mod tests {
     use alloc::{
         boxed::Box,
+        format,
         string::{String, ToString},
         vec,
         vec::Vec,
@@ -40,11 +41,11 @@ mod tests {
     use crate::{
         self as bevy_reflect,
         serde::{
-            ReflectDeserializer, ReflectDeserializerProcessor, ReflectSerializer,
+            ReflectDeserializer, ReflectDeserializerProcessor, ReflectSerializer, ReflectUpgrade,
             TypedReflectDeserializer,
         },
-        DynamicEnum, FromReflect, PartialReflect, Reflect, ReflectDeserialize, TypeRegistration,
-        TypeRegistry,
+        DynamicEnum, DynamicStruct, FromReflect, GetField, PartialReflect, Reflect,
+        ReflectDeserialize, TypeRegistration, TypeRegistry,
     };
 
     #[derive(Reflect, Debug, PartialEq)]
@@ -298,6 +299,149 @@ mod tests {
         assert_eq!(expected, output);
     }
 
+    #[test]
+    fn should_deserialize_evolved_struct() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(type_path_alias = "old_module::OldFoo", removed_fields("legacy"))]
+        struct Foo {
+            #[reflect(alias = "old_bar", alias = "older_bar")]
+            bar: i32,
+            #[reflect(default = "default_baz")]
+            baz: String,
+        }
+
+        fn default_baz() -> String {
+            String::from("baz")
+        }
+
+        let expected = Foo {
+            bar: 123,
+            baz: String::from("baz"),
+        };
+
+        let input = r#"{
+            "old_module::OldFoo": (
+                older_bar: 123,
+                legacy: (a: 1, b: [1, 2, 3]),
+            ),
+        }"#;
+
+        let mut registry = get_registry();
+        registry.register::<Foo>();
+        let reflect_deserializer = ReflectDeserializer::new(&registry);
+        let mut ron_deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let dynamic_output = reflect_deserializer
+            .deserialize(&mut ron_deserializer)
+            .unwrap();
+
+        // Missing defaulted fields are filled in, so the value is complete even without `FromReflect`
+        let mut output = Foo {
+            bar: 0,
+            baz: String::new(),
+        };
+        output.apply(dynamic_output.as_partial_reflect());
+        assert_eq!(expected, output);
+
+        let input = r#"(bar: 123, unknown: 1)"#;
+        let registration = registry.get(TypeId::of::<Foo>()).unwrap();
+        let reflect_deserializer = TypedReflectDeserializer::new(registration, &registry);
+        let mut ron_deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        assert!(reflect_deserializer
+            .deserialize(&mut ron_deserializer)
+            .is_err());
+    }
+
+    #[test]
+    fn should_upgrade_removed_fields() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Foo {
+            full_name: String,
+        }
+
+        fn upgrade_foo(value: &mut DynamicStruct, removed: &DynamicStruct) {
+            if let (Some(first), Some(last)) = (
+                removed.get_field::<String>("first_name"),
+                removed.get_field::<String>("last_name"),
+            ) {
+                value.insert("full_name", format!("{first} {last}"));
+            }
+        }
+
+        let mut registry = get_registry();
+        registry.register::<Foo>();
+        registry.get_mut(TypeId::of::<Foo>()).unwrap().insert(
+            ReflectUpgrade::new(upgrade_foo)
+                .with_removed_field::<String>("first_name")
+                .with_removed_field::<String>("last_name"),
+        );
+        let registration = registry.get(TypeId::of::<Foo>()).unwrap();
+
+        for input in [
+            r#"(first_name: "John", last_name: "Doe")"#,
+            r#"(full_name: "John Doe")"#,
+        ] {
+            let reflect_deserializer = TypedReflectDeserializer::new(registration, &registry);
+            let mut ron_deserializer = ron::de::Deserializer::from_str(input).unwrap();
+            let dynamic_output = reflect_deserializer
+                .deserialize(&mut ron_deserializer)
+                .unwrap();
+
+            let output =
+                <Foo as FromReflect>::from_reflect(dynamic_output.as_partial_reflect()).unwrap();
+            assert_eq!(
+                Foo {
+                    full_name: String::from("John Doe")
+                },
+                output
+            );
+        }
+    }
+
+    #[test]
+    fn should_upgrade_removed_fields_of_struct_variants() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum Shape {
+            Circle { diameter: f32 },
+            Square { side: f32 },
+        }
+
+        fn upgrade_circle(value: &mut DynamicStruct, removed: &DynamicStruct) {
+            if let Some(radius) = removed.get_field::<f32>("radius") {
+                value.insert("diameter", radius * 2.0);
+            }
+        }
+
+        let mut registry = get_registry();
+        registry.register::<Shape>();
+        registry.get_mut(TypeId::of::<Shape>()).unwrap().insert(
+            ReflectUpgrade::default().with_variant(
+                "Circle",
+                ReflectUpgrade::new(upgrade_circle).with_removed_field::<f32>("radius"),
+            ),
+        );
+        let registration = registry.get(TypeId::of::<Shape>()).unwrap();
+
+        for input in [r#"Circle(radius: 1.5)"#, r#"Circle(diameter: 3.0)"#] {
+            let reflect_deserializer = TypedReflectDeserializer::new(registration, &registry);
+            let mut ron_deserializer = ron::de::Deserializer::from_str(input).unwrap();
+            let dynamic_output = reflect_deserializer
+                .deserialize(&mut ron_deserializer)
+                .unwrap();
+
+            let output =
+                <Shape as FromReflect>::from_reflect(dynamic_output.as_partial_reflect()).unwrap();
+            assert_eq!(Shape::Circle { diameter: 3.0 }, output);
+        }
+
+        // Removed fields are only recognized on the variant they were registered for.
+        let reflect_deserializer = TypedReflectDeserializer::new(registration, &registry);
+        let mut ron_deserializer =
+            ron::de::Deserializer::from_str(r#"Square(radius: 1.5)"#).unwrap();
+        assert!(reflect_deserializer
+            .deserialize(&mut ron_deserializer)
+            .is_err());
+    }
+
     #[test]
     fn should_deserialize_option() {
         #[derive(Reflect, Debug, PartialEq)]
@@ -5,13 +5,14 @@ use crate::{
             helpers::{ExpectedValues, Ident},
             registration_utils::try_get_registration,
         },
-        SerializationData, TypedReflectDeserializer,
+        ReflectUpgrade, SerializationData, TypedReflectDeserializer,
     },
-    DynamicStruct, NamedField, StructInfo, StructVariantInfo, TypeRegistration, TypeRegistry,
+    DynamicStruct, NamedField, Struct, StructInfo, StructVariantInfo, TypeRegistration,
+    TypeRegistry,
 };
 use alloc::string::ToString;
 use core::slice::Iter;
-use serde::de::{Error, MapAccess, SeqAccess};
+use serde::de::{Error, IgnoredAny, MapAccess, SeqAccess};
 
 use super::ReflectDeserializerProcessor;
 
@@ -85,12 +86,18 @@ impl StructLikeInfo for StructVariantInfo {
 
 /// Deserializes a [struct-like] type from a mapping of fields, returning a [`DynamicStruct`].
 ///
+/// Field names are resolved using the type's [`SerializationData`], if any,
+/// which allows aliased field names and removed fields to appear in the data,
+/// and provides defaults for missing fields.
+/// If an `upgrade` is given, it is run on the result once all fields have been deserialized.
+///
 /// [struct-like]: StructLikeInfo
 pub(super) fn visit_struct<'de, T, V, P>(
     map: &mut V,
     info: &'static T,
     registration: &TypeRegistration,
     registry: &TypeRegistry,
+    upgrade: Option<&ReflectUpgrade>,
     mut processor: Option<&mut P>,
 ) -> Result<DynamicStruct, V::Error>
 where
@@ -98,26 +105,46 @@ where
     V: MapAccess<'de>,
     P: ReflectDeserializerProcessor,
 {
+    let serialization_data = registration.data::<SerializationData>();
     let mut dynamic_struct = DynamicStruct::default();
+    let mut removed_fields = DynamicStruct::default();
     while let Some(Ident(key)) = map.next_key::<Ident>()? {
-        let field = info.field::<V::Error>(&key).map_err(|_| {
-            let fields = info.iter_fields().map(NamedField::name);
-            make_custom_error(format_args!(
-                "unknown field `{}`, expected one of {:?}",
-                key,
-                ExpectedValues::from_iter(fields)
-            ))
-        })?;
+        let field = match info.field::<V::Error>(&key) {
+            Ok(field) => field,
+            Err(_) => {
+                if let Some(index) = serialization_data.and_then(|data| data.field_alias(&key)) {
+                    info.field_at::<V::Error>(index)?
+                } else if let Some(ty) = upgrade.and_then(|upgrade| upgrade.removed_field(&key)) {
+                    let value = map.next_value_seed(TypedReflectDeserializer::new_internal(
+                        try_get_registration(*ty, registry)?,
+                        registry,
+                        processor.as_deref_mut(),
+                    ))?;
+                    removed_fields.insert_boxed(&key, value);
+                    continue;
+                } else if serialization_data.is_some_and(|data| data.is_field_removed(&key)) {
+                    map.next_value::<IgnoredAny>()?;
+                    continue;
+                } else {
+                    let fields = info.iter_fields().map(NamedField::name);
+                    return Err(make_custom_error(format_args!(
+                        "unknown field `{}`, expected one of {:?}",
+                        key,
+                        ExpectedValues::from_iter(fields)
+                    )));
+                }
+            }
+        };
         let registration = try_get_registration(*field.ty(), registry)?;
         let value = map.next_value_seed(TypedReflectDeserializer::new_internal(
             registration,
             registry,
             processor.as_deref_mut(),
         ))?;
-        dynamic_struct.insert_boxed(&key, value);
+        dynamic_struct.insert_boxed(field.name(), value);
     }
 
-    if let Some(serialization_data) = registration.data::<SerializationData>() {
+    if let Some(serialization_data) = serialization_data {
         for (skipped_index, skipped_field) in serialization_data.iter_skipped() {
             let Ok(field) = info.field_at::<V::Error>(*skipped_index) else {
                 continue;
@@ -127,6 +154,22 @@ where
                 skipped_field.generate_default().into_partial_reflect(),
             );
         }
+
+        for (defaulted_index, defaulted_field) in serialization_data.iter_defaulted() {
+            let Ok(field) = info.field_at::<V::Error>(*defaulted_index) else {
+                continue;
+            };
+            if dynamic_struct.field(field.name()).is_none() {
+                dynamic_struct.insert_boxed(
+                    field.name(),
+                    defaulted_field.generate_default().into_partial_reflect(),
+                );
+            }
+        }
+    }
+
+    if let Some(upgrade) = upgrade {
+        upgrade.upgrade(&mut dynamic_struct, &removed_fields);
     }
 
     Ok(dynamic_struct)
@@ -134,12 +177,16 @@ where
 
 /// Deserializes a [struct-like] type from a sequence of fields, returning a [`DynamicStruct`].
 ///
+/// Since sequences don't contain field names, aliases and removed fields don't apply here.
+/// If an `upgrade` is given, it is run on the result without any removed fields.
+///
 /// [struct-like]: StructLikeInfo
 pub(super) fn visit_struct_seq<'de, T, V, P>(
     seq: &mut V,
     info: &T,
     registration: &TypeRegistration,
     registry: &TypeRegistry,
+    upgrade: Option<&ReflectUpgrade>,
     mut processor: Option<&mut P>,
 ) -> Result<DynamicStruct, V::Error>
 where
@@ -181,5 +228,9 @@ where
         dynamic_struct.insert_boxed(name, value);
     }
 
+    if let Some(upgrade) = upgrade {
+        upgrade.upgrade(&mut dynamic_struct, &DynamicStruct::default());
+    }
+
     Ok(dynamic_struct)
 }
@@ -1,7 +1,9 @@
+mod compact;
 mod de;
 mod ser;
 mod type_data;
 
+pub use compact::*;
 pub use de::*;
 pub use ser::*;
 pub use type_data::*;
@@ -84,6 +86,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_serialize_defaulted_newtype_struct_as_newtype() {
+        #[derive(Debug, Reflect, PartialEq)]
+        struct TestStruct(#[reflect(default)] i32);
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<TestStruct>();
+
+        let serializer = TypedReflectSerializer::new(&TestStruct(3), &registry);
+        let json = serde_json::to_string(&serializer).unwrap();
+        assert_eq!("3", json);
+
+        let registration = registry.get(core::any::TypeId::of::<TestStruct>()).unwrap();
+        let deserializer = TypedReflectDeserializer::new(registration, &registry);
+        let deserialized = deserializer
+            .deserialize(&mut serde_json::Deserializer::from_str(&json))
+            .unwrap();
+        assert_eq!(
+            Some(TestStruct(3)),
+            TestStruct::from_reflect(deserialized.as_partial_reflect())
+        );
+    }
+
     #[test]
     fn test_serialization_tuple_struct() {
         #[derive(Debug, Reflect, PartialEq)]
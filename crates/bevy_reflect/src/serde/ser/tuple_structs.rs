@@ -43,7 +43,9 @@ impl<P: ReflectSerializerProcessor> Serialize for TupleStructSerializer<'_, P> {
             .and_then(|registration| registration.data::<SerializationData>());
         let ignored_len = serialization_data.map(SerializationData::len).unwrap_or(0);
 
-        if self.tuple_struct.field_len() == 1 && serialization_data.is_none() {
+        if self.tuple_struct.field_len() == 1
+            && serialization_data.map_or(true, SerializationData::is_empty)
+        {
             let field = self.tuple_struct.field(0).unwrap();
             return serializer.serialize_newtype_struct(
                 tuple_struct_info.type_path_table().ident().unwrap(),
@@ -1,11 +1,52 @@
-use crate::Reflect;
-use alloc::boxed::Box;
-use bevy_platform_support::collections::{hash_map::Iter, HashMap};
+use crate::{DynamicStruct, Reflect, Type, TypePath};
+use alloc::{boxed::Box, vec::Vec};
+use bevy_platform_support::collections::{hash_map::Iter, HashMap, HashSet};
 
 /// Contains data relevant to the automatic reflect powered (de)serialization of a type.
+///
+/// Besides skipped fields, this also describes how a struct's schema has evolved over time,
+/// so that data serialized by older versions of the type can still be deserialized:
+///
+/// * Field aliases (`#[reflect(alias = "old_name")]`) are accepted in place of a field's current name.
+/// * Removed fields (`#[reflect(removed_fields("old_field"))]`) are silently discarded.
+/// * Fields marked `#[reflect(default)]` are filled with their default value when missing.
+///
+/// These only apply when deserializing a struct from a map of fields,
+/// as sequence-based formats don't contain field names.
+///
+/// # Example
+///
+/// ```
+/// # use core::any::TypeId;
+/// # use bevy_reflect::{FromReflect, Reflect, TypeRegistry, serde::TypedReflectDeserializer};
+/// # use serde::de::DeserializeSeed;
+/// #[derive(Reflect, Debug, PartialEq)]
+/// #[reflect(removed_fields("legacy_flag"))]
+/// struct Player {
+///   #[reflect(alias = "hp")]
+///   health: u32,
+///   #[reflect(default)]
+///   mana: u32,
+/// }
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Player>();
+///
+/// let registration = registry.get(TypeId::of::<Player>()).unwrap();
+/// let mut deserializer = ron::Deserializer::from_str("(hp: 10, legacy_flag: true)").unwrap();
+/// let value = TypedReflectDeserializer::new(registration, &registry)
+///   .deserialize(&mut deserializer)
+///   .unwrap();
+///
+/// let player = Player::from_reflect(value.as_partial_reflect());
+/// assert_eq!(player, Some(Player { health: 10, mana: 0 }));
+/// ```
 #[derive(Debug, Clone)]
 pub struct SerializationData {
     skipped_fields: HashMap<usize, SkippedField>,
+    defaulted_fields: HashMap<usize, SkippedField>,
+    field_aliases: HashMap<&'static str, usize>,
+    removed_fields: HashSet<&'static str>,
 }
 
 impl SerializationData {
@@ -20,8 +61,72 @@ impl SerializationData {
     pub fn new<I: Iterator<Item = (usize, SkippedField)>>(skipped_iter: I) -> Self {
         Self {
             skipped_fields: skipped_iter.collect(),
+            defaulted_fields: HashMap::default(),
+            field_aliases: HashMap::default(),
+            removed_fields: HashSet::default(),
         }
     }
+
+    /// Registers a function that generates the value of the field at the given index
+    /// when it is missing from the serialized data.
+    ///
+    /// This is used for fields marked `#[reflect(default)]`, which allows fields to be added
+    /// to a type without breaking previously serialized data.
+    pub fn with_field_default(
+        mut self,
+        index: usize,
+        default_fn: fn() -> Box<dyn Reflect>,
+    ) -> Self {
+        self.defaulted_fields
+            .insert(index, SkippedField::new(default_fn));
+        self
+    }
+
+    /// Registers `alias` as a former name of the field at the given index.
+    ///
+    /// When deserializing, a field named `alias` will be treated as the field at `index`.
+    pub fn with_field_alias(mut self, alias: &'static str, index: usize) -> Self {
+        self.field_aliases.insert(alias, index);
+        self
+    }
+
+    /// Registers `name` as a field that has been removed from the type.
+    ///
+    /// When deserializing, a field named `name` will be ignored rather than causing an error.
+    pub fn with_removed_field(mut self, name: &'static str) -> Self {
+        self.removed_fields.insert(name);
+        self
+    }
+
+    /// Returns the index of the field that has the given alias, if any.
+    pub fn field_alias(&self, alias: &str) -> Option<usize> {
+        self.field_aliases.get(alias).copied()
+    }
+
+    /// Returns true if a field with the given name has been removed from the type.
+    pub fn is_field_removed(&self, name: &str) -> bool {
+        self.removed_fields.contains(name)
+    }
+
+    /// Generates the default value for the field at the given index,
+    /// to be used when the field is missing from the serialized data.
+    ///
+    /// Returns `None` if the field has no registered default.
+    pub fn generate_missing_default(&self, index: usize) -> Option<Box<dyn Reflect>> {
+        self.defaulted_fields
+            .get(&index)
+            .map(SkippedField::generate_default)
+    }
+
+    /// Returns an iterator over the fields that have a default value for when they are missing
+    /// from the serialized data.
+    ///
+    /// Each item in the iterator is a tuple containing:
+    /// 1. The reflected index of the field
+    /// 2. The (de)serialization metadata of the field
+    pub fn iter_defaulted(&self) -> Iter<'_, usize, SkippedField> {
+        self.defaulted_fields.iter()
+    }
     /// Returns true if the given index corresponds to a field meant to be skipped during (de)serialization.
     ///
     /// # Example
@@ -134,3 +239,116 @@ impl SkippedField {
         (self.default_fn)()
     }
 }
+
+/// Type data containing a function that upgrades data serialized by an older version of a struct.
+///
+/// When deserializing a struct with this type data, the upgrade function is called with the
+/// deserialized [`DynamicStruct`] and a second [`DynamicStruct`] containing the values of any
+/// [removed fields](Self::with_removed_field) that were found in the data.
+/// The function can then modify the deserialized value, for example to convert an old field into a new one.
+///
+/// The upgrade function runs every time the struct is deserialized, so it should leave up-to-date data untouched.
+///
+/// For enums, upgrades of struct variants are registered [per variant](Self::with_variant)
+/// on the [`ReflectUpgrade`] of the enum.
+///
+/// Unlike the names given to `#[reflect(removed_fields(...))]`, which are simply discarded,
+/// the values of removed fields registered here are deserialized as the given type.
+/// Since the upgrade function receives these values by field name,
+/// removed fields can only be recovered from formats that serialize structs as maps.
+///
+/// # Example
+///
+/// ```
+/// # use core::any::TypeId;
+/// # use bevy_reflect::{FromReflect, GetField, Reflect, TypeRegistry, serde::{ReflectUpgrade, TypedReflectDeserializer}};
+/// # use serde::de::DeserializeSeed;
+/// #[derive(Reflect, Debug, PartialEq)]
+/// struct Timer {
+///   // Used to be `seconds: f32`
+///   millis: u32,
+/// }
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Timer>();
+/// registry.register::<f32>();
+/// registry.get_mut(TypeId::of::<Timer>()).unwrap().insert(
+///   ReflectUpgrade::new(|value, removed| {
+///     if let Some(seconds) = removed.get_field::<f32>("seconds") {
+///       value.insert("millis", (seconds * 1000.0) as u32);
+///     }
+///   })
+///   .with_removed_field::<f32>("seconds"),
+/// );
+///
+/// let registration = registry.get(TypeId::of::<Timer>()).unwrap();
+/// let mut deserializer = ron::Deserializer::from_str("(seconds: 1.5)").unwrap();
+/// let value = TypedReflectDeserializer::new(registration, &registry)
+///   .deserialize(&mut deserializer)
+///   .unwrap();
+///
+/// assert_eq!(Timer::from_reflect(value.as_partial_reflect()), Some(Timer { millis: 1500 }));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ReflectUpgrade {
+    removed_fields: Vec<(&'static str, Type)>,
+    upgrade_fn: fn(&mut DynamicStruct, &DynamicStruct),
+    variants: Vec<(&'static str, ReflectUpgrade)>,
+}
+
+impl Default for ReflectUpgrade {
+    /// Creates a [`ReflectUpgrade`] that leaves the value untouched,
+    /// to which [variant upgrades](Self::with_variant) can be added.
+    fn default() -> Self {
+        Self::new(|_, _| {})
+    }
+}
+
+impl ReflectUpgrade {
+    /// Creates a new [`ReflectUpgrade`] with the given upgrade function.
+    ///
+    /// The function receives the deserialized value and the values of any removed fields found in the data.
+    pub fn new(upgrade_fn: fn(&mut DynamicStruct, &DynamicStruct)) -> Self {
+        Self {
+            removed_fields: Vec::new(),
+            upgrade_fn,
+            variants: Vec::new(),
+        }
+    }
+
+    /// Registers a field that has been removed from the struct, whose value should be deserialized as `T`
+    /// and passed to the upgrade function.
+    ///
+    /// `T` must be registered in the [`TypeRegistry`](crate::TypeRegistry) used for deserialization.
+    pub fn with_removed_field<T: TypePath>(mut self, name: &'static str) -> Self {
+        self.removed_fields.push((name, Type::of::<T>()));
+        self
+    }
+
+    /// Returns the [`Type`] of the removed field with the given name, if any.
+    pub fn removed_field(&self, name: &str) -> Option<&Type> {
+        self.removed_fields
+            .iter()
+            .find(|(field_name, _)| *field_name == name)
+            .map(|(_, ty)| ty)
+    }
+
+    /// Runs the upgrade function on `value`, given the values of the `removed` fields found in the data.
+    pub fn upgrade(&self, value: &mut DynamicStruct, removed: &DynamicStruct) {
+        (self.upgrade_fn)(value, removed);
+    }
+
+    /// Registers the `upgrade` of the struct variant named `variant`, used when deserializing that variant of an enum.
+    pub fn with_variant(mut self, variant: &'static str, upgrade: ReflectUpgrade) -> Self {
+        self.variants.push((variant, upgrade));
+        self
+    }
+
+    /// Returns the upgrade of the struct variant with the given name, if any.
+    pub fn variant(&self, variant: &str) -> Option<&ReflectUpgrade> {
+        self.variants
+            .iter()
+            .find(|(variant_name, _)| *variant_name == variant)
+            .map(|(_, upgrade)| upgrade)
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{serde::Serializable, FromReflect, Reflect, TypeInfo, TypePath, Typed};
-use alloc::{boxed::Box, string::String};
+use alloc::{boxed::Box, string::String, vec::Vec};
 use bevy_platform_support::{
     collections::{HashMap, HashSet},
     sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard},
@@ -247,6 +247,31 @@ impl TypeRegistry {
             short_path_to_id.insert(short_name, registration.type_id());
         }
         type_path_to_id.insert(registration.type_info().type_path(), registration.type_id());
+        if let Some(aliases) = registration.data::<TypePathAliases>() {
+            for alias in aliases.iter() {
+                // Never shadow the actual type path of another registered type.
+                type_path_to_id
+                    .entry(alias)
+                    .or_insert_with(|| registration.type_id());
+            }
+        }
+    }
+
+    /// Registers `alias` as an additional [type path] for the type with the given [`TypeId`].
+    ///
+    /// Lookups by type path (such as [`get_with_type_path`](Self::get_with_type_path)) will then
+    /// resolve `alias` to the given type.
+    /// This allows data serialized with the type's old path to be deserialized after the type has been
+    /// moved or renamed.
+    ///
+    /// Aliases can also be declared on the type itself with `#[reflect(type_path_alias = "...")]`,
+    /// which registers [`TypePathAliases`] type data.
+    ///
+    /// Note that this will overwrite any existing mapping for `alias`.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn register_type_path_alias(&mut self, alias: &'static str, type_id: TypeId) {
+        self.type_path_to_id.insert(alias, type_id);
     }
 
     /// Registers the type data `D` for type `T`.
@@ -276,6 +301,64 @@ impl TypeRegistry {
         data.insert(D::from_type());
     }
 
+    /// Registers the given method for type `T` under the given name,
+    /// inserting [`ReflectMethods`] type data for `T` if it doesn't have any yet.
+    ///
+    /// Most of the time the `#[reflect(methods(...))]` derive attribute can be used instead.
+    /// This method is useful for registering methods of types you don't own,
+    /// or generic methods that need to be manually monomorphized.
+    ///
+    /// See [`ReflectMethods::register`] for the requirements on the method's signature.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` has not been registered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_reflect::{func::{ArgList, ReflectMethods}, TypeRegistry};
+    /// let mut type_registry = TypeRegistry::default();
+    /// type_registry.register::<Vec<i32>>();
+    /// type_registry
+    ///     .register_method::<Vec<i32>, _, _>("push", Vec::<i32>::push)
+    ///     .unwrap();
+    ///
+    /// let mut list = vec![1, 2];
+    /// let methods = type_registry
+    ///     .get_type_data::<ReflectMethods>(core::any::TypeId::of::<Vec<i32>>())
+    ///     .unwrap();
+    /// methods.call("push", &mut list, ArgList::new().with_owned(3_i32)).unwrap();
+    /// assert_eq!(list, vec![1, 2, 3]);
+    /// ```
+    ///
+    /// [`ReflectMethods`]: crate::func::ReflectMethods
+    /// [`ReflectMethods::register`]: crate::func::ReflectMethods::register
+    #[cfg(feature = "functions")]
+    pub fn register_method<T, F, Marker>(
+        &mut self,
+        name: impl Into<alloc::borrow::Cow<'static, str>>,
+        method: F,
+    ) -> Result<(), crate::func::MethodRegistrationError>
+    where
+        T: FromReflect + TypePath,
+        F: crate::func::IntoFunction<'static, Marker> + 'static,
+    {
+        let registration = self.get_mut(TypeId::of::<T>()).unwrap_or_else(|| {
+            panic!(
+                "attempted to call `TypeRegistry::register_method` for type `{T}` without registering `{T}` first",
+                T = T::type_path(),
+            )
+        });
+        if !registration.contains::<crate::func::ReflectMethods>() {
+            registration.insert(crate::func::ReflectMethods::new::<T>());
+        }
+        registration
+            .data_mut::<crate::func::ReflectMethods>()
+            .unwrap()
+            .register(name, method)?;
+        Ok(())
+    }
+
     pub fn contains(&self, type_id: TypeId) -> bool {
         self.registrations.contains_key(&type_id)
     }
@@ -852,6 +935,50 @@ impl<T: Reflect> FromType<T> for ReflectFromPtr {
     }
 }
 
+/// [`TypeData`] containing former [type paths] of a type.
+///
+/// When a type with this type data is registered, each alias is registered with the [`TypeRegistry`]
+/// so that lookups by type path resolve it to the type.
+/// This allows data serialized with a type's old path (e.g. scenes) to keep loading after the type has been
+/// moved to another module or renamed.
+///
+/// This type data is registered automatically by the `#[reflect(type_path_alias = "...")]` attribute.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, TypeRegistry};
+/// #[derive(Reflect)]
+/// #[reflect(type_path_alias = "my_game::old_module::Health")]
+/// struct Health(u32);
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Health>();
+///
+/// let registration = registry.get_with_type_path("my_game::old_module::Health").unwrap();
+/// assert_eq!(registration.type_id(), core::any::TypeId::of::<Health>());
+/// ```
+///
+/// [type paths]: TypePath::type_path
+#[derive(Debug, Clone, Default)]
+pub struct TypePathAliases {
+    aliases: Vec<&'static str>,
+}
+
+impl TypePathAliases {
+    /// Creates a new [`TypePathAliases`] with the given aliases.
+    pub fn new(aliases: impl IntoIterator<Item = &'static str>) -> Self {
+        Self {
+            aliases: aliases.into_iter().collect(),
+        }
+    }
+
+    /// Returns an iterator over the aliases.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &'static str> + '_ {
+        self.aliases.iter().copied()
+    }
+}
+
 #[cfg(test)]
 #[expect(
     unsafe_code,
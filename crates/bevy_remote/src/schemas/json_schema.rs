@@ -0,0 +1,363 @@
+//! Generates standard [JSON Schema] documents from a [`TypeRegistry`].
+//!
+//! [JSON Schema]: https://json-schema.org/specification
+
+use super::{
+    collect_types, default_value, field_docs, is_field_optional, is_field_skipped,
+    is_newtype_struct, is_serialized_with_serde, option_inner, type_docs, variant_docs, Primitive,
+};
+use bevy_reflect::{Type, TypeInfo, TypeRegistration, TypeRegistry, UnnamedField, VariantInfo};
+use serde_json::{json, Map, Value};
+
+/// The URI of the JSON Schema dialect used by the generated documents.
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Generates a JSON Schema document describing the JSON representation of the given types,
+/// as produced by Bevy's reflection-based serializers.
+///
+/// The document contains a definition in `$defs` for each type (keyed by its [type path]),
+/// along with every registered type they reference.
+/// Definitions refer to each other through `$ref`, and include the type's doc comment
+/// (when the `documentation` feature is enabled) and default value (when it registers `ReflectDefault`).
+///
+/// Types that implement their own serialization (i.e. register `ReflectSerialize`) are described
+/// by the shape of their serialized default value if they have one, and by their reflected structure otherwise.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, TypeRegistry, std_traits::ReflectDefault};
+/// # use bevy_remote::schemas::json_schema::export_json_schema;
+/// #[derive(Reflect, Default)]
+/// #[reflect(Default)]
+/// struct Health {
+///     current: u32,
+///     max: u32,
+/// }
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Health>();
+///
+/// let registration = registry.get(core::any::TypeId::of::<Health>()).unwrap();
+/// let schema = export_json_schema(&registry, [registration]);
+///
+/// let health = &schema["$defs"][registration.type_info().type_path()];
+/// assert_eq!(health["type"], "object");
+/// assert_eq!(health["properties"]["max"]["$ref"], "#/$defs/u32");
+/// assert_eq!(health["default"], serde_json::json!({ "current": 0, "max": 0 }));
+/// ```
+///
+/// [type path]: bevy_reflect::TypePath::type_path
+pub fn export_json_schema<'a>(
+    registry: &'a TypeRegistry,
+    types: impl IntoIterator<Item = &'a TypeRegistration>,
+) -> Value {
+    let definitions = collect_types(registry, types)
+        .into_iter()
+        .map(|registration| {
+            (
+                registration.type_info().type_path().to_owned(),
+                type_schema(registration, registry),
+            )
+        })
+        .collect::<Map<_, _>>();
+
+    json!({
+        "$schema": JSON_SCHEMA_DIALECT,
+        "$defs": definitions,
+    })
+}
+
+/// Returns the URI reference of the definition of the type with the given type path.
+pub fn definition_ref(type_path: &str) -> String {
+    let mut reference = String::from("#/$defs/");
+    for char in type_path.chars() {
+        match char {
+            // JSON Pointer escapes
+            '~' => reference.push_str("~0"),
+            '/' => reference.push_str("~1"),
+            // Characters that aren't allowed in a URI fragment
+            ' ' | '"' | '%' | '<' | '>' | '[' | ']' | '{' | '}' | '|' | '\\' | '^' | '`' => {
+                let mut buffer = [0; 4];
+                for byte in char.encode_utf8(&mut buffer).bytes() {
+                    reference.push_str(&format!("%{byte:02X}"));
+                }
+            }
+            char => reference.push(char),
+        }
+    }
+    reference
+}
+
+fn reference(ty: &Type) -> Value {
+    json!({ "$ref": definition_ref(ty.path()) })
+}
+
+fn type_schema(registration: &TypeRegistration, registry: &TypeRegistry) -> Value {
+    let info = registration.type_info();
+    let default = default_value(registration, registry);
+
+    let mut schema = match (&default, Primitive::from_type_path(info.type_path())) {
+        (_, Some(primitive)) => primitive_schema(primitive),
+        (Some(default), None) if is_serialized_with_serde(registration) => value_schema(default),
+        _ => structural_schema(registration),
+    };
+
+    let schema_map = schema.as_object_mut().expect("schemas are objects");
+    schema_map.insert(
+        "title".to_owned(),
+        info.type_path_table().short_path().into(),
+    );
+    if let Some(docs) = type_docs(info) {
+        schema_map.insert("description".to_owned(), docs.into());
+    }
+    if let Some(default) = default {
+        schema_map.insert("default".to_owned(), default);
+    }
+    schema
+}
+
+fn primitive_schema(primitive: Primitive) -> Value {
+    match primitive {
+        Primitive::Bool => json!({ "type": "boolean" }),
+        Primitive::Unsigned => json!({ "type": "integer", "minimum": 0 }),
+        Primitive::Signed => json!({ "type": "integer" }),
+        Primitive::Float => json!({ "type": "number" }),
+        Primitive::Char => json!({ "type": "string", "minLength": 1, "maxLength": 1 }),
+        Primitive::String => json!({ "type": "string" }),
+    }
+}
+
+/// Infers a schema from the shape of a serialized value.
+fn value_schema(value: &Value) -> Value {
+    match value {
+        Value::Null => json!({ "type": "null" }),
+        Value::Bool(_) => json!({ "type": "boolean" }),
+        Value::Number(number) if number.is_f64() => json!({ "type": "number" }),
+        Value::Number(_) => json!({ "type": "integer" }),
+        Value::String(_) => json!({ "type": "string" }),
+        Value::Array(items) => json!({
+            "type": "array",
+            "prefixItems": items.iter().map(value_schema).collect::<Vec<_>>(),
+            "items": false,
+            "minItems": items.len(),
+        }),
+        Value::Object(properties) => json!({
+            "type": "object",
+            "properties": properties
+                .iter()
+                .map(|(name, value)| (name.clone(), value_schema(value)))
+                .collect::<Map<_, _>>(),
+            "additionalProperties": false,
+        }),
+    }
+}
+
+fn tuple_schema<'a>(fields: impl ExactSizeIterator<Item = &'a Type>) -> Value {
+    let len = fields.len();
+    json!({
+        "type": "array",
+        "prefixItems": fields.map(reference).collect::<Vec<_>>(),
+        "items": false,
+        "minItems": len,
+    })
+}
+
+fn structural_schema(registration: &TypeRegistration) -> Value {
+    let info = registration.type_info();
+    if let Some(inner) = option_inner(info) {
+        return json!({ "oneOf": [{ "type": "null" }, reference(inner)] });
+    }
+
+    match info {
+        TypeInfo::Struct(info) => {
+            let mut properties = Map::new();
+            let mut required = Vec::new();
+            for (index, field) in info.iter().enumerate() {
+                if is_field_skipped(registration, index) {
+                    continue;
+                }
+                let mut property = reference(field.ty());
+                if let Some(docs) = field_docs(field) {
+                    property["description"] = docs.into();
+                }
+                properties.insert(field.name().to_owned(), property);
+                if !is_field_optional(registration, index) {
+                    required.push(field.name());
+                }
+            }
+            json!({
+                "type": "object",
+                "properties": properties,
+                "required": required,
+                "additionalProperties": false,
+            })
+        }
+        TypeInfo::TupleStruct(info) => {
+            if is_newtype_struct(registration, info.field_len()) {
+                return reference(info.field_at(0).unwrap().ty());
+            }
+            let fields = info
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| !is_field_skipped(registration, *index))
+                .map(|(_, field)| field.ty())
+                .collect::<Vec<_>>();
+            tuple_schema(fields.into_iter())
+        }
+        TypeInfo::Tuple(info) => tuple_schema(info.iter().map(UnnamedField::ty)),
+        TypeInfo::List(info) => json!({
+            "type": "array",
+            "items": reference(&info.item_ty()),
+        }),
+        TypeInfo::Array(info) => json!({
+            "type": "array",
+            "items": reference(&info.item_ty()),
+            "minItems": info.capacity(),
+            "maxItems": info.capacity(),
+        }),
+        TypeInfo::Set(info) => json!({
+            "type": "array",
+            "items": reference(&info.value_ty()),
+            "uniqueItems": true,
+        }),
+        // JSON only allows string keys, so keys are described by the value type alone.
+        TypeInfo::Map(info) => json!({
+            "type": "object",
+            "additionalProperties": reference(&info.value_ty()),
+        }),
+        TypeInfo::Enum(info) => {
+            let variants = info
+                .iter()
+                .map(|variant| {
+                    let mut schema = match variant {
+                        VariantInfo::Unit(variant) => json!({ "const": variant.name() }),
+                        VariantInfo::Tuple(tuple) => {
+                            let value = if tuple.field_len() == 1 {
+                                reference(tuple.field_at(0).unwrap().ty())
+                            } else {
+                                tuple_schema(tuple.iter().map(UnnamedField::ty))
+                            };
+                            externally_tagged(tuple.name(), value)
+                        }
+                        VariantInfo::Struct(variant) => {
+                            let properties = variant
+                                .iter()
+                                .map(|field| {
+                                    let mut property = reference(field.ty());
+                                    if let Some(docs) = field_docs(field) {
+                                        property["description"] = docs.into();
+                                    }
+                                    (field.name().to_owned(), property)
+                                })
+                                .collect::<Map<_, _>>();
+                            let value = json!({
+                                "type": "object",
+                                "properties": properties,
+                                "required": variant.field_names(),
+                                "additionalProperties": false,
+                            });
+                            externally_tagged(variant.name(), value)
+                        }
+                    };
+                    if let Some(docs) = variant_docs(variant) {
+                        schema["description"] = docs.into();
+                    }
+                    schema
+                })
+                .collect::<Vec<_>>();
+            json!({ "oneOf": variants })
+        }
+        TypeInfo::Opaque(_) => json!({}),
+    }
+}
+
+/// Describes a non-unit enum variant, which is serialized as a map with a single entry keyed by the variant's name.
+fn externally_tagged(name: &str, value: Value) -> Value {
+    json!({
+        "type": "object",
+        "properties": { name: value },
+        "required": [name],
+        "additionalProperties": false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_reflect::{prelude::ReflectDefault, Reflect};
+    use core::any::TypeId;
+    use std::collections::HashMap;
+
+    #[derive(Reflect, Default)]
+    #[reflect(Default)]
+    struct Player {
+        name: String,
+        items: Vec<Item>,
+        stats: HashMap<String, f32>,
+        position: (f32, f32),
+        target: Option<u64>,
+    }
+
+    #[derive(Reflect)]
+    enum Item {
+        Key,
+        Potion(u8),
+        Sword { damage: u32 },
+    }
+
+    #[test]
+    fn export_struct_and_references() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Player>();
+        let registration = registry.get(TypeId::of::<Player>()).unwrap();
+
+        let schema = export_json_schema(&registry, [registration]);
+        assert_eq!(schema["$schema"], JSON_SCHEMA_DIALECT);
+        let definitions = schema["$defs"].as_object().unwrap();
+
+        let player = &definitions[registration.type_info().type_path()];
+        assert_eq!(player["type"], "object");
+        assert_eq!(player["title"], "Player");
+        // `Player` registers `ReflectDefault`, so all of its fields can be omitted
+        assert_eq!(player["required"], json!([]));
+        assert_eq!(
+            player["properties"]["items"]["$ref"],
+            definition_ref(<Vec<Item> as bevy_reflect::TypePath>::type_path())
+        );
+        assert_eq!(player["default"]["name"], "");
+        assert_eq!(player["default"]["target"], Value::Null);
+
+        // Every reference has a definition
+        for field in ["name", "items", "stats", "position", "target"] {
+            let reference = player["properties"][field]["$ref"].as_str().unwrap();
+            assert!(
+                definitions
+                    .keys()
+                    .any(|type_path| definition_ref(type_path) == reference),
+                "missing definition for {reference}"
+            );
+        }
+
+        let item = &definitions[<Item as bevy_reflect::TypePath>::type_path()];
+        assert_eq!(item["oneOf"][0], json!({ "const": "Key" }));
+        assert_eq!(item["oneOf"][1]["required"], json!(["Potion"]));
+        assert_eq!(
+            item["oneOf"][2]["properties"]["Sword"]["properties"]["damage"]["$ref"],
+            "#/$defs/u32"
+        );
+
+        let option = &definitions[<Option<u64> as bevy_reflect::TypePath>::type_path()];
+        assert_eq!(option["oneOf"][0]["type"], "null");
+        assert_eq!(definitions["u64"]["minimum"], 0);
+    }
+
+    #[test]
+    fn definition_refs_are_valid_uri_fragments() {
+        assert_eq!(
+            "#/$defs/alloc::vec::Vec%3Cf32%3E",
+            definition_ref("alloc::vec::Vec<f32>")
+        );
+        assert_eq!("#/$defs/a~1b~0c", definition_ref("a/b~c"));
+    }
+}
@@ -0,0 +1,204 @@
+//! Generators for bindings of the types in a [`TypeRegistry`], for use by external tools.
+//!
+//! Unlike the `bevy/registry/schema` method, which describes types in a Bevy-specific format,
+//! these generators describe the JSON representation produced by Bevy's reflection-based
+//! serializers (e.g. [`TypedReflectSerializer`] with `serde_json`), using:
+//! - [`json_schema`]: a standard [JSON Schema] document, with one definition per type.
+//! - [`typescript`]: TypeScript declarations, with one declaration per named type.
+//!
+//! Both generators describe the requested types along with every type they reference.
+//! When a type registers [`ReflectDefault`], its default value is included,
+//! and when the `documentation` feature is enabled, so are the doc comments of types, fields and variants.
+//!
+//! The `export-type-bindings` tool in the Bevy repository uses these to generate bindings for Bevy's own types.
+//!
+//! [JSON Schema]: https://json-schema.org/specification
+//! [`ReflectDefault`]: bevy_reflect::std_traits::ReflectDefault
+
+use bevy_platform_support::collections::HashSet;
+use bevy_reflect::{
+    serde::{ReflectSerializeWithRegistry, SerializationData, TypedReflectSerializer},
+    std_traits::ReflectDefault,
+    NamedField, Type, TypeInfo, TypeRegistration, TypeRegistry, UnnamedField, VariantInfo,
+};
+use core::any::TypeId;
+use serde_json::Value;
+
+pub mod json_schema;
+pub mod typescript;
+
+/// Collects the registrations of `roots` and every registered type they reference (recursively),
+/// sorted by type path.
+fn collect_types<'a>(
+    registry: &'a TypeRegistry,
+    roots: impl IntoIterator<Item = &'a TypeRegistration>,
+) -> Vec<&'a TypeRegistration> {
+    let mut visited = HashSet::<TypeId>::default();
+    let mut stack = roots.into_iter().collect::<Vec<_>>();
+    let mut types = Vec::new();
+    while let Some(registration) = stack.pop() {
+        if !visited.insert(registration.type_id()) {
+            continue;
+        }
+        types.push(registration);
+        stack.extend(
+            referenced_types(registration.type_info()).filter_map(|type_id| registry.get(type_id)),
+        );
+    }
+    types.sort_unstable_by_key(|registration| registration.type_info().type_path());
+    types
+}
+
+/// Returns the types directly referenced by the fields, variants, or items of a type.
+fn referenced_types(info: &TypeInfo) -> impl Iterator<Item = TypeId> {
+    let ids: Vec<TypeId> = match info {
+        TypeInfo::Struct(info) => info.iter().map(NamedField::type_id).collect(),
+        TypeInfo::TupleStruct(info) => info.iter().map(UnnamedField::type_id).collect(),
+        TypeInfo::Tuple(info) => info.iter().map(UnnamedField::type_id).collect(),
+        TypeInfo::List(info) => vec![info.item_ty().id()],
+        TypeInfo::Array(info) => vec![info.item_ty().id()],
+        TypeInfo::Map(info) => vec![info.key_ty().id(), info.value_ty().id()],
+        TypeInfo::Set(info) => vec![info.value_ty().id()],
+        TypeInfo::Enum(info) => info
+            .iter()
+            .flat_map(|variant| match variant {
+                VariantInfo::Struct(variant) => variant.iter().map(NamedField::type_id).collect(),
+                VariantInfo::Tuple(variant) => variant.iter().map(UnnamedField::type_id).collect(),
+                VariantInfo::Unit(_) => Vec::new(),
+            })
+            .collect(),
+        TypeInfo::Opaque(_) => Vec::new(),
+    };
+    ids.into_iter()
+}
+
+/// Returns the type wrapped by `info` if it describes an [`Option`],
+/// which the reflection serializers represent as either `null` or the wrapped value.
+fn option_inner(info: &TypeInfo) -> Option<&Type> {
+    let TypeInfo::Enum(enum_info) = info else {
+        return None;
+    };
+    let table = info.type_path_table();
+    if table.module_path() != Some("core::option") || table.ident() != Some("Option") {
+        return None;
+    }
+    match enum_info.variant("Some")? {
+        VariantInfo::Tuple(variant) => variant.field_at(0).map(UnnamedField::ty),
+        _ => None,
+    }
+}
+
+/// Returns true if the type is serialized by its own `Serialize` implementation,
+/// in which case its reflected structure may not match its serialized representation.
+fn is_serialized_with_serde(registration: &TypeRegistration) -> bool {
+    registration
+        .data::<bevy_reflect::ReflectSerialize>()
+        .is_some()
+        || registration
+            .data::<ReflectSerializeWithRegistry>()
+            .is_some()
+}
+
+/// Returns true if the field at `index` of the type is skipped during serialization.
+fn is_field_skipped(registration: &TypeRegistration, index: usize) -> bool {
+    registration
+        .data::<SerializationData>()
+        .is_some_and(|data| data.is_field_skipped(index))
+}
+
+/// Returns true if the field at `index` of the type may be omitted from serialized data.
+fn is_field_optional(registration: &TypeRegistration, index: usize) -> bool {
+    registration.data::<ReflectDefault>().is_some()
+        || registration
+            .data::<SerializationData>()
+            .is_some_and(|data| data.generate_missing_default(index).is_some())
+}
+
+/// Returns true if a tuple struct is serialized as its single field rather than as a sequence.
+fn is_newtype_struct(registration: &TypeRegistration, field_len: usize) -> bool {
+    field_len == 1
+        && registration
+            .data::<SerializationData>()
+            .is_none_or(SerializationData::is_empty)
+}
+
+/// Serializes the default value of the type to JSON, if it registers [`ReflectDefault`].
+fn default_value(registration: &TypeRegistration, registry: &TypeRegistry) -> Option<Value> {
+    let value = registration.data::<ReflectDefault>()?.default();
+    serde_json::to_value(TypedReflectSerializer::new(
+        value.as_partial_reflect(),
+        registry,
+    ))
+    .ok()
+}
+
+/// Returns the doc comment of the type, if the `documentation` feature is enabled.
+fn type_docs(info: &TypeInfo) -> Option<String> {
+    #[cfg(feature = "documentation")]
+    return info.docs().map(normalize_docs);
+    #[cfg(not(feature = "documentation"))]
+    {
+        let _ = info;
+        None
+    }
+}
+
+/// Returns the doc comment of the variant, if the `documentation` feature is enabled.
+fn variant_docs(variant: &VariantInfo) -> Option<String> {
+    #[cfg(feature = "documentation")]
+    return variant.docs().map(normalize_docs);
+    #[cfg(not(feature = "documentation"))]
+    {
+        let _ = variant;
+        None
+    }
+}
+
+/// Returns the doc comment of the field, if the `documentation` feature is enabled.
+fn field_docs(field: &NamedField) -> Option<String> {
+    #[cfg(feature = "documentation")]
+    return field.docs().map(normalize_docs);
+    #[cfg(not(feature = "documentation"))]
+    {
+        let _ = field;
+        None
+    }
+}
+
+/// Removes the space following `///` from each line of a doc comment, along with surrounding whitespace.
+#[cfg(feature = "documentation")]
+fn normalize_docs(docs: &str) -> String {
+    docs.lines()
+        .map(|line| line.strip_prefix(' ').unwrap_or(line).trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_owned()
+}
+
+/// The JSON representation of a primitive type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Primitive {
+    Bool,
+    Unsigned,
+    Signed,
+    Float,
+    Char,
+    String,
+}
+
+impl Primitive {
+    fn from_type_path(type_path: &str) -> Option<Self> {
+        Some(match type_path {
+            "bool" => Primitive::Bool,
+            "u8" | "u16" | "u32" | "u64" | "u128" | "usize" => Primitive::Unsigned,
+            "i8" | "i16" | "i32" | "i64" | "i128" | "isize" => Primitive::Signed,
+            "f32" | "f64" => Primitive::Float,
+            "char" => Primitive::Char,
+            "str" | "alloc::string::String" | "alloc::borrow::Cow<str>" | "std::path::PathBuf" => {
+                Primitive::String
+            }
+            _ => return None,
+        })
+    }
+}
@@ -0,0 +1,409 @@
+//! Generates TypeScript declarations from a [`TypeRegistry`].
+
+use super::{
+    collect_types, default_value, field_docs, is_field_optional, is_field_skipped,
+    is_newtype_struct, is_serialized_with_serde, option_inner, type_docs, variant_docs, Primitive,
+};
+use bevy_platform_support::collections::HashMap;
+use bevy_reflect::{Type, TypeInfo, TypeRegistration, TypeRegistry, UnnamedField, VariantInfo};
+use core::{any::TypeId, fmt::Write};
+use serde_json::Value;
+
+/// Generates TypeScript declarations describing the JSON representation of the given types,
+/// as produced by Bevy's reflection-based serializers.
+///
+/// A declaration is generated for each named type (structs, tuple structs, enums and opaque types)
+/// among the given types and every registered type they reference,
+/// named after the type's [short path] (or its full [type path] if the short path is ambiguous).
+/// Primitives, options, tuples, and collections are written inline.
+///
+/// Declarations include the type's doc comments (when the `documentation` feature is enabled)
+/// and default value (when it registers `ReflectDefault`) as `JSDoc` comments.
+///
+/// Types that implement their own serialization (i.e. register `ReflectSerialize`) are described
+/// by the shape of their serialized default value if they have one, and by their reflected structure otherwise.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, TypeRegistry};
+/// # use bevy_remote::schemas::typescript::export_typescript;
+/// #[derive(Reflect)]
+/// struct Health {
+///     current: u32,
+///     max: Option<u32>,
+/// }
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Health>();
+///
+/// let registration = registry.get(core::any::TypeId::of::<Health>()).unwrap();
+/// let declarations = export_typescript(&registry, [registration]);
+///
+/// assert!(declarations.contains("export interface Health {\n  current: number;\n  max: number | null;\n}"));
+/// ```
+///
+/// [short path]: bevy_reflect::TypePath::short_type_path
+/// [type path]: bevy_reflect::TypePath::type_path
+pub fn export_typescript<'a>(
+    registry: &'a TypeRegistry,
+    types: impl IntoIterator<Item = &'a TypeRegistration>,
+) -> String {
+    let types = collect_types(registry, types);
+    let generator = TypeScriptGenerator {
+        registry,
+        names: declaration_names(&types),
+    };
+
+    let mut output = String::from(
+        "// This file was generated from a Bevy `TypeRegistry`. Do not edit it by hand.\n",
+    );
+    for registration in types {
+        if let Some(declaration) = generator.declaration(registration) {
+            output.push('\n');
+            output.push_str(&declaration);
+        }
+    }
+    output
+}
+
+/// Returns true if a declaration should be generated for the type, rather than writing it inline.
+fn is_named(registration: &TypeRegistration) -> bool {
+    let info = registration.type_info();
+    if Primitive::from_type_path(info.type_path()).is_some() || option_inner(info).is_some() {
+        return false;
+    }
+    matches!(
+        info,
+        TypeInfo::Struct(_) | TypeInfo::TupleStruct(_) | TypeInfo::Enum(_) | TypeInfo::Opaque(_)
+    )
+}
+
+/// Assigns a unique TypeScript identifier to each named type.
+fn declaration_names(types: &[&TypeRegistration]) -> HashMap<TypeId, String> {
+    let named = types
+        .iter()
+        .filter(|registration| is_named(registration))
+        .collect::<Vec<_>>();
+
+    let mut counts = HashMap::<String, usize>::default();
+    for registration in &named {
+        let short_path = registration.type_info().type_path_table().short_path();
+        *counts.entry(to_identifier(short_path)).or_default() += 1;
+    }
+
+    named
+        .into_iter()
+        .map(|registration| {
+            let table = registration.type_info().type_path_table();
+            let name = to_identifier(table.short_path());
+            let name = if counts[&name] > 1 {
+                to_identifier(table.path())
+            } else {
+                name
+            };
+            (registration.type_id(), name)
+        })
+        .collect()
+}
+
+/// Converts a type path into a valid TypeScript identifier, e.g. `Handle<Image>` into `Handle_Image`.
+fn to_identifier(path: &str) -> String {
+    let mut identifier = String::with_capacity(path.len());
+    for char in path.chars() {
+        if char.is_ascii_alphanumeric() {
+            identifier.push(char);
+        } else if !identifier.is_empty() && !identifier.ends_with('_') {
+            identifier.push('_');
+        }
+    }
+    let identifier = identifier.trim_end_matches('_');
+    if identifier.starts_with(|char: char| char.is_ascii_digit()) {
+        format!("_{identifier}")
+    } else {
+        identifier.to_owned()
+    }
+}
+
+/// Writes a `JSDoc` comment with the given documentation and default value, if any.
+fn write_jsdoc(output: &mut String, indent: &str, docs: Option<&str>, default: Option<&Value>) {
+    if docs.is_none() && default.is_none() {
+        return;
+    }
+    let mut lines = Vec::new();
+    if let Some(docs) = docs {
+        lines.extend(docs.lines());
+    }
+    let default = default.map(|default| format!("@default {default}"));
+    if let Some(default) = &default {
+        if !lines.is_empty() {
+            lines.push("");
+        }
+        lines.push(default);
+    }
+
+    let _ = writeln!(output, "{indent}/**");
+    for line in lines {
+        let line = line.replace("*/", "*\\/");
+        if line.is_empty() {
+            let _ = writeln!(output, "{indent} *");
+        } else {
+            let _ = writeln!(output, "{indent} * {line}");
+        }
+    }
+    let _ = writeln!(output, "{indent} */");
+}
+
+/// Infers a TypeScript type from the shape of a serialized value.
+fn value_type(value: &Value) -> String {
+    match value {
+        Value::Null => "null".to_owned(),
+        Value::Bool(_) => "boolean".to_owned(),
+        Value::Number(_) => "number".to_owned(),
+        Value::String(_) => "string".to_owned(),
+        Value::Array(items) => format!(
+            "[{}]",
+            items.iter().map(value_type).collect::<Vec<_>>().join(", ")
+        ),
+        Value::Object(properties) => format!(
+            "{{ {} }}",
+            properties
+                .iter()
+                .map(|(name, value)| format!("{}: {};", property_name(name), value_type(value)))
+                .collect::<Vec<_>>()
+                .join(" ")
+        ),
+    }
+}
+
+/// Quotes a property name if it isn't a valid identifier.
+fn property_name(name: &str) -> String {
+    let is_identifier = name
+        .chars()
+        .next()
+        .is_some_and(|char| char.is_ascii_alphabetic() || char == '_' || char == '$')
+        && name
+            .chars()
+            .all(|char| char.is_ascii_alphanumeric() || char == '_' || char == '$');
+    if is_identifier {
+        name.to_owned()
+    } else {
+        Value::from(name).to_string()
+    }
+}
+
+struct TypeScriptGenerator<'a> {
+    registry: &'a TypeRegistry,
+    names: HashMap<TypeId, String>,
+}
+
+impl TypeScriptGenerator<'_> {
+    /// Returns the TypeScript type expression for a reference to the given type.
+    fn type_expr(&self, ty: &Type) -> String {
+        if let Some(name) = self.names.get(&ty.id()) {
+            return name.clone();
+        }
+        if let Some(primitive) = Primitive::from_type_path(ty.path()) {
+            return match primitive {
+                Primitive::Bool => "boolean",
+                Primitive::Unsigned | Primitive::Signed | Primitive::Float => "number",
+                Primitive::Char | Primitive::String => "string",
+            }
+            .to_owned();
+        }
+        let Some(registration) = self.registry.get(ty.id()) else {
+            return "unknown".to_owned();
+        };
+        let info = registration.type_info();
+        if let Some(inner) = option_inner(info) {
+            return format!("{} | null", self.type_expr(inner));
+        }
+        match info {
+            TypeInfo::List(info) => self.array_expr(&info.item_ty()),
+            TypeInfo::Array(info) => self.array_expr(&info.item_ty()),
+            TypeInfo::Set(info) => self.array_expr(&info.value_ty()),
+            // JSON only allows string keys.
+            TypeInfo::Map(info) => format!("Record<string, {}>", self.type_expr(&info.value_ty())),
+            TypeInfo::Tuple(info) => self.tuple_expr(info.iter().map(UnnamedField::ty)),
+            _ => "unknown".to_owned(),
+        }
+    }
+
+    fn array_expr(&self, item: &Type) -> String {
+        let item = self.type_expr(item);
+        if item.contains(' ') {
+            format!("({item})[]")
+        } else {
+            format!("{item}[]")
+        }
+    }
+
+    fn tuple_expr<'b>(&self, fields: impl Iterator<Item = &'b Type>) -> String {
+        format!(
+            "[{}]",
+            fields
+                .map(|field| self.type_expr(field))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+
+    /// Returns the declaration of a named type, or `None` if the type is written inline.
+    fn declaration(&self, registration: &TypeRegistration) -> Option<String> {
+        let name = self.names.get(&registration.type_id())?;
+        let info = registration.type_info();
+        let default = default_value(registration, self.registry);
+
+        let mut output = String::new();
+        write_jsdoc(
+            &mut output,
+            "",
+            type_docs(info).as_deref(),
+            default.as_ref(),
+        );
+
+        if let (Some(default), true) = (&default, is_serialized_with_serde(registration)) {
+            let _ = writeln!(output, "export type {name} = {};", value_type(default));
+            return Some(output);
+        }
+
+        match info {
+            TypeInfo::Struct(info) => {
+                let _ = writeln!(output, "export interface {name} {{");
+                for (index, field) in info.iter().enumerate() {
+                    if is_field_skipped(registration, index) {
+                        continue;
+                    }
+                    write_jsdoc(&mut output, "  ", field_docs(field).as_deref(), None);
+                    let optional = if is_field_optional(registration, index) {
+                        "?"
+                    } else {
+                        ""
+                    };
+                    let _ = writeln!(
+                        output,
+                        "  {}{optional}: {};",
+                        property_name(field.name()),
+                        self.type_expr(field.ty())
+                    );
+                }
+                let _ = writeln!(output, "}}");
+            }
+            TypeInfo::TupleStruct(info) => {
+                let expr = if is_newtype_struct(registration, info.field_len()) {
+                    self.type_expr(info.field_at(0).unwrap().ty())
+                } else {
+                    self.tuple_expr(
+                        info.iter()
+                            .enumerate()
+                            .filter(|(index, _)| !is_field_skipped(registration, *index))
+                            .map(|(_, field)| field.ty()),
+                    )
+                };
+                let _ = writeln!(output, "export type {name} = {expr};");
+            }
+            TypeInfo::Enum(info) => {
+                if info.variant_len() == 0 {
+                    let _ = writeln!(output, "export type {name} = never;");
+                    return Some(output);
+                }
+                let _ = writeln!(output, "export type {name} =");
+                for variant in info.iter() {
+                    write_jsdoc(&mut output, "  ", variant_docs(variant).as_deref(), None);
+                    let expr = match variant {
+                        VariantInfo::Unit(variant) => Value::from(variant.name()).to_string(),
+                        VariantInfo::Tuple(tuple) => {
+                            let value = if tuple.field_len() == 1 {
+                                self.type_expr(tuple.field_at(0).unwrap().ty())
+                            } else {
+                                self.tuple_expr(tuple.iter().map(UnnamedField::ty))
+                            };
+                            format!("{{ {}: {value} }}", property_name(tuple.name()))
+                        }
+                        VariantInfo::Struct(variant) => {
+                            let fields = variant
+                                .iter()
+                                .map(|field| {
+                                    format!(
+                                        "{}: {};",
+                                        property_name(field.name()),
+                                        self.type_expr(field.ty())
+                                    )
+                                })
+                                .collect::<Vec<_>>()
+                                .join(" ");
+                            format!("{{ {}: {{ {fields} }} }}", property_name(variant.name()))
+                        }
+                    };
+                    let _ = writeln!(output, "  | {expr}");
+                }
+                output.truncate(output.trim_end().len());
+                output.push_str(";\n");
+            }
+            _ => {
+                let _ = writeln!(output, "export type {name} = unknown;");
+            }
+        }
+        Some(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_reflect::{prelude::ReflectDefault, Reflect};
+
+    #[derive(Reflect, Default)]
+    #[reflect(Default)]
+    struct Player {
+        name: String,
+        items: Vec<Item>,
+        position: (f32, f32),
+        id: PlayerId,
+    }
+
+    #[derive(Reflect, Default)]
+    struct PlayerId(u64);
+
+    #[derive(Reflect)]
+    enum Item {
+        Key,
+        Potion(u8),
+        Sword { damage: u32 },
+    }
+
+    mod other {
+        use bevy_reflect::Reflect;
+
+        #[derive(Reflect)]
+        pub struct PlayerId(pub u64, pub u64);
+    }
+
+    #[test]
+    fn export_declarations() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Player>();
+        registry.register::<other::PlayerId>();
+
+        let declarations = export_typescript(&registry, registry.iter());
+
+        assert!(declarations.contains(
+            "/**\n * @default {\"id\":0,\"items\":[],\"name\":\"\",\"position\":[0.0,0.0]}\n */\n\
+             export interface Player {\n  name?: string;\n  items?: Item[];\n  position?: [number, number];\n  id?: "
+        ));
+        assert!(declarations.contains(
+            "export type Item =\n  | \"Key\"\n  | { Potion: number }\n  | { Sword: { damage: number; } };\n"
+        ));
+        // Ambiguous short paths fall back to the full type path
+        assert!(!declarations.contains("export type PlayerId "));
+        assert!(declarations.contains("_PlayerId = number;\n"));
+        assert!(declarations.contains("_other_PlayerId = [number, number];\n"));
+    }
+
+    #[test]
+    fn identifiers() {
+        assert_eq!("Handle_Image", to_identifier("Handle<Image>"));
+        assert_eq!("_3d", to_identifier("3d"));
+        assert_eq!("a_b_c", to_identifier("a::b::c"));
+    }
+}
@@ -6,6 +6,7 @@ use bevy_ecs::{
 };
 use bevy_reflect::{prelude::ReflectDefault, Reflect};
 use bevy_transform::components::Transform;
+use core::time::Duration;
 use derive_more::derive::From;
 
 #[cfg(feature = "bevy_render")]
@@ -28,3 +29,17 @@ pub struct SceneRoot(pub Handle<Scene>);
 #[require(Transform)]
 #[cfg_attr(feature = "bevy_render", require(Visibility))]
 pub struct DynamicSceneRoot(pub Handle<DynamicScene>);
+
+/// Adding this component next to a [`DynamicSceneRoot`] will spawn the scene over several frames,
+/// writing entities to the world until the budget of each frame is exhausted.
+///
+/// The [`SceneInstanceReady`](crate::SceneInstanceReady) event is only triggered once the whole scene is spawned.
+/// Use [`SceneSpawner::instance_progress`](crate::SceneSpawner::instance_progress) to follow the spawn.
+#[derive(Component, Clone, Copy, Debug, Reflect, PartialEq)]
+#[reflect(Component, Debug, PartialEq)]
+pub enum SceneSpawnBudget {
+    /// Spawn at most this number of entities per frame.
+    Entities(usize),
+    /// Spawn entities until this much time has elapsed during a frame.
+    Time(Duration),
+}
@@ -1,4 +1,12 @@
-use crate::{ron, DynamicSceneBuilder, Scene, SceneSpawnError};
+use crate::{
+    entity_reference::{resolve_entity_references, ResolvedEntityMapper},
+    ron, DynamicSceneBuilder, DynamicSceneInstance, Scene, SceneEntityId, SceneSpawnError,
+};
+#[cfg(feature = "serialize")]
+use crate::{
+    serde::{CompactSceneDeserializer, CompactSceneSerializer},
+    BinarySceneLoaderError, BINARY_SCENE_MAGIC, BINARY_SCENE_VERSION,
+};
 use bevy_asset::Asset;
 use bevy_ecs::reflect::ReflectResource;
 use bevy_ecs::{
@@ -7,11 +15,12 @@ use bevy_ecs::{
     world::World,
 };
 use bevy_reflect::{PartialReflect, TypePath, TypeRegistry};
+use core::ops::Range;
 
 #[cfg(feature = "serialize")]
 use crate::serde::SceneSerializer;
 #[cfg(feature = "serialize")]
-use serde::Serialize;
+use serde::{de::DeserializeSeed, Serialize};
 
 /// A collection of serializable resources and dynamic entities.
 ///
@@ -26,6 +35,11 @@ pub struct DynamicScene {
     pub resources: Vec<Box<dyn PartialReflect>>,
     /// Entities contained in the dynamic scene.
     pub entities: Vec<DynamicEntity>,
+    /// Instances of other scenes contained in the dynamic scene, see [`DynamicScene::instances`].
+    pub(crate) instances: Vec<DynamicSceneInstance>,
+    /// Stable identifiers of the entities referenced by the components and resources of the scene,
+    /// by the placeholder entities standing for them, see [`DynamicScene::entity_references`].
+    pub(crate) entity_references: EntityHashMap<SceneEntityId>,
 }
 
 /// A reflection-powered serializable representation of an entity and its components.
@@ -40,6 +54,46 @@ pub struct DynamicEntity {
 }
 
 impl DynamicScene {
+    /// Create a new dynamic scene from its resources and entities.
+    pub fn new(resources: Vec<Box<dyn PartialReflect>>, entities: Vec<DynamicEntity>) -> Self {
+        Self {
+            resources,
+            entities,
+            ..Default::default()
+        }
+    }
+
+    /// Add instances of other scenes to the dynamic scene.
+    #[must_use]
+    pub fn with_instances(
+        mut self,
+        instances: impl IntoIterator<Item = DynamicSceneInstance>,
+    ) -> Self {
+        self.instances.extend(instances);
+        self
+    }
+
+    /// Returns the instances of other scenes contained in the dynamic scene.
+    ///
+    /// These are only spawned by the [`SceneSpawner`](crate::SceneSpawner).
+    pub fn instances(&self) -> &[DynamicSceneInstance] {
+        &self.instances
+    }
+
+    /// Returns a mutable reference to the instances of other scenes contained in the dynamic scene.
+    pub fn instances_mut(&mut self) -> &mut Vec<DynamicSceneInstance> {
+        &mut self.instances
+    }
+
+    /// Returns the stable identifiers of the entities referenced by the components and resources of the scene,
+    /// by the placeholder entities standing for them.
+    ///
+    /// These are read from scenes serialized with stable [entity references](crate::SceneEntityReferences),
+    /// and resolved to the entities of the scene when it is written to a world.
+    pub fn entity_references(&self) -> &EntityHashMap<SceneEntityId> {
+        &self.entity_references
+    }
+
     /// Create a new dynamic scene from a given scene.
     pub fn from_scene(scene: &Scene) -> Self {
         Self::from_world(&scene.world)
@@ -65,9 +119,25 @@ impl DynamicScene {
         type_registry: &AppTypeRegistry,
     ) -> Result<(), SceneSpawnError> {
         let type_registry = type_registry.read();
+        let references = resolve_entity_references(self)?;
+
+        self.reserve_entities(world, entity_map);
+        self.write_entities_with(
+            0..self.entities.len(),
+            world,
+            entity_map,
+            &type_registry,
+            &references,
+        )?;
+        self.write_resources_with(world, entity_map, &type_registry, &references)
+    }
 
-        // First ensure that every entity in the scene has a corresponding world
-        // entity in the entity map.
+    /// Ensure that every entity in the scene has a corresponding world entity in the entity map.
+    pub(crate) fn reserve_entities(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+    ) {
         for scene_entity in &self.entities {
             // Fetch the entity with the given entity id from the `entity_map`
             // or spawn a new entity with a transiently unique id if there is
@@ -76,8 +146,21 @@ impl DynamicScene {
                 .entry(scene_entity.entity)
                 .or_insert_with(|| world.spawn_empty().id());
         }
+    }
 
-        for scene_entity in &self.entities {
+    /// Write the components of the dynamic entities in `range` to the given world.
+    ///
+    /// The entities of the scene must have been [reserved](Self::reserve_entities) in `entity_map` beforehand,
+    /// and its entity references resolved to `references`.
+    pub(crate) fn write_entities_with(
+        &self,
+        range: Range<usize>,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+        type_registry: &TypeRegistry,
+        references: &EntityHashMap<Entity>,
+    ) -> Result<(), SceneSpawnError> {
+        for scene_entity in &self.entities[range] {
             // Fetch the entity with the given entity id from the `entity_map`.
             let entity = *entity_map
                 .get(&scene_entity.entity)
@@ -106,6 +189,12 @@ impl DynamicScene {
                 // If this component references entities in the scene, update
                 // them to the entities in the world.
                 if let Some(map_entities) = registration.data::<ReflectMapEntities>() {
+                    if !references.is_empty() {
+                        map_entities.map_entities(
+                            component.as_partial_reflect_mut(),
+                            &mut ResolvedEntityMapper(references),
+                        );
+                    }
                     SceneEntityMapper::world_scope(entity_map, world, |_, mapper| {
                         map_entities.map_entities(component.as_partial_reflect_mut(), mapper);
                     });
@@ -114,13 +203,25 @@ impl DynamicScene {
                 reflect_component.apply_or_insert(
                     &mut world.entity_mut(entity),
                     component.as_partial_reflect(),
-                    &type_registry,
+                    type_registry,
                 );
             }
         }
 
-        // Insert resources after all entities have been added to the world.
-        // This ensures the entities are available for the resources to reference during mapping.
+        Ok(())
+    }
+
+    /// Write the resources of the scene to the given world.
+    ///
+    /// Resources are written after all entities have been added to the world.
+    /// This ensures the entities are available for the resources to reference during mapping.
+    pub(crate) fn write_resources_with(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+        type_registry: &TypeRegistry,
+        references: &EntityHashMap<Entity>,
+    ) -> Result<(), SceneSpawnError> {
         for resource in &self.resources {
             let mut resource = resource.clone_value();
             let type_info = resource.get_represented_type_info().ok_or_else(|| {
@@ -142,6 +243,12 @@ impl DynamicScene {
             // If this component references entities in the scene, update
             // them to the entities in the world.
             if let Some(map_entities) = registration.data::<ReflectMapEntities>() {
+                if !references.is_empty() {
+                    map_entities.map_entities(
+                        resource.as_partial_reflect_mut(),
+                        &mut ResolvedEntityMapper(references),
+                    );
+                }
                 SceneEntityMapper::world_scope(entity_map, world, |_, mapper| {
                     map_entities.map_entities(resource.as_partial_reflect_mut(), mapper);
                 });
@@ -149,7 +256,7 @@ impl DynamicScene {
 
             // If the world already contains an instance of the given resource
             // just apply the (possibly) new value, otherwise insert the resource
-            reflect_resource.apply_or_insert(world, resource.as_partial_reflect(), &type_registry);
+            reflect_resource.apply_or_insert(world, resource.as_partial_reflect(), type_registry);
         }
 
         Ok(())
@@ -181,6 +288,49 @@ impl DynamicScene {
     pub fn serialize(&self, registry: &TypeRegistry) -> Result<String, ron::Error> {
         serialize_ron(SceneSerializer::new(self, registry))
     }
+
+    /// Serialize this dynamic scene into the binary Bevy scene format.
+    ///
+    /// The binary format encodes the scene with a [`CompactSceneSerializer`] and [`postcard`],
+    /// after a short header identifying the format and its version. Each type used by the scene is only
+    /// identified once, by its type path, along with a hash of the layout of the types.
+    /// It is much faster to load than RON, but isn't meant to be edited by hand, and can only be
+    /// deserialized by a [`TypeRegistry`] whose types have the same layout as the ones used to serialize it.
+    /// To deserialize the scene, use the [`BinarySceneLoader`] or [`DynamicScene::deserialize_binary`].
+    ///
+    /// [`BinarySceneLoader`]: crate::BinarySceneLoader
+    /// [`CompactSceneSerializer`]: crate::serde::CompactSceneSerializer
+    #[cfg(feature = "serialize")]
+    pub fn serialize_binary(&self, registry: &TypeRegistry) -> Result<Vec<u8>, postcard::Error> {
+        let mut bytes = BINARY_SCENE_MAGIC.to_vec();
+        bytes.push(BINARY_SCENE_VERSION);
+        let serializer = CompactSceneSerializer {
+            scene: self,
+            registry,
+        };
+        postcard::to_extend(&serializer, bytes)
+    }
+
+    /// Deserialize a dynamic scene serialized with [`DynamicScene::serialize_binary`].
+    #[cfg(feature = "serialize")]
+    pub fn deserialize_binary(
+        bytes: &[u8],
+        registry: &TypeRegistry,
+    ) -> Result<Self, BinarySceneLoaderError> {
+        let bytes = bytes
+            .strip_prefix(&BINARY_SCENE_MAGIC)
+            .ok_or(BinarySceneLoaderError::InvalidHeader)?;
+        let (&version, bytes) = bytes
+            .split_first()
+            .ok_or(BinarySceneLoaderError::InvalidHeader)?;
+        if version != BINARY_SCENE_VERSION {
+            return Err(BinarySceneLoaderError::UnsupportedVersion(version));
+        }
+        let scene_deserializer = CompactSceneDeserializer {
+            type_registry: registry,
+        };
+        Ok(scene_deserializer.deserialize(&mut postcard::Deserializer::from_bytes(bytes))?)
+    }
 }
 
 /// Serialize a given Rust data structure into rust object notation (ron).
@@ -212,6 +212,8 @@ impl<'w> DynamicSceneBuilder<'w> {
         DynamicScene {
             resources: self.extracted_resources.into_values().collect(),
             entities: self.extracted_scene.into_values().collect(),
+            instances: Vec::new(),
+            entity_references: Default::default(),
         }
     }
 
@@ -0,0 +1,306 @@
+use core::{any::TypeId, fmt};
+
+use crate::{DynamicEntity, DynamicScene, SceneSpawnError};
+use bevy_ecs::{
+    component::Component,
+    entity::{hash_map::EntityHashMap, Entity, EntityMapper},
+    hierarchy::ChildOf,
+    name::Name,
+    reflect::ReflectComponent,
+};
+use bevy_platform_support::collections::HashMap;
+use bevy_reflect::{std_traits::ReflectDefault, FromReflect, Reflect};
+use uuid::Uuid;
+
+/// A persistent identifier of an entity.
+///
+/// Scenes serialized with [`SceneEntityReferences::Uuid`] refer to entities with this component by their UUID
+/// instead of their raw [`Entity`] id.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq, Hash)]
+pub struct EntityUuid(pub Uuid);
+
+impl EntityUuid {
+    /// Create a new random persistent identifier.
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl Default for EntityUuid {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// How a [`SceneSerializer`](crate::serde::SceneSerializer) encodes the references to entities of the scene
+/// found in its components and resources.
+///
+/// Stable references keep scene files readable and mergeable, since they don't depend on the raw [`Entity`] ids
+/// of the world the scene was extracted from.
+/// They are resolved back to the entities of the scene when it is written to a world.
+///
+/// Entities which can't be identified, such as entities without a [`Name`] with [`NamePath`](Self::NamePath),
+/// or with the same path as another entity, are still referred to by their raw id.
+/// Stable references are only used by human-readable formats such as RON.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SceneEntityReferences {
+    /// Refer to entities by their raw [`Entity`] id.
+    #[default]
+    Raw,
+    /// Refer to entities by the path of [`Name`]s from the root of their hierarchy, like `/Level/Door`.
+    NamePath,
+    /// Refer to entities by their [`EntityUuid`].
+    Uuid,
+}
+
+/// A stable identifier of an entity of a scene, see [`SceneEntityReferences`].
+///
+/// Identifiers are formatted as their path, which always starts with `/`, or as their UUID.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SceneEntityId {
+    /// The path of [`Name`]s from the root of the hierarchy to the entity, like `/Level/Door`.
+    Path(String),
+    /// The [`EntityUuid`] of the entity.
+    Uuid(Uuid),
+}
+
+impl SceneEntityId {
+    /// Parse an identifier formatted with [`Display`](fmt::Display).
+    pub fn parse(id: &str) -> Option<Self> {
+        if id.starts_with('/') {
+            Some(Self::Path(id.to_string()))
+        } else {
+            Uuid::parse_str(id).ok().map(Self::Uuid)
+        }
+    }
+}
+
+impl fmt::Display for SceneEntityId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Path(path) => f.write_str(path),
+            Self::Uuid(uuid) => write!(f, "{uuid}"),
+        }
+    }
+}
+
+/// The stable identifiers of the entities of a scene, see [`SceneEntityReferences`].
+#[derive(Debug, Default)]
+pub struct SceneEntityIds {
+    ids: EntityHashMap<SceneEntityId>,
+}
+
+impl SceneEntityIds {
+    /// Identify the entities of `scene` as described by `references`.
+    ///
+    /// The [unresolved references](DynamicScene::entity_references) of the scene keep their identifier.
+    pub fn new(scene: &DynamicScene, references: SceneEntityReferences) -> Self {
+        let mut ids = scene.entity_references.clone();
+        let identifier = SceneIdentifier::new(scene);
+        match references {
+            SceneEntityReferences::Raw => {}
+            SceneEntityReferences::NamePath => ids.extend(
+                identifier
+                    .paths()
+                    .into_iter()
+                    .map(|(entity, path)| (entity, SceneEntityId::Path(path))),
+            ),
+            SceneEntityReferences::Uuid => ids.extend(
+                identifier
+                    .uuids()
+                    .into_iter()
+                    .map(|(entity, uuid)| (entity, SceneEntityId::Uuid(uuid))),
+            ),
+        }
+        Self { ids }
+    }
+
+    /// Returns the stable identifier of an entity of the scene, if it has one.
+    pub fn get(&self, entity: Entity) -> Option<&SceneEntityId> {
+        self.ids.get(&entity)
+    }
+
+    /// Returns `true` if no entity of the scene has a stable identifier.
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+}
+
+/// Placeholder entities standing for the stable identifiers found while deserializing a scene.
+#[derive(Default)]
+pub(crate) struct EntityReferenceTable {
+    placeholders: HashMap<SceneEntityId, Entity>,
+    references: EntityHashMap<SceneEntityId>,
+}
+
+impl EntityReferenceTable {
+    /// Returns the placeholder entity standing for `id`.
+    pub(crate) fn placeholder(&mut self, id: SceneEntityId) -> Entity {
+        if let Some(&entity) = self.placeholders.get(&id) {
+            return entity;
+        }
+        // Placeholders count down from `Entity::PLACEHOLDER`, far above the indices of the entities of a scene.
+        let entity = Entity::from_raw(u32::MAX - 1 - self.placeholders.len() as u32);
+        self.placeholders.insert(id.clone(), entity);
+        self.references.insert(entity, id);
+        entity
+    }
+
+    /// Returns the references of the scene by placeholder entity,
+    /// or the first entity of the scene that is also used as a placeholder.
+    pub(crate) fn into_references(
+        self,
+        entities: &[DynamicEntity],
+    ) -> Result<EntityHashMap<SceneEntityId>, Entity> {
+        match entities
+            .iter()
+            .find(|entity| self.references.contains_key(&entity.entity))
+        {
+            Some(entity) => Err(entity.entity),
+            None => Ok(self.references),
+        }
+    }
+}
+
+/// Map the placeholder entities of the [references](DynamicScene::entity_references) of a scene
+/// to the entities of the scene they identify.
+pub(crate) fn resolve_entity_references(
+    scene: &DynamicScene,
+) -> Result<EntityHashMap<Entity>, SceneSpawnError> {
+    if scene.entity_references.is_empty() {
+        return Ok(EntityHashMap::default());
+    }
+
+    let identifier = SceneIdentifier::new(scene);
+    let paths = identifier
+        .paths()
+        .into_iter()
+        .map(|(entity, path)| (path, entity))
+        .collect::<HashMap<_, _>>();
+    let uuids = identifier
+        .uuids()
+        .into_iter()
+        .map(|(entity, uuid)| (uuid, entity))
+        .collect::<HashMap<_, _>>();
+
+    scene
+        .entity_references
+        .iter()
+        .map(|(&placeholder, id)| {
+            let entity = match id {
+                SceneEntityId::Path(path) => paths.get(path),
+                SceneEntityId::Uuid(uuid) => uuids.get(uuid),
+            };
+            let entity = entity
+                .ok_or_else(|| SceneSpawnError::UnresolvedEntityReference { id: id.to_string() })?;
+            Ok((placeholder, *entity))
+        })
+        .collect()
+}
+
+/// Maps resolved placeholder entities to the entities of the scene, leaving other entities untouched.
+pub(crate) struct ResolvedEntityMapper<'a>(pub(crate) &'a EntityHashMap<Entity>);
+
+impl EntityMapper for ResolvedEntityMapper<'_> {
+    fn map_entity(&mut self, entity: Entity) -> Entity {
+        self.0.get(&entity).copied().unwrap_or(entity)
+    }
+}
+
+/// Computes the stable identifiers of the entities of a scene.
+struct SceneIdentifier<'a> {
+    scene: &'a DynamicScene,
+    names: EntityHashMap<String>,
+    parents: EntityHashMap<Entity>,
+    uuids: EntityHashMap<Uuid>,
+}
+
+impl<'a> SceneIdentifier<'a> {
+    fn new(scene: &'a DynamicScene) -> Self {
+        let mut names = EntityHashMap::default();
+        let mut parents = EntityHashMap::default();
+        let mut uuids = EntityHashMap::default();
+        for entity in &scene.entities {
+            if let Some(name) = component::<Name>(entity) {
+                names.insert(entity.entity, name.as_str().to_string());
+            }
+            if let Some(ChildOf(parent)) = component::<ChildOf>(entity) {
+                parents.insert(entity.entity, parent);
+            }
+            if let Some(EntityUuid(uuid)) = component::<EntityUuid>(entity) {
+                uuids.insert(entity.entity, uuid);
+            }
+        }
+        Self {
+            scene,
+            names,
+            parents,
+            uuids,
+        }
+    }
+
+    /// Returns the unique name paths of the entities of the scene.
+    fn paths(&self) -> EntityHashMap<String> {
+        unique(
+            self.scene
+                .entities
+                .iter()
+                .filter_map(|entity| Some((entity.entity, self.path(entity.entity, 0)?))),
+        )
+    }
+
+    /// Returns the unique UUIDs of the entities of the scene.
+    fn uuids(&self) -> EntityHashMap<Uuid> {
+        unique(self.uuids.iter().map(|(&entity, &uuid)| (entity, uuid)))
+    }
+
+    fn path(&self, entity: Entity, depth: usize) -> Option<String> {
+        // Give up on hierarchy cycles.
+        if depth > self.names.len() {
+            return None;
+        }
+        let name = self.names.get(&entity)?;
+        if name.contains('/') {
+            return None;
+        }
+        let parent_path = match self.parents.get(&entity) {
+            // The parent of the entity may be an unresolved reference itself.
+            Some(parent) => match self.scene.entity_references.get(parent) {
+                Some(SceneEntityId::Path(path)) => path.clone(),
+                Some(SceneEntityId::Uuid(uuid)) => {
+                    let (&parent, _) = self.uuids.iter().find(|(_, other)| *other == uuid)?;
+                    self.path(parent, depth + 1)?
+                }
+                None => self.path(*parent, depth + 1)?,
+            },
+            None => String::new(),
+        };
+        Some(format!("{parent_path}/{name}"))
+    }
+}
+
+/// Returns the component of type `T` of a dynamic entity.
+fn component<T: Component + FromReflect>(entity: &DynamicEntity) -> Option<T> {
+    entity
+        .components
+        .iter()
+        .find(|component| {
+            component
+                .get_represented_type_info()
+                .is_some_and(|info| info.type_id() == TypeId::of::<T>())
+        })
+        .and_then(|component| T::from_reflect(component.as_ref()))
+}
+
+/// Keep the identifiers that identify a single entity.
+fn unique<T: Clone + Eq + core::hash::Hash>(
+    ids: impl Iterator<Item = (Entity, T)>,
+) -> EntityHashMap<T> {
+    let ids = ids.collect::<Vec<_>>();
+    let mut counts = HashMap::<T, usize>::default();
+    for (_, id) in &ids {
+        *counts.entry(id.clone()).or_default() += 1;
+    }
+    ids.into_iter().filter(|(_, id)| counts[id] == 1).collect()
+}
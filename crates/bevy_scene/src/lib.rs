@@ -16,9 +16,13 @@ extern crate alloc;
 mod components;
 mod dynamic_scene;
 mod dynamic_scene_builder;
+mod entity_reference;
 mod scene;
 mod scene_filter;
 mod scene_loader;
+mod scene_overrides;
+#[cfg(feature = "serialize")]
+mod scene_saver;
 mod scene_spawner;
 
 #[cfg(feature = "serialize")]
@@ -31,9 +35,13 @@ use bevy_ecs::schedule::IntoSystemConfigs;
 pub use components::*;
 pub use dynamic_scene::*;
 pub use dynamic_scene_builder::*;
+pub use entity_reference::*;
 pub use scene::*;
 pub use scene_filter::*;
 pub use scene_loader::*;
+pub use scene_overrides::*;
+#[cfg(feature = "serialize")]
+pub use scene_saver::*;
 pub use scene_spawner::*;
 
 /// The scene prelude.
@@ -49,6 +57,8 @@ pub mod prelude {
 
 use bevy_app::prelude::*;
 use bevy_asset::AssetApp;
+#[cfg(feature = "serialize")]
+use bevy_ecs::world::FromWorld;
 
 /// Plugin that provides scene functionality to an [`App`].
 #[derive(Default)]
@@ -60,11 +70,18 @@ impl Plugin for ScenePlugin {
         app.init_asset::<DynamicScene>()
             .init_asset::<Scene>()
             .init_asset_loader::<SceneLoader>()
+            .init_asset_loader::<BinarySceneLoader>()
             .init_resource::<SceneSpawner>()
             .register_type::<SceneRoot>()
             .register_type::<DynamicSceneRoot>()
+            .register_type::<SceneSpawnBudget>()
+            .register_type::<EntityUuid>()
             .add_systems(SpawnScene, (scene_spawner, scene_spawner_system).chain());
 
+        // Scenes are only converted to the binary format when a user opts in, see `SceneBinaryProcessor`.
+        let saver = BinarySceneSaver::from_world(app.world_mut());
+        app.register_asset_processor::<SceneBinaryProcessor>(saver.into());
+
         // Register component hooks for DynamicSceneRoot
         app.world_mut()
             .register_component_hooks::<DynamicSceneRoot>()
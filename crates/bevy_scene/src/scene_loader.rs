@@ -67,3 +67,67 @@ impl AssetLoader for SceneLoader {
         &["scn", "scn.ron"]
     }
 }
+
+/// Magic bytes at the start of scenes serialized with [`DynamicScene::serialize_binary`].
+pub const BINARY_SCENE_MAGIC: [u8; 4] = *b"BSCN";
+/// Version of the binary scene format written by [`DynamicScene::serialize_binary`].
+pub const BINARY_SCENE_VERSION: u8 = 1;
+
+/// Asset loader for a Bevy dynamic scene in the binary format (`.scn.bin`).
+///
+/// The loader handles assets serialized with [`DynamicScene::serialize_binary`],
+/// for example by the [`SceneBinaryProcessor`](crate::SceneBinaryProcessor).
+#[derive(Debug)]
+pub struct BinarySceneLoader {
+    type_registry: TypeRegistryArc,
+}
+
+impl FromWorld for BinarySceneLoader {
+    fn from_world(world: &mut World) -> Self {
+        let type_registry = world.resource::<AppTypeRegistry>();
+        BinarySceneLoader {
+            type_registry: type_registry.0.clone(),
+        }
+    }
+}
+
+/// Possible errors that can be produced by [`BinarySceneLoader`]
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum BinarySceneLoaderError {
+    /// An [IO Error](std::io::Error)
+    #[error("Error while trying to read the scene file: {0}")]
+    Io(#[from] std::io::Error),
+    /// The file doesn't start with [`BINARY_SCENE_MAGIC`] and a format version.
+    #[error("The file is not a binary scene")]
+    InvalidHeader,
+    /// The file was written with an unsupported version of the binary scene format.
+    #[error("Unsupported binary scene format version: {0}")]
+    UnsupportedVersion(u8),
+    /// A [postcard Error](postcard::Error)
+    #[cfg(feature = "serialize")]
+    #[error("Could not parse binary scene: {0}")]
+    Postcard(#[from] postcard::Error),
+}
+
+#[cfg(feature = "serialize")]
+impl AssetLoader for BinarySceneLoader {
+    type Asset = DynamicScene;
+    type Settings = ();
+    type Error = BinarySceneLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        DynamicScene::deserialize_binary(&bytes, &self.type_registry.read())
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["scn.bin"]
+    }
+}
@@ -0,0 +1,564 @@
+use core::any::TypeId;
+
+use crate::{DynamicScene, SceneSpawnError};
+use bevy_asset::AssetPath;
+use bevy_ecs::{
+    entity::{hash_map::EntityHashMap, Entity, EntityMapper},
+    hierarchy::{ChildOf, Children},
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities},
+    world::{EntityRef, World},
+};
+use bevy_platform_support::collections::HashSet;
+use bevy_reflect::{
+    diff::ReflectPatch, PartialReflect, Reflect, ReflectFromReflect, TypeRegistration, TypeRegistry,
+};
+
+/// A scene instance stored in a [`DynamicScene`] as a reference to its source scene and the
+/// [overrides](SceneOverrides) applied on top of it.
+///
+/// Instances are spawned by the [`SceneSpawner`](crate::SceneSpawner) when it spawns the scene containing them,
+/// after loading their source scene through the [`AssetServer`](bevy_asset::AssetServer).
+/// They are not spawned by [`DynamicScene::write_to_world`].
+#[derive(Clone, Debug)]
+pub struct DynamicSceneInstance {
+    /// The entity of the containing scene this instance is spawned as a child of, if any.
+    pub parent: Option<Entity>,
+    /// The asset path of the source scene.
+    pub source: AssetPath<'static>,
+    /// The modifications made to this instance relative to its source scene.
+    pub overrides: SceneOverrides,
+}
+
+/// The modifications made to a scene instance relative to its source scene.
+///
+/// Overrides are recorded by comparing the entities of an instance to the entities of its source [`DynamicScene`]:
+/// - changes to the fields of components present in the source scene are stored as [`ReflectPatch`]es,
+/// - components added to or removed from entities of the source scene are stored as such,
+/// - children added to entities of the source scene are stored with their components and descendants.
+///
+/// Hierarchy components ([`ChildOf`] and [`Children`]) are never recorded as overrides.
+///
+/// The [`SceneSpawner`](crate::SceneSpawner) uses overrides to keep the modifications of
+/// dynamic scene instances when their source scene is reloaded, if it was spawned with overrides,
+/// see [`SceneSpawner::update_spawned_scenes`](crate::SceneSpawner::update_spawned_scenes).
+#[derive(Clone, Debug, Default)]
+pub struct SceneOverrides {
+    /// Overrides of the entities of the source scene.
+    pub entities: Vec<EntityOverrides>,
+}
+
+/// The modifications made to one entity of a scene instance relative to its source scene.
+#[derive(Debug)]
+pub struct EntityOverrides {
+    /// The identifier of the entity in the source scene.
+    pub entity: Entity,
+    /// Patches of the components of the source entity whose value changed, by component type.
+    pub changed_components: Vec<(TypeId, ReflectPatch)>,
+    /// Components added to the entity which are not part of the source entity.
+    pub added_components: Vec<Box<dyn PartialReflect>>,
+    /// Components of the source entity which were removed from the entity.
+    pub removed_components: Vec<TypeId>,
+    /// Entities added as children of the entity.
+    pub added_children: Vec<AddedEntity>,
+}
+
+/// An entity added to a scene instance which is not part of its source scene.
+#[derive(Debug, Default)]
+pub struct AddedEntity {
+    /// The components of the entity.
+    pub components: Vec<Box<dyn PartialReflect>>,
+    /// The children of the entity.
+    pub children: Vec<AddedEntity>,
+}
+
+impl Clone for EntityOverrides {
+    fn clone(&self) -> Self {
+        Self {
+            entity: self.entity,
+            changed_components: self.changed_components.clone(),
+            added_components: clone_components(&self.added_components),
+            removed_components: self.removed_components.clone(),
+            added_children: self.added_children.clone(),
+        }
+    }
+}
+
+impl Clone for AddedEntity {
+    fn clone(&self) -> Self {
+        Self {
+            components: clone_components(&self.components),
+            children: self.children.clone(),
+        }
+    }
+}
+
+impl EntityOverrides {
+    /// Create empty overrides for the entity of a source scene.
+    pub fn new(entity: Entity) -> Self {
+        Self {
+            entity,
+            changed_components: Vec::new(),
+            added_components: Vec::new(),
+            removed_components: Vec::new(),
+            added_children: Vec::new(),
+        }
+    }
+
+    /// Returns `true` if the entity was not modified.
+    pub fn is_empty(&self) -> bool {
+        self.changed_components.is_empty()
+            && self.added_components.is_empty()
+            && self.removed_components.is_empty()
+            && self.added_children.is_empty()
+    }
+}
+
+impl SceneOverrides {
+    /// Returns `true` if no entity of the instance was modified.
+    pub fn is_empty(&self) -> bool {
+        self.entities.iter().all(EntityOverrides::is_empty)
+    }
+
+    /// Record the modifications made to a scene instance spawned from `source`.
+    ///
+    /// `entity_map` maps the entities of `source` to the entities of the instance in `world`,
+    /// like [`InstanceInfo::entity_map`](crate::InstanceInfo::entity_map).
+    /// Components whose types are not registered with [`ReflectComponent`] are ignored.
+    ///
+    /// References to entities of the instance are stored as references to the corresponding entities of `source`.
+    pub fn extract(
+        source: &DynamicScene,
+        world: &World,
+        entity_map: &EntityHashMap<Entity>,
+        type_registry: &TypeRegistry,
+    ) -> Self {
+        let mut reverse_map = entity_map
+            .iter()
+            .map(|(&scene_entity, &entity)| (entity, scene_entity))
+            .collect::<EntityHashMap<_>>();
+        let mut mapper = InstanceEntityMapper(&mut reverse_map);
+
+        let mut entities = Vec::new();
+        for scene_entity in &source.entities {
+            let Some(entity) = entity_map
+                .get(&scene_entity.entity)
+                .and_then(|&entity| world.get_entity(entity).ok())
+            else {
+                continue;
+            };
+
+            let mut overrides = EntityOverrides::new(scene_entity.entity);
+            let mut source_components = HashSet::<TypeId>::default();
+            for component in &scene_entity.components {
+                let Some((type_id, registration, reflect_component)) =
+                    component_registration(component.as_ref(), type_registry)
+                else {
+                    continue;
+                };
+                source_components.insert(type_id);
+                if is_hierarchy_component(type_id) {
+                    continue;
+                }
+
+                let Some(current) = reflect_component.reflect(entity) else {
+                    overrides.removed_components.push(type_id);
+                    continue;
+                };
+                let mut current = clone_component(registration, current);
+                map_component(registration, current.as_mut(), &mut mapper);
+                // Both values have the same type, so diffing can't fail.
+                if let Ok(Some(patch)) = ReflectPatch::diff(component.as_ref(), current.as_ref()) {
+                    overrides.changed_components.push((type_id, patch));
+                }
+            }
+
+            overrides.added_components =
+                extract_components(entity, world, type_registry, &mut mapper)
+                    .into_iter()
+                    .filter(|component| {
+                        component
+                            .get_represented_type_info()
+                            .is_some_and(|info| !source_components.contains(&info.type_id()))
+                    })
+                    .collect();
+
+            if let Some(children) = entity.get::<Children>() {
+                let added_children = children
+                    .iter()
+                    .copied()
+                    .filter(|child| !mapper.0.contains_key(child))
+                    .filter_map(|child| world.get_entity(child).ok())
+                    .collect::<Vec<_>>();
+                overrides.added_children = added_children
+                    .into_iter()
+                    .map(|child| extract_added_entity(child, world, type_registry, &mut mapper))
+                    .collect();
+            }
+
+            if !overrides.is_empty() {
+                entities.push(overrides);
+            }
+        }
+
+        Self { entities }
+    }
+
+    /// Apply these overrides to a scene instance.
+    ///
+    /// `entity_map` maps the entities of the source scene to the entities of the instance in `world`,
+    /// like [`InstanceInfo::entity_map`](crate::InstanceInfo::entity_map).
+    /// Overrides of entities missing from `entity_map` are ignored.
+    ///
+    /// This method will return a [`SceneSpawnError`] if a type either is not registered
+    /// in the provided [`AppTypeRegistry`] resource, or doesn't reflect the
+    /// [`Component`](bevy_ecs::component::Component) trait, or if a patch doesn't match its component.
+    pub fn apply(
+        &self,
+        world: &mut World,
+        entity_map: &EntityHashMap<Entity>,
+        type_registry: &AppTypeRegistry,
+    ) -> Result<(), SceneSpawnError> {
+        self.apply_internal(world, entity_map, type_registry, true)
+    }
+
+    /// Apply these overrides to a scene instance, optionally spawning the added children.
+    ///
+    /// Added children are left out when reapplying overrides to an instance whose source scene was reloaded,
+    /// since they were never despawned.
+    pub(crate) fn apply_internal(
+        &self,
+        world: &mut World,
+        entity_map: &EntityHashMap<Entity>,
+        type_registry: &AppTypeRegistry,
+        spawn_children: bool,
+    ) -> Result<(), SceneSpawnError> {
+        let type_registry = type_registry.read();
+        let mut map = entity_map.clone();
+        let mut reverse_map = entity_map
+            .iter()
+            .map(|(&scene_entity, &entity)| (entity, scene_entity))
+            .collect::<EntityHashMap<_>>();
+
+        for overrides in &self.entities {
+            let Some(&entity) = entity_map.get(&overrides.entity) else {
+                continue;
+            };
+            if world.get_entity(entity).is_err() {
+                continue;
+            }
+
+            for (type_id, patch) in &overrides.changed_components {
+                let registration = type_registry.get(*type_id).ok_or_else(|| {
+                    SceneSpawnError::UnregisteredType {
+                        std_type_name: format!("{type_id:?}"),
+                    }
+                })?;
+                let reflect_component = reflect_component(registration)?;
+                let Some(current) = reflect_component.reflect(world.entity(entity)) else {
+                    continue;
+                };
+
+                let mut component = clone_component(registration, current);
+                map_component(
+                    registration,
+                    component.as_mut(),
+                    &mut InstanceEntityMapper(&mut reverse_map),
+                );
+                patch.apply(component.as_mut()).map_err(|error| {
+                    SceneSpawnError::InvalidOverride {
+                        type_path: registration.type_info().type_path().to_string(),
+                        error,
+                    }
+                })?;
+                map_component(
+                    registration,
+                    component.as_mut(),
+                    &mut InstanceEntityMapper(&mut map),
+                );
+                reflect_component.apply_or_insert(
+                    &mut world.entity_mut(entity),
+                    component.as_ref(),
+                    &type_registry,
+                );
+            }
+
+            insert_components(
+                world,
+                entity,
+                &overrides.added_components,
+                &type_registry,
+                &mut map,
+            )?;
+
+            for type_id in &overrides.removed_components {
+                if let Some(reflect_component) = type_registry
+                    .get(*type_id)
+                    .and_then(TypeRegistration::data::<ReflectComponent>)
+                {
+                    reflect_component.remove(&mut world.entity_mut(entity));
+                }
+            }
+
+            if spawn_children {
+                for child in &overrides.added_children {
+                    spawn_added_entity(world, entity, child, &type_registry, &mut map)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Maps the entities of a scene instance, leaving unknown entities untouched.
+struct InstanceEntityMapper<'a>(&'a mut EntityHashMap<Entity>);
+
+impl EntityMapper for InstanceEntityMapper<'_> {
+    fn map_entity(&mut self, entity: Entity) -> Entity {
+        self.0.get(&entity).copied().unwrap_or(entity)
+    }
+}
+
+pub(crate) fn clone_components(
+    components: &[Box<dyn PartialReflect>],
+) -> Vec<Box<dyn PartialReflect>> {
+    components
+        .iter()
+        .map(|component| component.clone_value())
+        .collect()
+}
+
+fn is_hierarchy_component(type_id: TypeId) -> bool {
+    type_id == TypeId::of::<ChildOf>() || type_id == TypeId::of::<Children>()
+}
+
+fn component_registration<'a>(
+    component: &dyn PartialReflect,
+    type_registry: &'a TypeRegistry,
+) -> Option<(TypeId, &'a TypeRegistration, &'a ReflectComponent)> {
+    let type_id = component.get_represented_type_info()?.type_id();
+    let registration = type_registry.get(type_id)?;
+    Some((
+        type_id,
+        registration,
+        registration.data::<ReflectComponent>()?,
+    ))
+}
+
+fn reflect_component(
+    registration: &TypeRegistration,
+) -> Result<&ReflectComponent, SceneSpawnError> {
+    registration
+        .data::<ReflectComponent>()
+        .ok_or_else(|| SceneSpawnError::UnregisteredComponent {
+            type_path: registration.type_info().type_path().to_string(),
+        })
+}
+
+/// Clone a component, retaining its original type if it can be converted through `FromReflect`.
+fn clone_component(
+    registration: &TypeRegistration,
+    component: &dyn Reflect,
+) -> Box<dyn PartialReflect> {
+    registration
+        .data::<ReflectFromReflect>()
+        .and_then(|fr| fr.from_reflect(component.as_partial_reflect()))
+        .map(PartialReflect::into_partial_reflect)
+        .unwrap_or_else(|| component.clone_value())
+}
+
+fn map_component(
+    registration: &TypeRegistration,
+    component: &mut dyn PartialReflect,
+    mapper: &mut dyn EntityMapper,
+) {
+    if let Some(map_entities) = registration.data::<ReflectMapEntities>() {
+        map_entities.map_entities(component, mapper);
+    }
+}
+
+/// Clone the reflected components of an entity, except for hierarchy components.
+fn extract_components(
+    entity: EntityRef,
+    world: &World,
+    type_registry: &TypeRegistry,
+    mapper: &mut InstanceEntityMapper,
+) -> Vec<Box<dyn PartialReflect>> {
+    entity
+        .archetype()
+        .components()
+        .filter_map(|component_id| {
+            let type_id = world.components().get_info(component_id)?.type_id()?;
+            if is_hierarchy_component(type_id) {
+                return None;
+            }
+            let registration = type_registry.get(type_id)?;
+            let component = registration.data::<ReflectComponent>()?.reflect(entity)?;
+            let mut component = clone_component(registration, component);
+            map_component(registration, component.as_mut(), mapper);
+            Some(component)
+        })
+        .collect()
+}
+
+fn extract_added_entity(
+    entity: EntityRef,
+    world: &World,
+    type_registry: &TypeRegistry,
+    mapper: &mut InstanceEntityMapper,
+) -> AddedEntity {
+    let components = extract_components(entity, world, type_registry, mapper);
+    let children = entity
+        .get::<Children>()
+        .into_iter()
+        .flat_map(|children| children.iter().copied())
+        .filter_map(|child| world.get_entity(child).ok())
+        .map(|child| extract_added_entity(child, world, type_registry, mapper))
+        .collect();
+    AddedEntity {
+        components,
+        children,
+    }
+}
+
+fn insert_components(
+    world: &mut World,
+    entity: Entity,
+    components: &[Box<dyn PartialReflect>],
+    type_registry: &TypeRegistry,
+    entity_map: &mut EntityHashMap<Entity>,
+) -> Result<(), SceneSpawnError> {
+    for component in components {
+        let mut component = component.clone_value();
+        let type_info = component.get_represented_type_info().ok_or_else(|| {
+            SceneSpawnError::NoRepresentedType {
+                type_path: component.reflect_type_path().to_string(),
+            }
+        })?;
+        let registration = type_registry.get(type_info.type_id()).ok_or_else(|| {
+            SceneSpawnError::UnregisteredButReflectedType {
+                type_path: type_info.type_path().to_string(),
+            }
+        })?;
+        let reflect_component = reflect_component(registration)?;
+        map_component(
+            registration,
+            component.as_mut(),
+            &mut InstanceEntityMapper(entity_map),
+        );
+        reflect_component.apply_or_insert(
+            &mut world.entity_mut(entity),
+            component.as_ref(),
+            type_registry,
+        );
+    }
+    Ok(())
+}
+
+fn spawn_added_entity(
+    world: &mut World,
+    parent: Entity,
+    added: &AddedEntity,
+    type_registry: &TypeRegistry,
+    entity_map: &mut EntityHashMap<Entity>,
+) -> Result<(), SceneSpawnError> {
+    let entity = world.spawn_empty().id();
+    insert_components(world, entity, &added.components, type_registry, entity_map)?;
+    world.entity_mut(parent).add_child(entity);
+    for child in &added.children {
+        spawn_added_entity(world, entity, child, type_registry, entity_map)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{
+        component::Component,
+        entity::{hash_map::EntityHashMap, Entity},
+        hierarchy::{ChildOf, Children},
+        reflect::{AppTypeRegistry, ReflectComponent},
+        world::World,
+    };
+    use bevy_reflect::Reflect;
+
+    use super::SceneOverrides;
+    use crate::DynamicScene;
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health {
+        current: u32,
+        max: u32,
+    }
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Speed(f32);
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Marker;
+
+    fn spawn(world: &mut World, scene: &DynamicScene) -> (Entity, EntityHashMap<Entity>) {
+        let mut entity_map = EntityHashMap::default();
+        scene.write_to_world(world, &mut entity_map).unwrap();
+        let entity = *entity_map.values().next().unwrap();
+        (entity, entity_map)
+    }
+
+    #[test]
+    fn extract_and_apply_overrides() {
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        {
+            let mut registry = registry.write();
+            registry.register::<Health>();
+            registry.register::<Speed>();
+            registry.register::<Marker>();
+            registry.register::<ChildOf>();
+            registry.register::<Children>();
+        }
+        world.insert_resource(registry.clone());
+
+        let mut scene_world = World::new();
+        scene_world.insert_resource(registry.clone());
+        scene_world.spawn((
+            Health {
+                current: 10,
+                max: 10,
+            },
+            Speed(1.0),
+        ));
+        let scene = DynamicScene::from_world(&scene_world);
+        let (entity, entity_map) = spawn(&mut world, &scene);
+
+        assert!(SceneOverrides::extract(&scene, &world, &entity_map, &registry.read()).is_empty());
+
+        world.get_mut::<Health>(entity).unwrap().current = 3;
+        world.entity_mut(entity).remove::<Speed>().insert(Marker);
+        world.entity_mut(entity).with_child(Speed(5.0));
+
+        let overrides = SceneOverrides::extract(&scene, &world, &entity_map, &registry.read());
+        let entity_overrides = &overrides.entities[0];
+        assert_eq!(entity_overrides.changed_components.len(), 1);
+        assert_eq!(entity_overrides.added_components.len(), 1);
+        assert_eq!(entity_overrides.removed_components.len(), 1);
+        assert_eq!(entity_overrides.added_children.len(), 1);
+
+        let (other, other_map) = spawn(&mut world, &scene);
+        overrides.apply(&mut world, &other_map, &registry).unwrap();
+
+        assert_eq!(
+            world.get::<Health>(other),
+            Some(&Health {
+                current: 3,
+                max: 10
+            })
+        );
+        assert!(world.get::<Speed>(other).is_none());
+        assert!(world.get::<Marker>(other).is_some());
+        let children = world.get::<Children>(other).unwrap();
+        assert_eq!(world.get::<Speed>(children[0]), Some(&Speed(5.0)));
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{BinarySceneLoader, DynamicScene, SceneLoader};
+use bevy_asset::{
+    io::{AsyncWriteExt, Writer},
+    processor::LoadTransformAndSave,
+    saver::{AssetSaver, SavedAsset},
+    transformer::IdentityAssetTransformer,
+};
+use bevy_ecs::{
+    reflect::AppTypeRegistry,
+    world::{FromWorld, World},
+};
+use bevy_reflect::TypeRegistryArc;
+use thiserror::Error;
+
+/// Asset processor converting dynamic scenes authored in RON (`.scn` / `.scn.ron`) to the binary scene format.
+///
+/// It is registered by the [`ScenePlugin`](crate::ScenePlugin), but isn't used unless selected in the meta file of a scene,
+/// or set as the default processor for RON scenes:
+///
+/// ```
+/// # use bevy_app::App;
+/// # use bevy_asset::AssetApp;
+/// # use bevy_scene::SceneBinaryProcessor;
+/// # let mut app = App::new();
+/// app.set_default_asset_processor::<SceneBinaryProcessor>("scn")
+///     .set_default_asset_processor::<SceneBinaryProcessor>("scn.ron");
+/// ```
+///
+/// Processed scenes are loaded with the [`BinarySceneLoader`].
+pub type SceneBinaryProcessor =
+    LoadTransformAndSave<SceneLoader, IdentityAssetTransformer<DynamicScene>, BinarySceneSaver>;
+
+/// Asset saver for a Bevy dynamic scene in the binary format.
+///
+/// The saver writes assets with [`DynamicScene::serialize_binary`], to be loaded with the [`BinarySceneLoader`].
+#[derive(Debug)]
+pub struct BinarySceneSaver {
+    type_registry: TypeRegistryArc,
+}
+
+impl FromWorld for BinarySceneSaver {
+    fn from_world(world: &mut World) -> Self {
+        let type_registry = world.resource::<AppTypeRegistry>();
+        BinarySceneSaver {
+            type_registry: type_registry.0.clone(),
+        }
+    }
+}
+
+/// Possible errors that can be produced by [`BinarySceneSaver`]
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum BinarySceneSaverError {
+    /// An [IO Error](std::io::Error)
+    #[error("Error while trying to write the scene file: {0}")]
+    Io(#[from] std::io::Error),
+    /// A [postcard Error](postcard::Error)
+    #[error("Could not serialize binary scene: {0}")]
+    Postcard(#[from] postcard::Error),
+}
+
+impl AssetSaver for BinarySceneSaver {
+    type Asset = DynamicScene;
+    type Settings = ();
+    type OutputLoader = BinarySceneLoader;
+    type Error = BinarySceneSaverError;
+
+    async fn save(
+        &self,
+        writer: &mut Writer,
+        asset: SavedAsset<'_, Self::Asset>,
+        _settings: &Self::Settings,
+    ) -> Result<(), Self::Error> {
+        let bytes = asset.serialize_binary(&self.type_registry.read())?;
+        writer.write_all(&bytes).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BinarySceneSaver;
+    use crate::{DynamicScene, ScenePlugin};
+    use bevy_app::{App, TaskPoolPlugin};
+    use bevy_asset::{
+        io::{
+            memory::{Dir, MemoryAssetReader},
+            AssetSource, AssetSourceId,
+        },
+        saver::{AssetSaver, SavedAsset},
+        AssetApp, AssetPlugin, AssetServer, Assets, ErasedLoadedAsset, Handle, LoadedAsset,
+    };
+    use bevy_ecs::{
+        component::Component,
+        reflect::{AppTypeRegistry, ReflectComponent},
+        world::{FromWorld, World},
+    };
+    use bevy_reflect::{FromReflect, Reflect};
+    use bevy_tasks::block_on;
+    use std::path::Path;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    fn load(app: &mut App, path: &'static str) -> DynamicScene {
+        let handle: Handle<DynamicScene> = app.world().resource::<AssetServer>().load(path);
+        for _ in 0..1000 {
+            app.update();
+            if let Some(scene) = app
+                .world_mut()
+                .resource_mut::<Assets<DynamicScene>>()
+                .remove(&handle)
+            {
+                return scene;
+            }
+        }
+        panic!("{path} wasn't loaded");
+    }
+
+    #[test]
+    fn binary_processor_round_trip() {
+        let dir = Dir::default();
+        let mut app = App::new();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build().with_reader({
+                let dir = dir.clone();
+                move || Box::new(MemoryAssetReader { root: dir.clone() })
+            }),
+        )
+        .add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin::default(),
+            ScenePlugin,
+        ))
+        .register_type::<Health>();
+
+        let type_registry = app.world().resource::<AppTypeRegistry>().clone();
+        let mut world = World::new();
+        world.insert_resource(type_registry.clone());
+        world.spawn(Health(42));
+        let scene = DynamicScene::from_world(&world)
+            .serialize(&type_registry.read())
+            .unwrap();
+        dir.insert_asset_text(Path::new("level.scn.ron"), &scene);
+
+        // Save the scene loaded from RON with the saver of the processor, and load the output with its loader.
+        let scene = ErasedLoadedAsset::from(LoadedAsset::from(load(&mut app, "level.scn.ron")));
+        let saver = BinarySceneSaver::from_world(app.world_mut());
+        let mut bytes = Vec::new();
+        block_on(saver.save(&mut bytes, SavedAsset::from_loaded(&scene).unwrap(), &())).unwrap();
+        dir.insert_asset(Path::new("level.scn.bin"), bytes);
+
+        let scene = load(&mut app, "level.scn.bin");
+        let [entity] = &scene.entities[..] else {
+            panic!("the scene should contain one entity");
+        };
+        let health = Health::from_reflect(&*entity.components[0]).unwrap();
+        assert_eq!(health.0, 42);
+    }
+}
@@ -1,5 +1,8 @@
-use crate::{DynamicScene, Scene};
-use bevy_asset::{AssetEvent, AssetId, Assets, Handle};
+use crate::{
+    entity_reference::resolve_entity_references, scene_overrides::clone_components, DynamicEntity,
+    DynamicScene, DynamicSceneInstance, Scene, SceneOverrides, SceneSpawnBudget,
+};
+use bevy_asset::{AssetEvent, AssetId, AssetServer, Assets, Handle};
 use bevy_ecs::{
     entity::{hash_map::EntityHashMap, Entity},
     event::{Event, EventCursor, Events},
@@ -8,8 +11,11 @@ use bevy_ecs::{
     resource::Resource,
     world::{Mut, World},
 };
-use bevy_platform_support::collections::{HashMap, HashSet};
-use bevy_reflect::Reflect;
+use bevy_platform_support::{
+    collections::{HashMap, HashSet},
+    time::Instant,
+};
+use bevy_reflect::{diff::ReflectPatchError, Reflect};
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -37,6 +43,28 @@ pub struct SceneInstanceReady {
 pub struct InstanceInfo {
     /// Mapping of entities from the scene world to the instance world.
     pub entity_map: EntityHashMap<Entity>,
+    /// The scene instances spawned from the [`instances`](DynamicScene::instances) of this instance's scene,
+    /// in the same order, with the handles keeping their source scenes loaded.
+    children: Vec<(InstanceId, Handle<DynamicScene>)>,
+}
+
+impl InstanceInfo {
+    fn new(
+        entity_map: EntityHashMap<Entity>,
+        children: Vec<(InstanceId, Handle<DynamicScene>)>,
+    ) -> Self {
+        Self {
+            entity_map,
+            children,
+        }
+    }
+
+    /// Returns the scene instances spawned from the [`instances`](DynamicScene::instances) of this instance's scene.
+    ///
+    /// They are despawned along with this instance, and updated with it when its scene is modified.
+    pub fn children(&self) -> impl Iterator<Item = InstanceId> + '_ {
+        self.children.iter().map(|(instance_id, _)| *instance_id)
+    }
 }
 
 /// Unique id identifying a scene instance.
@@ -50,6 +78,120 @@ impl InstanceId {
     }
 }
 
+/// Progress of the spawn of a scene instance, see [`SceneSpawner::instance_progress`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct SceneSpawnProgress {
+    /// Number of entities of the scene written to the world.
+    pub spawned_entities: usize,
+    /// Number of entities in the scene.
+    pub total_entities: usize,
+}
+
+impl SceneSpawnProgress {
+    /// Returns the fraction of the entities of the scene written to the world, between `0.0` and `1.0`.
+    pub fn fraction(&self) -> f32 {
+        if self.total_entities == 0 {
+            1.0
+        } else {
+            self.spawned_entities as f32 / self.total_entities as f32
+        }
+    }
+
+    /// Returns `true` if all the entities of the scene were written to the world.
+    pub fn is_complete(&self) -> bool {
+        self.spawned_entities >= self.total_entities
+    }
+}
+
+/// A dynamic scene instance spawned over several frames.
+struct IncrementalSpawn {
+    handle: Handle<DynamicScene>,
+    instance_id: InstanceId,
+    parent: Option<Entity>,
+    budget: SceneSpawnBudget,
+    entity_map: EntityHashMap<Entity>,
+    /// The placeholder entities of the entity references of the scene, resolved to entities of the scene.
+    references: EntityHashMap<Entity>,
+    /// Number of entities of the scene written to the world.
+    spawned: usize,
+    /// Number of entities of the scene, or `None` until the scene is loaded.
+    total: Option<usize>,
+}
+
+impl IncrementalSpawn {
+    fn new(
+        handle: Handle<DynamicScene>,
+        instance_id: InstanceId,
+        parent: Option<Entity>,
+        budget: SceneSpawnBudget,
+    ) -> Self {
+        Self {
+            handle,
+            instance_id,
+            parent,
+            budget,
+            entity_map: EntityHashMap::default(),
+            references: EntityHashMap::default(),
+            spawned: 0,
+            total: None,
+        }
+    }
+
+    /// Write the entities of the scene to the world until the budget of this frame is exhausted.
+    ///
+    /// Returns `true` once every entity and resource of the scene is written.
+    fn step(&mut self, world: &mut World) -> Result<bool, SceneSpawnError> {
+        world.resource_scope(|world, scenes: Mut<Assets<DynamicScene>>| {
+            let Some(scene) = scenes.get(self.handle.id()) else {
+                return Ok(false);
+            };
+            let type_registry = world.resource::<AppTypeRegistry>().clone();
+            let type_registry = type_registry.read();
+
+            // Reserve every entity up front so components can reference entities that aren't written yet.
+            // This is done again if the scene was modified during the spawn.
+            let total = scene.entities.len();
+            if self.total != Some(total) {
+                self.references = resolve_entity_references(scene)?;
+                scene.reserve_entities(world, &mut self.entity_map);
+                self.total = Some(total);
+                self.spawned = self.spawned.min(total);
+            }
+
+            let start = Instant::now();
+            let mut written = 0;
+            while self.spawned < total {
+                // Always write at least one entity so the spawn makes progress.
+                let exhausted = written > 0
+                    && match self.budget {
+                        SceneSpawnBudget::Entities(count) => written >= count,
+                        SceneSpawnBudget::Time(duration) => start.elapsed() >= duration,
+                    };
+                if exhausted {
+                    return Ok(false);
+                }
+                scene.write_entities_with(
+                    self.spawned..self.spawned + 1,
+                    world,
+                    &mut self.entity_map,
+                    &type_registry,
+                    &self.references,
+                )?;
+                self.spawned += 1;
+                written += 1;
+            }
+
+            scene.write_resources_with(
+                world,
+                &mut self.entity_map,
+                &type_registry,
+                &self.references,
+            )?;
+            Ok(true)
+        })
+    }
+}
+
 /// Handles spawning and despawning scenes in the world, either synchronously or batched through the [`scene_spawner_system`].
 ///
 /// Synchronous methods: (Scene operations will take effect immediately)
@@ -67,6 +209,8 @@ impl InstanceId {
 /// - [`spawn_dynamic_as_child`](Self::spawn_dynamic_as_child)
 /// - [`spawn`](Self::spawn)
 /// - [`spawn_as_child`](Self::spawn_as_child)
+/// - [`spawn_dynamic_incremental`](Self::spawn_dynamic_incremental)
+/// - [`spawn_dynamic_as_child_incremental`](Self::spawn_dynamic_as_child_incremental)
 /// - [`despawn`](Self::despawn)
 /// - [`despawn_instance`](Self::despawn_instance)
 #[derive(Default, Resource)]
@@ -79,6 +223,10 @@ pub struct SceneSpawner {
     scenes_to_despawn: Vec<AssetId<DynamicScene>>,
     instances_to_despawn: Vec<InstanceId>,
     scenes_with_parent: Vec<(InstanceId, Entity)>,
+    pending_overrides: HashMap<InstanceId, SceneOverrides>,
+    /// Copies of the dynamic scenes spawned with overrides, as they were when their instances were last updated.
+    dynamic_scene_snapshots: HashMap<AssetId<DynamicScene>, DynamicScene>,
+    incremental_spawns: Vec<IncrementalSpawn>,
 }
 
 /// Errors that can occur when spawning a scene.
@@ -133,6 +281,35 @@ pub enum SceneSpawnError {
         /// Id of the non-existent scene.
         id: AssetId<Scene>,
     },
+    /// Dynamic scene instance with the given id does not exist.
+    #[error("dynamic scene instance does not exist")]
+    NonExistentInstance {
+        /// Id of the non-existent instance.
+        id: InstanceId,
+    },
+    /// An override could not be applied to a component of a scene instance.
+    #[error("could not apply the override of component `{type_path}`: {error}")]
+    InvalidOverride {
+        /// Type of the overridden component.
+        type_path: String,
+        /// The error that occurred while applying the override.
+        error: ReflectPatchError,
+    },
+    /// The source scene of a scene instance was not loaded from an asset path.
+    #[error("the source scene of the instance has no asset path")]
+    NoSourcePath {
+        /// Id of the source scene.
+        id: AssetId<DynamicScene>,
+    },
+    /// The world has no [`AssetServer`] to load the source scenes of scene instances with.
+    #[error("scene instances require an `AssetServer` resource to load their source scene")]
+    MissingAssetServer,
+    /// Scene refers to an entity by a stable identifier which doesn't identify any of its entities.
+    #[error("scene refers to the entity `{id}` which it doesn't contain")]
+    UnresolvedEntityReference {
+        /// The stable identifier of the entity.
+        id: String,
+    },
 }
 
 impl SceneSpawner {
@@ -157,6 +334,72 @@ impl SceneSpawner {
         instance_id
     }
 
+    /// Schedule the spawn of a new instance of the provided dynamic scene, with `overrides` applied on top of it.
+    ///
+    /// Once the instances of a scene are spawned with non-empty overrides, the modifications made to them are kept
+    /// when the scene is [updated](Self::update_spawned_scenes).
+    pub fn spawn_dynamic_with_overrides(
+        &mut self,
+        id: impl Into<Handle<DynamicScene>>,
+        overrides: SceneOverrides,
+    ) -> InstanceId {
+        let instance_id = self.spawn_dynamic(id);
+        self.pending_overrides.insert(instance_id, overrides);
+        instance_id
+    }
+
+    /// Schedule the spawn of a new instance of the provided dynamic scene as a child of `parent`,
+    /// with `overrides` applied on top of it.
+    pub fn spawn_dynamic_as_child_with_overrides(
+        &mut self,
+        id: impl Into<Handle<DynamicScene>>,
+        overrides: SceneOverrides,
+        parent: Entity,
+    ) -> InstanceId {
+        let instance_id = self.spawn_dynamic_as_child(id, parent);
+        self.pending_overrides.insert(instance_id, overrides);
+        instance_id
+    }
+
+    /// Schedule the spawn of a new instance of the provided dynamic scene over several frames,
+    /// writing entities to the world until the `budget` of each frame is exhausted.
+    ///
+    /// The [`SceneInstanceReady`] event is only triggered once the whole scene is spawned.
+    /// Use [`instance_progress`](Self::instance_progress) to follow the spawn.
+    /// If the scene can't be written to the world, the spawn is cancelled and the entities it already spawned
+    /// are despawned.
+    pub fn spawn_dynamic_incremental(
+        &mut self,
+        id: impl Into<Handle<DynamicScene>>,
+        budget: SceneSpawnBudget,
+    ) -> InstanceId {
+        let instance_id = InstanceId::new();
+        self.incremental_spawns
+            .push(IncrementalSpawn::new(id.into(), instance_id, None, budget));
+        instance_id
+    }
+
+    /// Schedule the spawn of a new instance of the provided dynamic scene as a child of `parent` over several frames,
+    /// writing entities to the world until the `budget` of each frame is exhausted.
+    ///
+    /// See [`spawn_dynamic_incremental`](Self::spawn_dynamic_incremental).
+    pub fn spawn_dynamic_as_child_incremental(
+        &mut self,
+        id: impl Into<Handle<DynamicScene>>,
+        parent: Entity,
+        budget: SceneSpawnBudget,
+    ) -> InstanceId {
+        let instance_id = InstanceId::new();
+        self.incremental_spawns.push(IncrementalSpawn::new(
+            id.into(),
+            instance_id,
+            Some(parent),
+            budget,
+        ));
+        self.scenes_with_parent.push((instance_id, parent));
+        instance_id
+    }
+
     /// Schedule the spawn of a new instance of the provided scene.
     pub fn spawn(&mut self, id: impl Into<Handle<Scene>>) -> InstanceId {
         let instance_id = InstanceId::new();
@@ -189,23 +432,58 @@ impl SceneSpawner {
         world: &mut World,
         id: impl Into<AssetId<DynamicScene>>,
     ) -> Result<(), SceneSpawnError> {
-        if let Some(instance_ids) = self.spawned_dynamic_scenes.remove(&id.into()) {
+        let id = id.into();
+        self.dynamic_scene_snapshots.remove(&id);
+        if let Some(instance_ids) = self.spawned_dynamic_scenes.remove(&id) {
             for instance_id in instance_ids {
                 self.despawn_instance_sync(world, &instance_id);
             }
         }
+        let incremental_instances = self
+            .incremental_spawns
+            .iter()
+            .filter(|spawn| spawn.handle.id() == id)
+            .map(|spawn| spawn.instance_id)
+            .collect::<Vec<_>>();
+        for instance_id in incremental_instances {
+            self.despawn_instance_sync(world, &instance_id);
+        }
         Ok(())
     }
 
     /// Immediately despawns a scene instance, removing all its entities from the world.
+    ///
+    /// Instances being [spawned incrementally](Self::spawn_dynamic_incremental) are cancelled,
+    /// and the entities they already spawned are removed.
     pub fn despawn_instance_sync(&mut self, world: &mut World, instance_id: &InstanceId) {
-        if let Some(instance) = self.spawned_instances.remove(instance_id) {
-            for &entity in instance.entity_map.values() {
+        // Instances waiting for their scene to load are cancelled.
+        self.dynamic_scenes_to_spawn
+            .retain(|(_, instance, _)| instance != instance_id);
+        self.scenes_with_parent
+            .retain(|(instance, _)| instance != instance_id);
+        self.pending_overrides.remove(instance_id);
+
+        let (entity_map, children) = match self.spawned_instances.remove(instance_id) {
+            Some(instance) => (Some(instance.entity_map), instance.children),
+            None => {
+                let entity_map = self
+                    .incremental_spawns
+                    .iter()
+                    .position(|spawn| spawn.instance_id == *instance_id)
+                    .map(|index| self.incremental_spawns.remove(index).entity_map);
+                (entity_map, Vec::new())
+            }
+        };
+        if let Some(entity_map) = entity_map {
+            for &entity in entity_map.values() {
                 if let Ok(entity_mut) = world.get_entity_mut(entity) {
                     entity_mut.despawn();
                 };
             }
         }
+        for (child, _) in children {
+            self.despawn_nested_instance(world, child);
+        }
     }
 
     /// Immediately spawns a new instance of the provided dynamic scene.
@@ -216,10 +494,10 @@ impl SceneSpawner {
     ) -> Result<InstanceId, SceneSpawnError> {
         let mut entity_map = EntityHashMap::default();
         let id = id.into();
-        Self::spawn_dynamic_internal(world, id, &mut entity_map)?;
         let instance_id = InstanceId::new();
+        let children = self.spawn_dynamic_instance(world, id, instance_id, &mut entity_map)?;
         self.spawned_instances
-            .insert(instance_id, InstanceInfo { entity_map });
+            .insert(instance_id, InstanceInfo::new(entity_map, children));
         let spawned = self.spawned_dynamic_scenes.entry(id).or_default();
         spawned.insert(instance_id);
         Ok(instance_id)
@@ -239,6 +517,113 @@ impl SceneSpawner {
         })
     }
 
+    /// Spawns an instance of a dynamic scene, applies its pending overrides,
+    /// and schedules the spawn of the scene instances it contains, returning their ids.
+    fn spawn_dynamic_instance(
+        &mut self,
+        world: &mut World,
+        id: AssetId<DynamicScene>,
+        instance_id: InstanceId,
+        entity_map: &mut EntityHashMap<Entity>,
+    ) -> Result<Vec<(InstanceId, Handle<DynamicScene>)>, SceneSpawnError> {
+        Self::spawn_dynamic_internal(world, id, entity_map)?;
+        self.finish_dynamic_instance(world, id, instance_id, entity_map)
+    }
+
+    /// Applies the pending overrides of a spawned dynamic scene instance,
+    /// and schedules the spawn of the scene instances it contains, returning their ids.
+    fn finish_dynamic_instance(
+        &mut self,
+        world: &mut World,
+        id: AssetId<DynamicScene>,
+        instance_id: InstanceId,
+        entity_map: &mut EntityHashMap<Entity>,
+    ) -> Result<Vec<(InstanceId, Handle<DynamicScene>)>, SceneSpawnError> {
+        if let Some(overrides) = self.pending_overrides.remove(&instance_id) {
+            if !overrides.is_empty() && !self.dynamic_scene_snapshots.contains_key(&id) {
+                let scene = world
+                    .resource::<Assets<DynamicScene>>()
+                    .get(id)
+                    .ok_or(SceneSpawnError::NonExistentScene { id })?;
+                self.dynamic_scene_snapshots.insert(id, snapshot(scene));
+            }
+            let type_registry = world.resource::<AppTypeRegistry>().clone();
+            overrides.apply(world, entity_map, &type_registry)?;
+        }
+
+        let children = nested_instances(world, id, entity_map)?
+            .into_iter()
+            .map(|(handle, overrides, parent)| {
+                let child = self.spawn_nested_instance(handle.clone(), overrides, parent);
+                (child, handle)
+            })
+            .collect();
+        Ok(children)
+    }
+
+    fn spawn_nested_instance(
+        &mut self,
+        handle: Handle<DynamicScene>,
+        overrides: SceneOverrides,
+        parent: Option<Entity>,
+    ) -> InstanceId {
+        match parent {
+            Some(parent) => self.spawn_dynamic_as_child_with_overrides(handle, overrides, parent),
+            None => self.spawn_dynamic_with_overrides(handle, overrides),
+        }
+    }
+
+    /// Updates the nested instances of a dynamic scene instance after its scene was modified.
+    ///
+    /// A nested instance is kept if its source scene is unchanged, with the overrides of the modified scene applied
+    /// on top of it. Other nested instances are despawned and replaced.
+    fn update_nested_instances(
+        &mut self,
+        world: &mut World,
+        id: AssetId<DynamicScene>,
+        instance: &mut InstanceInfo,
+    ) -> Result<(), SceneSpawnError> {
+        let type_registry = world.resource::<AppTypeRegistry>().clone();
+        let mut previous = core::mem::take(&mut instance.children).into_iter();
+        for (handle, overrides, parent) in nested_instances(world, id, &instance.entity_map)? {
+            let child = match previous.next() {
+                Some((child, previous_handle)) if previous_handle == handle => {
+                    match self.spawned_instances.get(&child) {
+                        Some(child) => {
+                            overrides.apply_internal(
+                                world,
+                                &child.entity_map,
+                                &type_registry,
+                                false,
+                            )?;
+                        }
+                        None => {
+                            self.pending_overrides.insert(child, overrides);
+                        }
+                    }
+                    child
+                }
+                Some((child, _)) => {
+                    self.despawn_nested_instance(world, child);
+                    self.spawn_nested_instance(handle.clone(), overrides, parent)
+                }
+                None => self.spawn_nested_instance(handle.clone(), overrides, parent),
+            };
+            instance.children.push((child, handle));
+        }
+        for (child, _) in previous {
+            self.despawn_nested_instance(world, child);
+        }
+        Ok(())
+    }
+
+    fn despawn_nested_instance(&mut self, world: &mut World, instance_id: InstanceId) {
+        for instances in self.spawned_dynamic_scenes.values_mut() {
+            instances.remove(&instance_id);
+        }
+        self.despawn_instance_sync(world, &instance_id);
+    }
+
     /// Immediately spawns a new instance of the provided scene.
     pub fn spawn_sync(
         &mut self,
@@ -250,7 +635,7 @@ impl SceneSpawner {
         Self::spawn_sync_internal(world, id, &mut entity_map)?;
         let instance_id = InstanceId::new();
         self.spawned_instances
-            .insert(instance_id, InstanceInfo { entity_map });
+            .insert(instance_id, InstanceInfo::new(entity_map, Vec::new()));
         Ok(instance_id)
     }
 
@@ -275,23 +660,129 @@ impl SceneSpawner {
     /// Iterate through all instances of the provided scenes and update those immediately.
     ///
     /// Useful for updating already spawned scene instances after their corresponding scene has been modified.
+    /// If the scene was spawned [with overrides](Self::spawn_dynamic_with_overrides), the
+    /// [overrides](Self::instance_overrides) of each of its instances are kept. Instances of other scenes are reset
+    /// to the modified scene.
     pub fn update_spawned_scenes(
         &mut self,
         world: &mut World,
         scene_ids: &[AssetId<DynamicScene>],
     ) -> Result<(), SceneSpawnError> {
+        let type_registry = world.resource::<AppTypeRegistry>().clone();
         for id in scene_ids {
-            if let Some(spawned_instances) = self.spawned_dynamic_scenes.get(id) {
-                for instance_id in spawned_instances {
-                    if let Some(instance_info) = self.spawned_instances.get_mut(instance_id) {
-                        Self::spawn_dynamic_internal(world, *id, &mut instance_info.entity_map)?;
-                    }
+            let instance_ids = self
+                .spawned_dynamic_scenes
+                .get(id)
+                .map(|instances| instances.iter().copied().collect::<Vec<_>>())
+                .unwrap_or_default();
+            for instance_id in instance_ids {
+                let Some(mut instance_info) = self.spawned_instances.remove(&instance_id) else {
+                    continue;
+                };
+                // Overrides are recorded against the scene as it was when the instance was last updated.
+                let overrides = self.dynamic_scene_snapshots.get(id).map(|source| {
+                    SceneOverrides::extract(
+                        source,
+                        world,
+                        &instance_info.entity_map,
+                        &type_registry.read(),
+                    )
+                });
+                let result =
+                    Self::spawn_dynamic_internal(world, *id, &mut instance_info.entity_map)
+                        .and_then(|()| match overrides {
+                            Some(overrides) => overrides.apply_internal(
+                                world,
+                                &instance_info.entity_map,
+                                &type_registry,
+                                false,
+                            ),
+                            None => Ok(()),
+                        })
+                        .and_then(|()| {
+                            self.update_nested_instances(world, *id, &mut instance_info)
+                        });
+                self.spawned_instances.insert(instance_id, instance_info);
+                result?;
+            }
+            if let Some(scene_snapshot) = self.dynamic_scene_snapshots.get_mut(id) {
+                if let Some(scene) = world.resource::<Assets<DynamicScene>>().get(*id) {
+                    *scene_snapshot = snapshot(scene);
                 }
             }
         }
         Ok(())
     }
 
+    /// Record the modifications made to a dynamic scene instance relative to its source scene.
+    ///
+    /// These overrides are kept when the instance is [updated](Self::update_spawned_scenes)
+    /// after its source scene was modified, for example when it is hot reloaded.
+    pub fn instance_overrides(
+        &self,
+        world: &World,
+        instance_id: InstanceId,
+    ) -> Result<SceneOverrides, SceneSpawnError> {
+        let (_, source) = self.instance_source(world, instance_id)?;
+        let instance = self
+            .spawned_instances
+            .get(&instance_id)
+            .ok_or(SceneSpawnError::NonExistentInstance { id: instance_id })?;
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        Ok(SceneOverrides::extract(
+            source,
+            world,
+            &instance.entity_map,
+            &type_registry,
+        ))
+    }
+
+    /// Describe a dynamic scene instance as the asset path of its source scene and its [overrides](Self::instance_overrides).
+    ///
+    /// The returned instance can be added to a [`DynamicScene`] with [`DynamicScene::with_instances`]
+    /// to serialize it.
+    pub fn dynamic_scene_instance(
+        &self,
+        world: &World,
+        instance_id: InstanceId,
+    ) -> Result<DynamicSceneInstance, SceneSpawnError> {
+        let (id, _) = self.instance_source(world, instance_id)?;
+        let source = world
+            .get_resource::<AssetServer>()
+            .ok_or(SceneSpawnError::MissingAssetServer)?
+            .get_path(id)
+            .ok_or(SceneSpawnError::NoSourcePath { id })?
+            .into_owned();
+        Ok(DynamicSceneInstance {
+            parent: None,
+            source,
+            overrides: self.instance_overrides(world, instance_id)?,
+        })
+    }
+
+    /// Returns the scene a dynamic scene instance was spawned from, as it was when the instance was last updated.
+    fn instance_source<'a>(
+        &'a self,
+        world: &'a World,
+        instance_id: InstanceId,
+    ) -> Result<(AssetId<DynamicScene>, &'a DynamicScene), SceneSpawnError> {
+        let id = self
+            .spawned_dynamic_scenes
+            .iter()
+            .find(|(_, instances)| instances.contains(&instance_id))
+            .map(|(id, _)| *id)
+            .ok_or(SceneSpawnError::NonExistentInstance { id: instance_id })?;
+        // Scenes without a snapshot were written to their instances as they currently are.
+        let source = match self.dynamic_scene_snapshots.get(&id) {
+            Some(source) => source,
+            None => world
+                .resource::<Assets<DynamicScene>>()
+                .get(id)
+                .ok_or(SceneSpawnError::NonExistentScene { id })?,
+        };
+        Ok((id, source))
+    }
+
     /// Immediately despawns all scenes scheduled for despawn by despawning their instances.
     pub fn despawn_queued_scenes(&mut self, world: &mut World) -> Result<(), SceneSpawnError> {
         let scenes_to_despawn = core::mem::take(&mut self.scenes_to_despawn);
@@ -318,10 +809,10 @@ impl SceneSpawner {
         for (handle, instance_id, parent) in scenes_to_spawn {
             let mut entity_map = EntityHashMap::default();
 
-            match Self::spawn_dynamic_internal(world, handle.id(), &mut entity_map) {
-                Ok(_) => {
+            match self.spawn_dynamic_instance(world, handle.id(), instance_id, &mut entity_map) {
+                Ok(children) => {
                     self.spawned_instances
-                        .insert(instance_id, InstanceInfo { entity_map });
+                        .insert(instance_id, InstanceInfo::new(entity_map, children));
                     let spawned = self
                         .spawned_dynamic_scenes
                         .entry(handle.id())
@@ -343,6 +834,62 @@ impl SceneSpawner {
             }
         }
 
+        let incremental_spawns = core::mem::take(&mut self.incremental_spawns);
+        // A failed incremental spawn is cancelled, and the others keep spawning.
+        let mut result = Ok(());
+
+        for mut spawn in incremental_spawns {
+            let instance_id = spawn.instance_id;
+            match spawn.step(world) {
+                Ok(true) => {}
+                Ok(false) => {
+                    self.incremental_spawns.push(spawn);
+                    continue;
+                }
+                Err(err) => {
+                    self.incremental_spawns.push(spawn);
+                    self.despawn_instance_sync(world, &instance_id);
+                    result = result.and(Err(err));
+                    continue;
+                }
+            }
+
+            let IncrementalSpawn {
+                handle,
+                parent,
+                mut entity_map,
+                ..
+            } = spawn;
+            let children = match self.finish_dynamic_instance(
+                world,
+                handle.id(),
+                instance_id,
+                &mut entity_map,
+            ) {
+                Ok(children) => children,
+                Err(err) => {
+                    self.spawned_instances
+                        .insert(instance_id, InstanceInfo::new(entity_map, Vec::new()));
+                    self.despawn_instance_sync(world, &instance_id);
+                    result = result.and(Err(err));
+                    continue;
+                }
+            };
+            self.spawned_instances
+                .insert(instance_id, InstanceInfo::new(entity_map, children));
+            self.spawned_dynamic_scenes
+                .entry(handle.id())
+                .or_default()
+                .insert(instance_id);
+
+            // Scenes with parents need more setup before they are ready.
+            // See `set_scene_instance_parent_sync()`.
+            if parent.is_none() {
+                // Defer via commands otherwise SceneSpawner is not available in the observer.
+                world.commands().trigger(SceneInstanceReady { instance_id });
+            }
+        }
+
         let scenes_to_spawn = core::mem::take(&mut self.scenes_to_spawn);
 
         for (scene_handle, instance_id, parent) in scenes_to_spawn {
@@ -351,7 +898,7 @@ impl SceneSpawner {
             match Self::spawn_sync_internal(world, scene_handle.id(), &mut entity_map) {
                 Ok(_) => {
                     self.spawned_instances
-                        .insert(instance_id, InstanceInfo { entity_map });
+                        .insert(instance_id, InstanceInfo::new(entity_map, Vec::new()));
 
                     // Scenes with parents need more setup before they are ready.
                     // See `set_scene_instance_parent_sync()`.
@@ -368,7 +915,7 @@ impl SceneSpawner {
             }
         }
 
-        Ok(())
+        result
     }
 
     pub(crate) fn set_scene_instance_parent_sync(&mut self, world: &mut World) {
@@ -407,6 +954,30 @@ impl SceneSpawner {
         self.spawned_instances.contains_key(&instance_id)
     }
 
+    /// Get the progress of the spawn of a scene instance.
+    ///
+    /// Instances [spawned incrementally](Self::spawn_dynamic_incremental) report their progress
+    /// once their scene is loaded, and other instances once they are spawned.
+    /// Returns `None` if the spawn of the instance hasn't started.
+    pub fn instance_progress(&self, instance_id: InstanceId) -> Option<SceneSpawnProgress> {
+        if let Some(instance) = self.spawned_instances.get(&instance_id) {
+            let entities = instance.entity_map.len();
+            return Some(SceneSpawnProgress {
+                spawned_entities: entities,
+                total_entities: entities,
+            });
+        }
+        self.incremental_spawns
+            .iter()
+            .find(|spawn| spawn.instance_id == instance_id)
+            .and_then(|spawn| {
+                Some(SceneSpawnProgress {
+                    spawned_entities: spawn.spawned,
+                    total_entities: spawn.total?,
+                })
+            })
+    }
+
     /// Get an iterator over the entities in an instance, once it's spawned.
     ///
     /// Before the scene is spawned, the iterator will be empty. Use [`Self::instance_is_ready`]
@@ -424,6 +995,55 @@ impl SceneSpawner {
     }
 }
 
+/// Returns the source scene, overrides and parent entity of the instances contained in a dynamic scene,
+/// mapping their parent to the entities of the instance described by `entity_map`.
+fn nested_instances(
+    world: &World,
+    id: AssetId<DynamicScene>,
+    entity_map: &EntityHashMap<Entity>,
+) -> Result<Vec<(Handle<DynamicScene>, SceneOverrides, Option<Entity>)>, SceneSpawnError> {
+    let scene = world
+        .resource::<Assets<DynamicScene>>()
+        .get(id)
+        .ok_or(SceneSpawnError::NonExistentScene { id })?;
+    if scene.instances.is_empty() {
+        return Ok(Vec::new());
+    }
+    let asset_server = world
+        .get_resource::<AssetServer>()
+        .ok_or(SceneSpawnError::MissingAssetServer)?;
+    Ok(scene
+        .instances
+        .iter()
+        .map(|instance| {
+            (
+                asset_server.load(instance.source.clone()),
+                instance.overrides.clone(),
+                instance
+                    .parent
+                    .and_then(|parent| entity_map.get(&parent).copied()),
+            )
+        })
+        .collect())
+}
+
+/// Copy the entities of a dynamic scene, to record the overrides of its instances against once it is modified.
+fn snapshot(scene: &DynamicScene) -> DynamicScene {
+    DynamicScene {
+        resources: Vec::new(),
+        entities: scene
+            .entities
+            .iter()
+            .map(|entity| DynamicEntity {
+                entity: entity.entity,
+                components: clone_components(&entity.components),
+            })
+            .collect(),
+        instances: Vec::new(),
+        entity_references: scene.entity_references.clone(),
+    }
+}
+
 /// System that handles scheduled scene instance spawning and despawning through a [`SceneSpawner`].
 pub fn scene_spawner_system(world: &mut World) {
     world.resource_scope(|world, mut scene_spawner: Mut<SceneSpawner>| {
@@ -446,6 +1066,13 @@ pub fn scene_spawner_system(world: &mut World) {
         scene_spawner
             .scenes_to_spawn
             .retain(|(_, instance, _)| !dead_instances.contains(instance));
+        scene_spawner
+            .pending_overrides
+            .retain(|instance, _| !dead_instances.contains(instance));
+        for instance in &dead_instances {
+            // Cancels incremental spawns, removing the entities they already spawned.
+            scene_spawner.despawn_instance_sync(world, instance);
+        }
 
         let scene_asset_events = world.resource::<Events<AssetEvent<DynamicScene>>>();
 
@@ -487,7 +1114,12 @@ pub fn scene_spawner(
         (Changed<SceneRoot>, Without<DynamicSceneRoot>),
     >,
     mut dynamic_scene_to_spawn: Query<
-        (Entity, &DynamicSceneRoot, Option<&mut SceneInstance>),
+        (
+            Entity,
+            &DynamicSceneRoot,
+            Option<&SceneSpawnBudget>,
+            Option<&mut SceneInstance>,
+        ),
         (Changed<DynamicSceneRoot>, Without<SceneRoot>),
     >,
     mut scene_spawner: ResMut<SceneSpawner>,
@@ -501,8 +1133,15 @@ pub fn scene_spawner(
             commands.entity(entity).insert(SceneInstance(new_instance));
         }
     }
-    for (entity, dynamic_scene, instance) in &mut dynamic_scene_to_spawn {
-        let new_instance = scene_spawner.spawn_dynamic_as_child(dynamic_scene.0.clone(), entity);
+    for (entity, dynamic_scene, budget, instance) in &mut dynamic_scene_to_spawn {
+        let new_instance = match budget {
+            Some(&budget) => scene_spawner.spawn_dynamic_as_child_incremental(
+                dynamic_scene.0.clone(),
+                entity,
+                budget,
+            ),
+            None => scene_spawner.spawn_dynamic_as_child(dynamic_scene.0.clone(), entity),
+        };
         if let Some(mut old_instance) = instance {
             scene_spawner.despawn_instance(**old_instance);
             *old_instance = SceneInstance(new_instance);
@@ -529,13 +1168,22 @@ mod tests {
     use crate::{DynamicSceneBuilder, DynamicSceneRoot, ScenePlugin};
 
     use super::*;
+    use crate::{DynamicEntity, DynamicSceneInstance, EntityOverrides, SceneOverrides};
     use crate::{DynamicScene, SceneSpawner};
-    use bevy_app::ScheduleRunnerPlugin;
-    use bevy_asset::Assets;
+    use bevy_app::{ScheduleRunnerPlugin, TaskPoolPlugin};
+    use bevy_asset::{
+        io::{
+            memory::{Dir, MemoryAssetReader},
+            AssetSource, AssetSourceId,
+        },
+        AssetApp, Assets,
+    };
+    use bevy_ecs::hierarchy::ChildOf;
     use bevy_ecs::{
         entity::Entity,
         prelude::{AppTypeRegistry, World},
     };
+    use std::path::Path;
 
     #[derive(Component, Reflect, Default)]
     #[reflect(Component)]
@@ -730,6 +1378,145 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn spawn_dynamic_incremental() {
+        let mut app = setup();
+        app.world_mut()
+            .spawn_batch([ComponentF, ComponentF, ComponentF]);
+
+        // Build scene.
+        let scene = build_dynamic_scene(&mut app);
+        app.world_mut().add_observer(
+            |_: Trigger<SceneInstanceReady>, mut trigger_count: ResMut<TriggerCount>| {
+                trigger_count.0 += 1;
+            },
+        );
+
+        // Spawn scene, two entities per frame.
+        let scene_id = app
+            .world_mut()
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic_incremental(scene, SceneSpawnBudget::Entities(2));
+
+        for spawned_entities in [2, 4, 5] {
+            app.update();
+            let scene_spawner = app.world().resource::<SceneSpawner>();
+            assert_eq!(
+                scene_spawner.instance_progress(scene_id),
+                Some(SceneSpawnProgress {
+                    spawned_entities,
+                    total_entities: 5,
+                })
+            );
+            assert_eq!(
+                scene_spawner.instance_is_ready(scene_id),
+                spawned_entities == 5
+            );
+            assert_eq!(
+                app.world().resource::<TriggerCount>().0,
+                u32::from(spawned_entities == 5)
+            );
+        }
+        assert_eq!(
+            app.world_mut()
+                .query::<&ComponentF>()
+                .iter(app.world())
+                .len(),
+            10
+        );
+    }
+
+    #[test]
+    fn failed_incremental_spawn_is_cancelled() {
+        let mut app = setup();
+        let mut scenes = app.world_mut().resource_mut::<Assets<DynamicScene>>();
+        // `ComponentA` isn't registered, so the second entity of this scene can't be written.
+        let invalid = scenes.add(DynamicScene::new(
+            Vec::new(),
+            vec![
+                DynamicEntity {
+                    entity: Entity::from_raw(0),
+                    components: vec![Box::new(ComponentF)],
+                },
+                DynamicEntity {
+                    entity: Entity::from_raw(1),
+                    components: vec![Box::new(ComponentA { x: 1.0, y: 2.0 })],
+                },
+            ],
+        ));
+        let valid = scenes.add(DynamicScene::new(
+            Vec::new(),
+            vec![DynamicEntity {
+                entity: Entity::from_raw(0),
+                components: vec![Box::new(ComponentF)],
+            }],
+        ));
+        let mut scene_spawner = app.world_mut().resource_mut::<SceneSpawner>();
+        let invalid =
+            scene_spawner.spawn_dynamic_incremental(invalid, SceneSpawnBudget::Entities(10));
+        let valid = scene_spawner.spawn_dynamic_incremental(valid, SceneSpawnBudget::Entities(10));
+
+        let result =
+            app.world_mut()
+                .resource_scope(|world, mut scene_spawner: Mut<SceneSpawner>| {
+                    scene_spawner.spawn_queued_scenes(world)
+                });
+        assert!(matches!(
+            result,
+            Err(SceneSpawnError::UnregisteredButReflectedType { .. })
+        ));
+        let scene_spawner = app.world().resource::<SceneSpawner>();
+        assert!(scene_spawner.instance_is_ready(valid));
+        assert!(!scene_spawner.instance_is_ready(invalid));
+        assert_eq!(scene_spawner.instance_progress(invalid), None);
+        // The entity written by the failed spawn is despawned.
+        assert_eq!(
+            app.world_mut()
+                .query::<&ComponentF>()
+                .iter(app.world())
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn despawn_incremental_instance() {
+        let mut app = setup();
+
+        let scene = build_dynamic_scene(&mut app);
+        let scene_id = app
+            .world_mut()
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic_incremental(scene, SceneSpawnBudget::Entities(1));
+
+        app.update();
+        assert_eq!(
+            app.world_mut()
+                .query::<&ComponentF>()
+                .iter(app.world())
+                .len(),
+            3
+        );
+
+        app.world_mut()
+            .resource_mut::<SceneSpawner>()
+            .despawn_instance(scene_id);
+        app.update();
+
+        assert!(app
+            .world()
+            .resource::<SceneSpawner>()
+            .instance_progress(scene_id)
+            .is_none());
+        assert_eq!(
+            app.world_mut()
+                .query::<&ComponentF>()
+                .iter(app.world())
+                .len(),
+            2
+        );
+    }
+
     #[test]
     fn observe_scene() {
         let mut app = setup();
@@ -861,4 +1648,184 @@ mod tests {
         app.update();
         check(app.world_mut(), 0);
     }
+
+    #[test]
+    fn overrides_are_kept_when_the_scene_is_modified() {
+        let mut app = App::new();
+        app.add_plugins((
+            ScheduleRunnerPlugin::default(),
+            AssetPlugin::default(),
+            ScenePlugin,
+        ))
+        .register_type::<ComponentA>()
+        .register_type::<ComponentF>();
+
+        let mut scene_world = World::new();
+        scene_world.insert_resource(app.world().resource::<AppTypeRegistry>().clone());
+        let source = scene_world.spawn(ComponentA { x: 1.0, y: 2.0 }).id();
+        let mut scenes = app.world_mut().resource_mut::<Assets<DynamicScene>>();
+        let overridden = scenes.add(DynamicScene::from_world(&scene_world));
+        let plain = scenes.add(DynamicScene::from_world(&scene_world));
+
+        let mut overrides = EntityOverrides::new(source);
+        overrides.added_components.push(Box::new(ComponentF));
+        let mut scene_spawner = app.world_mut().resource_mut::<SceneSpawner>();
+        let overridden_instance = scene_spawner.spawn_dynamic_with_overrides(
+            overridden.clone(),
+            SceneOverrides {
+                entities: vec![overrides],
+            },
+        );
+        let plain_instance = scene_spawner.spawn_dynamic(plain.clone());
+        app.update();
+
+        // Only scenes spawned with overrides are copied to record the overrides of their instances against.
+        let scene_spawner = app.world().resource::<SceneSpawner>();
+        assert_eq!(scene_spawner.dynamic_scene_snapshots.len(), 1);
+        let overridden_entity = scene_spawner
+            .iter_instance_entities(overridden_instance)
+            .next()
+            .unwrap();
+        let plain_entity = scene_spawner
+            .iter_instance_entities(plain_instance)
+            .next()
+            .unwrap();
+        for entity in [overridden_entity, plain_entity] {
+            app.world_mut().get_mut::<ComponentA>(entity).unwrap().y = 10.0;
+        }
+
+        let mut scenes = app.world_mut().resource_mut::<Assets<DynamicScene>>();
+        for handle in [&overridden, &plain] {
+            scenes.get_mut(handle).unwrap().entities[0].components =
+                vec![Box::new(ComponentA { x: 5.0, y: 6.0 })];
+        }
+        // The scene spawner reads the modification events of the frame in the next frame.
+        app.update();
+        app.update();
+
+        let world = app.world();
+        let component = world.get::<ComponentA>(overridden_entity).unwrap();
+        assert_eq!((component.x, component.y), (5.0, 10.0));
+        assert!(world.entity(overridden_entity).contains::<ComponentF>());
+        let component = world.get::<ComponentA>(plain_entity).unwrap();
+        assert_eq!((component.x, component.y), (5.0, 6.0));
+    }
+
+    fn run_until(app: &mut App, mut predicate: impl FnMut(&mut World) -> bool) {
+        for _ in 0..1000 {
+            app.update();
+            if predicate(app.world_mut()) {
+                return;
+            }
+        }
+        panic!("ran out of updates waiting for the scene to spawn");
+    }
+
+    fn nested_instance_count(world: &mut World) -> usize {
+        world.query::<&ComponentA>().iter(world).count()
+    }
+
+    #[test]
+    fn nested_instances_follow_their_parent() {
+        let dir = Dir::default();
+        let mut app = App::new();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build().with_reader({
+                let dir = dir.clone();
+                move || Box::new(MemoryAssetReader { root: dir.clone() })
+            }),
+        )
+        .add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin::default(),
+            ScenePlugin,
+        ))
+        .register_type::<ComponentA>()
+        .register_type::<ComponentF>();
+
+        let type_registry = app.world().resource::<AppTypeRegistry>().clone();
+        let mut child_world = World::new();
+        child_world.insert_resource(type_registry.clone());
+        child_world.spawn(ComponentA { x: 1.0, y: 2.0 });
+        let child = DynamicScene::from_world(&child_world)
+            .serialize(&type_registry.read())
+            .unwrap();
+        dir.insert_asset_text(Path::new("child.scn.ron"), &child);
+
+        let root = Entity::from_raw(0);
+        let nested = DynamicSceneInstance {
+            parent: Some(root),
+            source: "child.scn.ron".into(),
+            overrides: SceneOverrides::default(),
+        };
+        let mut scene = DynamicScene::default();
+        scene.entities.push(DynamicEntity {
+            entity: root,
+            components: vec![Box::new(ComponentF)],
+        });
+        scene.instances_mut().push(nested.clone());
+        let handle = app.world().resource::<AssetServer>().add(scene);
+        let instance_id = app
+            .world_mut()
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic(handle.clone());
+
+        let children = |world: &World| {
+            world.resource::<SceneSpawner>().spawned_instances[&instance_id]
+                .children()
+                .collect::<Vec<_>>()
+        };
+        run_until(&mut app, |world| nested_instance_count(world) == 1);
+        let [child_id] = children(app.world())[..] else {
+            panic!("the nested instance wasn't recorded");
+        };
+        let parent = app
+            .world_mut()
+            .query_filtered::<&ChildOf, With<ComponentA>>()
+            .single(app.world())
+            .get();
+        assert!(app.world().entity(parent).contains::<ComponentF>());
+
+        // Reloading the scene keeps its nested instance.
+        let mut scenes = app.world_mut().resource_mut::<Assets<DynamicScene>>();
+        scenes
+            .get_mut(&handle)
+            .unwrap()
+            .instances_mut()
+            .push(DynamicSceneInstance {
+                parent: None,
+                ..nested.clone()
+            });
+        run_until(&mut app, |world| nested_instance_count(world) == 2);
+        let reloaded = children(app.world());
+        assert_eq!(reloaded.len(), 2);
+        assert_eq!(reloaded[0], child_id);
+
+        // Nested instances removed from the scene are despawned.
+        let mut scenes = app.world_mut().resource_mut::<Assets<DynamicScene>>();
+        scenes.get_mut(&handle).unwrap().instances_mut().truncate(1);
+        run_until(&mut app, |world| nested_instance_count(world) == 1);
+        assert_eq!(children(app.world()), [child_id]);
+        assert_eq!(
+            app.world()
+                .resource::<SceneSpawner>()
+                .spawned_instances
+                .len(),
+            2
+        );
+
+        // Nested instances are despawned with their parent instance.
+        app.world_mut()
+            .resource_mut::<SceneSpawner>()
+            .despawn_instance(instance_id);
+        app.update();
+        assert_eq!(nested_instance_count(app.world_mut()), 0);
+        let scene_spawner = app.world().resource::<SceneSpawner>();
+        assert!(scene_spawner.spawned_instances.is_empty());
+        assert!(scene_spawner
+            .spawned_dynamic_scenes
+            .values()
+            .all(|instances| !instances.contains(&child_id)));
+    }
 }
@@ -1,19 +1,26 @@
 //! `serde` serialization and deserialization implementation for Bevy scenes.
 
-use crate::{DynamicEntity, DynamicScene};
+use crate::{
+    entity_reference::EntityReferenceTable, AddedEntity, DynamicEntity, DynamicScene,
+    DynamicSceneInstance, EntityOverrides, SceneEntityId, SceneEntityIds, SceneEntityReferences,
+    SceneOverrides,
+};
+use bevy_asset::AssetPath;
 use bevy_ecs::entity::Entity;
 use bevy_platform_support::collections::HashSet;
 use bevy_reflect::{
+    diff::{ReflectPatch, ReflectPatchDeserializer, ReflectPatchSerializer},
     serde::{
-        ReflectDeserializer, TypeRegistrationDeserializer, TypedReflectDeserializer,
-        TypedReflectSerializer,
+        CompactReflectDeserializer, CompactReflectSerializer, ReflectDeserializer,
+        ReflectDeserializerProcessor, ReflectSerializerProcessor, TypeRegistrationDeserializer,
+        TypedReflectDeserializer, TypedReflectSerializer,
     },
-    PartialReflect, ReflectFromReflect, TypeRegistry,
+    PartialReflect, ReflectFromReflect, TypeRegistration, TypeRegistry,
 };
-use core::fmt::Formatter;
+use core::{any::TypeId, cell::RefCell, fmt::Formatter};
 use serde::{
     de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor},
-    ser::{SerializeMap, SerializeStruct},
+    ser::{SerializeMap, SerializeSeq, SerializeStruct, SerializeTuple},
     Deserialize, Deserializer, Serialize, Serializer,
 };
 
@@ -23,12 +30,39 @@ pub const SCENE_STRUCT: &str = "Scene";
 pub const SCENE_RESOURCES: &str = "resources";
 /// Name of the serialized entities field in a scene struct.
 pub const SCENE_ENTITIES: &str = "entities";
+/// Name of the serialized scene instances field in a scene struct.
+pub const SCENE_INSTANCES: &str = "instances";
 
 /// Name of the serialized entity struct type.
 pub const ENTITY_STRUCT: &str = "Entity";
 /// Name of the serialized component field in an entity struct.
 pub const ENTITY_FIELD_COMPONENTS: &str = "components";
 
+/// Name of the serialized scene instance struct type.
+pub const INSTANCE_STRUCT: &str = "Instance";
+/// Name of the serialized parent field in a scene instance struct.
+pub const INSTANCE_FIELD_PARENT: &str = "parent";
+/// Name of the serialized source scene field in a scene instance struct.
+pub const INSTANCE_FIELD_SOURCE: &str = "source";
+/// Name of the serialized overrides field in a scene instance struct.
+pub const INSTANCE_FIELD_OVERRIDES: &str = "overrides";
+
+/// Name of the serialized entity overrides struct type.
+pub const OVERRIDES_STRUCT: &str = "Overrides";
+/// Name of the serialized changed components field in an entity overrides struct.
+pub const OVERRIDES_FIELD_CHANGED: &str = "changed";
+/// Name of the serialized added components field in an entity overrides struct.
+pub const OVERRIDES_FIELD_ADDED: &str = "added";
+/// Name of the serialized removed components field in an entity overrides struct.
+pub const OVERRIDES_FIELD_REMOVED: &str = "removed";
+/// Name of the serialized added children field in an entity overrides struct.
+pub const OVERRIDES_FIELD_CHILDREN: &str = "children";
+
+/// Name of the serialized added entity struct type.
+pub const ADDED_ENTITY_STRUCT: &str = "AddedEntity";
+/// Name of the serialized children field in an added entity struct.
+pub const ADDED_ENTITY_FIELD_CHILDREN: &str = "children";
+
 /// Serializer for a [`DynamicScene`].
 ///
 /// Helper object defining Bevy's serialize format for a [`DynamicScene`] and implementing
@@ -59,6 +93,8 @@ pub struct SceneSerializer<'a> {
     pub scene: &'a DynamicScene,
     /// The type registry containing the types present in the scene.
     pub registry: &'a TypeRegistry,
+    /// How references to the entities of the scene are serialized.
+    pub entity_references: SceneEntityReferences,
 }
 
 impl<'a> SceneSerializer<'a> {
@@ -69,7 +105,17 @@ impl<'a> SceneSerializer<'a> {
     ///
     /// [`World`]: bevy_ecs::world::World
     pub fn new(scene: &'a DynamicScene, registry: &'a TypeRegistry) -> Self {
-        SceneSerializer { scene, registry }
+        SceneSerializer {
+            scene,
+            registry,
+            entity_references: SceneEntityReferences::Raw,
+        }
+    }
+
+    /// Serialize references to the entities of the scene as described by `entity_references`.
+    pub fn with_entity_references(mut self, entity_references: SceneEntityReferences) -> Self {
+        self.entity_references = entity_references;
+        self
     }
 }
 
@@ -78,12 +124,27 @@ impl<'a> Serialize for SceneSerializer<'a> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct(SCENE_STRUCT, 2)?;
+        // Scenes without instances keep their previous representation. Other formats can't omit trailing fields,
+        // so they keep the two fields they always had, and can't represent instances.
+        let serialize_instances = !self.scene.instances.is_empty();
+        if serialize_instances && !serializer.is_human_readable() {
+            return Err(serde::ser::Error::custom(
+                "scene instances can only be serialized in human-readable formats",
+            ));
+        }
+        // Stable entity references are only readable back by self-describing formats.
+        let entity_ids = serializer
+            .is_human_readable()
+            .then(|| SceneEntityIds::new(self.scene, self.entity_references))
+            .filter(|entity_ids| !entity_ids.is_empty());
+        let mut state =
+            serializer.serialize_struct(SCENE_STRUCT, if serialize_instances { 3 } else { 2 })?;
         state.serialize_field(
             SCENE_RESOURCES,
             &SceneMapSerializer {
                 entries: &self.scene.resources,
                 registry: self.registry,
+                entity_ids: entity_ids.as_ref(),
             },
         )?;
         state.serialize_field(
@@ -91,8 +152,20 @@ impl<'a> Serialize for SceneSerializer<'a> {
             &EntitiesSerializer {
                 entities: &self.scene.entities,
                 registry: self.registry,
+                entity_ids: entity_ids.as_ref(),
             },
         )?;
+        if serialize_instances {
+            state.serialize_field(
+                SCENE_INSTANCES,
+                &SceneInstancesSerializer {
+                    instances: &self.scene.instances,
+                    registry: self.registry,
+                },
+            )?;
+        } else {
+            state.skip_field(SCENE_INSTANCES)?;
+        }
         state.end()
     }
 }
@@ -103,6 +176,25 @@ pub struct EntitiesSerializer<'a> {
     pub entities: &'a [DynamicEntity],
     /// Type registry in which the component types used by the entities are registered.
     pub registry: &'a TypeRegistry,
+    /// Stable identifiers to serialize references to entities with, if any.
+    pub entity_ids: Option<&'a SceneEntityIds>,
+}
+
+impl<'a> EntitiesSerializer<'a> {
+    /// Create a new serializer for `entities`, whose types are registered in `registry`.
+    pub fn new(entities: &'a [DynamicEntity], registry: &'a TypeRegistry) -> Self {
+        Self {
+            entities,
+            registry,
+            entity_ids: None,
+        }
+    }
+
+    /// Serialize the references to entities which have a stable identifier in `entity_ids` with this identifier.
+    pub fn with_entity_ids(mut self, entity_ids: &'a SceneEntityIds) -> Self {
+        self.entity_ids = Some(entity_ids);
+        self
+    }
 }
 
 impl<'a> Serialize for EntitiesSerializer<'a> {
@@ -117,6 +209,7 @@ impl<'a> Serialize for EntitiesSerializer<'a> {
                 &EntitySerializer {
                     entity,
                     registry: self.registry,
+                    entity_ids: self.entity_ids,
                 },
             )?;
         }
@@ -130,6 +223,25 @@ pub struct EntitySerializer<'a> {
     pub entity: &'a DynamicEntity,
     /// Type registry in which the component types used by the entity are registered.
     pub registry: &'a TypeRegistry,
+    /// Stable identifiers to serialize references to entities with, if any.
+    pub entity_ids: Option<&'a SceneEntityIds>,
+}
+
+impl<'a> EntitySerializer<'a> {
+    /// Create a new serializer for `entity`, whose types are registered in `registry`.
+    pub fn new(entity: &'a DynamicEntity, registry: &'a TypeRegistry) -> Self {
+        Self {
+            entity,
+            registry,
+            entity_ids: None,
+        }
+    }
+
+    /// Serialize the references to entities which have a stable identifier in `entity_ids` with this identifier.
+    pub fn with_entity_ids(mut self, entity_ids: &'a SceneEntityIds) -> Self {
+        self.entity_ids = Some(entity_ids);
+        self
+    }
 }
 
 impl<'a> Serialize for EntitySerializer<'a> {
@@ -143,6 +255,7 @@ impl<'a> Serialize for EntitySerializer<'a> {
             &SceneMapSerializer {
                 entries: &self.entity.components,
                 registry: self.registry,
+                entity_ids: self.entity_ids,
             },
         )?;
         state.end()
@@ -161,6 +274,25 @@ pub struct SceneMapSerializer<'a> {
     pub entries: &'a [Box<dyn PartialReflect>],
     /// Type registry in which the types used in `entries` are registered.
     pub registry: &'a TypeRegistry,
+    /// Stable identifiers to serialize references to entities with, if any.
+    pub entity_ids: Option<&'a SceneEntityIds>,
+}
+
+impl<'a> SceneMapSerializer<'a> {
+    /// Create a new serializer for `entries`, whose types are registered in `registry`.
+    pub fn new(entries: &'a [Box<dyn PartialReflect>], registry: &'a TypeRegistry) -> Self {
+        Self {
+            entries,
+            registry,
+            entity_ids: None,
+        }
+    }
+
+    /// Serialize the references to entities which have a stable identifier in `entity_ids` with this identifier.
+    pub fn with_entity_ids(mut self, entity_ids: &'a SceneEntityIds) -> Self {
+        self.entity_ids = Some(entity_ids);
+        self
+    }
 }
 
 impl<'a> Serialize for SceneMapSerializer<'a> {
@@ -185,20 +317,263 @@ impl<'a> Serialize for SceneMapSerializer<'a> {
         };
 
         for (type_path, partial_reflect) in sorted_entries {
+            match self.entity_ids {
+                Some(entity_ids) => state.serialize_entry(
+                    type_path,
+                    &TypedReflectSerializer::with_processor(
+                        partial_reflect,
+                        self.registry,
+                        entity_ids,
+                    ),
+                )?,
+                None => state.serialize_entry(
+                    type_path,
+                    &TypedReflectSerializer::new(partial_reflect, self.registry),
+                )?,
+            }
+        }
+        state.end()
+    }
+}
+
+impl ReflectSerializerProcessor for SceneEntityIds {
+    fn try_serialize<S>(
+        &self,
+        value: &dyn PartialReflect,
+        _registry: &TypeRegistry,
+        serializer: S,
+    ) -> Result<Result<S::Ok, S>, S::Error>
+    where
+        S: Serializer,
+    {
+        let Some(entity) = value.try_downcast_ref::<Entity>() else {
+            return Ok(Err(serializer));
+        };
+        match self.get(*entity) {
+            Some(id) => serializer.collect_str(id).map(Ok),
+            None => Ok(Err(serializer)),
+        }
+    }
+}
+
+/// Handles serialization of scene instances as a list of their source scene and overrides.
+pub struct SceneInstancesSerializer<'a> {
+    /// The scene instances to serialize.
+    pub instances: &'a [DynamicSceneInstance],
+    /// Type registry in which the component types used by the overrides are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for SceneInstancesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.instances.len()))?;
+        for instance in self.instances {
+            state.serialize_element(&SceneInstanceSerializer {
+                instance,
+                registry: self.registry,
+            })?;
+        }
+        state.end()
+    }
+}
+
+/// Handles serialization of a scene instance.
+pub struct SceneInstanceSerializer<'a> {
+    /// The scene instance to serialize.
+    pub instance: &'a DynamicSceneInstance,
+    /// Type registry in which the component types used by the overrides are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for SceneInstanceSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct(INSTANCE_STRUCT, 3)?;
+        state.serialize_field(INSTANCE_FIELD_PARENT, &self.instance.parent)?;
+        state.serialize_field(INSTANCE_FIELD_SOURCE, &self.instance.source)?;
+        state.serialize_field(
+            INSTANCE_FIELD_OVERRIDES,
+            &SceneOverridesSerializer {
+                overrides: &self.instance.overrides,
+                registry: self.registry,
+            },
+        )?;
+        state.end()
+    }
+}
+
+/// Handles serialization of the overrides of a scene instance as a map of source entity id to entity overrides.
+pub struct SceneOverridesSerializer<'a> {
+    /// The overrides to serialize.
+    pub overrides: &'a SceneOverrides,
+    /// Type registry in which the component types used by the overrides are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for SceneOverridesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.overrides.entities.len()))?;
+        for overrides in &self.overrides.entities {
+            state.serialize_entry(
+                &overrides.entity,
+                &EntityOverridesSerializer {
+                    overrides,
+                    registry: self.registry,
+                },
+            )?;
+        }
+        state.end()
+    }
+}
+
+struct EntityOverridesSerializer<'a> {
+    overrides: &'a EntityOverrides,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> EntityOverridesSerializer<'a> {
+    fn type_path<E: serde::ser::Error>(&self, type_id: TypeId) -> Result<&'a str, E> {
+        self.registry
+            .get(type_id)
+            .map(|registration| registration.type_info().type_path())
+            .ok_or_else(|| E::custom(format_args!("unregistered type: `{type_id:?}`")))
+    }
+}
+
+impl<'a> Serialize for EntityOverridesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut changed = self
+            .overrides
+            .changed_components
+            .iter()
+            .map(|(type_id, patch)| Ok((self.type_path(*type_id)?, patch)))
+            .collect::<Result<Vec<_>, S::Error>>()?;
+        changed.sort_by_key(|(type_path, _)| *type_path);
+        let mut removed = self
+            .overrides
+            .removed_components
+            .iter()
+            .map(|type_id| self.type_path(*type_id))
+            .collect::<Result<Vec<_>, S::Error>>()?;
+        removed.sort_unstable();
+
+        let mut state = serializer.serialize_struct(OVERRIDES_STRUCT, 4)?;
+        state.serialize_field(
+            OVERRIDES_FIELD_CHANGED,
+            &ChangedComponentsSerializer {
+                changed: &changed,
+                registry: self.registry,
+            },
+        )?;
+        state.serialize_field(
+            OVERRIDES_FIELD_ADDED,
+            &SceneMapSerializer {
+                entries: &self.overrides.added_components,
+                registry: self.registry,
+                entity_ids: None,
+            },
+        )?;
+        state.serialize_field(OVERRIDES_FIELD_REMOVED, &removed)?;
+        state.serialize_field(
+            OVERRIDES_FIELD_CHILDREN,
+            &AddedEntitiesSerializer {
+                entities: &self.overrides.added_children,
+                registry: self.registry,
+            },
+        )?;
+        state.end()
+    }
+}
+
+struct ChangedComponentsSerializer<'a> {
+    changed: &'a [(&'a str, &'a ReflectPatch)],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for ChangedComponentsSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.changed.len()))?;
+        for (type_path, patch) in self.changed {
             state.serialize_entry(
                 type_path,
-                &TypedReflectSerializer::new(partial_reflect, self.registry),
+                &ReflectPatchSerializer::new(patch, self.registry),
             )?;
         }
         state.end()
     }
 }
 
+struct AddedEntitiesSerializer<'a> {
+    entities: &'a [AddedEntity],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for AddedEntitiesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.entities.len()))?;
+        for entity in self.entities {
+            state.serialize_element(&AddedEntitySerializer {
+                entity,
+                registry: self.registry,
+            })?;
+        }
+        state.end()
+    }
+}
+
+struct AddedEntitySerializer<'a> {
+    entity: &'a AddedEntity,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for AddedEntitySerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct(ADDED_ENTITY_STRUCT, 2)?;
+        state.serialize_field(
+            ENTITY_FIELD_COMPONENTS,
+            &SceneMapSerializer {
+                entries: &self.entity.components,
+                registry: self.registry,
+                entity_ids: None,
+            },
+        )?;
+        state.serialize_field(
+            ADDED_ENTITY_FIELD_CHILDREN,
+            &AddedEntitiesSerializer {
+                entities: &self.entity.children,
+                registry: self.registry,
+            },
+        )?;
+        state.end()
+    }
+}
+
 #[derive(Deserialize)]
 #[serde(field_identifier, rename_all = "lowercase")]
 enum SceneField {
     Resources,
     Entities,
+    Instances,
 }
 
 #[derive(Deserialize)]
@@ -207,6 +582,30 @@ enum EntityField {
     Components,
 }
 
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum InstanceField {
+    Parent,
+    Source,
+    Overrides,
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum OverridesField {
+    Changed,
+    Added,
+    Removed,
+    Children,
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum AddedEntityField {
+    Components,
+    Children,
+}
+
 /// Handles scene deserialization.
 pub struct SceneDeserializer<'a> {
     /// Type registry in which the components and resources types used in the scene to deserialize are registered.
@@ -220,11 +619,20 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneDeserializer<'a> {
     where
         D: Deserializer<'de>,
     {
+        // Only human-readable formats may contain scene instances, see `SceneSerializer`.
+        let instances = deserializer.is_human_readable();
+        let fields: &'static [&'static str] = if instances {
+            &[SCENE_RESOURCES, SCENE_ENTITIES, SCENE_INSTANCES]
+        } else {
+            &[SCENE_RESOURCES, SCENE_ENTITIES]
+        };
         deserializer.deserialize_struct(
             SCENE_STRUCT,
-            &[SCENE_RESOURCES, SCENE_ENTITIES],
+            fields,
             SceneVisitor {
                 type_registry: self.type_registry,
+                instances,
+                entity_references: RefCell::default(),
             },
         )
     }
@@ -232,6 +640,35 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneDeserializer<'a> {
 
 struct SceneVisitor<'a> {
     pub type_registry: &'a TypeRegistry,
+    /// Whether the scene may contain instances after its entities.
+    pub instances: bool,
+    /// Placeholders for the stable entity references found in the scene.
+    pub entity_references: RefCell<EntityReferenceTable>,
+}
+
+impl<'a> SceneVisitor<'a> {
+    fn finish<E: Error>(
+        self,
+        resources: Vec<Box<dyn PartialReflect>>,
+        entities: Vec<DynamicEntity>,
+        instances: Vec<DynamicSceneInstance>,
+    ) -> Result<DynamicScene, E> {
+        let entity_references = self
+            .entity_references
+            .into_inner()
+            .into_references(&entities)
+            .map_err(|entity| {
+                Error::custom(format_args!(
+                    "entity {entity} of the scene collides with the placeholder of an entity reference"
+                ))
+            })?;
+        Ok(DynamicScene {
+            resources,
+            entities,
+            instances,
+            entity_references,
+        })
+    }
 }
 
 impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
@@ -246,21 +683,30 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
         A: SeqAccess<'de>,
     {
         let resources = seq
-            .next_element_seed(SceneMapDeserializer {
+            .next_element_seed(SceneMapVisitor {
                 registry: self.type_registry,
+                entity_references: Some(&self.entity_references),
             })?
             .ok_or_else(|| Error::missing_field(SCENE_RESOURCES))?;
 
         let entities = seq
-            .next_element_seed(SceneEntitiesDeserializer {
+            .next_element_seed(SceneEntitiesVisitor {
                 type_registry: self.type_registry,
+                entity_references: Some(&self.entity_references),
             })?
             .ok_or_else(|| Error::missing_field(SCENE_ENTITIES))?;
 
-        Ok(DynamicScene {
-            resources,
-            entities,
-        })
+        // Scenes without instances may omit them.
+        let instances = if self.instances {
+            seq.next_element_seed(SceneInstancesDeserializer {
+                type_registry: self.type_registry,
+            })?
+            .unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+
+        self.finish(resources, entities, instances)
     }
 
     fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
@@ -269,21 +715,32 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
     {
         let mut resources = None;
         let mut entities = None;
+        let mut instances = None;
         while let Some(key) = map.next_key()? {
             match key {
                 SceneField::Resources => {
                     if resources.is_some() {
                         return Err(Error::duplicate_field(SCENE_RESOURCES));
                     }
-                    resources = Some(map.next_value_seed(SceneMapDeserializer {
+                    resources = Some(map.next_value_seed(SceneMapVisitor {
                         registry: self.type_registry,
+                        entity_references: Some(&self.entity_references),
                     })?);
                 }
                 SceneField::Entities => {
                     if entities.is_some() {
                         return Err(Error::duplicate_field(SCENE_ENTITIES));
                     }
-                    entities = Some(map.next_value_seed(SceneEntitiesDeserializer {
+                    entities = Some(map.next_value_seed(SceneEntitiesVisitor {
+                        type_registry: self.type_registry,
+                        entity_references: Some(&self.entity_references),
+                    })?);
+                }
+                SceneField::Instances => {
+                    if instances.is_some() {
+                        return Err(Error::duplicate_field(SCENE_INSTANCES));
+                    }
+                    instances = Some(map.next_value_seed(SceneInstancesDeserializer {
                         type_registry: self.type_registry,
                     })?);
                 }
@@ -292,11 +749,9 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
 
         let resources = resources.ok_or_else(|| Error::missing_field(SCENE_RESOURCES))?;
         let entities = entities.ok_or_else(|| Error::missing_field(SCENE_ENTITIES))?;
+        let instances = instances.unwrap_or_default();
 
-        Ok(DynamicScene {
-            resources,
-            entities,
-        })
+        self.finish(resources, entities, instances)
     }
 }
 
@@ -313,14 +768,28 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneEntitiesDeserializer<'a> {
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_map(SceneEntitiesVisitor {
+        SceneEntitiesVisitor {
             type_registry: self.type_registry,
-        })
+            entity_references: None,
+        }
+        .deserialize(deserializer)
     }
 }
 
 struct SceneEntitiesVisitor<'a> {
     pub type_registry: &'a TypeRegistry,
+    pub entity_references: Option<&'a RefCell<EntityReferenceTable>>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneEntitiesVisitor<'a> {
+    type Value = Vec<DynamicEntity>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
 }
 
 impl<'a, 'de> Visitor<'de> for SceneEntitiesVisitor<'a> {
@@ -336,9 +805,10 @@ impl<'a, 'de> Visitor<'de> for SceneEntitiesVisitor<'a> {
     {
         let mut entities = Vec::new();
         while let Some(entity) = map.next_key::<Entity>()? {
-            let entity = map.next_value_seed(SceneEntityDeserializer {
+            let entity = map.next_value_seed(SceneEntityVisitor {
                 entity,
-                type_registry: self.type_registry,
+                registry: self.type_registry,
+                entity_references: self.entity_references,
             })?;
             entities.push(entity);
         }
@@ -362,20 +832,30 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneEntityDeserializer<'a> {
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_struct(
-            ENTITY_STRUCT,
-            &[ENTITY_FIELD_COMPONENTS],
-            SceneEntityVisitor {
-                entity: self.entity,
-                registry: self.type_registry,
-            },
-        )
+        SceneEntityVisitor {
+            entity: self.entity,
+            registry: self.type_registry,
+            entity_references: None,
+        }
+        .deserialize(deserializer)
     }
 }
 
 struct SceneEntityVisitor<'a> {
     pub entity: Entity,
     pub registry: &'a TypeRegistry,
+    pub entity_references: Option<&'a RefCell<EntityReferenceTable>>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneEntityVisitor<'a> {
+    type Value = DynamicEntity;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(ENTITY_STRUCT, &[ENTITY_FIELD_COMPONENTS], self)
+    }
 }
 
 impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
@@ -390,8 +870,9 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
         A: SeqAccess<'de>,
     {
         let components = seq
-            .next_element_seed(SceneMapDeserializer {
+            .next_element_seed(SceneMapVisitor {
                 registry: self.registry,
+                entity_references: self.entity_references,
             })?
             .ok_or_else(|| Error::missing_field(ENTITY_FIELD_COMPONENTS))?;
 
@@ -413,8 +894,9 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
                         return Err(Error::duplicate_field(ENTITY_FIELD_COMPONENTS));
                     }
 
-                    components = Some(map.next_value_seed(SceneMapDeserializer {
+                    components = Some(map.next_value_seed(SceneMapVisitor {
                         registry: self.registry,
+                        entity_references: self.entity_references,
                     })?);
                 }
             }
@@ -430,57 +912,551 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
     }
 }
 
-/// Handles deserialization of a sequence of values with unique types.
-pub struct SceneMapDeserializer<'a> {
-    /// Type registry in which the types of the values to deserialize are registered.
-    pub registry: &'a TypeRegistry,
+/// Handles deserialization of a list of scene instances.
+pub struct SceneInstancesDeserializer<'a> {
+    /// Type registry in which the component types used by the overrides to deserialize are registered.
+    pub type_registry: &'a TypeRegistry,
 }
 
-impl<'a, 'de> DeserializeSeed<'de> for SceneMapDeserializer<'a> {
-    type Value = Vec<Box<dyn PartialReflect>>;
+impl<'a, 'de> DeserializeSeed<'de> for SceneInstancesDeserializer<'a> {
+    type Value = Vec<DynamicSceneInstance>;
 
     fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_map(SceneMapVisitor {
-            registry: self.registry,
+        deserializer.deserialize_seq(SceneInstancesVisitor {
+            type_registry: self.type_registry,
         })
     }
 }
 
-struct SceneMapVisitor<'a> {
-    pub registry: &'a TypeRegistry,
+struct SceneInstancesVisitor<'a> {
+    pub type_registry: &'a TypeRegistry,
 }
 
-impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
-    type Value = Vec<Box<dyn PartialReflect>>;
+impl<'a, 'de> Visitor<'de> for SceneInstancesVisitor<'a> {
+    type Value = Vec<DynamicSceneInstance>;
 
     fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
-        formatter.write_str("map of reflect types")
+        formatter.write_str("list of scene instances")
     }
 
     fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
     where
         A: SeqAccess<'de>,
     {
-        let mut dynamic_properties = Vec::new();
-        while let Some(entity) = seq.next_element_seed(ReflectDeserializer::new(self.registry))? {
-            dynamic_properties.push(entity);
+        let mut instances = Vec::new();
+        while let Some(instance) = seq.next_element_seed(SceneInstanceDeserializer {
+            type_registry: self.type_registry,
+        })? {
+            instances.push(instance);
         }
 
-        Ok(dynamic_properties)
+        Ok(instances)
     }
+}
 
-    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+/// Handles deserialization of a scene instance.
+pub struct SceneInstanceDeserializer<'a> {
+    /// Type registry in which the component types used by the overrides to deserialize are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneInstanceDeserializer<'a> {
+    type Value = DynamicSceneInstance;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
     where
-        A: MapAccess<'de>,
+        D: Deserializer<'de>,
     {
-        let mut added = <HashSet<_>>::default();
-        let mut entries = Vec::new();
-        while let Some(registration) =
-            map.next_key_seed(TypeRegistrationDeserializer::new(self.registry))?
-        {
+        deserializer.deserialize_struct(
+            INSTANCE_STRUCT,
+            &[
+                INSTANCE_FIELD_PARENT,
+                INSTANCE_FIELD_SOURCE,
+                INSTANCE_FIELD_OVERRIDES,
+            ],
+            SceneInstanceVisitor {
+                type_registry: self.type_registry,
+            },
+        )
+    }
+}
+
+struct SceneInstanceVisitor<'a> {
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for SceneInstanceVisitor<'a> {
+    type Value = DynamicSceneInstance;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("scene instance struct")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let parent = seq
+            .next_element::<Option<Entity>>()?
+            .ok_or_else(|| Error::missing_field(INSTANCE_FIELD_PARENT))?;
+        let source = seq
+            .next_element::<AssetPath<'static>>()?
+            .ok_or_else(|| Error::missing_field(INSTANCE_FIELD_SOURCE))?;
+        let overrides = seq
+            .next_element_seed(SceneOverridesDeserializer {
+                type_registry: self.type_registry,
+            })?
+            .ok_or_else(|| Error::missing_field(INSTANCE_FIELD_OVERRIDES))?;
+
+        Ok(DynamicSceneInstance {
+            parent,
+            source,
+            overrides,
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut parent = None;
+        let mut source = None;
+        let mut overrides = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                InstanceField::Parent => {
+                    if parent.is_some() {
+                        return Err(Error::duplicate_field(INSTANCE_FIELD_PARENT));
+                    }
+                    parent = Some(map.next_value::<Option<Entity>>()?);
+                }
+                InstanceField::Source => {
+                    if source.is_some() {
+                        return Err(Error::duplicate_field(INSTANCE_FIELD_SOURCE));
+                    }
+                    source = Some(map.next_value::<AssetPath<'static>>()?);
+                }
+                InstanceField::Overrides => {
+                    if overrides.is_some() {
+                        return Err(Error::duplicate_field(INSTANCE_FIELD_OVERRIDES));
+                    }
+                    overrides = Some(map.next_value_seed(SceneOverridesDeserializer {
+                        type_registry: self.type_registry,
+                    })?);
+                }
+            }
+        }
+
+        let source = source.ok_or_else(|| Error::missing_field(INSTANCE_FIELD_SOURCE))?;
+        Ok(DynamicSceneInstance {
+            parent: parent.flatten(),
+            source,
+            overrides: overrides.unwrap_or_default(),
+        })
+    }
+}
+
+/// Handles deserialization of the overrides of a scene instance.
+pub struct SceneOverridesDeserializer<'a> {
+    /// Type registry in which the component types used by the overrides to deserialize are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneOverridesDeserializer<'a> {
+    type Value = SceneOverrides;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(SceneOverridesVisitor {
+            type_registry: self.type_registry,
+        })
+    }
+}
+
+struct SceneOverridesVisitor<'a> {
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for SceneOverridesVisitor<'a> {
+    type Value = SceneOverrides;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("map of entity overrides")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entities = Vec::new();
+        while let Some(entity) = map.next_key::<Entity>()? {
+            let overrides = map.next_value_seed(EntityOverridesDeserializer {
+                entity,
+                type_registry: self.type_registry,
+            })?;
+            entities.push(overrides);
+        }
+
+        Ok(SceneOverrides { entities })
+    }
+}
+
+struct EntityOverridesDeserializer<'a> {
+    entity: Entity,
+    type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for EntityOverridesDeserializer<'a> {
+    type Value = EntityOverrides;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            OVERRIDES_STRUCT,
+            &[
+                OVERRIDES_FIELD_CHANGED,
+                OVERRIDES_FIELD_ADDED,
+                OVERRIDES_FIELD_REMOVED,
+                OVERRIDES_FIELD_CHILDREN,
+            ],
+            self,
+        )
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for EntityOverridesDeserializer<'a> {
+    type Value = EntityOverrides;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("entity overrides struct")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let registry = self.type_registry;
+        let changed_components = seq
+            .next_element_seed(ChangedComponentsDeserializer { registry })?
+            .ok_or_else(|| Error::missing_field(OVERRIDES_FIELD_CHANGED))?;
+        let added_components = seq
+            .next_element_seed(SceneMapDeserializer { registry })?
+            .ok_or_else(|| Error::missing_field(OVERRIDES_FIELD_ADDED))?;
+        let removed_components = seq
+            .next_element_seed(RemovedComponentsDeserializer { registry })?
+            .ok_or_else(|| Error::missing_field(OVERRIDES_FIELD_REMOVED))?;
+        let added_children = seq
+            .next_element_seed(AddedEntitiesDeserializer { registry })?
+            .ok_or_else(|| Error::missing_field(OVERRIDES_FIELD_CHILDREN))?;
+
+        Ok(EntityOverrides {
+            entity: self.entity,
+            changed_components,
+            added_components,
+            removed_components,
+            added_children,
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let registry = self.type_registry;
+        let mut overrides = EntityOverrides::new(self.entity);
+        let mut seen = <HashSet<_>>::default();
+        while let Some(key) = map.next_key::<OverridesField>()? {
+            let field = match key {
+                OverridesField::Changed => {
+                    overrides.changed_components =
+                        map.next_value_seed(ChangedComponentsDeserializer { registry })?;
+                    OVERRIDES_FIELD_CHANGED
+                }
+                OverridesField::Added => {
+                    overrides.added_components =
+                        map.next_value_seed(SceneMapDeserializer { registry })?;
+                    OVERRIDES_FIELD_ADDED
+                }
+                OverridesField::Removed => {
+                    overrides.removed_components =
+                        map.next_value_seed(RemovedComponentsDeserializer { registry })?;
+                    OVERRIDES_FIELD_REMOVED
+                }
+                OverridesField::Children => {
+                    overrides.added_children =
+                        map.next_value_seed(AddedEntitiesDeserializer { registry })?;
+                    OVERRIDES_FIELD_CHILDREN
+                }
+            };
+            if !seen.insert(field) {
+                return Err(Error::duplicate_field(field));
+            }
+        }
+
+        Ok(overrides)
+    }
+}
+
+struct ChangedComponentsDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ChangedComponentsDeserializer<'a> {
+    type Value = Vec<(TypeId, ReflectPatch)>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for ChangedComponentsDeserializer<'a> {
+    type Value = Vec<(TypeId, ReflectPatch)>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("map of component patches")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut changed = Vec::new();
+        while let Some(registration) =
+            map.next_key_seed(TypeRegistrationDeserializer::new(self.registry))?
+        {
+            let patch = map.next_value_seed(ReflectPatchDeserializer::new(self.registry))?;
+            changed.push((registration.type_id(), patch));
+        }
+
+        Ok(changed)
+    }
+}
+
+struct RemovedComponentsDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for RemovedComponentsDeserializer<'a> {
+    type Value = Vec<TypeId>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for RemovedComponentsDeserializer<'a> {
+    type Value = Vec<TypeId>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("list of component types")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut removed = Vec::new();
+        while let Some(registration) =
+            seq.next_element_seed(TypeRegistrationDeserializer::new(self.registry))?
+        {
+            removed.push(registration.type_id());
+        }
+
+        Ok(removed)
+    }
+}
+
+struct AddedEntitiesDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for AddedEntitiesDeserializer<'a> {
+    type Value = Vec<AddedEntity>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for AddedEntitiesDeserializer<'a> {
+    type Value = Vec<AddedEntity>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("list of added entities")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut entities = Vec::new();
+        while let Some(entity) = seq.next_element_seed(AddedEntityDeserializer {
+            registry: self.registry,
+        })? {
+            entities.push(entity);
+        }
+
+        Ok(entities)
+    }
+}
+
+struct AddedEntityDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for AddedEntityDeserializer<'a> {
+    type Value = AddedEntity;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            ADDED_ENTITY_STRUCT,
+            &[ENTITY_FIELD_COMPONENTS, ADDED_ENTITY_FIELD_CHILDREN],
+            self,
+        )
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for AddedEntityDeserializer<'a> {
+    type Value = AddedEntity;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("added entity struct")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let registry = self.registry;
+        let components = seq
+            .next_element_seed(SceneMapDeserializer { registry })?
+            .ok_or_else(|| Error::missing_field(ENTITY_FIELD_COMPONENTS))?;
+        let children = seq
+            .next_element_seed(AddedEntitiesDeserializer { registry })?
+            .ok_or_else(|| Error::missing_field(ADDED_ENTITY_FIELD_CHILDREN))?;
+
+        Ok(AddedEntity {
+            components,
+            children,
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let registry = self.registry;
+        let mut components = None;
+        let mut children = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                AddedEntityField::Components => {
+                    if components.is_some() {
+                        return Err(Error::duplicate_field(ENTITY_FIELD_COMPONENTS));
+                    }
+                    components = Some(map.next_value_seed(SceneMapDeserializer { registry })?);
+                }
+                AddedEntityField::Children => {
+                    if children.is_some() {
+                        return Err(Error::duplicate_field(ADDED_ENTITY_FIELD_CHILDREN));
+                    }
+                    children = Some(map.next_value_seed(AddedEntitiesDeserializer { registry })?);
+                }
+            }
+        }
+
+        let components = components.ok_or_else(|| Error::missing_field(ENTITY_FIELD_COMPONENTS))?;
+        Ok(AddedEntity {
+            components,
+            children: children.unwrap_or_default(),
+        })
+    }
+}
+
+/// Handles deserialization of a sequence of values with unique types.
+pub struct SceneMapDeserializer<'a> {
+    /// Type registry in which the types of the values to deserialize are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneMapDeserializer<'a> {
+    type Value = Vec<Box<dyn PartialReflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        SceneMapVisitor {
+            registry: self.registry,
+            entity_references: None,
+        }
+        .deserialize(deserializer)
+    }
+}
+
+struct SceneMapVisitor<'a> {
+    pub registry: &'a TypeRegistry,
+    pub entity_references: Option<&'a RefCell<EntityReferenceTable>>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneMapVisitor<'a> {
+    type Value = Vec<Box<dyn PartialReflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
+    type Value = Vec<Box<dyn PartialReflect>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("map of reflect types")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut dynamic_properties = Vec::new();
+        while let Some(entity) = seq.next_element_seed(ReflectDeserializer::new(self.registry))? {
+            dynamic_properties.push(entity);
+        }
+
+        Ok(dynamic_properties)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut added = <HashSet<_>>::default();
+        let mut entries = Vec::new();
+        while let Some(registration) =
+            map.next_key_seed(TypeRegistrationDeserializer::new(self.registry))?
+        {
             if !added.insert(registration.type_id()) {
                 return Err(Error::custom(format_args!(
                     "duplicate reflect type: `{}`",
@@ -488,8 +1464,19 @@ impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
                 )));
             }
 
-            let value =
-                map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?;
+            let value = match self.entity_references {
+                Some(entity_references) => {
+                    let mut processor = EntityReferenceProcessor(entity_references);
+                    map.next_value_seed(TypedReflectDeserializer::with_processor(
+                        registration,
+                        self.registry,
+                        &mut processor,
+                    ))?
+                }
+                None => {
+                    map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?
+                }
+            };
 
             // Attempt to convert using FromReflect.
             let value = self
@@ -507,21 +1494,246 @@ impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
     }
 }
 
+/// Deserializes stable entity references to placeholder entities.
+struct EntityReferenceProcessor<'a>(&'a RefCell<EntityReferenceTable>);
+
+impl ReflectDeserializerProcessor for EntityReferenceProcessor<'_> {
+    fn try_deserialize<'de, D>(
+        &mut self,
+        registration: &TypeRegistration,
+        _registry: &TypeRegistry,
+        deserializer: D,
+    ) -> Result<Result<Box<dyn PartialReflect>, D>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if registration.type_id() != TypeId::of::<Entity>() || !deserializer.is_human_readable() {
+            return Ok(Err(deserializer));
+        }
+        let entity = deserializer.deserialize_any(EntityReferenceVisitor(self.0))?;
+        Ok(Ok(Box::new(entity)))
+    }
+}
+
+struct EntityReferenceVisitor<'a>(&'a RefCell<EntityReferenceTable>);
+
+impl<'a, 'de> Visitor<'de> for EntityReferenceVisitor<'a> {
+    type Value = Entity;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("entity id, name path or UUID")
+    }
+
+    fn visit_u64<E>(self, bits: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Entity::try_from_bits(bits).map_err(Error::custom)
+    }
+
+    fn visit_i64<E>(self, bits: i64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        let bits = u64::try_from(bits).map_err(Error::custom)?;
+        self.visit_u64(bits)
+    }
+
+    fn visit_str<E>(self, id: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        let id = SceneEntityId::parse(id).ok_or_else(|| {
+            Error::custom(format_args!("invalid entity name path or UUID: `{id}`"))
+        })?;
+        Ok(self.0.borrow_mut().placeholder(id))
+    }
+}
+
+/// Serializer for a [`DynamicScene`] in the compact layout of the binary scene format,
+/// see [`DynamicScene::serialize_binary`].
+///
+/// The resources and components of the scene are written as a single stream with a [`CompactReflectSerializer`],
+/// which writes each distinct type path once and lays values out positionally.
+/// Like that stream, the output can only be read back by a [`TypeRegistry`] with the same type layouts.
+///
+/// # Output
+///
+/// This serializer outputs a tuple of:
+/// 1. The resources of the scene followed by the components of each entity, as a compact stream of values
+/// 2. The number of resources at the start of the stream
+/// 3. The entities, as a sequence of `(entity, component count)` pairs, in the order of their components
+/// 4. The [entity references](DynamicScene::entity_references) of the scene, as `(entity, identifier)` pairs
+/// 5. The [instances](DynamicScene::instances) of the scene
+pub struct CompactSceneSerializer<'a> {
+    /// The scene to serialize.
+    pub scene: &'a DynamicScene,
+    /// The type registry containing the types present in the scene.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for CompactSceneSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let values = self
+            .scene
+            .resources
+            .iter()
+            .chain(
+                self.scene
+                    .entities
+                    .iter()
+                    .flat_map(|entity| &entity.components),
+            )
+            .map(|value| value.as_partial_reflect());
+        let entities = self
+            .scene
+            .entities
+            .iter()
+            .map(|entity| (entity.entity, entity.components.len()))
+            .collect::<Vec<_>>();
+        let mut entity_references = self
+            .scene
+            .entity_references
+            .iter()
+            .map(|(entity, id)| (*entity, id.to_string()))
+            .collect::<Vec<_>>();
+        entity_references.sort_unstable();
+
+        let mut state = serializer.serialize_tuple(5)?;
+        state.serialize_element(&CompactReflectSerializer::new(values, self.registry))?;
+        state.serialize_element(&self.scene.resources.len())?;
+        state.serialize_element(&entities)?;
+        state.serialize_element(&entity_references)?;
+        state.serialize_element(&SceneInstancesSerializer {
+            instances: &self.scene.instances,
+            registry: self.registry,
+        })?;
+        state.end()
+    }
+}
+
+/// Handles deserialization of a scene serialized with a [`CompactSceneSerializer`].
+pub struct CompactSceneDeserializer<'a> {
+    /// Type registry in which the components and resources types used in the scene to deserialize are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for CompactSceneDeserializer<'a> {
+    type Value = DynamicScene;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(
+            5,
+            CompactSceneVisitor {
+                type_registry: self.type_registry,
+            },
+        )
+    }
+}
+
+struct CompactSceneVisitor<'a> {
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for CompactSceneVisitor<'a> {
+    type Value = DynamicScene;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("compact scene")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let values = seq
+            .next_element_seed(CompactReflectDeserializer::new(self.type_registry))?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let resource_count = seq
+            .next_element::<usize>()?
+            .ok_or_else(|| Error::invalid_length(1, &self))?;
+        let entities = seq
+            .next_element::<Vec<(Entity, usize)>>()?
+            .ok_or_else(|| Error::invalid_length(2, &self))?;
+        let entity_references = seq
+            .next_element::<Vec<(Entity, String)>>()?
+            .ok_or_else(|| Error::invalid_length(3, &self))?;
+        let instances = seq
+            .next_element_seed(SceneInstancesDeserializer {
+                type_registry: self.type_registry,
+            })?
+            .ok_or_else(|| Error::invalid_length(4, &self))?;
+
+        let value_count = resource_count + entities.iter().map(|(_, count)| count).sum::<usize>();
+        if values.len() != value_count {
+            return Err(Error::custom(format_args!(
+                "the scene has {value_count} resources and components, but {} values were found",
+                values.len()
+            )));
+        }
+        let mut values = values.into_iter().map(|value| {
+            // Attempt to convert using FromReflect.
+            value
+                .get_represented_type_info()
+                .and_then(|info| self.type_registry.get(info.type_id()))
+                .and_then(|registration| registration.data::<ReflectFromReflect>())
+                .and_then(|from_reflect| from_reflect.from_reflect(value.as_partial_reflect()))
+                .map(PartialReflect::into_partial_reflect)
+                .unwrap_or(value)
+        });
+        let resources = values.by_ref().take(resource_count).collect();
+        let entities = entities
+            .into_iter()
+            .map(|(entity, count)| DynamicEntity {
+                entity,
+                components: values.by_ref().take(count).collect(),
+            })
+            .collect();
+        let entity_references = entity_references
+            .into_iter()
+            .map(|(entity, id)| {
+                SceneEntityId::parse(&id)
+                    .map(|id| (entity, id))
+                    .ok_or_else(|| Error::custom(format_args!("invalid entity identifier `{id}`")))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(DynamicScene {
+            resources,
+            entities,
+            instances,
+            entity_references,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
         ron,
         serde::{SceneDeserializer, SceneSerializer},
-        DynamicScene, DynamicSceneBuilder,
+        AddedEntity, BinarySceneLoaderError, DynamicScene, DynamicSceneBuilder,
+        DynamicSceneInstance, EntityOverrides, EntityUuid, SceneEntityReferences, SceneOverrides,
+        SceneSpawnError, BINARY_SCENE_MAGIC, BINARY_SCENE_VERSION,
     };
     use bevy_ecs::{
         entity::{hash_map::EntityHashMap, Entity, VisitEntities, VisitEntitiesMut},
+        hierarchy::{ChildOf, Children},
+        name::Name,
         prelude::{Component, ReflectComponent, ReflectResource, Resource, World},
         query::{With, Without},
         reflect::{AppTypeRegistry, ReflectMapEntities},
         world::FromWorld,
     };
-    use bevy_reflect::{Reflect, ReflectDeserialize, ReflectSerialize};
+    use bevy_reflect::{
+        diff::ReflectPatch, Reflect, ReflectDeserialize, ReflectSerialize, TypePath,
+    };
     use bincode::Options;
     use serde::{de::DeserializeSeed, Deserialize, Serialize};
     use std::io::BufReader;
@@ -612,6 +1824,7 @@ mod tests {
             registry.register::<MyEntityRef>();
             registry.register::<Entity>();
             registry.register::<MyResource>();
+            registry.register::<(Name, ChildOf, Children, EntityUuid)>();
         }
         world.insert_resource(registry);
         world
@@ -796,6 +2009,265 @@ mod tests {
         assert_eq!(&qux, world.query::<&Qux>().single(&world));
     }
 
+    fn roundtrip_ron_with_entity_references(
+        world: &World,
+        entity_references: SceneEntityReferences,
+    ) -> (String, DynamicScene) {
+        let scene = DynamicScene::from_world(world);
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let serializer =
+            SceneSerializer::new(&scene, &registry).with_entity_references(entity_references);
+        let serialized = ron::ser::to_string_pretty(&serializer, Default::default()).unwrap();
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let deserialized_scene = SceneDeserializer {
+            type_registry: &registry,
+        }
+        .deserialize(&mut deserializer)
+        .unwrap();
+        (serialized, deserialized_scene)
+    }
+
+    #[test]
+    fn should_roundtrip_name_path_entity_references() {
+        let mut world = create_world();
+        let parent = world.spawn(Name::new("Level")).id();
+        let door = world.spawn((Name::new("Door"), ChildOf(parent))).id();
+        world.spawn((Name::new("Lever"), ChildOf(parent), MyEntityRef(door)));
+
+        let (serialized, deserialized_scene) =
+            roundtrip_ron_with_entity_references(&world, SceneEntityReferences::NamePath);
+        assert!(serialized.contains("\"/Level/Door\""));
+        assert!(!deserialized_scene.entity_references.is_empty());
+
+        let mut dst_world = create_world();
+        deserialized_scene
+            .write_to_world(&mut dst_world, &mut EntityHashMap::default())
+            .unwrap();
+
+        let lever_to_door = dst_world
+            .query::<(&Name, &MyEntityRef)>()
+            .iter(&dst_world)
+            .find(|(name, _)| name.as_str() == "Lever")
+            .map(|(_, entity_ref)| entity_ref.0)
+            .unwrap();
+        assert_eq!(
+            "Door",
+            dst_world.get::<Name>(lever_to_door).unwrap().as_str()
+        );
+        let level = dst_world.get::<ChildOf>(lever_to_door).unwrap().0;
+        assert_eq!("Level", dst_world.get::<Name>(level).unwrap().as_str());
+    }
+
+    #[test]
+    fn should_roundtrip_uuid_entity_references() {
+        let mut world = create_world();
+        let uuid = EntityUuid::new();
+        let target = world.spawn((uuid, Foo(1))).id();
+        world.spawn((MyEntityRef(target), Bar(2)));
+
+        let (serialized, deserialized_scene) =
+            roundtrip_ron_with_entity_references(&world, SceneEntityReferences::Uuid);
+        assert!(serialized.contains(&format!("\"{}\"", uuid.0)));
+
+        let mut dst_world = create_world();
+        deserialized_scene
+            .write_to_world(&mut dst_world, &mut EntityHashMap::default())
+            .unwrap();
+
+        let target_ref = dst_world
+            .query_filtered::<&MyEntityRef, With<Bar>>()
+            .single(&dst_world)
+            .0;
+        assert_eq!(Some(&uuid), dst_world.get::<EntityUuid>(target_ref));
+    }
+
+    #[test]
+    fn should_fail_on_unresolved_entity_reference() {
+        let world = create_world();
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let input = r#"(
+  entities: {
+    4294967296: (
+      components: {
+        "bevy_scene::serde::tests::MyEntityRef": ("/Missing"),
+      },
+    ),
+  },
+  resources: {},
+)"#;
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let scene = SceneDeserializer {
+            type_registry: &registry,
+        }
+        .deserialize(&mut deserializer)
+        .unwrap();
+
+        let mut dst_world = create_world();
+        assert!(matches!(
+            scene.write_to_world(&mut dst_world, &mut EntityHashMap::default()),
+            Err(SceneSpawnError::UnresolvedEntityReference { id }) if id == "/Missing"
+        ));
+    }
+
+    #[test]
+    fn should_fail_on_entity_colliding_with_reference_placeholder() {
+        let world = create_world();
+        let registry = world.resource::<AppTypeRegistry>().read();
+        // The first placeholder is `Entity::from_raw(u32::MAX - 1)`.
+        let input = r#"(
+  entities: {
+    4294967296: (
+      components: {
+        "bevy_scene::serde::tests::MyEntityRef": ("/Door"),
+      },
+    ),
+    8589934590: (
+      components: {},
+    ),
+  },
+  resources: {},
+)"#;
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let Err(error) = (SceneDeserializer {
+            type_registry: &registry,
+        })
+        .deserialize(&mut deserializer) else {
+            panic!("the entity colliding with a placeholder should be rejected");
+        };
+        assert!(error.to_string().contains("collides"));
+    }
+
+    #[test]
+    fn should_roundtrip_instances() {
+        let world = create_world();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<i32>();
+        let registry = world.resource::<AppTypeRegistry>().read();
+
+        let source = Entity::from_raw(0);
+        let overrides = EntityOverrides {
+            changed_components: vec![(
+                core::any::TypeId::of::<Foo>(),
+                ReflectPatch::diff(&Foo(1), &Foo(5)).unwrap().unwrap(),
+            )],
+            added_components: vec![Box::new(Baz(3))],
+            removed_components: vec![core::any::TypeId::of::<Bar>()],
+            added_children: vec![AddedEntity {
+                components: vec![Box::new(Foo(7))],
+                children: Vec::new(),
+            }],
+            ..EntityOverrides::new(source)
+        };
+        let scene = DynamicScene::default().with_instances([DynamicSceneInstance {
+            parent: None,
+            source: "scenes/source.scn.ron".into(),
+            overrides: SceneOverrides {
+                entities: vec![overrides],
+            },
+        }]);
+
+        // Formats which can't omit fields keep the layout of scenes without instances.
+        assert!(postcard::to_allocvec(&SceneSerializer::new(&scene, &registry)).is_err());
+
+        let serialized = scene.serialize(&registry).unwrap();
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let deserialized_scene = SceneDeserializer {
+            type_registry: &registry,
+        }
+        .deserialize(&mut deserializer)
+        .unwrap();
+        assert_instances_eq(&deserialized_scene, source);
+
+        // The binary scene format carries instances as well.
+        let serialized = scene.serialize_binary(&registry).unwrap();
+        let deserialized_scene = DynamicScene::deserialize_binary(&serialized, &registry).unwrap();
+        assert_instances_eq(&deserialized_scene, source);
+    }
+
+    fn assert_instances_eq(deserialized_scene: &DynamicScene, source: Entity) {
+        let [instance] = deserialized_scene.instances() else {
+            panic!("expected 1 scene instance");
+        };
+        assert_eq!(instance.source, "scenes/source.scn.ron".into());
+        let [overrides] = instance.overrides.entities.as_slice() else {
+            panic!("expected overrides of 1 entity");
+        };
+        assert_eq!(overrides.entity, source);
+        assert_eq!(
+            overrides.removed_components,
+            [core::any::TypeId::of::<Bar>()]
+        );
+
+        let mut foo = Foo(1);
+        overrides.changed_components[0].1.apply(&mut foo).unwrap();
+        assert_eq!(foo.0, 5);
+        assert!(overrides.added_components[0]
+            .reflect_partial_eq(&Baz(3))
+            .unwrap());
+        assert!(overrides.added_children[0].components[0]
+            .reflect_partial_eq(&Foo(7))
+            .unwrap());
+    }
+
+    #[test]
+    fn should_roundtrip_binary_scene() {
+        let mut world = create_world();
+
+        world.spawn(MyComponent {
+            foo: [1, 2, 3],
+            bar: (1.3, 3.7),
+            baz: MyEnum::Tuple("Hello World!".to_string()),
+        });
+        world.spawn(MyComponent {
+            foo: [4, 5, 6],
+            bar: (0.5, 1.5),
+            baz: MyEnum::Unit,
+        });
+        world.insert_resource(MyResource { foo: 123 });
+
+        let registry = world.resource::<AppTypeRegistry>();
+        let registry = &registry.read();
+
+        let scene = DynamicSceneBuilder::from_world(&world)
+            .extract_entities(world.iter_entities().map(|entity| entity.id()))
+            .extract_resources()
+            .build();
+
+        let serialized_scene = scene.serialize_binary(registry).unwrap();
+        assert!(serialized_scene.starts_with(&BINARY_SCENE_MAGIC));
+
+        // Each type path is only written once, no matter how many entities use the type.
+        let type_path = MyComponent::type_path().as_bytes();
+        assert_eq!(
+            1,
+            serialized_scene
+                .windows(type_path.len())
+                .filter(|bytes| *bytes == type_path)
+                .count()
+        );
+
+        let deserialized_scene =
+            DynamicScene::deserialize_binary(&serialized_scene, registry).unwrap();
+
+        assert_eq!(1, deserialized_scene.resources.len());
+        assert_eq!(2, deserialized_scene.entities.len());
+        assert_scene_eq(&scene, &deserialized_scene);
+
+        assert!(matches!(
+            DynamicScene::deserialize_binary(&serialized_scene[1..], registry),
+            Err(BinarySceneLoaderError::InvalidHeader)
+        ));
+
+        let mut future_version = serialized_scene.clone();
+        future_version[BINARY_SCENE_MAGIC.len()] = BINARY_SCENE_VERSION + 1;
+        assert!(matches!(
+            DynamicScene::deserialize_binary(&future_version, registry),
+            Err(BinarySceneLoaderError::UnsupportedVersion(version)) if version == BINARY_SCENE_VERSION + 1
+        ));
+    }
+
     #[test]
     fn should_roundtrip_postcard() {
         let mut world = create_world();
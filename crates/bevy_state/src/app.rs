@@ -1,12 +1,14 @@
 use bevy_app::{App, MainScheduleOrder, Plugin, PreStartup, PreUpdate, SubApp};
-use bevy_ecs::{event::Events, schedule::IntoSystemConfigs, world::FromWorld};
+use bevy_ecs::{event::Events, schedule::IntoSystemConfigs, system::IntoSystem, world::FromWorld};
 use bevy_utils::once;
 use log::warn;
 
 use crate::{
     state::{
-        setup_state_transitions_in_world, ComputedStates, FreelyMutableState, NextState, State,
-        StateTransition, StateTransitionEvent, StateTransitionSteps, States, SubStates,
+        register_state_gate_systems, register_state_stack_systems,
+        setup_state_transitions_in_world, ComputedStates, FreelyMutableState, GateStatus,
+        NextState, NextStateStack, State, StateGates, StateStack, StateStackEvent, StateTransition,
+        StateTransitionEvent, StateTransitionSteps, States, SubStates, TransitionGateFailed,
     },
     state_scoped::clear_state_scoped_entities,
 };
@@ -65,6 +67,23 @@ pub trait AppExtStates {
     /// For more information refer to [`StateScoped`](crate::state_scoped::StateScoped).
     fn enable_state_scoped_entities<S: States>(&mut self) -> &mut Self;
 
+    /// Enable the [`StateStack<S>`] of state `S`, to push states on top of each other and pop back to them.
+    ///
+    /// Adds [`StateStack<S>`] and [`NextStateStack<S>`] resources, and enables use of the
+    /// [`OnPause`](crate::state::OnPause) and [`OnResume`](crate::state::OnResume) schedules.
+    ///
+    /// This method is idempotent: it has no effect when called again using the same generic type.
+    fn enable_state_stack<S: FreelyMutableState>(&mut self) -> &mut Self;
+
+    /// Adds a gate holding transitions out of `state` until it opens.
+    ///
+    /// Adds the [`StateGates<S>`] resource on first use, through which gates, timeouts and failure states
+    /// can also be configured at runtime. See [`StateGates<S>`] for more information.
+    fn add_state_gate<S, O, M>(&mut self, state: S, gate: impl IntoSystem<(), O, M>) -> &mut Self
+    where
+        S: FreelyMutableState,
+        O: Into<GateStatus> + 'static;
+
     #[cfg(feature = "bevy_reflect")]
     /// Registers the state type `T` using [`App::register_type`],
     /// and adds [`ReflectState`](crate::reflect::ReflectState) type data to `T` in the type registry.
@@ -230,6 +249,50 @@ impl AppExtStates for SubApp {
         )
     }
 
+    fn enable_state_stack<S: FreelyMutableState>(&mut self) -> &mut Self {
+        warn_if_no_states_plugin_installed(self);
+        if self.world().contains_resource::<StateStack<S>>() {
+            let name = core::any::type_name::<S>();
+            warn!("State stack {} is already enabled.", name);
+            return self;
+        }
+        let stack = StateStack(
+            self.world()
+                .get_resource::<State<S>>()
+                .map(|state| state.get().clone())
+                .into_iter()
+                .collect(),
+        );
+        self.insert_resource(stack)
+            .init_resource::<NextStateStack<S>>()
+            .add_event::<StateStackEvent<S>>();
+        let schedule = self.get_schedule_mut(StateTransition).expect(
+            "The `StateTransition` schedule is missing. Did you forget to add StatesPlugin or DefaultPlugins before calling enable_state_stack?"
+        );
+        register_state_stack_systems::<S>(schedule);
+        self
+    }
+
+    fn add_state_gate<S, O, M>(&mut self, state: S, gate: impl IntoSystem<(), O, M>) -> &mut Self
+    where
+        S: FreelyMutableState,
+        O: Into<GateStatus> + 'static,
+    {
+        warn_if_no_states_plugin_installed(self);
+        if !self.world().contains_resource::<StateGates<S>>() {
+            self.init_resource::<StateGates<S>>()
+                .add_event::<TransitionGateFailed<S>>();
+            let schedule = self.get_schedule_mut(StateTransition).expect(
+                "The `StateTransition` schedule is missing. Did you forget to add StatesPlugin or DefaultPlugins before calling add_state_gate?"
+            );
+            register_state_gate_systems::<S>(schedule);
+        }
+        self.world_mut()
+            .resource_mut::<StateGates<S>>()
+            .add_gate(state, gate);
+        self
+    }
+
     #[cfg(feature = "bevy_reflect")]
     fn register_type_state<S>(&mut self) -> &mut Self
     where
@@ -281,6 +344,20 @@ impl AppExtStates for App {
         self
     }
 
+    fn enable_state_stack<S: FreelyMutableState>(&mut self) -> &mut Self {
+        self.main_mut().enable_state_stack::<S>();
+        self
+    }
+
+    fn add_state_gate<S, O, M>(&mut self, state: S, gate: impl IntoSystem<(), O, M>) -> &mut Self
+    where
+        S: FreelyMutableState,
+        O: Into<GateStatus> + 'static,
+    {
+        self.main_mut().add_state_gate(state, gate);
+        self
+    }
+
     #[cfg(feature = "bevy_reflect")]
     fn register_type_state<S>(&mut self) -> &mut Self
     where
@@ -318,10 +395,20 @@ mod tests {
     use crate::{
         self as bevy_state,
         app::StatesPlugin,
-        state::{State, StateTransition, StateTransitionEvent},
+        state::{
+            GateFailure, GateProgress, GateStatus, NextState, NextStateStack, OnEnter, OnExit,
+            OnPause, OnResume, PendingTransition, State, StateGates, StateStack, StateStackEvent,
+            StateTransition, StateTransitionEvent, TransitionGateFailed,
+        },
+        state_scoped::StateScoped,
     };
+    use alloc::{vec, vec::Vec};
     use bevy_app::App;
-    use bevy_ecs::event::Events;
+    use bevy_ecs::{
+        event::Events,
+        resource::Resource,
+        system::{Res, ResMut},
+    };
     use bevy_state_macros::States;
 
     use super::AppExtStates;
@@ -373,4 +460,270 @@ mod tests {
         assert_eq!(last.exited, None);
         assert_eq!(last.entered, Some(TestState::C));
     }
+
+    #[derive(Resource, Default)]
+    struct ScheduleLog(Vec<&'static str>);
+
+    fn log(entry: &'static str) -> impl Fn(ResMut<ScheduleLog>) {
+        move |mut log: ResMut<ScheduleLog>| log.0.push(entry)
+    }
+
+    #[test]
+    fn state_stack_pushes_and_pops_states() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .init_resource::<ScheduleLog>()
+            .init_state::<TestState>()
+            .enable_state_scoped_entities::<TestState>()
+            .enable_state_stack::<TestState>()
+            .add_systems(OnExit(TestState::A), log("exit A"))
+            .add_systems(OnPause(TestState::A), log("pause A"))
+            .add_systems(OnResume(TestState::A), log("resume A"))
+            .add_systems(OnEnter(TestState::B), log("enter B"))
+            .add_systems(OnExit(TestState::B), log("exit B"));
+
+        let world = app.world_mut();
+        world.run_schedule(StateTransition);
+        let scoped = world.spawn(StateScoped(TestState::A)).id();
+
+        world
+            .resource_mut::<NextStateStack<TestState>>()
+            .push(TestState::B);
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<State<TestState>>().0, TestState::B);
+        let stack = world.resource::<StateStack<TestState>>();
+        assert_eq!(stack.get(), &[TestState::A, TestState::B]);
+        assert!(stack.is_paused(&TestState::A));
+        assert!(world.get_entity(scoped).is_ok());
+
+        world.resource_mut::<NextStateStack<TestState>>().pop();
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<State<TestState>>().0, TestState::A);
+        assert_eq!(
+            world.resource::<StateStack<TestState>>().get(),
+            &[TestState::A]
+        );
+        assert!(world.get_entity(scoped).is_ok());
+        assert_eq!(
+            world.resource::<ScheduleLog>().0,
+            vec!["pause A", "enter B", "exit B", "resume A"]
+        );
+
+        let events = world.resource::<Events<StateStackEvent<TestState>>>();
+        let mut reader = events.get_cursor();
+        assert_eq!(
+            reader.read(events).cloned().collect::<Vec<_>>(),
+            vec![
+                StateStackEvent::Pushed {
+                    paused: TestState::A,
+                    entered: TestState::B,
+                },
+                StateStackEvent::Popped {
+                    exited: TestState::B,
+                    resumed: TestState::A,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn next_state_replaces_top_of_state_stack() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .init_state::<TestState>()
+            .enable_state_stack::<TestState>();
+
+        let world = app.world_mut();
+        world.run_schedule(StateTransition);
+        world
+            .resource_mut::<NextStateStack<TestState>>()
+            .push(TestState::B);
+        world.run_schedule(StateTransition);
+
+        world
+            .resource_mut::<NextState<TestState>>()
+            .set(TestState::C);
+        world.run_schedule(StateTransition);
+        assert_eq!(
+            world.resource::<StateStack<TestState>>().get(),
+            &[TestState::A, TestState::C]
+        );
+
+        // Popping the last state is ignored.
+        world.resource_mut::<NextStateStack<TestState>>().pop();
+        world.run_schedule(StateTransition);
+        world.resource_mut::<NextStateStack<TestState>>().pop();
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<State<TestState>>().0, TestState::A);
+        assert_eq!(
+            world.resource::<StateStack<TestState>>().get(),
+            &[TestState::A]
+        );
+
+        // An ignored pop doesn't discard a pending `NextState`.
+        world.resource_mut::<NextStateStack<TestState>>().pop();
+        world
+            .resource_mut::<NextState<TestState>>()
+            .set(TestState::B);
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<State<TestState>>().0, TestState::B);
+        assert_eq!(
+            world.resource::<StateStack<TestState>>().get(),
+            &[TestState::B]
+        );
+    }
+
+    #[derive(Resource, Default)]
+    struct Loaded(u32);
+
+    #[test]
+    fn state_gates_hold_transitions_until_ready() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .init_resource::<Loaded>()
+            .init_state::<TestState>()
+            .add_state_gate(TestState::A, |loaded: Res<Loaded>| {
+                if loaded.0 >= 2 {
+                    GateStatus::Ready
+                } else {
+                    GateStatus::Pending(GateProgress {
+                        done: loaded.0,
+                        total: 2,
+                    })
+                }
+            });
+
+        let world = app.world_mut();
+        world.run_schedule(StateTransition);
+        world
+            .resource_mut::<NextState<TestState>>()
+            .set(TestState::B);
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<State<TestState>>().0, TestState::A);
+        let pending = world.resource::<PendingTransition<TestState>>();
+        assert_eq!(pending.target(), &TestState::B);
+        assert_eq!(pending.progress(), GateProgress { done: 0, total: 2 });
+
+        world.resource_mut::<Loaded>().0 = 1;
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<State<TestState>>().0, TestState::A);
+        assert_eq!(
+            world
+                .resource::<PendingTransition<TestState>>()
+                .progress()
+                .fraction(),
+            0.5
+        );
+
+        world.resource_mut::<Loaded>().0 = 2;
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<State<TestState>>().0, TestState::B);
+        assert!(!world.contains_resource::<PendingTransition<TestState>>());
+
+        // Transitions out of ungated states aren't held.
+        world
+            .resource_mut::<NextState<TestState>>()
+            .set(TestState::C);
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<State<TestState>>().0, TestState::C);
+    }
+
+    #[test]
+    fn failed_state_gates_route_to_failure_state() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .init_state::<TestState>()
+            .add_state_gate(TestState::A, || false);
+        app.world_mut()
+            .resource_mut::<StateGates<TestState>>()
+            .add_gate_once(TestState::A, || GateStatus::Failed)
+            .set_failure_state(TestState::A, TestState::C);
+
+        let world = app.world_mut();
+        world.run_schedule(StateTransition);
+        world
+            .resource_mut::<NextState<TestState>>()
+            .set(TestState::B);
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<State<TestState>>().0, TestState::C);
+
+        let events = world.resource::<Events<TransitionGateFailed<TestState>>>();
+        let mut reader = events.get_cursor();
+        assert_eq!(
+            reader.read(events).cloned().collect::<Vec<_>>(),
+            vec![TransitionGateFailed {
+                state: TestState::A,
+                target: TestState::B,
+                reason: GateFailure::Failed,
+            }]
+        );
+
+        // The one-off gate was removed, the remaining gate times out.
+        world
+            .resource_mut::<StateGates<TestState>>()
+            .set_timeout(TestState::A, core::time::Duration::ZERO);
+        world
+            .resource_mut::<NextState<TestState>>()
+            .set(TestState::A);
+        world.run_schedule(StateTransition);
+        world
+            .resource_mut::<NextState<TestState>>()
+            .set(TestState::B);
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<State<TestState>>().0, TestState::C);
+        let events = world.resource::<Events<TransitionGateFailed<TestState>>>();
+        assert_eq!(
+            reader.read(events).last().map(|event| event.reason),
+            Some(GateFailure::TimedOut)
+        );
+    }
+
+    #[test]
+    fn state_stack_operations_bypass_state_gates() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .init_state::<TestState>()
+            .enable_state_stack::<TestState>()
+            .add_state_gate(TestState::A, || false);
+
+        let world = app.world_mut();
+        world.run_schedule(StateTransition);
+        world
+            .resource_mut::<NextState<TestState>>()
+            .set(TestState::B);
+        world.run_schedule(StateTransition);
+        assert!(world.contains_resource::<PendingTransition<TestState>>());
+
+        world
+            .resource_mut::<NextStateStack<TestState>>()
+            .push(TestState::C);
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<State<TestState>>().0, TestState::C);
+        assert!(!world.contains_resource::<PendingTransition<TestState>>());
+
+        // The abandoned transition isn't applied once the gated state is resumed.
+        world.resource_mut::<NextStateStack<TestState>>().pop();
+        world.run_schedule(StateTransition);
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<State<TestState>>().0, TestState::A);
+        assert!(!world.contains_resource::<PendingTransition<TestState>>());
+        assert!(world
+            .resource::<Events<TransitionGateFailed<TestState>>>()
+            .is_empty());
+    }
+
+    #[test]
+    fn gate_progress_saturates() {
+        let progress = GateProgress {
+            done: u32::MAX,
+            total: u32::MAX,
+        } + GateProgress { done: 1, total: 2 };
+        assert_eq!(
+            progress,
+            GateProgress {
+                done: u32::MAX,
+                total: u32::MAX,
+            }
+        );
+    }
 }
@@ -1,7 +1,7 @@
 use bevy_ecs::{system::Commands, world::World};
 use log::debug;
 
-use crate::state::{FreelyMutableState, NextState};
+use crate::state::{FreelyMutableState, NextState, NextStateStack};
 
 /// Extension trait for [`Commands`] adding `bevy_state` helpers.
 pub trait CommandsStatesExt {
@@ -13,6 +13,18 @@ pub trait CommandsStatesExt {
     /// Note that commands introduce sync points to the ECS schedule, so modifying `NextState`
     /// directly may be more efficient depending on your use-case.
     fn set_state<S: FreelyMutableState>(&mut self, state: S);
+
+    /// Pushes a state on top of the [`StateStack<S>`](crate::prelude::StateStack), pausing the current state.
+    ///
+    /// Internally this schedules a command that updates the [`NextStateStack<S>`](crate::prelude::NextStateStack)
+    /// resource with `state`.
+    fn push_state<S: FreelyMutableState>(&mut self, state: S);
+
+    /// Pops the top of the [`StateStack<S>`](crate::prelude::StateStack), resuming the state below it.
+    ///
+    /// Internally this schedules a command that updates the [`NextStateStack<S>`](crate::prelude::NextStateStack)
+    /// resource.
+    fn pop_state<S: FreelyMutableState>(&mut self);
 }
 
 impl CommandsStatesExt for Commands<'_, '_> {
@@ -27,4 +39,16 @@ impl CommandsStatesExt for Commands<'_, '_> {
             next.set(state);
         });
     }
+
+    fn push_state<S: FreelyMutableState>(&mut self, state: S) {
+        self.queue(move |w: &mut World| {
+            w.resource_mut::<NextStateStack<S>>().push(state);
+        });
+    }
+
+    fn pop_state<S: FreelyMutableState>(&mut self) {
+        self.queue(move |w: &mut World| {
+            w.resource_mut::<NextStateStack<S>>().pop();
+        });
+    }
 }
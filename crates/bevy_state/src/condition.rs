@@ -1,4 +1,4 @@
-use crate::state::{State, States};
+use crate::state::{FreelyMutableState, State, StateStack, States};
 use bevy_ecs::{change_detection::DetectChanges, system::Res};
 
 /// A [`Condition`](bevy_ecs::prelude::Condition)-satisfying system that returns `true`
@@ -107,6 +107,62 @@ pub fn in_state<S: States>(state: S) -> impl FnMut(Option<Res<State<S>>>) -> boo
     }
 }
 
+/// Generates a [`Condition`](bevy_ecs::prelude::Condition)-satisfying closure that returns `true`
+/// if `state` is anywhere in the [`StateStack<S>`], whether it is active or paused.
+///
+/// Will return `false` if the state stack is not enabled or doesn't contain `state`.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_state::prelude::*;
+/// # use bevy_app::{App, Update};
+/// # use bevy_state::app::StatesPlugin;
+/// # #[derive(Resource, Default)]
+/// # struct Counter(u8);
+/// # let mut app = App::new();
+/// # app
+/// #   .init_resource::<Counter>()
+/// #   .add_plugins(StatesPlugin);
+/// #[derive(States, Clone, Copy, Default, Eq, PartialEq, Hash, Debug)]
+/// enum GameState {
+///     #[default]
+///     InGame,
+///     PauseMenu,
+/// }
+///
+/// app
+///     .init_state::<GameState>()
+///     .enable_state_stack::<GameState>()
+///     .add_systems(Update,
+///         // `in_state_stack` keeps returning true while
+///         // `InGame` is paused by the pause menu
+///         my_system.run_if(in_state_stack(GameState::InGame)),
+///     );
+///
+/// fn my_system(mut counter: ResMut<Counter>) {
+///     counter.0 += 1;
+/// }
+///
+/// app.update();
+/// assert_eq!(app.world().resource::<Counter>().0, 1);
+///
+/// app.world_mut().resource_mut::<NextStateStack<GameState>>().push(GameState::PauseMenu);
+///
+/// // `InGame` is paused, but still in the stack
+/// app.update();
+/// assert_eq!(app.world().resource::<Counter>().0, 2);
+/// ```
+pub fn in_state_stack<S: FreelyMutableState>(
+    state: S,
+) -> impl FnMut(Option<Res<StateStack<S>>>) -> bool + Clone {
+    move |stack: Option<Res<StateStack<S>>>| match stack {
+        Some(stack) => stack.contains(&state),
+        None => false,
+    }
+}
+
 /// A [`Condition`](bevy_ecs::prelude::Condition)-satisfying system that returns `true`
 /// if the state machine changed state.
 ///
@@ -194,6 +250,7 @@ mod tests {
             (test_system, test_system)
                 .distributive_run_if(state_exists::<TestState>)
                 .distributive_run_if(in_state(TestState::A).or(in_state(TestState::B)))
+                .distributive_run_if(in_state_stack(TestState::A))
                 .distributive_run_if(state_changed::<TestState>),
         );
     }
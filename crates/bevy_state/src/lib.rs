@@ -26,6 +26,10 @@
 //! - 3 Transition Schedules - [`OnEnter<S>`](crate::state::OnEnter), [`OnExit<S>`](crate::state::OnExit) and [`OnTransition<S>`](crate::state::OnTransition) - which are used
 //!   to trigger systems specifically during matching transitions.
 //! - A [`StateTransitionEvent<S>`](crate::state::StateTransitionEvent) that gets fired when a given state changes.
+//! - A [`StateStack<S>`](crate::state::StateStack) to push states on top of each other and pop back to them,
+//!   with the [`OnPause<S>`](crate::state::OnPause) and [`OnResume<S>`](crate::state::OnResume) schedules.
+//! - [`StateGates<S>`](crate::state::StateGates) - which hold requested transitions until assets are loaded,
+//!   tasks are finished, or other conditions are met.
 //! - The [`in_state<S>`](crate::condition::in_state) and [`state_changed<S>`](crate::condition::state_changed) run conditions - which are used
 //!   to determine whether a system should run based on the current state.
 
@@ -82,9 +86,11 @@ pub mod prelude {
         commands::CommandsStatesExt,
         condition::*,
         state::{
-            last_transition, ComputedStates, EnterSchedules, ExitSchedules, NextState, OnEnter,
-            OnExit, OnTransition, State, StateSet, StateTransition, StateTransitionEvent, States,
-            SubStates, TransitionSchedules,
+            last_transition, ComputedStates, EnterSchedules, ExitSchedules, GateProgress,
+            GateStatus, NextState, NextStateStack, OnEnter, OnExit, OnPause, OnResume,
+            OnTransition, PendingTransition, State, StateGates, StateSet, StateStack,
+            StateStackEvent, StateTransition, StateTransitionEvent, States, SubStates,
+            TransitionGateFailed, TransitionSchedules,
         },
         state_scoped::StateScoped,
     };
@@ -2,8 +2,10 @@ mod computed_states;
 mod freely_mutable_state;
 mod resources;
 mod state_set;
+mod state_stack;
 mod states;
 mod sub_states;
+mod transition_gates;
 mod transitions;
 
 pub use bevy_state_macros::*;
@@ -11,8 +13,10 @@ pub use computed_states::*;
 pub use freely_mutable_state::*;
 pub use resources::*;
 pub use state_set::*;
+pub use state_stack::*;
 pub use states::*;
 pub use sub_states::*;
+pub use transition_gates::*;
 pub use transitions::*;
 
 #[cfg(test)]
@@ -3,7 +3,6 @@ use core::ops::Deref;
 use bevy_ecs::{
     change_detection::DetectChangesMut,
     resource::Resource,
-    system::ResMut,
     world::{FromWorld, World},
 };
 
@@ -142,7 +141,7 @@ impl<S: FreelyMutableState> NextState<S> {
 }
 
 pub(crate) fn take_next_state<S: FreelyMutableState>(
-    next_state: Option<ResMut<NextState<S>>>,
+    next_state: Option<impl DetectChangesMut<Inner = NextState<S>>>,
 ) -> Option<S> {
     let mut next_state = next_state?;
 
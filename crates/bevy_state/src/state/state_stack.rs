@@ -0,0 +1,329 @@
+use alloc::vec::Vec;
+use core::mem;
+
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    event::{Event, EventReader, EventWriter},
+    resource::Resource,
+    schedule::{IntoSystemConfigs, Schedule, ScheduleLabel},
+    system::{Commands, In, IntoSystem, ResMut},
+    world::World,
+};
+use log::warn;
+
+use super::{
+    freely_mutable_state::FreelyMutableState, resources::State, states::States, take_next_state,
+    ApplyStateTransition, EnterSchedules, ExitSchedules, NextState, PendingTransition,
+    StateTransitionEvent,
+};
+
+#[cfg(feature = "bevy_reflect")]
+use bevy_ecs::prelude::ReflectResource;
+
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::prelude::ReflectDefault;
+
+/// The stack of values of a [`State<S>`], for nested flows such as pause menus and dialogs.
+///
+/// The top of the stack is always the current value of [`State<S>`]: it is the only *active* state,
+/// and [`in_state`](crate::condition::in_state) only matches it.
+/// The states below it are *paused*: they are kept, but their systems stop running
+/// until the states above them are popped.
+///
+/// To push or pop a state, queue an operation in the [`NextStateStack<S>`] resource.
+/// Setting [`NextState<S>`] still replaces the top of the stack.
+///
+/// Pushing a state runs [`OnPause`] for the paused state and [`OnEnter`](super::OnEnter) for the pushed state,
+/// but not [`OnExit`](super::OnExit) for the paused state,
+/// so its [`StateScoped`](crate::state_scoped::StateScoped) entities are kept.
+/// Popping a state runs [`OnExit`](super::OnExit) for the popped state and [`OnResume`] for the resumed state.
+///
+/// The stack is enabled with [`enable_state_stack`](crate::app::AppExtStates::enable_state_stack).
+///
+/// ```
+/// use bevy_state::prelude::*;
+/// use bevy_ecs::prelude::*;
+///
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, States)]
+/// enum GameState {
+///     #[default]
+///     InGame,
+///     PauseMenu,
+///     SettingsMenu,
+/// }
+///
+/// fn open_pause_menu(mut next_state_stack: ResMut<NextStateStack<GameState>>) {
+///     next_state_stack.push(GameState::PauseMenu);
+/// }
+///
+/// fn is_game_paused(state_stack: Res<StateStack<GameState>>) -> bool {
+///     state_stack.is_paused(&GameState::InGame)
+/// }
+/// ```
+#[derive(Resource, Debug, Clone)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(Resource, Default, Debug)
+)]
+pub struct StateStack<S: FreelyMutableState>(pub(crate) Vec<S>);
+
+impl<S: FreelyMutableState> Default for StateStack<S> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<S: FreelyMutableState> StateStack<S> {
+    /// Returns the states of the stack, from the bottom to the top.
+    pub fn get(&self) -> &[S] {
+        &self.0
+    }
+
+    /// Returns the active state at the top of the stack.
+    pub fn top(&self) -> Option<&S> {
+        self.0.last()
+    }
+
+    /// Returns the paused states below the top of the stack, from the bottom to the top.
+    pub fn paused(&self) -> &[S] {
+        let len = self.0.len().saturating_sub(1);
+        &self.0[..len]
+    }
+
+    /// Returns `true` if `state` is paused below the top of the stack.
+    pub fn is_paused(&self, state: &S) -> bool {
+        self.paused().contains(state)
+    }
+
+    /// Returns `true` if `state` is anywhere in the stack, active or paused.
+    pub fn contains(&self, state: &S) -> bool {
+        self.0.contains(state)
+    }
+
+    /// Returns the number of states in the stack.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the stack is empty, which is only the case when [`State<S>`] doesn't exist.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// The next operation on the [`StateStack<S>`].
+///
+/// Like [`NextState<S>`], only the value of this resource during the
+/// [`StateTransition`](super::StateTransition) schedule matters, so at most one operation is applied per transition.
+/// A pending operation takes precedence over a pending [`NextState<S>`], which is discarded.
+///
+/// Stack operations aren't held by [`StateGates<S>`](super::StateGates): they are applied even if the current state
+/// is gated, and abandon the [`PendingTransition<S>`] held by its gates.
+///
+/// ```
+/// use bevy_state::prelude::*;
+/// use bevy_ecs::prelude::*;
+///
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, States)]
+/// enum GameState {
+///     #[default]
+///     InGame,
+///     PauseMenu,
+/// }
+///
+/// fn close_pause_menu(mut next_state_stack: ResMut<NextStateStack<GameState>>) {
+///     next_state_stack.pop();
+/// }
+/// ```
+#[derive(Resource, Debug, Default, Clone)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(Resource, Default, Debug)
+)]
+pub enum NextStateStack<S: FreelyMutableState> {
+    /// No stack operation is pending
+    #[default]
+    Unchanged,
+    /// Push a state on top of the stack, pausing the current state
+    Push(S),
+    /// Pop the top of the stack, resuming the state below it
+    Pop,
+}
+
+impl<S: FreelyMutableState> NextStateStack<S> {
+    /// Tentatively push `state` on top of the stack.
+    pub fn push(&mut self, state: S) {
+        *self = Self::Push(state);
+    }
+
+    /// Tentatively pop the top of the stack.
+    pub fn pop(&mut self) {
+        *self = Self::Pop;
+    }
+
+    /// Remove any pending operation on the [`StateStack<S>`].
+    pub fn reset(&mut self) {
+        *self = Self::Unchanged;
+    }
+}
+
+/// Event sent when a state is pushed to or popped from a [`StateStack<S>`].
+///
+/// A matching [`StateTransitionEvent<S>`] is sent as well, without the paused or resumed state.
+#[derive(Debug, Clone, PartialEq, Eq, Event)]
+pub enum StateStackEvent<S: States> {
+    /// A state was pushed on top of the stack.
+    Pushed {
+        /// The state that was paused.
+        paused: S,
+        /// The state being entered.
+        entered: S,
+    },
+    /// The top of the stack was popped.
+    Popped {
+        /// The state being exited.
+        exited: S,
+        /// The state that was resumed.
+        resumed: S,
+    },
+}
+
+/// The label of a [`Schedule`] that **only** runs whenever the provided state
+/// is paused by pushing another state on top of it in the [`StateStack<S>`].
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OnPause<S: States>(pub S);
+
+/// The label of a [`Schedule`] that **only** runs whenever the provided state
+/// is resumed by popping the state on top of it from the [`StateStack<S>`].
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OnResume<S: States>(pub S);
+
+/// Registers the systems applying [`NextStateStack<S>`] operations and running [`OnPause`] and [`OnResume`] schedules.
+pub(crate) fn register_state_stack_systems<S: FreelyMutableState>(schedule: &mut Schedule) {
+    schedule
+        .add_systems(
+            (
+                apply_state_stack_operation::<S>
+                    .before(super::freely_mutable_state::apply_state_transition::<S>),
+                sync_state_stack::<S>
+                    .after(super::freely_mutable_state::apply_state_transition::<S>),
+            )
+                .in_set(ApplyStateTransition::<S>::default()),
+        )
+        .add_systems(
+            last_stack_event::<S>
+                .pipe(run_pause::<S>)
+                .in_set(ExitSchedules::<S>::default()),
+        )
+        .add_systems(
+            last_stack_event::<S>
+                .pipe(run_resume::<S>)
+                .in_set(EnterSchedules::<S>::default()),
+        );
+}
+
+pub(super) fn apply_state_stack_operation<S: FreelyMutableState>(
+    mut commands: Commands,
+    mut transition_event: EventWriter<StateTransitionEvent<S>>,
+    mut stack_event: EventWriter<StateStackEvent<S>>,
+    current_state: Option<ResMut<State<S>>>,
+    next_state: Option<ResMut<NextState<S>>>,
+    next_state_stack: Option<ResMut<NextStateStack<S>>>,
+    stack: Option<ResMut<StateStack<S>>>,
+) {
+    let (Some(mut next_state_stack), Some(mut stack)) = (next_state_stack, stack) else {
+        return;
+    };
+    let operation = match mem::take(next_state_stack.bypass_change_detection()) {
+        NextStateStack::Unchanged => return,
+        operation => {
+            next_state_stack.set_changed();
+            operation
+        }
+    };
+    let Some(mut current_state) = current_state else {
+        warn!(
+            "Tried to push or pop state `{}`, but it doesn't exist.",
+            core::any::type_name::<S>()
+        );
+        return;
+    };
+    // An invalid operation is dropped on its own, leaving the pending `NextState` and gated transition alone.
+    if matches!(operation, NextStateStack::Pop) && stack.0.len() < 2 {
+        warn!(
+            "Tried to pop state `{}`, but there is no paused state to resume.",
+            core::any::type_name::<S>()
+        );
+        return;
+    }
+    // The stack operation takes precedence over a pending `NextState`, and over a transition held by gates.
+    take_next_state(next_state);
+    commands.remove_resource::<PendingTransition<S>>();
+
+    match operation {
+        NextStateStack::Unchanged => {}
+        NextStateStack::Push(entered) => {
+            let paused = mem::replace(&mut current_state.0, entered.clone());
+            stack.0.push(entered.clone());
+            transition_event.send(StateTransitionEvent {
+                exited: None,
+                entered: Some(entered.clone()),
+            });
+            stack_event.send(StateStackEvent::Pushed { paused, entered });
+        }
+        NextStateStack::Pop => {
+            stack.0.pop();
+            let resumed = stack.0.last().unwrap().clone();
+            let exited = mem::replace(&mut current_state.0, resumed.clone());
+            transition_event.send(StateTransitionEvent {
+                exited: Some(exited.clone()),
+                entered: None,
+            });
+            stack_event.send(StateStackEvent::Popped { exited, resumed });
+        }
+    }
+}
+
+/// Keeps the top of the stack in sync with [`State<S>`] when it's set through [`NextState<S>`].
+fn sync_state_stack<S: FreelyMutableState>(
+    current_state: Option<ResMut<State<S>>>,
+    stack: Option<ResMut<StateStack<S>>>,
+) {
+    let Some(mut stack) = stack else {
+        return;
+    };
+    match current_state {
+        Some(current_state) => match stack.0.last() {
+            Some(top) if *top == current_state.0 => {}
+            Some(_) => *stack.0.last_mut().unwrap() = current_state.0.clone(),
+            None => stack.0.push(current_state.0.clone()),
+        },
+        None => {
+            if !stack.0.is_empty() {
+                stack.0.clear();
+            }
+        }
+    }
+}
+
+fn last_stack_event<S: States>(
+    mut reader: EventReader<StateStackEvent<S>>,
+) -> Option<StateStackEvent<S>> {
+    reader.read().last().cloned()
+}
+
+fn run_pause<S: States>(event: In<Option<StateStackEvent<S>>>, world: &mut World) {
+    let Some(StateStackEvent::Pushed { paused, .. }) = event.0 else {
+        return;
+    };
+    let _ = world.try_run_schedule(OnPause(paused));
+}
+
+fn run_resume<S: States>(event: In<Option<StateStackEvent<S>>>, world: &mut World) {
+    let Some(StateStackEvent::Popped { resumed, .. }) = event.0 else {
+        return;
+    };
+    let _ = world.try_run_schedule(OnResume(resumed));
+}
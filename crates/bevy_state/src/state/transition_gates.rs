@@ -0,0 +1,564 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::time::Duration;
+
+use bevy_ecs::{
+    event::Event,
+    resource::Resource,
+    schedule::{IntoSystemConfigs, Schedule},
+    system::{BoxedSystem, IntoSystem},
+    world::{Mut, World},
+};
+use bevy_platform_support::{collections::HashMap, time::Instant};
+use log::warn;
+
+use super::{
+    freely_mutable_state::{apply_state_transition, FreelyMutableState},
+    resources::State,
+    state_stack::apply_state_stack_operation,
+    take_next_state, ApplyStateTransition, NextState,
+};
+
+/// The status of a transition gate, see [`StateGates<S>`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GateStatus {
+    /// The gate is still waiting, and holds the transition.
+    Pending(GateProgress),
+    /// The gate is open.
+    Ready,
+    /// The gate failed, and the transition is abandoned.
+    Failed,
+}
+
+impl From<bool> for GateStatus {
+    /// `true` opens the gate, `false` keeps it pending.
+    fn from(ready: bool) -> Self {
+        if ready {
+            Self::Ready
+        } else {
+            Self::Pending(GateProgress { done: 0, total: 1 })
+        }
+    }
+}
+
+/// How much of the work a gate waits on is done.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GateProgress {
+    /// Number of units of work done.
+    pub done: u32,
+    /// Total number of units of work.
+    pub total: u32,
+}
+
+impl GateProgress {
+    /// Returns the fraction of work done, between `0.0` and `1.0`.
+    ///
+    /// Returns `1.0` if there is no work to do.
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.done as f32 / self.total as f32
+        }
+    }
+}
+
+/// Combines the progress of two gates, saturating at [`u32::MAX`].
+impl core::ops::Add for GateProgress {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            done: self.done.saturating_add(rhs.done),
+            total: self.total.saturating_add(rhs.total),
+        }
+    }
+}
+
+/// Why a gated transition was abandoned, see [`TransitionGateFailed<S>`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GateFailure {
+    /// A gate returned [`GateStatus::Failed`].
+    Failed,
+    /// The gates didn't open before the [timeout](StateGates::set_timeout) of the state.
+    TimedOut,
+}
+
+/// Event sent when a gated transition of `S` is abandoned.
+#[derive(Debug, Clone, PartialEq, Eq, Event)]
+pub struct TransitionGateFailed<S: FreelyMutableState> {
+    /// The state whose gates held the transition.
+    pub state: S,
+    /// The state the transition was requested to.
+    pub target: S,
+    /// Why the transition was abandoned.
+    pub reason: GateFailure,
+}
+
+struct Gate<S> {
+    state: S,
+    system: BoxedSystem<(), GateStatus>,
+    initialized: bool,
+    once: bool,
+}
+
+/// Gates holding transitions out of the states of `S` until they open.
+///
+/// A gate is a system returning a [`GateStatus`], or a `bool` for simple conditions.
+/// While the current state has gates, transitions requested through [`NextState<S>`] are held
+/// in the [`PendingTransition<S>`] resource until all of its gates are [ready](GateStatus::Ready).
+/// If a gate [fails](GateStatus::Failed) or the gates don't open before the [timeout](Self::set_timeout) of the state,
+/// the transition is abandoned, a [`TransitionGateFailed<S>`] event is sent
+/// and the [failure state](Self::set_failure_state) of the state is entered, if any.
+///
+/// Gates are only run while a transition is pending.
+/// Pushing and popping states with [`NextStateStack<S>`](super::NextStateStack) isn't gated:
+/// stack operations are applied even while the current state is gated, and abandon the transition it holds
+/// without sending [`TransitionGateFailed<S>`].
+/// Waiting on tasks is done with [`tasks_finished`] when the `bevy_tasks` feature is enabled,
+/// and waiting on assets with [`assets_loaded`] when the `bevy_asset` feature is enabled.
+///
+/// Gates are enabled with [`add_state_gate`](crate::app::AppExtStates::add_state_gate).
+///
+/// ```
+/// use bevy_state::prelude::*;
+/// use bevy_ecs::prelude::*;
+/// use core::time::Duration;
+///
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, States)]
+/// enum GameState {
+///     #[default]
+///     Loading,
+///     InGame,
+///     LoadingFailed,
+/// }
+///
+/// #[derive(Resource)]
+/// struct LevelGenerated(bool);
+///
+/// fn configure_gates(mut gates: ResMut<StateGates<GameState>>) {
+///     gates
+///         .add_gate(GameState::Loading, |level: Res<LevelGenerated>| level.0)
+///         .set_timeout(GameState::Loading, Duration::from_secs(30))
+///         .set_failure_state(GameState::Loading, GameState::LoadingFailed);
+/// }
+/// ```
+#[derive(Resource)]
+pub struct StateGates<S: FreelyMutableState> {
+    gates: Vec<Gate<S>>,
+    timeouts: HashMap<S, Duration>,
+    failure_states: HashMap<S, S>,
+}
+
+impl<S: FreelyMutableState> Default for StateGates<S> {
+    fn default() -> Self {
+        Self {
+            gates: Vec::new(),
+            timeouts: HashMap::default(),
+            failure_states: HashMap::default(),
+        }
+    }
+}
+
+impl<S: FreelyMutableState> StateGates<S> {
+    /// Adds a gate holding transitions out of `state`.
+    pub fn add_gate<O, M>(&mut self, state: S, gate: impl IntoSystem<(), O, M>) -> &mut Self
+    where
+        O: Into<GateStatus> + 'static,
+    {
+        self.push(state, gate, false)
+    }
+
+    /// Adds a gate holding the next transition out of `state`.
+    ///
+    /// The gate is removed once the transition is applied or abandoned,
+    /// which makes it suitable for one-off work such as tasks spawned when entering `state`.
+    pub fn add_gate_once<O, M>(&mut self, state: S, gate: impl IntoSystem<(), O, M>) -> &mut Self
+    where
+        O: Into<GateStatus> + 'static,
+    {
+        self.push(state, gate, true)
+    }
+
+    /// Abandons transitions out of `state` if its gates don't open within `timeout` of the request.
+    pub fn set_timeout(&mut self, state: S, timeout: Duration) -> &mut Self {
+        self.timeouts.insert(state, timeout);
+        self
+    }
+
+    /// Enters `failure_state` when a transition out of `state` is abandoned.
+    ///
+    /// The transition to the failure state isn't gated.
+    pub fn set_failure_state(&mut self, state: S, failure_state: S) -> &mut Self {
+        self.failure_states.insert(state, failure_state);
+        self
+    }
+
+    /// Returns `true` if transitions out of `state` are gated.
+    pub fn is_gated(&self, state: &S) -> bool {
+        self.gates.iter().any(|gate| gate.state == *state)
+    }
+
+    fn push<O, M>(&mut self, state: S, gate: impl IntoSystem<(), O, M>, once: bool) -> &mut Self
+    where
+        O: Into<GateStatus> + 'static,
+    {
+        self.gates.push(Gate {
+            state,
+            system: Box::new(IntoSystem::into_system(gate.map(Into::<GateStatus>::into))),
+            initialized: false,
+            once,
+        });
+        self
+    }
+
+    /// Runs the gates of `state` and combines their status.
+    fn run(&mut self, state: &S, world: &mut World) -> GateStatus {
+        let mut progress = GateProgress::default();
+        let mut ready = true;
+        for gate in self.gates.iter_mut().filter(|gate| gate.state == *state) {
+            if !gate.initialized {
+                gate.system.initialize(world);
+                gate.initialized = true;
+            }
+            let status = if gate.system.validate_param(world) {
+                gate.system.run((), world)
+            } else {
+                GateStatus::from(false)
+            };
+            match status {
+                GateStatus::Pending(gate_progress) => {
+                    ready = false;
+                    progress = progress + gate_progress;
+                }
+                GateStatus::Ready => progress = progress + GateProgress { done: 1, total: 1 },
+                GateStatus::Failed => return GateStatus::Failed,
+            }
+        }
+        if ready {
+            GateStatus::Ready
+        } else {
+            GateStatus::Pending(progress)
+        }
+    }
+
+    /// Removes the one-off gates of `state`.
+    fn remove_once(&mut self, state: &S) {
+        self.gates.retain(|gate| !gate.once || gate.state != *state);
+    }
+}
+
+/// A transition of `S` held by the [`StateGates<S>`] of the current state.
+#[derive(Resource, Debug, Clone)]
+pub struct PendingTransition<S: FreelyMutableState> {
+    target: S,
+    requested_at: Instant,
+    progress: GateProgress,
+}
+
+impl<S: FreelyMutableState> PendingTransition<S> {
+    /// Returns the state the transition was requested to.
+    pub fn target(&self) -> &S {
+        &self.target
+    }
+
+    /// Returns the combined progress of the gates holding the transition.
+    pub fn progress(&self) -> GateProgress {
+        self.progress
+    }
+
+    /// Returns the time elapsed since the transition was requested.
+    pub fn elapsed(&self) -> Duration {
+        self.requested_at.elapsed()
+    }
+}
+
+/// Registers the system holding transitions of `S` until the [`StateGates<S>`] of the current state open.
+///
+/// Gates run before stack operations are applied, so that a stack operation applied in the same transition
+/// still discards the [`NextState<S>`] released by the gates.
+pub(crate) fn register_state_gate_systems<S: FreelyMutableState>(schedule: &mut Schedule) {
+    schedule.add_systems(
+        gate_state_transition::<S>
+            .before(apply_state_stack_operation::<S>)
+            .before(apply_state_transition::<S>)
+            .in_set(ApplyStateTransition::<S>::default()),
+    );
+}
+
+/// Holds transitions requested through [`NextState<S>`] until the gates of the current state open.
+fn gate_state_transition<S: FreelyMutableState>(world: &mut World) {
+    let Some(current) = world
+        .get_resource::<State<S>>()
+        .map(|state| state.get().clone())
+    else {
+        return;
+    };
+    world.resource_scope(|world, mut gates: Mut<StateGates<S>>| {
+        if !gates.is_gated(&current) {
+            world.remove_resource::<PendingTransition<S>>();
+            return;
+        }
+
+        // Hold new requests, replacing any pending one.
+        if let Some(target) = take_next_state(world.get_resource_mut::<NextState<S>>()) {
+            let requested_at = world
+                .get_resource::<PendingTransition<S>>()
+                .map_or_else(Instant::now, |pending| pending.requested_at);
+            world.insert_resource(PendingTransition {
+                target,
+                requested_at,
+                progress: GateProgress::default(),
+            });
+        }
+        let Some(pending) = world.get_resource::<PendingTransition<S>>() else {
+            return;
+        };
+        let target = pending.target.clone();
+        let timed_out = gates
+            .timeouts
+            .get(&current)
+            .is_some_and(|timeout| pending.requested_at.elapsed() >= *timeout);
+
+        let failure = match gates.run(&current, world) {
+            GateStatus::Ready => {
+                world.remove_resource::<PendingTransition<S>>();
+                gates.remove_once(&current);
+                world.resource_mut::<NextState<S>>().set(target);
+                return;
+            }
+            GateStatus::Failed => GateFailure::Failed,
+            GateStatus::Pending(_) if timed_out => GateFailure::TimedOut,
+            GateStatus::Pending(progress) => {
+                world.resource_mut::<PendingTransition<S>>().progress = progress;
+                return;
+            }
+        };
+
+        warn!(
+            "Transition of state `{}` from {:?} to {:?} was abandoned: {:?}.",
+            core::any::type_name::<S>(),
+            current,
+            target,
+            failure
+        );
+        world.remove_resource::<PendingTransition<S>>();
+        gates.remove_once(&current);
+        if let Some(failure_state) = gates.failure_states.get(&current) {
+            world
+                .resource_mut::<NextState<S>>()
+                .set(failure_state.clone());
+        }
+        world.send_event(TransitionGateFailed {
+            state: current,
+            target,
+            reason: failure,
+        });
+    });
+}
+
+/// Generates a gate that opens once the assets of `handles` and their dependencies are loaded,
+/// and fails if any of them fails to load.
+///
+/// The gate keeps the handles alive, and reports the number of loaded assets as its progress.
+/// Assets which aren't loaded through the [`AssetServer`](bevy_asset::AssetServer) are considered loaded.
+#[cfg(feature = "bevy_asset")]
+pub fn assets_loaded(
+    handles: impl IntoIterator<Item = impl Into<bevy_asset::UntypedHandle>>,
+) -> impl FnMut(bevy_ecs::system::Res<bevy_asset::AssetServer>) -> GateStatus + Send + Sync + 'static
+{
+    use bevy_asset::RecursiveDependencyLoadState;
+
+    let handles = handles.into_iter().map(Into::into).collect::<Vec<_>>();
+    move |asset_server: bevy_ecs::system::Res<bevy_asset::AssetServer>| {
+        let mut progress = GateProgress {
+            done: 0,
+            total: handles.len() as u32,
+        };
+        for handle in &handles {
+            match asset_server.get_recursive_dependency_load_state(handle.id()) {
+                Some(RecursiveDependencyLoadState::Loaded) | None => progress.done += 1,
+                Some(RecursiveDependencyLoadState::Failed(_)) => return GateStatus::Failed,
+                Some(_) => {}
+            }
+        }
+        if progress.done == progress.total {
+            GateStatus::Ready
+        } else {
+            GateStatus::Pending(progress)
+        }
+    }
+}
+
+/// Generates a gate that opens once all of `tasks` are finished.
+///
+/// The gate keeps the tasks alive, and reports the number of finished tasks as its progress.
+/// The outputs of the tasks are discarded, so tasks should report their results through other means,
+/// such as a channel or [`CommandQueue`](bevy_ecs::world::CommandQueue).
+/// Dropping the gate, for example when it's [only added once](StateGates::add_gate_once), cancels unfinished tasks.
+#[cfg(feature = "bevy_tasks")]
+pub fn tasks_finished<T: Send + 'static>(
+    tasks: impl IntoIterator<Item = bevy_tasks::Task<T>>,
+) -> impl FnMut() -> GateStatus + Send + Sync + 'static {
+    let tasks = tasks.into_iter().collect::<Vec<_>>();
+    move || {
+        let progress = GateProgress {
+            done: tasks.iter().filter(|task| task.is_finished()).count() as u32,
+            total: tasks.len() as u32,
+        };
+        if progress.done == progress.total {
+            GateStatus::Ready
+        } else {
+            GateStatus::Pending(progress)
+        }
+    }
+}
+
+#[cfg(all(
+    test,
+    feature = "bevy_asset",
+    feature = "bevy_app",
+    feature = "bevy_reflect"
+))]
+mod tests {
+    use super::{assets_loaded, GateFailure, StateGates, TransitionGateFailed};
+    use crate::{
+        self as bevy_state,
+        app::{AppExtStates, StatesPlugin},
+        state::{NextState, State},
+    };
+    use alloc::{boxed::Box, vec};
+    use bevy_app::{App, TaskPoolPlugin};
+    use bevy_asset::{
+        io::{
+            memory::{Dir, MemoryAssetReader},
+            AssetSource, AssetSourceId, Reader,
+        },
+        Asset, AssetApp, AssetLoader, AssetPlugin, AssetServer, LoadContext,
+    };
+    use bevy_ecs::event::Events;
+    use bevy_reflect::TypePath;
+    use bevy_state_macros::States;
+    use std::path::Path;
+
+    #[derive(States, Default, PartialEq, Eq, Hash, Debug, Clone)]
+    enum TestState {
+        #[default]
+        Loading,
+        InGame,
+        LoadingFailed,
+    }
+
+    #[derive(Asset, TypePath)]
+    struct TestAsset;
+
+    struct TestAssetLoader;
+
+    impl AssetLoader for TestAssetLoader {
+        type Asset = TestAsset;
+        type Settings = ();
+        type Error = std::io::Error;
+
+        async fn load(
+            &self,
+            _reader: &mut dyn Reader,
+            _settings: &(),
+            _load_context: &mut LoadContext<'_>,
+        ) -> Result<TestAsset, Self::Error> {
+            Ok(TestAsset)
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["test"]
+        }
+    }
+
+    /// Requests a transition out of `TestState::Loading` gated on the asset at `path`,
+    /// and returns the state entered once the transition is applied or abandoned.
+    fn load_and_transition(path: &'static str) -> (App, TestState) {
+        let dir = Dir::default();
+        dir.insert_asset(Path::new("level.test"), vec![0]);
+        let mut app = App::new();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || Box::new(MemoryAssetReader { root: dir.clone() })),
+        )
+        .add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin::default(),
+            StatesPlugin,
+        ))
+        .init_asset::<TestAsset>()
+        .register_asset_loader(TestAssetLoader)
+        .init_state::<TestState>();
+
+        let handle = app
+            .world()
+            .resource::<AssetServer>()
+            .load::<TestAsset>(path);
+        app.add_state_gate(TestState::Loading, assets_loaded([handle]));
+        app.world_mut()
+            .resource_mut::<StateGates<TestState>>()
+            .set_failure_state(TestState::Loading, TestState::LoadingFailed);
+        app.world_mut()
+            .resource_mut::<NextState<TestState>>()
+            .set(TestState::InGame);
+
+        for _ in 0..1000 {
+            app.update();
+            let state = app.world().resource::<State<TestState>>().get().clone();
+            if state != TestState::Loading {
+                return (app, state);
+            }
+        }
+        panic!("the transition was never applied");
+    }
+
+    #[test]
+    fn assets_loaded_opens_once_loaded() {
+        let (app, state) = load_and_transition("level.test");
+        assert_eq!(state, TestState::InGame);
+        assert!(app
+            .world()
+            .resource::<Events<TransitionGateFailed<TestState>>>()
+            .is_empty());
+    }
+
+    #[cfg(feature = "bevy_tasks")]
+    #[test]
+    fn tasks_finished_opens_once_finished() {
+        use super::tasks_finished;
+        use bevy_tasks::AsyncComputeTaskPool;
+
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), StatesPlugin))
+            .init_state::<TestState>();
+        let task = AsyncComputeTaskPool::get().spawn(async {});
+        app.add_state_gate(TestState::Loading, tasks_finished([task]));
+        app.world_mut()
+            .resource_mut::<NextState<TestState>>()
+            .set(TestState::InGame);
+
+        for _ in 0..1000 {
+            app.update();
+            if *app.world().resource::<State<TestState>>().get() == TestState::InGame {
+                return;
+            }
+        }
+        panic!("the transition was never applied");
+    }
+
+    #[test]
+    fn assets_loaded_fails_when_loading_fails() {
+        let (app, state) = load_and_transition("missing.test");
+        assert_eq!(state, TestState::LoadingFailed);
+        let events = app
+            .world()
+            .resource::<Events<TransitionGateFailed<TestState>>>();
+        let mut reader = events.get_cursor();
+        assert_eq!(
+            reader.read(events).map(|event| event.reason).last(),
+            Some(GateFailure::Failed)
+        );
+    }
+}
@@ -1,8 +1,15 @@
-use bevy_app::FixedMain;
-use bevy_ecs::world::World;
+use alloc::vec::Vec;
+use bevy_app::{App, FixedMain};
+use bevy_ecs::{
+    change_detection::Mut,
+    event::{EventRegistry, ShouldUpdateEvents},
+    resource::Resource,
+    schedule::{InternedScheduleLabel, ScheduleLabel},
+    world::World,
+};
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::Reflect;
-use core::time::Duration;
+use core::{mem, time::Duration};
 
 use crate::{time::Time, virt::Virtual};
 
@@ -64,6 +71,9 @@ use crate::{time::Time, virt::Virtual};
 /// [`FixedUpdate`](bevy_app::FixedUpdate), even if it is still during the same
 /// frame. Any [`overstep()`](Time::overstep) present in the accumulator will be
 /// processed according to the new [`timestep()`](Time::timestep) value.
+///
+/// Additional fixed timestep loops, each running its own schedule with its own
+/// clock, can be registered with [`FixedLoopsAppExt::add_fixed_loop`].
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
 pub struct Fixed {
@@ -251,9 +261,206 @@ pub(super) fn run_fixed_main_schedule(world: &mut World) {
     *world.resource_mut::<Time>() = world.resource::<Time<Virtual>>().as_generic();
 }
 
+/// The additional fixed timestep loops run by [`RunFixedMainLoop`](bevy_app::RunFixedMainLoop), besides [`FixedMain`].
+///
+/// Each loop runs its own schedule zero or more times per update with its own
+/// [`Time<Fixed>`] clock, which accumulates [`Time<Virtual>`](Virtual) just like
+/// the clock of [`FixedMain`]. This allows running, for example, physics at 120 Hz
+/// and AI at 10 Hz without emulating the slower rate with timers.
+///
+/// While the schedule of a loop is running, the clock of the loop is swapped in as
+/// the [`Time<Fixed>`] resource and as the generic [`Time`] resource, so systems in the
+/// loop read and configure their own clock as they would in [`FixedUpdate`](bevy_app::FixedUpdate).
+/// Changes made to the clock through this resource during its own loop are discarded.
+///
+/// Loops are run in the order they were added, after [`FixedMain`]. All the iterations of
+/// [`FixedMain`] in an update run before the ones of the loops, and each loop runs all of its iterations
+/// before the next one: the loops aren't interleaved by time, so a loop observes the world as left by
+/// every iteration of [`FixedMain`] in this update, even those whose clock is ahead of its own.
+///
+/// Events are only dropped once [`FixedMain`] and every loop have run since they were last updated,
+/// so that the systems of each loop can read them like the ones of [`FixedUpdate`](bevy_app::FixedUpdate).
+/// A loop with a long timestep thus keeps events alive for longer.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::ScheduleLabel;
+/// # use bevy_time::prelude::*;
+/// #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+/// struct AiUpdate;
+///
+/// fn think(time: Res<Time>) {
+///     // `time.delta()` is 100 milliseconds
+/// }
+///
+/// fn slow_down_ai(mut loops: ResMut<FixedLoops>) {
+///     if let Some(time) = loops.get_mut(AiUpdate) {
+///         time.set_timestep_hz(5.0);
+///     }
+/// }
+///
+/// # let mut app = App::new();
+/// app.add_fixed_loop(AiUpdate, Time::<Fixed>::from_hz(10.0))
+///     .add_systems(AiUpdate, think);
+/// ```
+#[derive(Resource, Debug, Default)]
+pub struct FixedLoops {
+    loops: Vec<FixedLoop>,
+    fixed_main_ran: bool,
+}
+
+#[derive(Debug)]
+struct FixedLoop {
+    label: InternedScheduleLabel,
+    time: Time<Fixed>,
+    /// Whether the loop ran since events were last updated.
+    ran: bool,
+}
+
+impl FixedLoops {
+    /// Adds a loop running the schedule `label` with the given clock, or replaces its clock.
+    ///
+    /// Returns the previous clock of the loop, if it already existed.
+    pub fn insert(&mut self, label: impl ScheduleLabel, time: Time<Fixed>) -> Option<Time<Fixed>> {
+        match self.get_mut(label.intern()) {
+            Some(current) => Some(mem::replace(current, time)),
+            None => {
+                self.loops.push(FixedLoop {
+                    label: label.intern(),
+                    time,
+                    ran: false,
+                });
+                None
+            }
+        }
+    }
+
+    /// Removes the loop running the schedule `label`, returning its clock.
+    pub fn remove(&mut self, label: impl ScheduleLabel) -> Option<Time<Fixed>> {
+        let label = label.intern();
+        let index = self.loops.iter().position(|other| other.label == label)?;
+        Some(self.loops.remove(index).time)
+    }
+
+    /// Returns the clock of the loop running the schedule `label`.
+    pub fn get(&self, label: impl ScheduleLabel) -> Option<&Time<Fixed>> {
+        let label = label.intern();
+        self.loops
+            .iter()
+            .find_map(|other| (other.label == label).then_some(&other.time))
+    }
+
+    /// Returns the clock of the loop running the schedule `label` mutably.
+    pub fn get_mut(&mut self, label: impl ScheduleLabel) -> Option<&mut Time<Fixed>> {
+        let label = label.intern();
+        self.loops
+            .iter_mut()
+            .find_map(|other| (other.label == label).then_some(&mut other.time))
+    }
+
+    /// Returns an iterator over the schedule labels and clocks of the loops, in the order they are run.
+    pub fn iter(&self) -> impl Iterator<Item = (InternedScheduleLabel, &Time<Fixed>)> {
+        self.loops.iter().map(|other| (other.label, &other.time))
+    }
+}
+
+/// Extension trait for [`App`] to register additional fixed timestep loops.
+pub trait FixedLoopsAppExt {
+    /// Adds a fixed timestep loop running the schedule `label` with the given clock, see [`FixedLoops`].
+    ///
+    /// If the loop already exists, its clock is replaced.
+    fn add_fixed_loop(&mut self, label: impl ScheduleLabel, time: Time<Fixed>) -> &mut Self;
+}
+
+impl FixedLoopsAppExt for App {
+    fn add_fixed_loop(&mut self, label: impl ScheduleLabel, time: Time<Fixed>) -> &mut Self {
+        let label = label.intern();
+        self.init_schedule(label)
+            .world_mut()
+            .get_resource_or_init::<FixedLoops>()
+            .insert(label, time);
+        self
+    }
+}
+
+/// Runs the schedules of the [`FixedLoops`] zero or more times based on delta
+/// of [`Time<Virtual>`](Virtual) and the [`Time::overstep`] of their clocks.
+pub(super) fn run_fixed_loops(world: &mut World) {
+    let delta = world.resource::<Time<Virtual>>().delta();
+    let Some(mut loops) = world.get_resource_mut::<FixedLoops>() else {
+        return;
+    };
+    if loops.loops.is_empty() {
+        return;
+    }
+    for other in &mut loops.loops {
+        other.time.accumulate(delta);
+    }
+    let labels: Vec<_> = loops.loops.iter().map(|other| other.label).collect();
+
+    for label in labels {
+        let Some(time) = world.resource::<FixedLoops>().get(label).copied() else {
+            continue;
+        };
+
+        // Switch the fixed clock to the one of this loop while it runs
+        let fixed_time = mem::replace(&mut *world.resource_mut::<Time<Fixed>>(), time);
+        let ran = world
+            .try_schedule_scope(label, |world, schedule| {
+                let mut ran = false;
+                while world.resource_mut::<Time<Fixed>>().expend() {
+                    *world.resource_mut::<Time>() = world.resource::<Time<Fixed>>().as_generic();
+                    schedule.run(world);
+                    ran = true;
+                }
+                ran
+            })
+            // Without a schedule, there is no system to wait for.
+            .unwrap_or(true);
+        let time = mem::replace(&mut *world.resource_mut::<Time<Fixed>>(), fixed_time);
+
+        let mut loops = world.resource_mut::<FixedLoops>();
+        if let Some(current) = loops.loops.iter_mut().find(|other| other.label == label) {
+            current.time = time;
+            current.ran |= ran;
+        }
+    }
+
+    *world.resource_mut::<Time>() = world.resource::<Time<Virtual>>().as_generic();
+    hold_event_updates(world);
+}
+
+/// Holds the event updates signaled by [`FixedMain`] until every fixed loop has run.
+fn hold_event_updates(world: &mut World) {
+    world.resource_scope(|world, mut loops: Mut<FixedLoops>| {
+        let Some(mut registry) = world.get_resource_mut::<EventRegistry>() else {
+            return;
+        };
+        if registry.should_update == ShouldUpdateEvents::Always {
+            return;
+        }
+        loops.fixed_main_ran |= registry.should_update == ShouldUpdateEvents::Ready;
+        if loops.fixed_main_ran && loops.loops.iter().all(|other| other.ran) {
+            registry.should_update = ShouldUpdateEvents::Ready;
+            loops.fixed_main_ran = false;
+            for other in &mut loops.loops {
+                other.ran = false;
+            }
+        } else {
+            registry.should_update = ShouldUpdateEvents::Waiting;
+        }
+    });
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::{TimePlugin, TimeUpdateStrategy};
+    use bevy_ecs::{
+        event::{Event, EventReader, Events},
+        system::{Res, ResMut},
+    };
 
     #[test]
     fn test_set_timestep() {
@@ -358,4 +565,106 @@ mod test {
         assert_eq!(time.elapsed(), Duration::from_secs(6));
         assert_eq!(time.overstep(), Duration::from_secs(1));
     }
+
+    #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    struct SlowUpdate;
+
+    #[derive(Resource, Default)]
+    struct SlowUpdates {
+        count: u32,
+        delta: Duration,
+        elapsed: Duration,
+    }
+
+    fn count_slow_updates(
+        mut updates: ResMut<SlowUpdates>,
+        time: Res<Time>,
+        fixed_time: Res<Time<Fixed>>,
+    ) {
+        assert_eq!(time.delta(), fixed_time.delta());
+        updates.count += 1;
+        updates.delta = time.delta();
+        updates.elapsed = fixed_time.elapsed();
+    }
+
+    #[test]
+    fn fixed_loops_run_with_their_own_clock() {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin)
+            .init_resource::<SlowUpdates>()
+            .add_fixed_loop(SlowUpdate, Time::<Fixed>::from_seconds(1.0))
+            .add_systems(SlowUpdate, count_slow_updates)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                150,
+            )));
+
+        // The first update has a zero delta
+        for _ in 0..7 {
+            app.update();
+        }
+        assert_eq!(app.world().resource::<SlowUpdates>().count, 0);
+
+        app.update();
+        let updates = app.world().resource::<SlowUpdates>();
+        assert_eq!(updates.count, 1);
+        assert_eq!(updates.delta, Duration::from_secs(1));
+        assert_eq!(updates.elapsed, Duration::from_secs(1));
+
+        // The clocks are switched back after the loop
+        let loops = app.world().resource::<FixedLoops>();
+        assert_eq!(
+            loops.get(SlowUpdate).unwrap().elapsed(),
+            Duration::from_secs(1)
+        );
+        assert_eq!(
+            loops.get(SlowUpdate).unwrap().overstep(),
+            Duration::from_millis(50)
+        );
+        assert_eq!(
+            app.world().resource::<Time<Fixed>>().timestep(),
+            Time::<Fixed>::DEFAULT_TIMESTEP
+        );
+        assert_eq!(
+            app.world().resource::<Time>().elapsed(),
+            app.world().resource::<Time<Virtual>>().elapsed()
+        );
+    }
+
+    #[derive(Event)]
+    struct SlowEvent;
+
+    #[derive(Resource, Default)]
+    struct SlowEventsRead(usize);
+
+    fn read_slow_events(mut events: EventReader<SlowEvent>, mut read: ResMut<SlowEventsRead>) {
+        read.0 += events.read().count();
+    }
+
+    #[test]
+    fn fixed_loops_hold_events_until_they_run() {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin)
+            .add_event::<SlowEvent>()
+            .init_resource::<SlowEventsRead>()
+            .add_fixed_loop(SlowUpdate, Time::<Fixed>::from_seconds(1.0))
+            .add_systems(SlowUpdate, read_slow_events)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                150,
+            )));
+        app.world_mut().send_event(SlowEvent);
+
+        // `FixedMain` runs many times before the first iteration of the loop
+        for _ in 0..8 {
+            app.update();
+        }
+        assert_eq!(app.world().resource::<SlowEventsRead>().0, 1);
+        assert_eq!(app.world().resource::<Events<SlowEvent>>().len(), 1);
+
+        // The event is dropped after the loop has run twice more
+        for _ in 0..16 {
+            app.update();
+        }
+        assert_eq!(app.world().resource::<SlowEventsRead>().0, 1);
+        assert_eq!(app.world().resource::<Events<SlowEvent>>().len(), 0);
+    }
 }
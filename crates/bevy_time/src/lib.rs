@@ -16,16 +16,22 @@ extern crate alloc;
 pub mod common_conditions;
 mod fixed;
 mod real;
+mod scaled;
 mod stopwatch;
 mod time;
+mod time_scale;
 mod timer;
+mod timer_queue;
 mod virt;
 
 pub use fixed::*;
 pub use real::*;
+pub use scaled::*;
 pub use stopwatch::*;
 pub use time::*;
+pub use time_scale::*;
 pub use timer::*;
+pub use timer_queue::*;
 pub use virt::*;
 
 /// The time prelude.
@@ -33,7 +39,10 @@ pub use virt::*;
 /// This includes the most common types in this crate, re-exported for your convenience.
 pub mod prelude {
     #[doc(hidden)]
-    pub use crate::{Fixed, Real, Time, Timer, TimerMode, Virtual};
+    pub use crate::{
+        ClockAppExt, ClockSource, Fixed, FixedLoops, FixedLoopsAppExt, GlobalTimeScale, Real,
+        Scaled, Time, TimeScale, Timer, TimerMode, TimerQueue, TimerQueueAppExt, Virtual,
+    };
 }
 
 use bevy_app::{prelude::*, RunFixedMainLoop};
@@ -65,6 +74,7 @@ impl Plugin for TimePlugin {
             .init_resource::<Time<Real>>()
             .init_resource::<Time<Virtual>>()
             .init_resource::<Time<Fixed>>()
+            .init_resource::<FixedLoops>()
             .init_resource::<TimeUpdateStrategy>();
 
         #[cfg(feature = "bevy_reflect")]
@@ -73,18 +83,25 @@ impl Plugin for TimePlugin {
                 .register_type::<Time<Real>>()
                 .register_type::<Time<Virtual>>()
                 .register_type::<Time<Fixed>>()
-                .register_type::<Timer>();
+                .register_type::<Timer>()
+                .register_type::<TimeScale>()
+                .register_type::<GlobalTimeScale>();
         }
 
         app.add_systems(
             First,
-            time_system
-                .in_set(TimeSystem)
-                .ambiguous_with(event_update_system),
+            (
+                time_system
+                    .in_set(TimeSystem)
+                    .ambiguous_with(event_update_system),
+                propagate_time_scales.after(TimeSystem),
+            ),
         )
         .add_systems(
             RunFixedMainLoop,
-            run_fixed_main_schedule.in_set(RunFixedMainLoopSystem::FixedMainLoop),
+            (run_fixed_main_schedule, run_fixed_loops)
+                .chain()
+                .in_set(RunFixedMainLoopSystem::FixedMainLoop),
         );
 
         // Ensure the events are not dropped until `FixedMain` systems can observe them
@@ -176,10 +193,15 @@ pub fn time_system(
 
 #[cfg(test)]
 mod tests {
-    use crate::{Fixed, Time, TimePlugin, TimeUpdateStrategy, Virtual};
-    use bevy_app::{App, FixedUpdate, Startup, Update};
+    use crate::{
+        ClockAppExt, ClockSource, Fixed, GlobalTimeScale, Scaled, Time, TimePlugin, TimeScale,
+        TimeUpdateStrategy, Virtual,
+    };
+    use alloc::vec::Vec;
+    use bevy_app::{App, FixedUpdate, PostUpdate, Startup, Update};
     use bevy_ecs::{
         event::{Event, EventReader, EventRegistry, EventWriter, Events, ShouldUpdateEvents},
+        hierarchy::ChildOf,
         resource::Resource,
         system::{Local, Res, ResMut},
     };
@@ -396,4 +418,104 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn schedule_clock_is_the_generic_time() {
+        struct Gameplay;
+
+        #[derive(Resource, Default)]
+        struct Deltas(Vec<(Duration, Duration)>);
+
+        fn record_update(mut deltas: ResMut<Deltas>, time: Res<Time>) {
+            deltas.0.push((time.delta(), Duration::ZERO));
+        }
+
+        fn record_post_update(mut deltas: ResMut<Deltas>, time: Res<Time>) {
+            deltas.0.last_mut().unwrap().1 = time.delta();
+        }
+
+        let mut app = App::new();
+        app.add_plugins(TimePlugin)
+            .init_resource::<Deltas>()
+            .add_clock::<Gameplay>(ClockSource::Virtual)
+            .set_schedule_clock::<Scaled<Gameplay>>(Update)
+            .add_systems(Update, record_update)
+            .add_systems(PostUpdate, record_post_update)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )));
+        app.world_mut()
+            .resource_mut::<Time<Scaled<Gameplay>>>()
+            .set_relative_speed(0.5);
+
+        app.update();
+        app.update();
+        app.world_mut()
+            .resource_mut::<Time<Scaled<Gameplay>>>()
+            .pause();
+        app.update();
+
+        let deltas = &app.world().resource::<Deltas>().0;
+        assert_eq!(
+            deltas[1],
+            (Duration::from_millis(50), Duration::from_millis(100))
+        );
+        assert_eq!(deltas[2], (Duration::ZERO, Duration::from_millis(100)));
+        assert!(!app.world().resource::<Time<Virtual>>().is_paused());
+    }
+
+    #[test]
+    fn time_scales_propagate_down_the_hierarchy() {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin);
+
+        let world = app.world_mut();
+        let root = world.spawn(TimeScale::new(0.5)).id();
+        let unscaled = world.spawn(ChildOf(root)).id();
+        let child = world.spawn((TimeScale::new(0.5), ChildOf(unscaled))).id();
+        let grandchild = world.spawn(ChildOf(child)).id();
+        let other = world.spawn(TimeScale::default()).id();
+        let outside = world.spawn_empty().id();
+        app.update();
+
+        let time_scale =
+            |app: &App, entity| app.world().get::<GlobalTimeScale>(entity).unwrap().get();
+        assert_eq!(time_scale(&app, root), 0.5);
+        assert_eq!(time_scale(&app, unscaled), 0.5);
+        assert_eq!(time_scale(&app, child), 0.25);
+        assert_eq!(time_scale(&app, grandchild), 0.25);
+        assert_eq!(time_scale(&app, other), 1.0);
+        assert!(app.world().get::<GlobalTimeScale>(outside).is_none());
+
+        // Children added later and changed scales are picked up
+        let late = app.world_mut().spawn(ChildOf(grandchild)).id();
+        app.world_mut().get_mut::<TimeScale>(root).unwrap().set(0.0);
+        app.update();
+        assert_eq!(time_scale(&app, late), 0.0);
+        assert_eq!(time_scale(&app, unscaled), 0.0);
+
+        // Entities moved out of a scaled hierarchy are reset
+        app.world_mut().entity_mut(unscaled).remove::<ChildOf>();
+        app.update();
+        assert_eq!(time_scale(&app, unscaled), 1.0);
+        assert_eq!(time_scale(&app, late), 0.5);
+
+        // Removed scales are picked up as well
+        app.world_mut().entity_mut(child).remove::<TimeScale>();
+        app.update();
+        assert_eq!(time_scale(&app, child), 1.0);
+        assert_eq!(time_scale(&app, late), 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn negative_time_scales_are_rejected() {
+        TimeScale::new(-1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn nan_time_scales_are_rejected() {
+        TimeScale::default().set(f32::NAN);
+    }
 }
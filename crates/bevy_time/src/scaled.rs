@@ -0,0 +1,318 @@
+use bevy_app::{App, First, MainScheduleOrder};
+use bevy_ecs::{
+    schedule::{ExecutorKind, InternedScheduleLabel, IntoSystemConfigs, Schedule, ScheduleLabel},
+    system::{Res, ResMut},
+};
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::Reflect;
+use core::{
+    fmt::{self, Debug, Formatter},
+    marker::PhantomData,
+    time::Duration,
+};
+
+use crate::{real::Real, time::Time, virt::Virtual, TimeSystem};
+
+/// The clock a [`Scaled`] clock is derived from.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+pub enum ClockSource {
+    /// Follow [`Time<Real>`](Real), ignoring the pause and speed of virtual time.
+    Real,
+    /// Follow [`Time<Virtual>`](Virtual), including its pause and speed.
+    #[default]
+    Virtual,
+}
+
+/// A user-defined game clock, derived from [`Time<Real>`](Real) or [`Time<Virtual>`](Virtual)
+/// with its own pause and relative speed.
+///
+/// A specialization of the [`Time`] structure. **For method documentation, see
+/// [`Time<Scaled<C>>#impl-Time<Scaled<C>>`].**
+///
+/// The marker type `C` distinguishes the clocks, for example `Time<Scaled<Gameplay>>`
+/// and `Time<Scaled<Ui>>`. A clock is added as a resource with [`ClockAppExt::add_clock`]
+/// and advanced in [`First`], after [`TimeSystem`],
+/// by the delta of its [`ClockSource`] multiplied by its [`effective_speed()`](Time::effective_speed).
+///
+/// Pausing a clock derived from [`Time<Virtual>`](Virtual), for example to stop gameplay while a
+/// menu is open, doesn't affect the virtual clock itself, and clocks derived from
+/// [`Time<Real>`](Real) keep running when the virtual clock is paused.
+///
+/// To make a clock the generic [`Time`] resource while a schedule runs, use
+/// [`ClockAppExt::set_schedule_clock`].
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_time::prelude::*;
+/// struct Gameplay;
+///
+/// fn open_menu(mut gameplay_time: ResMut<Time<Scaled<Gameplay>>>) {
+///     gameplay_time.pause();
+/// }
+///
+/// # let mut app = App::new();
+/// app.add_clock::<Gameplay>(ClockSource::Virtual)
+///     .set_schedule_clock::<Scaled<Gameplay>>(Update);
+/// ```
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+pub struct Scaled<C> {
+    source: ClockSource,
+    paused: bool,
+    relative_speed: f64,
+    effective_speed: f64,
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    marker: PhantomData<fn() -> C>,
+}
+
+impl<C> Default for Scaled<C> {
+    fn default() -> Self {
+        Self {
+            source: ClockSource::default(),
+            paused: false,
+            relative_speed: 1.0,
+            effective_speed: 1.0,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<C> Debug for Scaled<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scaled")
+            .field("source", &self.source)
+            .field("paused", &self.paused)
+            .field("relative_speed", &self.relative_speed)
+            .field("effective_speed", &self.effective_speed)
+            .finish()
+    }
+}
+
+impl<C> Clone for Scaled<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for Scaled<C> {}
+
+impl<C> Time<Scaled<C>> {
+    /// Create a new clock derived from `source`.
+    pub fn from_source(source: ClockSource) -> Self {
+        let mut ret = Self::default();
+        ret.context_mut().source = source;
+        ret
+    }
+
+    /// Returns the clock this clock is derived from.
+    #[inline]
+    pub fn source(&self) -> ClockSource {
+        self.context().source
+    }
+
+    /// Returns the speed the clock advances relative to its source, as [`f32`].
+    #[inline]
+    pub fn relative_speed(&self) -> f32 {
+        self.relative_speed_f64() as f32
+    }
+
+    /// Returns the speed the clock advances relative to its source, as [`f64`].
+    #[inline]
+    pub fn relative_speed_f64(&self) -> f64 {
+        self.context().relative_speed
+    }
+
+    /// Returns the speed the clock advanced relative to its source in
+    /// this update, as [`f32`].
+    ///
+    /// Returns `0.0` if the clock was paused.
+    #[inline]
+    pub fn effective_speed(&self) -> f32 {
+        self.context().effective_speed as f32
+    }
+
+    /// Returns the speed the clock advanced relative to its source in
+    /// this update, as [`f64`].
+    ///
+    /// Returns `0.0` if the clock was paused.
+    #[inline]
+    pub fn effective_speed_f64(&self) -> f64 {
+        self.context().effective_speed
+    }
+
+    /// Sets the speed the clock advances relative to its source, given as an [`f32`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio` is negative or not finite.
+    #[inline]
+    pub fn set_relative_speed(&mut self, ratio: f32) {
+        self.set_relative_speed_f64(ratio as f64);
+    }
+
+    /// Sets the speed the clock advances relative to its source, given as an [`f64`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio` is negative or not finite.
+    #[inline]
+    pub fn set_relative_speed_f64(&mut self, ratio: f64) {
+        assert!(ratio.is_finite(), "tried to go infinitely fast");
+        assert!(ratio >= 0.0, "tried to go back in time");
+        self.context_mut().relative_speed = ratio;
+    }
+
+    /// Stops the clock, preventing it from advancing until resumed.
+    #[inline]
+    pub fn pause(&mut self) {
+        self.context_mut().paused = true;
+    }
+
+    /// Resumes the clock if paused.
+    #[inline]
+    pub fn unpause(&mut self) {
+        self.context_mut().paused = false;
+    }
+
+    /// Returns `true` if the clock is currently paused.
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.context().paused
+    }
+
+    /// Returns `true` if the clock was paused at the start of this update.
+    #[inline]
+    pub fn was_paused(&self) -> bool {
+        self.context().effective_speed == 0.0
+    }
+
+    fn advance_with_source_delta(&mut self, source_delta: Duration) {
+        let effective_speed = if self.context().paused {
+            0.0
+        } else {
+            self.context().relative_speed
+        };
+        let delta = if effective_speed != 1.0 {
+            source_delta.mul_f64(effective_speed)
+        } else {
+            // avoid rounding when at normal speed
+            source_delta
+        };
+        self.context_mut().effective_speed = effective_speed;
+        self.advance_by(delta);
+    }
+}
+
+/// Advances the [`Time<Scaled<C>>`](Scaled) clock based on the delta of its [`ClockSource`].
+pub fn update_scaled_clock<C: 'static>(
+    real: Res<Time<Real>>,
+    virt: Res<Time<Virtual>>,
+    mut clock: ResMut<Time<Scaled<C>>>,
+) {
+    let source_delta = match clock.source() {
+        ClockSource::Real => real.delta(),
+        ClockSource::Virtual => virt.delta(),
+    };
+    clock.advance_with_source_delta(source_delta);
+}
+
+/// The schedule run before a schedule whose clock was set with [`ClockAppExt::set_schedule_clock`],
+/// making its clock the generic [`Time`] resource.
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EnterScheduleClock(pub InternedScheduleLabel);
+
+/// The schedule run after a schedule whose clock was set with [`ClockAppExt::set_schedule_clock`],
+/// making [`Time<Virtual>`](Virtual) the generic [`Time`] resource again.
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExitScheduleClock(pub InternedScheduleLabel);
+
+/// Extension trait for [`App`] to add custom clocks and select the clock of schedules.
+pub trait ClockAppExt {
+    /// Adds the [`Time<Scaled<C>>`](Scaled) clock derived from `source`, and the system advancing it.
+    ///
+    /// If the clock already exists, only its source is changed.
+    fn add_clock<C: 'static>(&mut self, source: ClockSource) -> &mut Self;
+
+    /// Makes the [`Time<T>`](Time) clock the generic [`Time`] resource while the `schedule` runs,
+    /// so that its systems can be reused with different clocks.
+    ///
+    /// The generic [`Time`] resource is set back to [`Time<Virtual>`](Virtual) after the schedule.
+    /// The `schedule` must be one of the [`MainScheduleOrder`] schedules, such as [`Update`](bevy_app::Update).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `schedule` isn't in the [`MainScheduleOrder`].
+    fn set_schedule_clock<T: Default + Send + Sync + 'static>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+    ) -> &mut Self;
+}
+
+impl ClockAppExt for App {
+    fn add_clock<C: 'static>(&mut self, source: ClockSource) -> &mut Self {
+        if let Some(mut clock) = self.world_mut().get_resource_mut::<Time<Scaled<C>>>() {
+            clock.context_mut().source = source;
+            return self;
+        }
+        self.insert_resource(Time::<Scaled<C>>::from_source(source))
+            .add_systems(First, update_scaled_clock::<C>.after(TimeSystem))
+    }
+
+    fn set_schedule_clock<T: Default + Send + Sync + 'static>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+    ) -> &mut Self {
+        let schedule = schedule.intern();
+        let enter = EnterScheduleClock(schedule);
+        let exit = ExitScheduleClock(schedule);
+
+        let mut order = self.world_mut().resource_mut::<MainScheduleOrder>();
+        if !order.labels.contains(&enter.intern()) {
+            order.insert_before(schedule, enter.clone());
+            order.insert_after(schedule, exit.clone());
+        }
+
+        // Replace the schedules, in case the clock of the schedule was already set.
+        let mut enter_schedule = Schedule::new(enter);
+        enter_schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+        enter_schedule.add_systems(use_generic_clock::<T>);
+        let mut exit_schedule = Schedule::new(exit);
+        exit_schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+        exit_schedule.add_systems(use_generic_clock::<Virtual>);
+
+        self.add_schedule(enter_schedule)
+            .add_schedule(exit_schedule)
+    }
+}
+
+fn use_generic_clock<T: Default + Send + Sync + 'static>(
+    clock: Res<Time<T>>,
+    mut time: ResMut<Time>,
+) {
+    *time = clock.as_generic();
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Gameplay;
+
+    #[test]
+    fn test_scaled_clock() {
+        let mut time = Time::<Scaled<Gameplay>>::from_source(ClockSource::Real);
+        assert_eq!(time.source(), ClockSource::Real);
+
+        time.set_relative_speed(0.5);
+        time.advance_with_source_delta(Duration::from_millis(200));
+        assert_eq!(time.delta(), Duration::from_millis(100));
+        assert_eq!(time.effective_speed(), 0.5);
+
+        time.pause();
+        time.advance_with_source_delta(Duration::from_millis(200));
+        assert_eq!(time.delta(), Duration::ZERO);
+        assert_eq!(time.elapsed(), Duration::from_millis(100));
+        assert!(time.was_paused());
+    }
+}
@@ -0,0 +1,187 @@
+use alloc::vec::Vec;
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    component::Component,
+    entity::{hash_set::EntityHashSet, Entity},
+    hierarchy::{ChildOf, Children},
+    prelude::require,
+    query::{Added, Changed, Or},
+    removal_detection::RemovedComponents,
+    system::{Commands, ParamSet, Query},
+};
+use core::time::Duration;
+#[cfg(feature = "bevy_reflect")]
+use {
+    bevy_ecs::reflect::ReflectComponent,
+    bevy_reflect::{std_traits::ReflectDefault, Reflect},
+};
+
+/// Scales the passage of time for an entity and its descendants.
+///
+/// Time scales multiply down the hierarchy: the resulting scale of an entity, the product of
+/// its own [`TimeScale`] and the ones of its ancestors, is computed into its [`GlobalTimeScale`]
+/// in [`First`](bevy_app::First), after [`TimeSystem`](crate::TimeSystem).
+/// Entities without a [`TimeScale`] don't affect the scale of their descendants, but like every
+/// descendant of an entity with a [`TimeScale`], they are given a [`GlobalTimeScale`].
+///
+/// Time scales are not applied automatically: systems opt in by scaling the delta they use,
+/// so bullet-time can slow the enemies down without slowing the player.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_time::prelude::*;
+/// # #[derive(Component)]
+/// # struct Velocity(f32);
+/// # #[derive(Component)]
+/// # struct Position(f32);
+/// fn move_entities(time: Res<Time>, mut query: Query<(&mut Position, &Velocity, &GlobalTimeScale)>) {
+///     for (mut position, velocity, time_scale) in &mut query {
+///         position.0 += velocity.0 * time_scale.delta_secs(&time);
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+#[require(GlobalTimeScale)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Default, PartialEq, Debug)
+)]
+pub struct TimeScale(f32);
+
+impl Default for TimeScale {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+impl TimeScale {
+    /// Creates a time scale, `1.0` being the normal speed and `0.0` stopping time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scale` is negative or not finite.
+    pub fn new(scale: f32) -> Self {
+        let mut time_scale = Self::default();
+        time_scale.set(scale);
+        time_scale
+    }
+
+    /// Returns the time scale.
+    #[inline]
+    pub fn get(&self) -> f32 {
+        self.0
+    }
+
+    /// Sets the time scale.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scale` is negative or not finite.
+    pub fn set(&mut self, scale: f32) {
+        assert!(scale.is_finite(), "tried to go infinitely fast");
+        assert!(scale >= 0.0, "tried to go back in time");
+        self.0 = scale;
+    }
+}
+
+/// The time scale of an entity, combined with the [`TimeScale`] of its ancestors.
+///
+/// This is computed from the [`TimeScale`] of the entity and its ancestors, and should not be modified directly.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Default, PartialEq, Debug)
+)]
+pub struct GlobalTimeScale(f32);
+
+impl Default for GlobalTimeScale {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+impl GlobalTimeScale {
+    /// Returns the combined time scale.
+    #[inline]
+    pub fn get(&self) -> f32 {
+        self.0
+    }
+
+    /// Returns `delta` scaled by the time scale.
+    #[inline]
+    pub fn scale(&self, delta: Duration) -> Duration {
+        delta.mul_f32(self.0)
+    }
+
+    /// Returns the [`delta_secs()`](crate::Time::delta_secs) of `time` scaled by the time scale.
+    #[inline]
+    pub fn delta_secs<T: Default>(&self, time: &crate::Time<T>) -> f32 {
+        time.delta_secs() * self.0
+    }
+}
+
+/// Computes the [`GlobalTimeScale`] of entities from their [`TimeScale`] and the ones of their ancestors,
+/// and inserts it on the descendants of entities with a [`TimeScale`] which don't have one yet.
+///
+/// Like transform propagation, only the hierarchies below entities whose [`TimeScale`] or [`ChildOf`] changed,
+/// or which were just given a [`GlobalTimeScale`], are updated.
+pub fn propagate_time_scales(
+    mut commands: Commands,
+    mut global_time_scales: ParamSet<(
+        Query<Entity, Or<(Changed<TimeScale>, Changed<ChildOf>, Added<GlobalTimeScale>)>>,
+        Query<&mut GlobalTimeScale>,
+    )>,
+    mut removed_time_scales: RemovedComponents<TimeScale>,
+    mut removed_parents: RemovedComponents<ChildOf>,
+    time_scales: Query<&TimeScale>,
+    parents: Query<&ChildOf>,
+    children: Query<&Children>,
+) {
+    let dirty = global_time_scales
+        .p0()
+        .iter()
+        .chain(removed_time_scales.read())
+        .chain(removed_parents.read())
+        .collect::<EntityHashSet>();
+    let mut global_time_scales = global_time_scales.p1();
+    let mut stack = Vec::new();
+
+    for &entity in &dirty {
+        // The descendants of nested dirty entities are visited from the topmost one.
+        if parents
+            .iter_ancestors(entity)
+            .any(|ancestor| dirty.contains(&ancestor))
+        {
+            continue;
+        }
+        let mut scale = 1.0;
+        let mut scaled = false;
+        for ancestor in parents.iter_ancestors(entity) {
+            if let Ok(time_scale) = time_scales.get(ancestor) {
+                scale *= time_scale.0;
+                scaled = true;
+            }
+        }
+        stack.push((entity, scale, scaled));
+
+        while let Some((entity, parent_scale, parent_scaled)) = stack.pop() {
+            let time_scale = time_scales.get(entity).ok();
+            let scale = parent_scale * time_scale.map_or(1.0, TimeScale::get);
+            let scaled = parent_scaled || time_scale.is_some();
+            match global_time_scales.get_mut(entity) {
+                Ok(mut global_time_scale) => {
+                    global_time_scale.set_if_neq(GlobalTimeScale(scale));
+                }
+                Err(_) if scaled => {
+                    commands.entity(entity).insert(GlobalTimeScale(scale));
+                }
+                Err(_) => {}
+            }
+            if let Ok(entity_children) = children.get(entity) {
+                stack.extend(entity_children.iter().map(|&child| (child, scale, scaled)));
+            }
+        }
+    }
+}
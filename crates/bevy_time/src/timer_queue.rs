@@ -0,0 +1,520 @@
+use crate::{time::Time, timer::TimerMode, virt::Virtual, TimeSystem};
+use alloc::vec::Vec;
+use bevy_app::{App, First};
+#[cfg(feature = "bevy_reflect")]
+use bevy_ecs::reflect::{ReflectMapEntities, ReflectResource};
+use bevy_ecs::{
+    entity::{Entities, Entity, EntityMapper, MapEntities},
+    event::Event,
+    resource::Resource,
+    schedule::{IntoSystemConfigs, SystemSet},
+    system::{Commands, Res, ResMut},
+};
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use core::time::Duration;
+
+/// A queue of timers triggering the event `E` as an observer trigger once they elapse.
+///
+/// Unlike a [`Timer`](crate::Timer), which must be ticked by the system owning it, the timers
+/// of the queue are ticked centrally with the delta of [`Time<Virtual>`](Virtual), so they honor
+/// its pause and relative speed. Elapsed timers [trigger](Commands::trigger) their event,
+/// optionally targeting an entity, in the [`First`] schedule in the [`TimerQueueSystem`] set.
+///
+/// The queue of an event type is added with [`TimerQueueAppExt::add_timer_queue`].
+/// To capture pending timers in saves, register `TimerQueue<E>` in the type registry.
+/// The targets of pending timers are [mapped](MapEntities) when the queue is loaded from a scene.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_time::prelude::*;
+/// # use core::time::Duration;
+/// #[derive(Event, Clone)]
+/// struct Explode;
+///
+/// fn arm_bomb(mut commands: Commands, mut timers: ResMut<TimerQueue<Explode>>) {
+///     let bomb = commands.spawn_empty().id();
+///     timers.schedule_on(bomb, Duration::from_secs_f32(2.5), TimerMode::Once, Explode);
+/// }
+///
+/// # let mut app = App::new();
+/// app.add_timer_queue::<Explode>()
+///     .add_observer(|trigger: Trigger<Explode>, mut commands: Commands| {
+///         commands.entity(trigger.target()).despawn();
+///     });
+/// ```
+#[derive(Resource, Debug)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Resource, Default, MapEntities)
+)]
+pub struct TimerQueue<E: Event> {
+    elapsed: Duration,
+    next_handle: u64,
+    next_sequence: u64,
+    // A binary min-heap ordered by the time each timer elapses, then by scheduling order.
+    // It's kept in a `Vec` rather than a `BinaryHeap` so that pending timers can be reflected.
+    timers: Vec<QueuedTimer<E>>,
+}
+
+impl<E: Event> Default for TimerQueue<E> {
+    fn default() -> Self {
+        Self {
+            elapsed: Duration::ZERO,
+            next_handle: 0,
+            next_sequence: 0,
+            timers: Vec::new(),
+        }
+    }
+}
+
+impl<E: Event> MapEntities for TimerQueue<E> {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        for timer in &mut self.timers {
+            if let Some(target) = &mut timer.target {
+                *target = entity_mapper.map_entity(*target);
+            }
+        }
+    }
+}
+
+/// A handle to a timer scheduled in a [`TimerQueue`], used to cancel it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(PartialEq, Hash))]
+pub struct TimerHandle(u64);
+
+/// A timer scheduled in a [`TimerQueue`].
+#[derive(Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+struct QueuedTimer<E> {
+    handle: TimerHandle,
+    target: Option<Entity>,
+    elapses_at: Duration,
+    sequence: u64,
+    interval: Option<Duration>,
+    event: E,
+}
+
+impl<E> QueuedTimer<E> {
+    fn key(&self) -> (Duration, u64) {
+        (self.elapses_at, self.sequence)
+    }
+}
+
+impl<E: Event> TimerQueue<E> {
+    /// Schedules a timer triggering `event` without a target after `delay`.
+    ///
+    /// With [`TimerMode::Repeating`], the event is triggered again every `delay` until the timer is canceled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mode` is [`TimerMode::Repeating`] and `delay` is zero.
+    pub fn schedule(&mut self, delay: Duration, mode: TimerMode, event: E) -> TimerHandle {
+        self.insert(None, delay, mode, event)
+    }
+
+    /// Schedules a timer triggering `event` targeting `entity` after `delay`.
+    ///
+    /// The timer is dropped without triggering if `entity` was despawned when it elapses.
+    /// With [`TimerMode::Repeating`], the event is triggered again every `delay` until the timer is canceled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mode` is [`TimerMode::Repeating`] and `delay` is zero.
+    pub fn schedule_on(
+        &mut self,
+        entity: Entity,
+        delay: Duration,
+        mode: TimerMode,
+        event: E,
+    ) -> TimerHandle {
+        self.insert(Some(entity), delay, mode, event)
+    }
+
+    /// Cancels a pending timer, returning its event.
+    pub fn cancel(&mut self, handle: TimerHandle) -> Option<E> {
+        let index = self
+            .timers
+            .iter()
+            .position(|timer| timer.handle == handle)?;
+        Some(self.remove(index).event)
+    }
+
+    /// Returns `true` if the timer is still pending.
+    pub fn contains(&self, handle: TimerHandle) -> bool {
+        self.timers.iter().any(|timer| timer.handle == handle)
+    }
+
+    /// Returns the time left before the timer elapses, if it's still pending.
+    pub fn remaining(&self, handle: TimerHandle) -> Option<Duration> {
+        self.timers
+            .iter()
+            .find(|timer| timer.handle == handle)
+            .map(|timer| timer.elapses_at.saturating_sub(self.elapsed))
+    }
+
+    /// Returns the virtual time elapsed since the queue was created.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns the number of pending timers.
+    pub fn len(&self) -> usize {
+        self.timers.len()
+    }
+
+    /// Returns `true` if no timer is pending.
+    pub fn is_empty(&self) -> bool {
+        self.timers.is_empty()
+    }
+
+    /// Cancels all pending timers.
+    pub fn clear(&mut self) {
+        self.timers.clear();
+    }
+
+    fn insert(
+        &mut self,
+        target: Option<Entity>,
+        delay: Duration,
+        mode: TimerMode,
+        event: E,
+    ) -> TimerHandle {
+        let interval = match mode {
+            TimerMode::Once => None,
+            TimerMode::Repeating => {
+                assert_ne!(
+                    delay,
+                    Duration::ZERO,
+                    "attempted to schedule a repeating timer with a zero interval"
+                );
+                Some(delay)
+            }
+        };
+        let handle = TimerHandle(self.next_handle);
+        self.next_handle += 1;
+        self.push(QueuedTimer {
+            handle,
+            target,
+            elapses_at: self.elapsed + delay,
+            sequence: 0,
+            interval,
+            event,
+        });
+        handle
+    }
+
+    /// Returns the timer which elapses first, if any.
+    fn first(&self) -> Option<&QueuedTimer<E>> {
+        self.timers.first()
+    }
+
+    /// Adds a timer to the heap, after the timers elapsing at the same time.
+    fn push(&mut self, mut timer: QueuedTimer<E>) {
+        timer.sequence = self.next_sequence;
+        self.next_sequence += 1;
+        self.timers.push(timer);
+        self.sift_up(self.timers.len() - 1);
+    }
+
+    /// Removes the timer at `index` of the heap.
+    fn remove(&mut self, index: usize) -> QueuedTimer<E> {
+        let timer = self.timers.swap_remove(index);
+        if index < self.timers.len() {
+            self.sift_down(index);
+            self.sift_up(index);
+        }
+        timer
+    }
+
+    fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if self.timers[parent].key() <= self.timers[index].key() {
+                break;
+            }
+            self.timers.swap(parent, index);
+            index = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut index: usize) {
+        loop {
+            let mut first = index;
+            for child in [2 * index + 1, 2 * index + 2] {
+                if child < self.timers.len() && self.timers[child].key() < self.timers[first].key()
+                {
+                    first = child;
+                }
+            }
+            if first == index {
+                break;
+            }
+            self.timers.swap(index, first);
+            index = first;
+        }
+    }
+}
+
+/// The [`SystemSet`] in [`First`] where the timers of every [`TimerQueue`] are ticked and trigger their events.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, SystemSet)]
+pub struct TimerQueueSystem;
+
+/// Extension trait for [`App`] to add [`TimerQueue`]s.
+pub trait TimerQueueAppExt {
+    /// Adds a [`TimerQueue`] for the event `E`, and the system triggering its elapsed timers.
+    fn add_timer_queue<E: Event + Clone>(&mut self) -> &mut Self;
+}
+
+impl TimerQueueAppExt for App {
+    fn add_timer_queue<E: Event + Clone>(&mut self) -> &mut Self {
+        if self.world().contains_resource::<TimerQueue<E>>() {
+            return self;
+        }
+        self.init_resource::<TimerQueue<E>>().add_systems(
+            First,
+            trigger_elapsed_timers::<E>
+                .in_set(TimerQueueSystem)
+                .after(TimeSystem),
+        )
+    }
+}
+
+/// Ticks the [`TimerQueue<E>`] with the delta of [`Time<Virtual>`](Virtual) and triggers the events of elapsed timers.
+pub fn trigger_elapsed_timers<E: Event + Clone>(
+    mut commands: Commands,
+    entities: &Entities,
+    time: Res<Time<Virtual>>,
+    mut queue: ResMut<TimerQueue<E>>,
+) {
+    let delta = time.delta();
+    if !delta.is_zero() {
+        queue.elapsed += delta;
+    }
+    let elapsed = queue.elapsed;
+
+    while queue
+        .first()
+        .is_some_and(|timer| timer.elapses_at <= elapsed)
+    {
+        let mut timer = queue.remove(0);
+        if timer
+            .target
+            .is_some_and(|target| !entities.contains(target))
+        {
+            continue;
+        }
+
+        let target = timer.target;
+        let event = match timer.interval {
+            Some(interval) => {
+                let event = timer.event.clone();
+                timer.elapses_at += interval;
+                queue.push(timer);
+                event
+            }
+            None => timer.event,
+        };
+        match target {
+            Some(target) => commands.trigger_targets(event, target),
+            None => commands.trigger(event),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TimePlugin, TimeUpdateStrategy};
+    use bevy_ecs::{observer::Trigger, resource::Resource, system::ResMut};
+
+    #[derive(Event, Clone)]
+    #[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+    struct Ring(u32);
+
+    #[derive(Resource, Default)]
+    struct Rings(Vec<(u32, Entity)>);
+
+    fn app_with_ring_queue() -> App {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin)
+            .add_timer_queue::<Ring>()
+            .init_resource::<Rings>()
+            .add_observer(|trigger: Trigger<Ring>, mut rings: ResMut<Rings>| {
+                rings.0.push((trigger.event().0, trigger.target()));
+            })
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )));
+        // The first update has a zero delta
+        app.update();
+        app
+    }
+
+    fn rings(app: &App) -> Vec<u32> {
+        let rings = app.world().resource::<Rings>();
+        rings.0.iter().map(|(ring, _)| *ring).collect()
+    }
+
+    #[test]
+    fn timers_trigger_in_order() {
+        let mut app = app_with_ring_queue();
+        let entity = app.world_mut().spawn_empty().id();
+        let mut queue = app.world_mut().resource_mut::<TimerQueue<Ring>>();
+        queue.schedule(Duration::from_millis(250), TimerMode::Once, Ring(2));
+        queue.schedule_on(entity, Duration::from_millis(150), TimerMode::Once, Ring(1));
+        let canceled = queue.schedule(Duration::from_millis(200), TimerMode::Once, Ring(3));
+        assert!(queue.cancel(canceled).is_some());
+        assert_eq!(queue.len(), 2);
+
+        app.update();
+        assert!(rings(&app).is_empty());
+        app.update();
+        assert_eq!(rings(&app), [1]);
+        assert_eq!(app.world().resource::<Rings>().0[0].1, entity);
+        app.update();
+        assert_eq!(rings(&app), [1, 2]);
+        assert!(app.world().resource::<TimerQueue<Ring>>().is_empty());
+    }
+
+    #[test]
+    fn timers_elapsing_together_trigger_in_scheduling_order() {
+        let mut app = app_with_ring_queue();
+        let mut queue = app.world_mut().resource_mut::<TimerQueue<Ring>>();
+        for (ring, delay) in [(0, 300), (1, 100), (2, 200), (3, 100), (4, 300), (5, 200)] {
+            queue.schedule(Duration::from_millis(delay), TimerMode::Once, Ring(ring));
+        }
+        let canceled = queue.schedule(Duration::from_millis(100), TimerMode::Once, Ring(6));
+        queue.cancel(canceled);
+
+        app.update();
+        app.update();
+        app.update();
+        assert_eq!(rings(&app), [1, 3, 2, 5, 0, 4]);
+    }
+
+    #[test]
+    fn repeating_timers_follow_virtual_time() {
+        let mut app = app_with_ring_queue();
+        let mut queue = app.world_mut().resource_mut::<TimerQueue<Ring>>();
+        let handle = queue.schedule(Duration::from_millis(100), TimerMode::Repeating, Ring(0));
+
+        app.update();
+        app.update();
+        assert_eq!(rings(&app), [0, 0]);
+
+        app.world_mut().resource_mut::<Time<Virtual>>().pause();
+        app.update();
+        assert_eq!(rings(&app), [0, 0]);
+
+        let mut time = app.world_mut().resource_mut::<Time<Virtual>>();
+        time.unpause();
+        time.set_relative_speed(2.0);
+        app.update();
+        assert_eq!(rings(&app), [0, 0, 0, 0]);
+
+        let mut queue = app.world_mut().resource_mut::<TimerQueue<Ring>>();
+        assert_eq!(queue.remaining(handle), Some(Duration::from_millis(100)));
+        queue.cancel(handle);
+        app.update();
+        assert_eq!(rings(&app).len(), 4);
+    }
+
+    #[test]
+    fn timers_on_despawned_entities_are_dropped() {
+        let mut app = app_with_ring_queue();
+        let entity = app.world_mut().spawn_empty().id();
+        app.world_mut()
+            .resource_mut::<TimerQueue<Ring>>()
+            .schedule_on(entity, Duration::from_millis(100), TimerMode::Once, Ring(0));
+        app.world_mut().despawn(entity);
+
+        app.update();
+        assert!(rings(&app).is_empty());
+        assert!(app.world().resource::<TimerQueue<Ring>>().is_empty());
+    }
+
+    #[cfg(feature = "bevy_reflect")]
+    #[test]
+    fn pending_timers_are_reflected() {
+        use bevy_reflect::{PartialReflect, ReflectRef, TypeRegistry};
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<TimerQueue<Ring>>();
+        assert!(registry
+            .get_type_data::<ReflectResource>(core::any::TypeId::of::<TimerQueue<Ring>>())
+            .is_some());
+
+        let mut queue = TimerQueue::<Ring>::default();
+        queue.schedule(Duration::from_secs(1), TimerMode::Once, Ring(7));
+        let ReflectRef::Struct(queue) = queue.reflect_ref() else {
+            panic!("`TimerQueue` should be reflected as a struct");
+        };
+        let timers = queue
+            .field("timers")
+            .unwrap()
+            .reflect_ref()
+            .as_list()
+            .unwrap();
+        assert_eq!(timers.len(), 1);
+    }
+
+    #[cfg(all(feature = "bevy_reflect", feature = "serialize"))]
+    #[test]
+    fn pending_timers_roundtrip_through_reflection() {
+        use bevy_ecs::reflect::AppTypeRegistry;
+        use bevy_reflect::{
+            serde::{ReflectDeserializer, ReflectSerializer},
+            FromReflect,
+        };
+        use serde::de::DeserializeSeed;
+
+        struct Remap(Entity, Entity);
+
+        impl EntityMapper for Remap {
+            fn map_entity(&mut self, entity: Entity) -> Entity {
+                if entity == self.0 {
+                    self.1
+                } else {
+                    entity
+                }
+            }
+        }
+
+        let mut app = app_with_ring_queue();
+        app.register_type::<TimerQueue<Ring>>();
+        let saved_target = app.world_mut().spawn_empty().id();
+        let mut queue = app.world_mut().resource_mut::<TimerQueue<Ring>>();
+        queue.schedule_on(
+            saved_target,
+            Duration::from_millis(100),
+            TimerMode::Once,
+            Ring(1),
+        );
+        queue.schedule(Duration::from_millis(200), TimerMode::Once, Ring(2));
+
+        let registry = app.world().resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+        let queue = app.world().resource::<TimerQueue<Ring>>();
+        let serialized = ron::to_string(&ReflectSerializer::new(queue, &registry)).unwrap();
+        let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
+        let deserialized = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        let mut queue = TimerQueue::<Ring>::from_reflect(deserialized.as_ref()).unwrap();
+        drop(registry);
+
+        let loaded_target = app.world_mut().spawn_empty().id();
+        queue.map_entities(&mut Remap(saved_target, loaded_target));
+        app.insert_resource(queue);
+
+        app.update();
+        app.update();
+        assert_eq!(
+            app.world().resource::<Rings>().0,
+            [(1, loaded_target), (2, Entity::PLACEHOLDER)]
+        );
+    }
+}
@@ -0,0 +1,309 @@
+use crate::{components::Transform, plugins::TransformSystem};
+use bevy_app::{App, FixedFirst, FixedLast, Plugin, PostUpdate};
+use bevy_ecs::{
+    component::Component,
+    prelude::require,
+    schedule::{IntoSystemConfigs, IntoSystemSetConfigs, SystemSet},
+    system::{Query, Res},
+};
+use bevy_math::Quat;
+use bevy_time::{Fixed, Time};
+use core::time::Duration;
+
+#[cfg(feature = "bevy_reflect")]
+use {bevy_ecs::reflect::ReflectComponent, bevy_reflect::prelude::*};
+
+/// Set enum for the systems of the [`TransformInterpolationPlugin`].
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+pub enum TransformInterpolationSystem {
+    /// Restores the [`Transform`] of the last fixed tick before the fixed timestep schedules run, in [`FixedFirst`].
+    RestoreFixedTransform,
+    /// Stores the [`Transform`] of the fixed tick after the fixed timestep schedules ran, in [`FixedLast`].
+    StoreFixedTransform,
+    /// Writes the interpolated [`Transform`] for rendering, in [`PostUpdate`] before [`TransformSystem::TransformPropagate`].
+    Interpolate,
+}
+
+/// Smooths the movement of entities with [`TransformInterpolation`] between fixed timesteps.
+///
+/// Entities moved in the [`FixedMain`](bevy_app::FixedMain) schedules only change their
+/// [`Transform`] when a fixed tick runs, which can cause visible stutter when the fixed timestep
+/// doesn't match the frame rate. This plugin stores the [`Transform`] of the last two fixed ticks
+/// and writes a [`Transform`] blended between them every frame, according to
+/// [`Time::<Fixed>::overstep_fraction`](Time::overstep_fraction).
+///
+/// The blended [`Transform`] is only used for rendering: before the fixed timestep schedules run,
+/// the [`Transform`] of the last fixed tick is restored, so fixed timestep systems never observe it.
+///
+/// This plugin is not added by default and requires the [`TimePlugin`](bevy_time::TimePlugin).
+#[derive(Default)]
+pub struct TransformInterpolationPlugin;
+
+impl Plugin for TransformInterpolationPlugin {
+    fn build(&self, app: &mut App) {
+        #[cfg(feature = "bevy_reflect")]
+        app.register_type::<TransformInterpolation>()
+            .register_type::<FixedTickTransforms>();
+
+        app.add_systems(
+            FixedFirst,
+            restore_fixed_transforms.in_set(TransformInterpolationSystem::RestoreFixedTransform),
+        )
+        .add_systems(
+            FixedLast,
+            store_fixed_transforms.in_set(TransformInterpolationSystem::StoreFixedTransform),
+        )
+        .configure_sets(
+            PostUpdate,
+            TransformInterpolationSystem::Interpolate.before(TransformSystem::TransformPropagate),
+        )
+        .add_systems(
+            PostUpdate,
+            interpolate_transforms.in_set(TransformInterpolationSystem::Interpolate),
+        );
+    }
+}
+
+/// Marks an entity whose [`Transform`] is blended between fixed timesteps by the [`TransformInterpolationPlugin`].
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[require(FixedTickTransforms)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Default, PartialEq, Debug)
+)]
+pub enum TransformInterpolation {
+    /// Blends between the two last fixed ticks, rendering one fixed timestep behind.
+    #[default]
+    Interpolate,
+    /// Predicts the next fixed tick from the two last fixed ticks, rendering without latency,
+    /// at the cost of overshooting when the movement changes.
+    Extrapolate,
+}
+
+/// The [`Transform`]s of the last two fixed ticks of an entity with [`TransformInterpolation`].
+///
+/// The [`Transform`] is snapped instead of being blended when it's changed outside of the
+/// fixed timestep schedules. To snap it from a fixed timestep system, for example when
+/// teleporting the entity, use [`teleport`](Self::teleport).
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Default, PartialEq, Debug)
+)]
+pub struct FixedTickTransforms {
+    previous: Option<Transform>,
+    current: Option<Transform>,
+    tick_delta: Duration,
+    rendered: Option<Transform>,
+    teleported: bool,
+}
+
+impl FixedTickTransforms {
+    /// Returns the [`Transform`] of the previous fixed tick.
+    pub fn previous(&self) -> Option<&Transform> {
+        self.previous.as_ref()
+    }
+
+    /// Returns the [`Transform`] of the last fixed tick.
+    pub fn current(&self) -> Option<&Transform> {
+        self.current.as_ref()
+    }
+
+    /// Snaps to the [`Transform`] of the current fixed tick instead of blending from the previous one.
+    pub fn teleport(&mut self) {
+        self.teleported = true;
+    }
+
+    fn snap(&mut self, transform: Transform) {
+        self.previous = Some(transform);
+        self.current = Some(transform);
+        self.rendered = None;
+    }
+}
+
+fn restore_fixed_transforms(mut query: Query<(&mut Transform, &mut FixedTickTransforms)>) {
+    for (mut transform, mut ticks) in &mut query {
+        let Some(rendered) = ticks.rendered.take() else {
+            continue;
+        };
+        match ticks.current {
+            // Only restore the fixed tick if the blended transform wasn't changed since it was written.
+            Some(current) if *transform == rendered => *transform = current,
+            _ => ticks.snap(*transform),
+        }
+    }
+}
+
+fn store_fixed_transforms(
+    time: Res<Time<Fixed>>,
+    mut query: Query<(&Transform, &mut FixedTickTransforms)>,
+) {
+    for (transform, mut ticks) in &mut query {
+        if ticks.teleported || ticks.current.is_none() {
+            // Newly spawned or teleported entities have nothing to blend from.
+            ticks.teleported = false;
+            ticks.snap(*transform);
+        } else {
+            ticks.previous = ticks.current.replace(*transform);
+        }
+        ticks.tick_delta = time.delta();
+    }
+}
+
+fn interpolate_transforms(
+    time: Res<Time<Fixed>>,
+    mut query: Query<(
+        &mut Transform,
+        &mut FixedTickTransforms,
+        &TransformInterpolation,
+    )>,
+) {
+    for (mut transform, mut ticks, interpolation) in &mut query {
+        let (Some(previous), Some(current)) = (ticks.previous, ticks.current) else {
+            continue;
+        };
+        // Snap if the transform was changed outside of the fixed timestep schedules.
+        if *transform != ticks.rendered.unwrap_or(current) {
+            ticks.snap(*transform);
+            continue;
+        }
+
+        let blended = match interpolation {
+            TransformInterpolation::Interpolate => {
+                // The timestep may have changed since the last tick, so the overstep can exceed it.
+                let t = time.overstep_fraction().clamp(0.0, 1.0);
+                Transform {
+                    translation: previous.translation.lerp(current.translation, t),
+                    rotation: previous.rotation.slerp(current.rotation, t),
+                    scale: previous.scale.lerp(current.scale, t),
+                }
+            }
+            TransformInterpolation::Extrapolate => {
+                // Extrapolate relative to the duration of the last tick,
+                // so a changed timestep doesn't change the predicted speed.
+                let t = if ticks.tick_delta.is_zero() {
+                    0.0
+                } else {
+                    (time.overstep().as_secs_f32() / ticks.tick_delta.as_secs_f32()).min(1.0)
+                };
+                let rotation = current.rotation * previous.rotation.inverse();
+                Transform {
+                    translation: current.translation
+                        + (current.translation - previous.translation) * t,
+                    rotation: (Quat::IDENTITY.slerp(rotation, t) * current.rotation).normalize(),
+                    scale: current.scale + (current.scale - previous.scale) * t,
+                }
+            }
+        };
+
+        if *transform != blended {
+            *transform = blended;
+        }
+        ticks.rendered = Some(blended);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_app::{FixedUpdate, TaskPoolPlugin};
+    use bevy_ecs::{entity::Entity, query::With};
+    use bevy_math::Vec3;
+    use bevy_time::{TimePlugin, TimeUpdateStrategy};
+
+    fn move_right(mut query: Query<&mut Transform, With<TransformInterpolation>>) {
+        for mut transform in &mut query {
+            transform.translation.x += 1.0;
+        }
+    }
+
+    fn app_with_moving_entity(interpolation: TransformInterpolation) -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            TimePlugin,
+            TransformInterpolationPlugin,
+        ))
+        .add_systems(FixedUpdate, move_right)
+        .insert_resource(Time::<Fixed>::from_seconds(1.0))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        let entity = app
+            .world_mut()
+            .spawn((Transform::default(), interpolation))
+            .id();
+        // The first update has a zero delta, the next four run the first fixed tick.
+        for _ in 0..5 {
+            app.update();
+        }
+        (app, entity)
+    }
+
+    fn translation(app: &App, entity: Entity) -> Vec3 {
+        app.world().get::<Transform>(entity).unwrap().translation
+    }
+
+    #[test]
+    fn interpolates_between_fixed_ticks() {
+        let (mut app, entity) = app_with_moving_entity(TransformInterpolation::Interpolate);
+        // A newly spawned entity is not blended.
+        assert_eq!(translation(&app, entity).x, 1.0);
+
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(translation(&app, entity).x, 1.0);
+
+        app.update();
+        assert_eq!(translation(&app, entity).x, 1.25);
+        app.update();
+        assert_eq!(translation(&app, entity).x, 1.5);
+
+        // Fixed timestep systems observe the transform of the last fixed tick.
+        app.update();
+        app.update();
+        assert_eq!(translation(&app, entity).x, 2.0);
+        let ticks = app.world().get::<FixedTickTransforms>(entity).unwrap();
+        assert_eq!(ticks.current().unwrap().translation.x, 3.0);
+    }
+
+    #[test]
+    fn extrapolates_from_fixed_ticks() {
+        let (mut app, entity) = app_with_moving_entity(TransformInterpolation::Extrapolate);
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(translation(&app, entity).x, 2.0);
+
+        app.update();
+        assert_eq!(translation(&app, entity).x, 2.25);
+    }
+
+    #[test]
+    fn snaps_when_changed_outside_fixed_ticks() {
+        let (mut app, entity) = app_with_moving_entity(TransformInterpolation::Interpolate);
+        for _ in 0..5 {
+            app.update();
+        }
+        assert_eq!(translation(&app, entity).x, 1.25);
+
+        app.world_mut()
+            .get_mut::<Transform>(entity)
+            .unwrap()
+            .translation
+            .x = 10.0;
+        app.update();
+        assert_eq!(translation(&app, entity).x, 10.0);
+
+        // The next fixed tick moves on from the teleported transform.
+        for _ in 0..2 {
+            app.update();
+        }
+        let ticks = app.world().get::<FixedTickTransforms>(entity).unwrap();
+        assert_eq!(ticks.current().unwrap().translation.x, 11.0);
+        assert_eq!(ticks.previous().unwrap().translation.x, 10.0);
+    }
+}
@@ -0,0 +1,70 @@
+//! Tool used to generate TypeScript declarations and a JSON Schema document for Bevy's reflected types.
+
+use std::{fs, path::PathBuf};
+
+use bevy_app::App;
+use bevy_ecs::reflect::{AppTypeRegistry, ReflectComponent, ReflectResource};
+use bevy_remote::schemas::{json_schema::export_json_schema, typescript::export_typescript};
+use clap::Parser;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(short, long, default_value = "bindings")]
+    /// Directory the bindings are written to
+    out_dir: PathBuf,
+
+    #[arg(short, long, default_value = "bevy")]
+    /// Name of the generated files, without extension
+    name: String,
+
+    #[arg(short, long)]
+    /// Only export types whose type path starts with one of these prefixes, e.g. `bevy_transform`
+    filter: Vec<String>,
+
+    #[arg(short, long)]
+    /// Export every registered type, rather than only components and resources
+    all: bool,
+}
+
+fn main() {
+    let cli = Args::parse();
+
+    let mut app = App::new();
+    app.add_plugins((bevy_time::TimePlugin, bevy_transform::TransformPlugin));
+
+    let registry = app.world().resource::<AppTypeRegistry>().read();
+    let types = registry
+        .iter()
+        .filter(|registration| {
+            cli.all
+                || registration.data::<ReflectComponent>().is_some()
+                || registration.data::<ReflectResource>().is_some()
+        })
+        .filter(|registration| {
+            let type_path = registration.type_info().type_path();
+            cli.filter.is_empty()
+                || cli
+                    .filter
+                    .iter()
+                    .any(|prefix| type_path.starts_with(prefix))
+        })
+        .collect::<Vec<_>>();
+
+    fs::create_dir_all(&cli.out_dir).expect("failed to create output directory");
+
+    let declarations = export_typescript(&registry, types.iter().copied());
+    let declarations_path = cli.out_dir.join(format!("{}.d.ts", cli.name));
+    fs::write(&declarations_path, declarations).expect("failed to write TypeScript declarations");
+
+    let schema = export_json_schema(&registry, types.iter().copied());
+    let schema = serde_json::to_string_pretty(&schema).expect("failed to serialize JSON Schema");
+    let schema_path = cli.out_dir.join(format!("{}.schema.json", cli.name));
+    fs::write(&schema_path, schema).expect("failed to write JSON Schema");
+
+    println!(
+        "Exported {} types to {} and {}",
+        types.len(),
+        declarations_path.display(),
+        schema_path.display()
+    );
+}
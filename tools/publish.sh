@@ -47,6 +47,7 @@ crates=(
     bevy_ui
     bevy_winit
     bevy_dev_tools
+    bevy_hot_reload
     bevy_internal
     bevy_dylib
 )